@@ -11,10 +11,25 @@
 //! *   **Additional concepts by:** mcelb1200
 //! *   **Rust implementation by:** mcelb1200
 
-use clap::Parser;
+use clap::{Parser, ValueEnum};
 use osc_lib::OscArg;
-use std::time::Duration;
-use x32_lib::{MixerClient, error::Result};
+use std::time::{Duration, Instant};
+use x32_lib::command::channel;
+use x32_lib::common::{db_to_level, level_to_db};
+use x32_lib::error::X32Error;
+use x32_lib::{error::Result, Keepalive, MixerClient};
+
+/// The default NOM attenuation per doubling of open mics, i.e. `10 * log10(2)` dB.
+pub const DEFAULT_NOM_DB_PER_DOUBLING: f32 = 3.01;
+
+/// How the automixer turns channel levels into fader levels.
+#[derive(Clone, Copy, Debug, ValueEnum, PartialEq)]
+pub enum Mode {
+    /// Opens a channel at unity when it is above the sensitivity threshold, else closes it
+    Gate,
+    /// Shares a constant gain budget between channels in proportion to their levels (Dugan-style)
+    GainShare,
+}
 
 /// A utility to provide automixing functionality for the Behringer X32/X-Air consoles.
 #[derive(Parser, Debug)]
@@ -28,6 +43,10 @@ pub struct Args {
     #[arg(long, default_value_t = 5)]
     pub down_delay: u64,
 
+    /// Minimum time in seconds a channel stays open after it activates
+    #[arg(long, default_value_t = 1.0, value_parser = parse_hold)]
+    pub hold: f32,
+
     /// Meter update rate from the console in milliseconds
     #[arg(long, default_value_t = 50)]
     pub meter_rate_ms: u64,
@@ -52,16 +71,36 @@ pub struct Args {
     #[arg(long, default_value_t = 1)]
     pub bus_number: u8,
 
-    /// Enable Number Of Mics (NOM) feature
+    /// Automix mode (gate, gain-share)
+    #[arg(long, value_enum, default_value_t = Mode::Gate)]
+    pub mode: Mode,
+
+    /// Enable Number Of Mics (NOM) feature (gate mode only)
     #[arg(long)]
     pub nom: bool,
+
+    /// Master attenuation in dB each time the number of open mics doubles
+    #[arg(long, default_value_t = DEFAULT_NOM_DB_PER_DOUBLING)]
+    pub nom_db_per_doubling: f32,
+}
+
+/// Parses a hold time in seconds, rejecting negative, non-finite and out-of-range values.
+pub fn parse_hold(s: &str) -> std::result::Result<f32, String> {
+    match s.trim().parse::<f32>() {
+        Ok(hold) if hold >= 0.0 && Duration::try_from_secs_f32(hold).is_ok() => Ok(hold),
+        Ok(_) => Err(format!(
+            "hold must be a finite number of seconds, 0 or more: {}",
+            s
+        )),
+        Err(e) => Err(format!("invalid hold time {}: {}", s, e)),
+    }
 }
 
 /// The main entry point for the automixer application.
 pub async fn run(args: Args) -> Result<()> {
     println!("Connecting to X32 at {}...", args.ip);
 
-    let client = MixerClient::connect(&args.ip, true).await?;
+    let client = MixerClient::connect(&args.ip, false).await?;
 
     run_automix(args, client).await
 }
@@ -76,95 +115,137 @@ pub async fn run(args: Args) -> Result<()> {
 /// * `args` - The command-line arguments containing the automix configuration.
 /// * `client` - The MixerClient connected to the mixer.
 async fn run_automix(args: Args, client: MixerClient) -> Result<()> {
+    if args.nom && args.mode == Mode::GainShare {
+        // Gain sharing already keeps the total level constant as channels open.
+        return Err(X32Error::Custom(
+            "--nom can only be used in gate mode".to_string(),
+        ));
+    }
+
     // We maintain state for Dugan UDP throttling and noise tracking
     let mut last_sent_levels: [f32; 32] = [0.0; 32];
+    let mut channel_status = [ChannelStatus::default(); 32];
+    let hold = Duration::from_secs_f32(args.hold);
+    let down_delay = Duration::from_secs(args.down_delay);
     let mut smoothed_levels: [f32; 32] = [0.0; 32];
 
     let attack_coef = 0.8;
     let release_coef = 0.2;
 
-    let fader_addresses: [String; 32] = core::array::from_fn(|i| {
-        let ch = i + 1;
-        if args.use_bus {
-            format!("/ch/{:02}/mix/{:02}/level", ch, args.bus_number)
-        } else {
-            format!("/ch/{:02}/mix/fader", ch)
-        }
-    });
+    let fader_addresses = fader_addresses(&args);
+
+    let master_address = if args.use_bus {
+        format!("/bus/{:02}/mix/fader", args.bus_number)
+    } else {
+        "/main/st/mix/fader".to_string()
+    };
+
+    // Remember the levels the automixer is about to take over, to put them back on exit.
+    let start_ch = args.start_channel.saturating_sub(1) as usize;
+    let stop_ch = (args.stop_channel as usize).min(32);
+    let mut managed: Vec<String> = fader_addresses[start_ch.min(stop_ch)..stop_ch].to_vec();
+    if args.nom {
+        managed.push(master_address.clone());
+    }
+    let snapshot = FaderSnapshot::capture(&client, &managed).await;
+
+    // NOM attenuation is applied relative to the master level when the automixer starts.
+    let baseline_master = snapshot
+        .level(&master_address)
+        .unwrap_or_else(|| db_to_level(0.0));
+    let mut active_channels = 0;
 
     let mut rx = client.subscribe();
+    let ctrl_c = tokio::signal::ctrl_c();
+    tokio::pin!(ctrl_c);
     let mut meter_interval = tokio::time::interval(Duration::from_secs(9));
-
-    loop {
-        tokio::select! {
-            _ = meter_interval.tick() => {
-                client.send_message(
-                    "/meters",
-                    vec![
-                        OscArg::String("/meters/1".to_string()),
-                        OscArg::Int(0),
-                        OscArg::Int(0),
-                        OscArg::Int(args.meter_rate_ms as i32 / 50),
-                    ]
-                ).await?;
+    let mut keepalive = Keepalive::new();
+
+    // Put the faders back however the loop ends, whether on Ctrl-C or an error.
+    let automix = async {
+        loop {
+            // Meter blobs arrive every few tens of milliseconds, so this runs often enough
+            // to renew /xremote well before it lapses.
+            if let Err(e) = keepalive.tick(&client).await {
+                eprintln!("Failed to send keepalive: {}", e);
             }
-            result = rx.recv() => {
-                let response = match result {
-                    Ok(msg) => msg,
-                    Err(tokio::sync::broadcast::error::RecvError::Lagged(_)) => continue,
-                    Err(tokio::sync::broadcast::error::RecvError::Closed) => break Ok(()),
-                };
-                if response.path == "/meters/1" {
-                    if let Some(OscArg::Blob(data)) = response.args.first() {
-                        let start_ch = args.start_channel.saturating_sub(1) as usize;
-                        let stop_ch = args.stop_channel as usize;
-
-                        // 1. Parse levels and apply fast attack / slow release envelope
-                        let mut current_levels = [0.0; 32];
-                        for ch in start_ch..stop_ch {
-                            let start = ch * 4;
-                            let end = start + 4;
-                            if let Some(bytes) =
-                                data.get(start..end).and_then(|s| s.try_into().ok())
-                            {
-                                let level = f32::from_be_bytes(bytes);
-                                if level > smoothed_levels[ch] {
-                                    smoothed_levels[ch] = smoothed_levels[ch] * (1.0 - attack_coef) + level * attack_coef;
-                                } else {
-                                    smoothed_levels[ch] = smoothed_levels[ch] * (1.0 - release_coef) + level * release_coef;
+
+            tokio::select! {
+                _ = &mut ctrl_c => break Ok(()),
+                _ = meter_interval.tick() => {
+                    client.send_message(
+                        "/meters",
+                        vec![
+                            OscArg::String("/meters/1".to_string()),
+                            OscArg::Int(args.meter_rate_ms as i32 / 50),
+                        ]
+                    ).await?;
+                }
+                result = rx.recv() => {
+                    let response = match result {
+                        Ok(msg) => msg,
+                        Err(tokio::sync::broadcast::error::RecvError::Lagged(_)) => continue,
+                        Err(tokio::sync::broadcast::error::RecvError::Closed) => break Ok(()),
+                    };
+                    if response.path == "/meters/1" {
+                        if let Some(OscArg::Blob(data)) = response.args.first() {
+                            // 1. Parse levels and apply fast attack / slow release envelope
+                            let mut current_levels = [0.0; 32];
+                            for ch in start_ch..stop_ch {
+                                let start = ch * 4;
+                                let end = start + 4;
+                                if let Some(bytes) =
+                                    data.get(start..end).and_then(|s| s.try_into().ok())
+                                {
+                                    let level = f32::from_be_bytes(bytes);
+                                    if level > smoothed_levels[ch] {
+                                        smoothed_levels[ch] = smoothed_levels[ch] * (1.0 - attack_coef) + level * attack_coef;
+                                    } else {
+                                        smoothed_levels[ch] = smoothed_levels[ch] * (1.0 - release_coef) + level * release_coef;
+                                    }
+                                    current_levels[ch] = smoothed_levels[ch];
                                 }
-                                current_levels[ch] = smoothed_levels[ch];
                             }
-                        }
 
-                        // 2. Calculate Dugan gains if NOM is enabled, else simple threshold
-                        let mut full_gains = [0.0; 32];
-                        if args.nom {
-                            let levels_slice = &current_levels[start_ch..stop_ch];
-                            let mut temp_gains = [0.0; 32];
-                            calculate_dugan_gains(levels_slice, args.sensitivity, &mut temp_gains);
-                            for (i, &g) in temp_gains.iter().enumerate().take(levels_slice.len()) {
-                                full_gains[start_ch + i] = g;
+                            // 2. Turn the levels into fader levels for the selected mode
+                            let mut full_gains = [0.0; 32];
+                            calculate_gains(
+                                args.mode,
+                                &current_levels[start_ch..stop_ch],
+                                args.sensitivity,
+                                &mut full_gains[start_ch..stop_ch],
+                            );
+
+                            // In gate mode, keep channels open through the hold time and down delay
+                            if args.mode == Mode::Gate {
+                                let now = Instant::now();
+                                for ch in start_ch..stop_ch {
+                                    let open = channel_status[ch].update(full_gains[ch] > 0.0, now, hold, down_delay);
+                                    full_gains[ch] = if open { 0.75 } else { 0.0 };
+                                }
                             }
-                        } else {
-                            // Legacy simple threshold (0.75 represents unity gain on X32, 1.0 represents +10dB which can cause feedback)
-                            for ch in start_ch..stop_ch {
-                                if current_levels[ch] > args.sensitivity {
-                                    full_gains[ch] = 0.75;
+
+                            // 3. Attenuate the master by the NOM law whenever the number of open mics changes
+                            if args.nom {
+                                let open = full_gains[start_ch..stop_ch].iter().filter(|&&g| g > 0.0).count();
+                                if open != active_channels {
+                                    active_channels = open;
+                                    let level = nom_master_level(baseline_master, open, args.nom_db_per_doubling);
+                                    client.send_message(&master_address, vec![OscArg::Float(level)]).await?;
                                 }
                             }
-                        }
 
-                        // 3. UDP Throttling: Only send updates if fader level changed by > 0.01
-                        for ch in start_ch..stop_ch {
-                            let new_gain = full_gains[ch];
-                            if (new_gain - last_sent_levels[ch]).abs() > 0.01 {
-                                last_sent_levels[ch] = new_gain;
-                                if let Some(addr) = fader_addresses.get(ch) {
-                                    client.send_message(
-                                        addr,
-                                        vec![OscArg::Float(new_gain)],
-                                    ).await?;
+                            // 4. UDP Throttling: Only send updates if fader level changed by > 0.01
+                            for ch in start_ch..stop_ch {
+                                let new_gain = full_gains[ch];
+                                if (new_gain - last_sent_levels[ch]).abs() > 0.01 {
+                                    last_sent_levels[ch] = new_gain;
+                                    if let Some(addr) = fader_addresses.get(ch) {
+                                        client.send_message(
+                                            addr,
+                                            vec![OscArg::Float(new_gain)],
+                                        ).await?;
+                                    }
                                 }
                             }
                         }
@@ -172,42 +253,183 @@ async fn run_automix(args: Args, client: MixerClient) -> Result<()> {
                 }
             }
         }
+    };
+    snapshot.restore_after(&client, automix).await
+}
+
+/// The fader levels found on the console before the automixer took them over.
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct FaderSnapshot {
+    levels: Vec<(String, f32)>,
+}
+
+impl FaderSnapshot {
+    /// Queries and records the current level of each address.
+    ///
+    /// Addresses that cannot be read are left out, and so are not restored.
+    ///
+    /// # Arguments
+    ///
+    /// * `client` - The MixerClient connected to the mixer.
+    /// * `addresses` - The fader or send level addresses to record.
+    pub async fn capture(client: &MixerClient, addresses: &[String]) -> Self {
+        let mut levels = Vec::with_capacity(addresses.len());
+        for address in addresses {
+            match client.query_value(address).await {
+                Ok(OscArg::Float(level)) => levels.push((address.clone(), level)),
+                _ => eprintln!("Could not read {}, it will not be restored", address),
+            }
+        }
+        Self { levels }
+    }
+
+    /// Returns the recorded level of `address`, if it was captured.
+    pub fn level(&self, address: &str) -> Option<f32> {
+        self.levels
+            .iter()
+            .find(|(a, _)| a == address)
+            .map(|&(_, level)| level)
+    }
+
+    /// Runs `task`, then sends every recorded level back to the mixer however it ended.
+    ///
+    /// # Returns
+    ///
+    /// The task's error if it failed, else the result of the restore.
+    pub async fn restore_after(
+        &self,
+        client: &MixerClient,
+        task: impl std::future::Future<Output = Result<()>>,
+    ) -> Result<()> {
+        let result = task.await;
+        println!("Restoring faders...");
+        let restored = self.restore(client).await;
+        result.and(restored)
+    }
+
+    /// Sends every recorded level back to the mixer.
+    pub async fn restore(&self, client: &MixerClient) -> Result<()> {
+        for (address, level) in &self.levels {
+            client
+                .send_message(address, vec![OscArg::Float(*level)])
+                .await?;
+        }
+        Ok(())
     }
 }
 
-/// Converts a linear fader level (0.0 to 1.0) to decibels.
-fn level_to_db(level: f32) -> f32 {
-    if level >= 0.5 {
-        40.0 * level - 30.0
-    } else if level >= 0.25 {
-        80.0 * level - 50.0
-    } else if level >= 0.0625 {
-        160.0 * level - 70.0
-    } else {
-        480.0 * level - 90.0
+/// The open/closed state of a gated channel.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct ChannelStatus {
+    /// When the channel was opened, or `None` while it is closed.
+    pub opened_at: Option<Instant>,
+    /// When the channel was last above the threshold.
+    pub last_active: Option<Instant>,
+}
+
+impl ChannelStatus {
+    /// Updates the state with the channel's latest activity and returns whether it is open.
+    ///
+    /// A channel opens as soon as it is active. Once it drops below the threshold, it
+    /// closes only after it has been open for at least `hold` and inactive for at
+    /// least `down_delay`.
+    ///
+    /// # Arguments
+    ///
+    /// * `active` - Whether the channel is currently above the threshold.
+    /// * `now` - The current time.
+    /// * `hold` - The minimum time the channel stays open after opening.
+    /// * `down_delay` - How long the channel must be inactive before it closes.
+    pub fn update(
+        &mut self,
+        active: bool,
+        now: Instant,
+        hold: Duration,
+        down_delay: Duration,
+    ) -> bool {
+        if active {
+            self.opened_at.get_or_insert(now);
+            self.last_active = Some(now);
+            return true;
+        }
+
+        if let (Some(opened_at), Some(last_active)) = (self.opened_at, self.last_active) {
+            if now.duration_since(opened_at) >= hold
+                && now.duration_since(last_active) >= down_delay
+            {
+                self.opened_at = None;
+            }
+        }
+        self.opened_at.is_some()
     }
 }
 
-/// Converts a decibel value to a linear fader level (0.0 to 1.0).
-fn db_to_level(db: f32) -> f32 {
-    let level = if db > -10.0 {
-        (db + 30.0) / 40.0
-    } else if db > -30.0 {
-        (db + 50.0) / 80.0
-    } else if db > -60.0 {
-        (db + 70.0) / 160.0
+/// Returns the address of each channel's fader: its send to the chosen bus, or its
+/// main fader.
+fn fader_addresses(args: &Args) -> [String; 32] {
+    core::array::from_fn(|i| {
+        let ch = i as u8 + 1;
+        if args.use_bus {
+            channel::send_level(ch, args.bus_number)
+        } else {
+            channel::fader_level(ch)
+        }
+    })
+}
+
+/// Returns the NOM attenuation in dB for `open_mics` open microphones.
+///
+/// Every doubling of open mics adds `db_per_doubling` of attenuation, which with the
+/// default of 3.01 dB is the standard `10 * log10(N)` law. No attenuation is applied
+/// with one or no open mics.
+pub fn nom_attenuation_db(open_mics: usize, db_per_doubling: f32) -> f32 {
+    if open_mics <= 1 {
+        0.0
     } else {
-        (db + 90.0) / 480.0
-    };
-    level.clamp(0.0, 1.0)
+        db_per_doubling * (open_mics as f32).log2()
+    }
+}
+
+/// Returns the master fader level for `open_mics` open microphones.
+///
+/// # Arguments
+///
+/// * `baseline` - The master fader level (0.0 to 1.0) with a single open mic.
+/// * `open_mics` - The number of channels currently open.
+/// * `db_per_doubling` - The attenuation per doubling of open mics.
+fn nom_master_level(baseline: f32, open_mics: usize, db_per_doubling: f32) -> f32 {
+    db_to_level(level_to_db(baseline) - nom_attenuation_db(open_mics, db_per_doubling))
+}
+
+/// Calculates the fader level for each channel from its momentary level.
+///
+/// # Arguments
+///
+/// * `mode` - The automix mode.
+/// * `levels` - The smoothed meter level of each channel.
+/// * `sensitivity` - The level a channel must exceed to be opened.
+/// * `gains_out` - Receives the fader level (0.0 to 1.0) of each channel.
+pub fn calculate_gains(mode: Mode, levels: &[f32], sensitivity: f32, gains_out: &mut [f32]) {
+    match mode {
+        Mode::Gate => {
+            // 0.75 represents unity gain on X32, 1.0 represents +10dB which can cause feedback
+            for (gain, &level) in gains_out.iter_mut().zip(levels) {
+                *gain = if level > sensitivity { 0.75 } else { 0.0 };
+            }
+        }
+        Mode::GainShare => calculate_dugan_gains(levels, sensitivity, gains_out),
+    }
 }
 
 /// Calculates the gain for each channel based on the Dugan algorithm.
 ///
-/// The Dugan algorithm works by calculating the sum of the linear weights
-/// of all channels. The gain for each channel is its weight divided by the total sum.
-/// This ensures that the overall system gain remains constant (NOM attenuation),
-/// preventing feedback and noise buildup.
+/// The Dugan algorithm shares a constant gain budget between the channels: the gain
+/// for each channel is its level divided by the sum of all levels. This ensures that
+/// the overall system gain remains constant (NOM attenuation), preventing feedback
+/// and noise buildup.
+///
+/// The meter levels are linear amplitudes, so they are used as the weights directly. (Earlier
+/// versions passed them through the fader curve to dB and back, which skewed the shares.)
 ///
 /// We also apply priority ducking by only including channels above the noise_floor.
 fn calculate_dugan_gains(levels: &[f32], noise_floor: f32, gains_out: &mut [f32]) {
@@ -217,12 +439,8 @@ fn calculate_dugan_gains(levels: &[f32], noise_floor: f32, gains_out: &mut [f32]
 
     for (i, &level) in levels.iter().enumerate() {
         if level > noise_floor {
-            let db = level_to_db(level);
-            // Convert dB to linear weight. 10^(dB/20) gives voltage gain,
-            // which works well for Dugan sum.
-            let weight = 10.0_f32.powf(db / 20.0);
-            weights[i] = weight;
-            sum_weights += weight;
+            weights[i] = level;
+            sum_weights += level;
         } else {
             weights[i] = 0.0;
         }
@@ -250,11 +468,93 @@ fn calculate_dugan_gains(levels: &[f32], noise_floor: f32, gains_out: &mut [f32]
 mod tests {
     use super::*;
 
+    /// Starts a stand-in mixer that answers every query with a level per channel and
+    /// passes on every message that sets something.
+    async fn mock_mixer() -> (
+        std::net::SocketAddr,
+        tokio::sync::mpsc::UnboundedReceiver<osc_lib::OscMessage>,
+    ) {
+        let server = tokio::net::UdpSocket::bind("127.0.0.1:0").await.unwrap();
+        let addr = server.local_addr().unwrap();
+        let (set_tx, set_rx) = tokio::sync::mpsc::unbounded_channel();
+        tokio::spawn(async move {
+            let mut buf = [0u8; 1024];
+            loop {
+                let (len, peer) = server.recv_from(&mut buf).await.unwrap();
+                let msg = osc_lib::OscMessage::from_bytes(&buf[..len]).unwrap();
+                if msg.args.is_empty() {
+                    let level = if msg.path == "/ch/01/mix/fader" {
+                        0.25
+                    } else {
+                        0.6
+                    };
+                    let reply = osc_lib::OscMessage::new(msg.path, vec![OscArg::Float(level)]);
+                    server
+                        .send_to(&reply.to_bytes().unwrap(), peer)
+                        .await
+                        .unwrap();
+                } else {
+                    set_tx.send(msg).unwrap();
+                }
+            }
+        });
+        (addr, set_rx)
+    }
+
+    #[tokio::test]
+    async fn test_fader_snapshot_restores_captured_levels() {
+        let (addr, mut set_rx) = mock_mixer().await;
+
+        let client = MixerClient::connect(&addr.to_string(), false)
+            .await
+            .unwrap();
+        let addresses = vec![
+            "/ch/01/mix/fader".to_string(),
+            "/ch/02/mix/fader".to_string(),
+        ];
+        let snapshot = FaderSnapshot::capture(&client, &addresses).await;
+        assert_eq!(snapshot.level("/ch/02/mix/fader"), Some(0.6));
+
+        // Move the faders, as the automixer would, then restore them.
+        client
+            .send_message("/ch/01/mix/fader", vec![OscArg::Float(0.0)])
+            .await
+            .unwrap();
+        snapshot.restore(&client).await.unwrap();
+
+        let mut sent = Vec::new();
+        for _ in 0..3 {
+            sent.push(set_rx.recv().await.unwrap());
+        }
+        assert_eq!(sent[1].path, "/ch/01/mix/fader");
+        assert_eq!(sent[1].args, vec![OscArg::Float(0.25)]);
+        assert_eq!(sent[2].path, "/ch/02/mix/fader");
+        assert_eq!(sent[2].args, vec![OscArg::Float(0.6)]);
+    }
+
+    #[tokio::test]
+    async fn test_fader_snapshot_restores_after_error() {
+        let (addr, mut set_rx) = mock_mixer().await;
+        let client = MixerClient::connect(&addr.to_string(), false)
+            .await
+            .unwrap();
+        let snapshot = FaderSnapshot::capture(&client, &["/ch/01/mix/fader".to_string()]).await;
+
+        let failed = async { Err(X32Error::Custom("lost the console".to_string())) };
+        let result = snapshot.restore_after(&client, failed).await;
+        assert!(matches!(result, Err(X32Error::Custom(e)) if e == "lost the console"));
+
+        let sent = set_rx.recv().await.unwrap();
+        assert_eq!(sent.path, "/ch/01/mix/fader");
+        assert_eq!(sent.args, vec![OscArg::Float(0.25)]);
+    }
+
     #[test]
     fn test_fader_address_generation_main_mix() {
         let args = Args {
             ip: "127.0.0.1".to_string(),
             down_delay: 5,
+            hold: 1.0,
             meter_rate_ms: 50,
             sensitivity: 0.005,
             start_channel: 1,
@@ -262,17 +562,11 @@ mod tests {
             use_bus: false,
             bus_number: 1,
             nom: false,
+            mode: Mode::Gate,
+            nom_db_per_doubling: DEFAULT_NOM_DB_PER_DOUBLING,
         };
 
-        let fader_addresses: [String; 32] = core::array::from_fn(|i| {
-            let ch = i + 1;
-            let base = format!("/ch/{:02}", ch);
-            if args.use_bus {
-                format!("{}/mix/{:02}/level", base, args.bus_number)
-            } else {
-                format!("{}/mix/fader", base)
-            }
-        });
+        let fader_addresses = fader_addresses(&args);
 
         assert_eq!(fader_addresses[0], "/ch/01/mix/fader");
         assert_eq!(fader_addresses[31], "/ch/32/mix/fader");
@@ -283,6 +577,7 @@ mod tests {
         let args = Args {
             ip: "127.0.0.1".to_string(),
             down_delay: 5,
+            hold: 1.0,
             meter_rate_ms: 50,
             sensitivity: 0.005,
             start_channel: 1,
@@ -290,17 +585,11 @@ mod tests {
             use_bus: true,
             bus_number: 5,
             nom: false,
+            mode: Mode::Gate,
+            nom_db_per_doubling: DEFAULT_NOM_DB_PER_DOUBLING,
         };
 
-        let fader_addresses: [String; 32] = core::array::from_fn(|i| {
-            let ch = i + 1;
-            let base = format!("/ch/{:02}", ch);
-            if args.use_bus {
-                format!("{}/mix/{:02}/level", base, args.bus_number)
-            } else {
-                format!("{}/mix/fader", base)
-            }
-        });
+        let fader_addresses = fader_addresses(&args);
 
         assert_eq!(fader_addresses[0], "/ch/01/mix/05/level");
         assert_eq!(fader_addresses[31], "/ch/32/mix/05/level");
@@ -341,6 +630,18 @@ mod tests {
         assert!((db_to_level(-90.0) - 0.0).abs() < 0.01);
     }
 
+    #[test]
+    fn test_nom_attenuation_one_to_four_mics() {
+        assert_eq!(nom_attenuation_db(0, DEFAULT_NOM_DB_PER_DOUBLING), 0.0);
+        assert_eq!(nom_attenuation_db(1, DEFAULT_NOM_DB_PER_DOUBLING), 0.0);
+        assert!((nom_attenuation_db(4, DEFAULT_NOM_DB_PER_DOUBLING) - 6.02).abs() < 0.01);
+
+        // Relative to a master at 0 dB, four open mics bring it down by ~6 dB.
+        let one = nom_master_level(0.75, 1, DEFAULT_NOM_DB_PER_DOUBLING);
+        let four = nom_master_level(0.75, 4, DEFAULT_NOM_DB_PER_DOUBLING);
+        assert!((level_to_db(one) - level_to_db(four) - 6.02).abs() < 0.05);
+    }
+
     #[test]
     fn test_dugan_gain_calculation() {
         // Two equal channels should get 0.5 (-6dB) gain each.
@@ -366,4 +667,71 @@ mod tests {
         assert_eq!(gains[0], 0.0);
         assert_eq!(gains[1], 0.0);
     }
+
+    #[test]
+    fn test_gain_share_two_channels() {
+        // Shares of 3/4 and 1/4: -2.50 dB and -12.04 dB.
+        let mut gains = [0.0; 2];
+        calculate_gains(Mode::GainShare, &[0.3, 0.1], 0.005, &mut gains);
+        assert!((level_to_db(gains[0]) - -2.50).abs() < 0.01);
+        assert!((level_to_db(gains[1]) - -12.04).abs() < 0.01);
+    }
+
+    #[test]
+    fn test_gain_share_three_channels() {
+        // Shares of 1/2, 1/4 and 1/4; the quiet channel is left out of the budget.
+        let mut gains = [0.0; 4];
+        calculate_gains(Mode::GainShare, &[0.2, 0.1, 0.1, 0.001], 0.005, &mut gains);
+        assert!((level_to_db(gains[0]) - -6.02).abs() < 0.01);
+        assert!((level_to_db(gains[1]) - -12.04).abs() < 0.01);
+        assert!((level_to_db(gains[2]) - -12.04).abs() < 0.01);
+        assert_eq!(gains[3], 0.0);
+    }
+
+    #[test]
+    fn test_parse_hold_rejects_invalid_times() {
+        assert_eq!(parse_hold("1.5"), Ok(1.5));
+        assert_eq!(parse_hold("0"), Ok(0.0));
+        assert!(parse_hold("-1").is_err());
+        assert!(parse_hold("inf").is_err());
+        assert!(parse_hold("NaN").is_err());
+        assert!(parse_hold("1e30").is_err());
+        assert!(parse_hold("long").is_err());
+    }
+
+    #[test]
+    fn test_channel_status_hold_and_down_delay() {
+        let hold = Duration::from_secs(2);
+        let down_delay = Duration::from_millis(500);
+        let t0 = Instant::now();
+        let at = |ms| t0 + Duration::from_millis(ms);
+        let mut status = ChannelStatus::default();
+
+        assert!(!status.update(false, at(0), hold, down_delay));
+        assert!(status.update(true, at(100), hold, down_delay));
+        assert_eq!(status.opened_at, Some(at(100)));
+
+        // Past the down delay but within the hold time: still open.
+        assert!(status.update(false, at(1000), hold, down_delay));
+        // Activity does not restart the hold time.
+        assert!(status.update(true, at(1800), hold, down_delay));
+        assert_eq!(status.opened_at, Some(at(100)));
+        // Hold time over, but the down delay since the last activity is not.
+        assert!(status.update(false, at(2200), hold, down_delay));
+        // Both over: closed.
+        assert!(!status.update(false, at(2300), hold, down_delay));
+        assert_eq!(status.opened_at, None);
+
+        // Reopening starts a new hold time.
+        assert!(status.update(true, at(3000), hold, down_delay));
+        assert!(status.update(false, at(4900), hold, down_delay));
+        assert!(!status.update(false, at(5000), hold, down_delay));
+    }
+
+    #[test]
+    fn test_gate_mode_opens_channels_at_unity() {
+        let mut gains = [0.0; 3];
+        calculate_gains(Mode::Gate, &[0.3, 0.1, 0.001], 0.005, &mut gains);
+        assert_eq!(gains, [0.75, 0.75, 0.0]);
+    }
 }
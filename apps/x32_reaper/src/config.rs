@@ -3,6 +3,9 @@ use std::fs::File;
 use std::io::{BufRead, Read};
 use std::path::Path;
 
+/// The OSC port of X32 and M32 consoles.
+pub const DEFAULT_X32_PORT: u16 = 10023;
+
 #[derive(Debug, Clone)]
 pub struct Config {
     pub verbose: bool,
@@ -11,6 +14,10 @@ pub struct Config {
     pub xx_send_mask: i32,
     pub xr_send_mask: i32,
     pub x32_ip: String,
+    /// The console's OSC port: 10023 for the X32/M32, 10024 for X-Air.
+    pub x32_port: u16,
+    /// The local port the X32 socket is bound to, or 0 for a dynamic port.
+    pub x32_local_port: u16,
     pub reaper_ip: String,
     pub reaper_send_port: u16,
     pub reaper_recv_port: u16,
@@ -21,6 +28,10 @@ pub struct Config {
     #[allow(dead_code)]
     pub eq_ctrl_on: bool,
     pub master_on: bool,
+    /// Forward the X32 input meters to Reaper's track meters. Set from `--meters`.
+    pub meters_on: bool,
+    /// Arm the selected channel's track for recording with `recarm_btn`.
+    pub recarm_on: bool,
     pub trk_min: i32,
     pub trk_max: i32,
     pub aux_min: i32,
@@ -38,9 +49,20 @@ pub struct Config {
     pub marker_btn: i32,
     pub ch_bank_offset: i32,
     pub bank_size: i32,
+    /// The bank C user button (5-12) that toggles record-arm.
+    pub recarm_btn: i32,
 }
 
 impl Config {
+    /// Returns the console's `ip:port` address.
+    pub fn x32_addr(&self) -> String {
+        if self.x32_ip.contains(':') {
+            format!("[{}]:{}", self.x32_ip, self.x32_port)
+        } else {
+            format!("{}:{}", self.x32_ip, self.x32_port)
+        }
+    }
+
     pub fn load<P: AsRef<Path>>(path: P) -> Result<Self> {
         let file = File::open(path).context("Failed to open config file")?;
 
@@ -94,8 +116,21 @@ impl Config {
             .context("Missing xr_send_mask in line 1")?
             .parse::<i32>()?;
 
-        // Line 2: X32 IP
-        let x32_ip = next_line()?;
+        // Line 2: X32 IP, optionally followed by the console port and the local port
+        let line2 = next_line()?;
+        let mut parts2 = line2.split_whitespace();
+        let x32_ip = parts2
+            .next()
+            .context("Missing X32 IP in line 2")?
+            .to_string();
+        let x32_port = match parts2.next() {
+            Some(p) => p.parse::<u16>()?,
+            None => DEFAULT_X32_PORT,
+        };
+        let x32_local_port = match parts2.next() {
+            Some(p) => p.parse::<u16>()?,
+            None => 0,
+        };
 
         // Line 3: Reaper Host IP
         let reaper_ip = next_line()?;
@@ -138,6 +173,10 @@ impl Config {
             .context("Missing master_on in line 6")?
             .parse::<i32>()?
             != 0;
+        let recarm_on = match parts6.next() {
+            Some(p) => p.parse::<i32>()? != 0,
+            None => false,
+        };
 
         // Line 7: Ranges
         let line7 = next_line()?;
@@ -226,6 +265,10 @@ impl Config {
             .next()
             .context("Missing bank_size")?
             .parse::<i32>()?;
+        let recarm_btn = match parts_last.next() {
+            Some(p) => p.parse::<i32>()?,
+            None => 0,
+        };
 
         // If transport_on is OFF, check if there are extra bank buttons in the file?
         if ch_bank_on && !transport_on {
@@ -246,6 +289,8 @@ impl Config {
             xx_send_mask,
             xr_send_mask,
             x32_ip,
+            x32_port,
+            x32_local_port,
             reaper_ip,
             reaper_send_port,
             reaper_recv_port,
@@ -255,6 +300,8 @@ impl Config {
             bank_c_color,
             eq_ctrl_on,
             master_on,
+            meters_on: false,
+            recarm_on,
             trk_min,
             trk_max,
             aux_min,
@@ -272,6 +319,7 @@ impl Config {
             marker_btn,
             ch_bank_offset,
             bank_size,
+            recarm_btn,
         })
     }
 }
@@ -349,5 +397,39 @@ mod tests {
         let config = result.unwrap();
         assert_eq!(config.verbose, true);
         assert_eq!(config.x32_ip, "192.168.1.100");
+        assert_eq!(config.x32_port, DEFAULT_X32_PORT);
+        assert_eq!(config.x32_local_port, 0);
+        assert_eq!(config.x32_addr(), "192.168.1.100:10023");
+        assert!(!config.recarm_on);
+    }
+
+    #[test]
+    fn test_config_load_custom_x32_port() {
+        let mut file = NamedTempFile::new().unwrap();
+        let config = "
+800 600 0 10 20 1 2
+192.168.1.100 10024 10050
+127.0.0.1
+8000
+8001
+1 1 1 5 1 1
+1 32 1 16 1 4 1 16 1 8 0
+1 1
+1 1
+1 1
+1 1
+1 1
+1 1
+1 1
+1 1
+1 2 3 4 5
+";
+        write!(file, "{}", config.trim()).unwrap();
+
+        let config = Config::load(file.path()).unwrap();
+        assert_eq!(config.x32_ip, "192.168.1.100");
+        assert_eq!(config.x32_port, 10024);
+        assert_eq!(config.x32_local_port, 10050);
+        assert_eq!(config.x32_addr(), "192.168.1.100:10024");
     }
 }
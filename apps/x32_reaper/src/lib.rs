@@ -13,12 +13,15 @@
 
 use anyhow::{Context, Result};
 use clap::Parser;
-use osc_lib::{OscArg, OscMessage};
+use osc_lib::{OscArg, OscBundle, OscMessage, OscPacket};
 use std::fmt::Write;
 use std::net::SocketAddr;
 use std::sync::Arc;
 use tokio::net::UdpSocket;
 use tokio::sync::Mutex;
+use x32_lib::common::quantize_fader;
+use x32_lib::keepalive::{keepalive, KEEPALIVE_INTERVAL};
+use x32_lib::user_ctrl::{Bank, UserCtrl};
 use x32_lib::MixerClient;
 
 pub mod config;
@@ -34,6 +37,10 @@ pub struct Args {
     /// Path to config file (default: .X32Reaper.ini)
     #[arg(long, default_value = ".X32Reaper.ini")]
     pub config: String,
+
+    /// Forward the X32 input channel meters to Reaper's track meters
+    #[arg(long)]
+    pub meters: bool,
 }
 
 // Flags
@@ -54,6 +61,7 @@ const MASTERVOLUME: i32 = 0x0200;
 const MASTERSELECT: i32 = 0x0400;
 const MASTERSOLO: i32 = 0x0800;
 const MASTERMUTE: i32 = 0x1000;
+const TRACKRECARM: i32 = 0x2000;
 
 const X32PAN: i32 = 0x0001;
 const X32FADER: i32 = 0x0002;
@@ -66,20 +74,22 @@ const X32SOLO: i32 = 0x0040;
 const X32FX: i32 = 0x0080;
 const X32MPAN: i32 = 0x0100;
 const X32MFADER: i32 = 0x0200;
+const X32RECARM: i32 = 0x0400;
 
 /// The main entry point for the application.
 pub async fn run(args: Args) -> Result<()> {
     println!("X32Reaper - Rust Rewrite");
 
-    let config = match Config::load(&args.config) {
+    let mut config = match Config::load(&args.config) {
         Ok(c) => c,
         Err(e) => {
             eprintln!("Failed to load config file '{}': {}", args.config, e);
             std::process::exit(1);
         }
     };
+    config.meters_on = args.meters;
 
-    println!("X32 at IP {}", config.x32_ip);
+    println!("X32 at {}", config.x32_addr());
     println!(
         "REAPER at IP {}\nreceives on port {}\nsends to port {}",
         config.reaper_ip, config.reaper_recv_port, config.reaper_send_port
@@ -96,7 +106,9 @@ pub async fn run(args: Args) -> Result<()> {
         .parse()
         .context("Invalid Reaper IP")?;
 
-    let x32_client = Arc::new(MixerClient::connect(&config.x32_ip, true).await?);
+    let x32_client = Arc::new(
+        MixerClient::connect_from(&config.x32_addr(), config.x32_local_port, false).await?,
+    );
     let mut x32_rx = x32_client.subscribe();
     let mut buf_reaper = [0u8; 4096];
 
@@ -110,8 +122,21 @@ pub async fn run(args: Args) -> Result<()> {
     )
     .await?;
 
+    let mut keepalive_timer = tokio::time::interval(KEEPALIVE_INTERVAL);
+
     loop {
         tokio::select! {
+            _ = keepalive_timer.tick() => {
+                if let Err(e) = keepalive(&x32_client).await {
+                    eprintln!("Failed to send keepalive: {}", e);
+                }
+                // The meter subscription lapses after 10 seconds, like /xremote.
+                if config.meters_on {
+                    if let Err(e) = x32_client.send_message("/meters", vec![OscArg::String("/meters/1".to_string())]).await {
+                        eprintln!("Failed to renew meters: {}", e);
+                    }
+                }
+            }
             res = x32_rx.recv() => {
                 if let Ok(msg) = res {
                     let bytes = msg.to_bytes().unwrap_or_default();
@@ -147,86 +172,37 @@ async fn init_user_ctrl(
     state: &mut AppState,
     reaper_info: Option<(&UdpSocket, SocketAddr)>,
 ) -> Result<()> {
-    let mp = ["MP13000", "MP14000", "MP15000", "MP16000"];
-    let mn = [
-        "MN16000", "MN16001", "MN16002", "MN16003", "MN16004", "MN16005", "MN16006", "MN16007",
-    ];
-
+    let mut ctrl = UserCtrl::new(Bank::C);
     if config.transport_on {
-        for i in 1..=4 {
-            let msg = OscMessage {
-                path: format!("/config/userctrl/C/enc/{}", i),
-                args: vec![OscArg::String(mp[i - 1].to_string())],
-            };
-            let _ = x_client.send_message(&msg.path, msg.args.clone()).await;
-        }
-        for i in 5..=12 {
-            let msg = OscMessage {
-                path: format!("/config/userctrl/C/btn/{}", i),
-                args: vec![OscArg::String(mn[i - 5].to_string())],
-            };
-            let _ = x_client.send_message(&msg.path, msg.args.clone()).await;
-        }
-        for i in 33..=36 {
-            let msg = OscMessage {
-                path: format!("/-stat/userpar/{:02}/value", i),
-                args: vec![OscArg::Int(64)],
-            };
-            let _ = x_client.send_message(&msg.path, msg.args.clone()).await;
-        }
-        for i in 17..=24 {
-            let msg = OscMessage {
-                path: format!("/-stat/userpar/{:02}/value", i),
-                args: vec![OscArg::Int(0)],
-            };
-            let _ = x_client.send_message(&msg.path, msg.args.clone()).await;
-        }
+        ctrl = ctrl.transport();
     } else {
+        let mut buttons = Vec::new();
         if config.marker_btn_on {
-            let btn_idx = config.marker_btn;
-            if (5..=12).contains(&btn_idx) {
-                let msg = OscMessage {
-                    path: format!("/config/userctrl/C/btn/{}", btn_idx),
-                    args: vec![OscArg::String(mn[btn_idx as usize - 5].to_string())],
-                };
-                let _ = x_client.send_message(&msg.path, msg.args.clone()).await;
-                let msg2 = OscMessage {
-                    path: format!("/-stat/userpar/{:02}/value", 12 + btn_idx),
-                    args: vec![OscArg::Int(0)],
-                };
-                let _ = x_client.send_message(&msg2.path, msg2.args.clone()).await;
-            }
+            buttons.push(config.marker_btn);
+        }
+        if config.recarm_on {
+            buttons.push(config.recarm_btn);
         }
         if config.ch_bank_on {
-            for &btn_idx in &[config.bank_up, config.bank_dn] {
-                if (5..=12).contains(&btn_idx) {
-                    let msg = OscMessage {
-                        path: format!("/config/userctrl/C/btn/{}", btn_idx),
-                        args: vec![OscArg::String(mn[btn_idx as usize - 5].to_string())],
-                    };
-                    let _ = x_client.send_message(&msg.path, msg.args.clone()).await;
-                    let msg2 = OscMessage {
-                        path: format!("/-stat/userpar/{:02}/value", 12 + btn_idx),
-                        args: vec![OscArg::Int(0)],
-                    };
-                    let _ = x_client.send_message(&msg2.path, msg2.args.clone()).await;
-                }
-            }
+            buttons.extend([config.bank_up, config.bank_dn]);
+        }
+        for btn in buttons.into_iter().filter(|b| (5..=12).contains(b)) {
+            ctrl = ctrl.button(btn as u8);
         }
     }
 
-    if config.transport_on || config.marker_btn_on || config.ch_bank_on {
-        let msg = OscMessage {
-            path: "/config/userctrl/C/color".to_string(),
-            args: vec![OscArg::Int(config.bank_c_color)],
-        };
-        let _ = x_client.send_message(&msg.path, msg.args.clone()).await;
-
-        let msg2 = OscMessage {
-            path: "/-stat/userbank".to_string(),
-            args: vec![OscArg::Int(2)],
-        };
-        let _ = x_client.send_message(&msg2.path, msg2.args.clone()).await;
+    let bank_used =
+        config.transport_on || config.marker_btn_on || config.ch_bank_on || config.recarm_on;
+    if bank_used {
+        ctrl = ctrl.color(config.bank_c_color);
+    }
+    for (path, args) in ctrl.messages()? {
+        let _ = x_client.send_message(&path, args).await;
+    }
+    if bank_used {
+        let _ = x_client
+            .send_message("/-stat/userbank", vec![OscArg::Int(Bank::C.index())])
+            .await;
     }
 
     if config.ch_bank_on {
@@ -271,8 +247,26 @@ async fn update_bk_ch(
         send_to_r(r_sock, r_addr, &msg_sel).await?;
     }
 
+    let messages = bank_messages(config, state);
+    if config.delay_bank > 0 {
+        // A bank delay asks for the messages to be paced, so send them one at a time.
+        for msg in messages {
+            let _ = x_client.send_message(&msg.path, msg.args).await;
+            tokio::time::sleep(std::time::Duration::from_millis(config.delay_bank)).await;
+        }
+    } else {
+        for bundle in bank_bundles(messages)? {
+            let _ = x_client.send_bundle(bundle).await;
+        }
+    }
+    Ok(())
+}
+
+/// Builds the messages that load the current bank's tracks onto the X32 channel strips.
+fn bank_messages(config: &Config, state: &AppState) -> Vec<OscMessage> {
     // ⚡ Bolt: Pre-allocate a String and reuse it via `write!` to avoid multiple heap allocations per channel
     let mut path_buf = String::with_capacity(64);
+    let mut messages = Vec::with_capacity(config.bank_size.max(0) as usize * 22);
 
     for i in 1..=config.bank_size {
         let src_idx = (i - 1 + state.ch_bank_offset * config.bank_size) as usize;
@@ -287,7 +281,7 @@ async fn update_bk_ch(
             path: path_buf.clone(),
             args: vec![OscArg::Float(track.fader)],
         };
-        let _ = x_client.send_message(&msg.path, msg.args.clone()).await;
+        messages.push(msg);
 
         path_buf.clear();
         write!(&mut path_buf, "/ch/{:02}/mix/pan", i).expect("Failed to format OSC path");
@@ -295,7 +289,7 @@ async fn update_bk_ch(
             path: path_buf.clone(),
             args: vec![OscArg::Float(track.pan)],
         };
-        let _ = x_client.send_message(&msg.path, msg.args.clone()).await;
+        messages.push(msg);
 
         path_buf.clear();
         write!(&mut path_buf, "/ch/{:02}/mix/on", i).expect("Failed to format OSC path");
@@ -303,7 +297,7 @@ async fn update_bk_ch(
             path: path_buf.clone(),
             args: vec![OscArg::Int(if track.mute > 0.5 { 0 } else { 1 })],
         };
-        let _ = x_client.send_message(&msg.path, msg.args.clone()).await;
+        messages.push(msg);
 
         for j in 1..=16 {
             path_buf.clear();
@@ -313,7 +307,7 @@ async fn update_bk_ch(
                 path: path_buf.clone(),
                 args: vec![OscArg::Float(track.mixbus[j as usize - 1])],
             };
-            let _ = x_client.send_message(&msg.path, msg.args.clone()).await;
+            messages.push(msg);
         }
 
         path_buf.clear();
@@ -322,7 +316,7 @@ async fn update_bk_ch(
             path: path_buf.clone(),
             args: vec![OscArg::String(track.scribble.clone())],
         };
-        let _ = x_client.send_message(&msg.path, msg.args.clone()).await;
+        messages.push(msg);
 
         path_buf.clear();
         write!(&mut path_buf, "/ch/{:02}/config/color", i).expect("Failed to format OSC path");
@@ -330,7 +324,7 @@ async fn update_bk_ch(
             path: path_buf.clone(),
             args: vec![OscArg::Int(track.color)],
         };
-        let _ = x_client.send_message(&msg.path, msg.args.clone()).await;
+        messages.push(msg);
 
         path_buf.clear();
         write!(&mut path_buf, "/ch/{:02}/config/icon", i).expect("Failed to format OSC path");
@@ -338,9 +332,44 @@ async fn update_bk_ch(
             path: path_buf.clone(),
             args: vec![OscArg::Int(track.icon)],
         };
-        let _ = x_client.send_message(&msg.path, msg.args.clone()).await;
+        messages.push(msg);
     }
-    Ok(())
+    messages
+}
+
+/// The largest encoded bundle sent to the X32, small enough to fit in one Ethernet
+/// frame without IP fragmentation.
+const MAX_BUNDLE_SIZE: usize = 1400;
+
+/// The encoded size of a bundle's `#bundle` tag and time tag.
+const BUNDLE_HEADER_SIZE: usize = 16;
+
+/// Groups the messages for a bank update into as few bundles as possible, none of
+/// them over [`MAX_BUNDLE_SIZE`] bytes once encoded.
+///
+/// A whole bank of 32 strips is far larger than one datagram should be, and the tail
+/// of an oversized datagram is silently dropped by the receiver.
+fn bank_bundles(messages: Vec<OscMessage>) -> Result<Vec<OscBundle>> {
+    let mut bundles = Vec::new();
+    let mut content: Vec<OscPacket> = Vec::new();
+    let mut size = BUNDLE_HEADER_SIZE;
+    for msg in messages {
+        // Each element is its encoded message prefixed with a 4-byte length.
+        let len = 4 + msg
+            .to_bytes()
+            .map_err(|e| anyhow::anyhow!("OSC error: {:?}", e))?
+            .len();
+        if !content.is_empty() && size + len > MAX_BUNDLE_SIZE {
+            bundles.push(OscBundle::new(std::mem::take(&mut content)));
+            size = BUNDLE_HEADER_SIZE;
+        }
+        content.push(msg.into());
+        size += len;
+    }
+    if !content.is_empty() {
+        bundles.push(OscBundle::new(content));
+    }
+    Ok(bundles)
 }
 
 // Expanded process_x32_message with full functionality
@@ -363,6 +392,31 @@ async fn process_x32_message(
 
     let mut path_buf = String::with_capacity(64);
 
+    if msg.path == "/meters/1" {
+        if let (true, Some(OscArg::Blob(blob))) = (config.meters_on, msg.args.first()) {
+            let content = meter_tracks(blob, config, state_guard.ch_bank_offset)
+                .into_iter()
+                .map(|(track, level)| {
+                    path_buf.clear();
+                    write!(&mut path_buf, "/track/{}/vu", track)
+                        .expect("Failed to format OSC path");
+                    OscMessage::builder(path_buf.as_str())
+                        .float(level)
+                        .build()
+                        .into()
+                })
+                .collect::<Vec<_>>();
+            if !content.is_empty() {
+                // One bundle per meter update rather than a packet per track.
+                let bytes = OscBundle::new(content)
+                    .to_bytes()
+                    .map_err(|e| anyhow::anyhow!("OSC error: {:?}", e))?;
+                r_sock.send_to(&bytes, r_addr).await?;
+            }
+        }
+        return Ok(());
+    }
+
     // Logic for /ch/, /auxin/, /fxrtn/, /bus/, /dca/, /main/st/mix/
 
     let mut cnum = -1;
@@ -412,23 +466,23 @@ async fn process_x32_message(
     if cnum1 != -1 {
         if msg.path.contains("/mix/pan") {
             xr_mask = X32PAN;
-            if let Some(OscArg::Float(f)) = msg.args.first() {
+            if let Some(f) = msg.arg_float(0) {
                 if config.ch_bank_on && msg.path.starts_with("/ch/") {
                     if let Some(track) = state_guard.bank_tracks.get_mut((cnum - 1) as usize) {
-                        track.pan = *f;
+                        track.pan = f;
                     }
                 }
                 rb_msg = Some(OscMessage {
                     path: format!("/track/{}/pan", cnum1),
-                    args: vec![OscArg::Float(*f)],
+                    args: vec![OscArg::Float(f)],
                 });
             }
         } else if msg.path.contains("/mix/fader") {
             xr_mask = X32FADER;
-            if let Some(OscArg::Float(f)) = msg.args.first() {
+            if let Some(f) = msg.arg_float(0) {
                 if config.ch_bank_on && msg.path.starts_with("/ch/") {
                     if let Some(track) = state_guard.bank_tracks.get_mut((cnum - 1) as usize) {
-                        track.fader = *f;
+                        track.fader = f;
                     }
                 }
                 // Check DCA?
@@ -445,7 +499,7 @@ async fn process_x32_message(
                                         .expect("Failed to format OSC path");
                                     let m = OscMessage {
                                         path: path_buf.clone(),
-                                        args: vec![OscArg::Float(*f)],
+                                        args: vec![OscArg::Float(f)],
                                     };
                                     send_to_r(r_sock, r_addr, &m).await?;
                                 }
@@ -455,13 +509,13 @@ async fn process_x32_message(
                 }
                 rb_msg = Some(OscMessage {
                     path: format!("/track/{}/volume", cnum1),
-                    args: vec![OscArg::Float(*f)],
+                    args: vec![OscArg::Float(f)],
                 });
             }
         } else if msg.path.contains("/mix/on") {
             xr_mask = X32MUTE;
-            if let Some(OscArg::Int(i)) = msg.args.first() {
-                let val = if *i == 1 { 0.0 } else { 1.0 };
+            if let Some(i) = msg.arg_int(0) {
+                let val = if i == 1 { 0.0 } else { 1.0 };
                 if config.ch_bank_on && msg.path.starts_with("/ch/") {
                     if let Some(track) = state_guard.bank_tracks.get_mut((cnum - 1) as usize) {
                         track.mute = val;
@@ -495,15 +549,15 @@ async fn process_x32_message(
             }
         } else if msg.path.contains("/config/name") {
             xr_mask = X32NAME;
-            if let Some(OscArg::String(s)) = msg.args.first() {
+            if let Some(s) = msg.arg_str(0) {
                 if config.ch_bank_on && msg.path.starts_with("/ch/") {
                     if let Some(track) = state_guard.bank_tracks.get_mut((cnum - 1) as usize) {
-                        track.scribble = s.clone();
+                        track.scribble = s.to_string();
                     }
                 }
                 rb_msg = Some(OscMessage {
                     path: format!("/track/{}/name", cnum1),
-                    args: vec![OscArg::String(s.clone())],
+                    args: vec![OscArg::String(s.to_string())],
                 });
             }
         }
@@ -514,19 +568,19 @@ async fn process_x32_message(
             if let Some(part) = extract_nth_segment(&msg.path, 4) {
                 if let Ok(bus) = part.parse::<i32>() {
                     let reaper_bus = bus + config.track_send_offset;
-                    if let Some(OscArg::Float(f)) = msg.args.first() {
+                    if let Some(f) = msg.arg_float(0) {
                         if config.ch_bank_on && msg.path.starts_with("/ch/") {
                             if let Some(track) =
                                 state_guard.bank_tracks.get_mut((cnum - 1) as usize)
                             {
                                 if (1..=16).contains(&bus) {
-                                    track.mixbus[bus as usize - 1] = *f;
+                                    track.mixbus[bus as usize - 1] = f;
                                 }
                             }
                         }
                         rb_msg = Some(OscMessage {
                             path: format!("/track/{}/send/{}/volume", cnum1, reaper_bus),
-                            args: vec![OscArg::Float(*f)],
+                            args: vec![OscArg::Float(f)],
                         });
                     }
                 }
@@ -538,18 +592,18 @@ async fn process_x32_message(
     if msg.path.starts_with("/main/st/mix/") && config.master_on {
         if msg.path.contains("fader") {
             xr_mask = X32MFADER;
-            if let Some(OscArg::Float(f)) = msg.args.first() {
+            if let Some(f) = msg.arg_float(0) {
                 rb_msg = Some(OscMessage {
                     path: "/master/volume".to_string(),
-                    args: vec![OscArg::Float(*f)],
+                    args: vec![OscArg::Float(f)],
                 });
             }
         } else if msg.path.contains("pan") {
             xr_mask = X32MPAN;
-            if let Some(OscArg::Float(f)) = msg.args.first() {
+            if let Some(f) = msg.arg_float(0) {
                 rb_msg = Some(OscMessage {
                     path: "/master/pan".to_string(),
-                    args: vec![OscArg::Float(*f)],
+                    args: vec![OscArg::Float(f)],
                 });
             }
         } else if msg.path.contains("on") {
@@ -572,8 +626,8 @@ async fn process_x32_message(
                     .send_message("/-stat/selidx", vec![OscArg::Int(70)])
                     .await;
             }
-            if let Some(OscArg::Int(i)) = msg.args.first() {
-                let action = if *i == 1 {
+            if let Some(i) = msg.arg_int(0) {
+                let action = if i == 1 {
                     "/action/40731"
                 } else {
                     "/action/40730"
@@ -600,9 +654,12 @@ async fn process_x32_message(
                 )
                 .await?;
             }
-            if let Some(OscArg::Int(i)) = msg.args.first() {
-                let raw_sel = *i;
+            if let Some(i) = msg.arg_int(0) {
+                let raw_sel = i;
                 state_guard.x_selected = raw_sel + 1;
+                if let Some(led) = recarm_led(config, &state_guard) {
+                    let _ = x_client.send_message(&led.path, led.args).await;
+                }
                 let mut r_sel = -2;
 
                 if raw_sel < config.bank_size && config.trk_max > 0 {
@@ -635,8 +692,8 @@ async fn process_x32_message(
             xr_mask = X32SOLO;
             if let Some(part) = extract_nth_segment(&msg.path, 3) {
                 if let Ok(sw_idx) = part.parse::<i32>() {
-                    if let Some(OscArg::Int(val)) = msg.args.first() {
-                        let fval = if *val == 1 { 1.0 } else { 0.0 };
+                    if let Some(val) = msg.arg_int(0) {
+                        let fval = if val == 1 { 1.0 } else { 0.0 };
                         // Map back to reaper track
                         // This is reverse mapping from X32 solo sw index to Reaper track
                         // Logic similar to selidx mapping but reverse
@@ -681,13 +738,13 @@ async fn process_x32_message(
         } else if msg.path.contains("userpar") {
             if let Some(part) = extract_nth_segment(&msg.path, 3) {
                 if let Ok(par_idx) = part.parse::<i32>() {
-                    if let Some(OscArg::Int(val)) = msg.args.first() {
+                    if let Some(val) = msg.arg_int(0) {
                         let sockets = Sockets {
                             x_client,
                             r_sock,
                             r_addr,
                         };
-                        handle_user_par(par_idx, *val, config, &mut state_guard, sockets).await?;
+                        handle_user_par(par_idx, val, config, &mut state_guard, sockets).await?;
                     }
                 }
             }
@@ -873,6 +930,31 @@ async fn handle_user_par(
     } else if val == 0 {
         // Button up
         let btn_idx = idx - 12;
+        if config.recarm_on && btn_idx == config.recarm_btn {
+            if let Some(track) = recarm_track(state.x_selected, config, state.ch_bank_offset) {
+                let armed = !state.armed_tracks.contains(&track);
+                if armed {
+                    state.armed_tracks.insert(track);
+                } else {
+                    state.armed_tracks.remove(&track);
+                }
+                if (X32RECARM & config.xr_send_mask) != 0 {
+                    send_to_r(
+                        r_sock,
+                        r_addr,
+                        &OscMessage {
+                            path: format!("/track/{}/recarm", track),
+                            args: vec![OscArg::Float(if armed { 1.0 } else { 0.0 })],
+                        },
+                    )
+                    .await?;
+                }
+                if let Some(led) = recarm_led(config, state) {
+                    let _ = x_client.send_message(&led.path, led.args).await;
+                }
+            }
+        }
+
         if btn_idx == config.marker_btn {
             send_to_r(
                 r_sock,
@@ -960,8 +1042,8 @@ async fn process_single_reaper_message(
             if let Ok(tnum) = part.parse::<i32>() {
                 if msg.path.contains("/volume") {
                     xx_mask = TRACKFADER;
-                    if let Some(OscArg::Float(f)) = msg.args.first() {
-                        let x32_val = (f * 1023.5) as i32 as f32 / 1023.0;
+                    if let Some(f) = msg.arg_float(0) {
+                        let x32_val = quantize_fader(f);
                         if tnum >= config.trk_min && tnum <= config.trk_max {
                             if config.ch_bank_on {
                                 let idx = tnum - config.trk_min;
@@ -1028,18 +1110,18 @@ async fn process_single_reaper_message(
                     }
                 } else if msg.path.contains("/pan") {
                     xx_mask = TRACKPAN;
-                    if let Some(OscArg::Float(f)) = msg.args.first() {
+                    if let Some(f) = msg.arg_float(0) {
                         if tnum >= config.trk_min && tnum <= config.trk_max {
                             if config.ch_bank_on {
                                 let idx = tnum - config.trk_min;
                                 if let Some(track) = state_guard.bank_tracks.get_mut(idx as usize) {
-                                    track.pan = *f;
+                                    track.pan = f;
                                 }
                                 let bank_cnum = idx - state_guard.ch_bank_offset * config.bank_size;
                                 if bank_cnum >= 0 && bank_cnum < config.bank_size {
                                     xb_msg = Some(OscMessage {
                                         path: format!("/ch/{:02}/mix/pan", bank_cnum + 1),
-                                        args: vec![OscArg::Float(*f)],
+                                        args: vec![OscArg::Float(f)],
                                     });
                                 }
                             } else {
@@ -1047,7 +1129,7 @@ async fn process_single_reaper_message(
                                 if cnum <= config.bank_size {
                                     xb_msg = Some(OscMessage {
                                         path: format!("/ch/{:02}/mix/pan", cnum),
-                                        args: vec![OscArg::Float(*f)],
+                                        args: vec![OscArg::Float(f)],
                                     });
                                 }
                             }
@@ -1055,8 +1137,8 @@ async fn process_single_reaper_message(
                     }
                 } else if msg.path.contains("/mute") {
                     xx_mask = TRACKMUTE;
-                    if let Some(OscArg::Float(f)) = msg.args.first() {
-                        let x_val = if *f > 0.0 { 0 } else { 1 }; // Reaper 1=mute, X32 0=on (unmute) ??
+                    if let Some(f) = msg.arg_float(0) {
+                        let x_val = if f > 0.0 { 0 } else { 1 }; // Reaper 1=mute, X32 0=on (unmute) ??
                         // C code: if (endian.ii == 1) endian.ff = 0.0 else endian.ff = 1.0; (for X32->Reaper)
                         // For Reaper->X32 (line 1157):
                         // if (endian.ff > 0.0) Xb_ls = Xfprint(..., 'i', &zero); else ... 'i', &one.
@@ -1067,7 +1149,7 @@ async fn process_single_reaper_message(
                         if tnum >= config.trk_min && tnum <= config.trk_max && config.ch_bank_on {
                             let idx = tnum - config.trk_min;
                             if let Some(track) = state_guard.bank_tracks.get_mut(idx as usize) {
-                                track.mute = *f;
+                                track.mute = f;
                             }
                             let bank_cnum = idx - state_guard.ch_bank_offset * config.bank_size;
                             if bank_cnum >= 0 && bank_cnum < config.bank_size {
@@ -1080,8 +1162,8 @@ async fn process_single_reaper_message(
                     }
                 } else if msg.path.contains("/solo") {
                     xx_mask = TRACKSOLO;
-                    if let Some(OscArg::Float(f)) = msg.args.first() {
-                        let i_val = *f as i32;
+                    if let Some(f) = msg.arg_float(0) {
+                        let i_val = f as i32;
                         let mut x_cnum = -1;
 
                         if tnum >= config.trk_min && tnum <= config.trk_max {
@@ -1090,7 +1172,7 @@ async fn process_single_reaper_message(
                                 if let Some(track) =
                                     state_guard.bank_tracks.get_mut((t - 1) as usize)
                                 {
-                                    track.solo = *f;
+                                    track.solo = f;
                                 }
                                 t -= state_guard.ch_bank_offset * config.bank_size;
                             }
@@ -1114,10 +1196,27 @@ async fn process_single_reaper_message(
                             });
                         }
                     }
+                } else if msg.path.contains("/recarm") {
+                    xx_mask = TRACKRECARM;
+                    if let (true, Some(f)) = (config.recarm_on, msg.arg_float(0)) {
+                        if f > 0.5 {
+                            state_guard.armed_tracks.insert(tnum);
+                        } else {
+                            state_guard.armed_tracks.remove(&tnum);
+                        }
+                        let selected = recarm_track(
+                            state_guard.x_selected,
+                            config,
+                            state_guard.ch_bank_offset,
+                        );
+                        if selected == Some(tnum) {
+                            xb_msg = recarm_led(config, &state_guard);
+                        }
+                    }
                 } else if msg.path.contains("/select") {
                     xx_mask = TRACKSELECT;
-                    if let Some(OscArg::Float(f)) = msg.args.first() {
-                        if *f > 0.5 {
+                    if let Some(f) = msg.arg_float(0) {
+                        if f > 0.5 {
                             state_guard.r_selected = tnum;
                             // Map to X32 selection
                             let mut x_sel = -1;
@@ -1157,46 +1256,46 @@ async fn process_single_reaper_message(
         if config.master_on {
             if msg.path.contains("volume") {
                 xx_mask = MASTERVOLUME;
-                if let Some(OscArg::Float(f)) = msg.args.first() {
+                if let Some(f) = msg.arg_float(0) {
                     xb_msg = Some(OscMessage {
                         path: "/main/st/mix/fader".to_string(),
-                        args: vec![OscArg::Float(*f)],
+                        args: vec![OscArg::Float(quantize_fader(f))],
                     });
                 }
             } else if msg.path.contains("pan") {
                 xx_mask = MASTERPAN;
-                if let Some(OscArg::Float(f)) = msg.args.first() {
+                if let Some(f) = msg.arg_float(0) {
                     xb_msg = Some(OscMessage {
                         path: "/main/st/mix/pan".to_string(),
-                        args: vec![OscArg::Float(*f)],
+                        args: vec![OscArg::Float(f)],
                     });
                 }
             } else if msg.path.contains("select") {
                 xx_mask = MASTERSELECT;
-                if let Some(OscArg::Float(f)) = msg.args.first() {
+                if let Some(f) = msg.arg_float(0) {
                     xb_msg = Some(OscMessage {
                         path: "/-stat/selidx".to_string(),
                         // X32 master fader is select index 71 (LR)
                         // Actually, looking at X32 implementation, Main LR is usually 71 (0-based) or 71 (1-based)? Let's just use 71.
-                        args: vec![OscArg::Int(if *f > 0.5 { 71 } else { 0 })],
+                        args: vec![OscArg::Int(if f > 0.5 { 71 } else { 0 })],
                     });
                 }
             } else if msg.path.contains("solo") {
                 xx_mask = MASTERSOLO;
-                if let Some(OscArg::Float(f)) = msg.args.first() {
+                if let Some(f) = msg.arg_float(0) {
                     xb_msg = Some(OscMessage {
                         // Main LR solo is 72 on X32 (-stat/solosw/72)
                         path: "/-stat/solosw/72".to_string(),
-                        args: vec![OscArg::Int(if *f > 0.5 { 1 } else { 0 })],
+                        args: vec![OscArg::Int(if f > 0.5 { 1 } else { 0 })],
                     });
                 }
             } else if msg.path.contains("mute") {
                 xx_mask = MASTERMUTE;
-                if let Some(OscArg::Float(f)) = msg.args.first() {
+                if let Some(f) = msg.arg_float(0) {
                     xb_msg = Some(OscMessage {
                         path: "/main/st/mix/on".to_string(),
                         // Reaper: 1.0=mute, 0.0=unmute. X32: 1=on (unmute), 0=off (mute)
-                        args: vec![OscArg::Int(if *f > 0.0 { 0 } else { 1 })],
+                        args: vec![OscArg::Int(if f > 0.0 { 0 } else { 1 })],
                     });
                 }
             }
@@ -1204,8 +1303,8 @@ async fn process_single_reaper_message(
     } else if config.transport_on {
         // Transport buttons from Reaper
         if msg.path.starts_with("/play") {
-            if let Some(OscArg::Float(f)) = msg.args.first() {
-                let val = if *f > 0.5 { 127 } else { 0 };
+            if let Some(f) = msg.arg_float(0) {
+                let val = if f > 0.5 { 127 } else { 0 };
                 state_guard.play = val == 127;
                 xb_msg = Some(OscMessage {
                     path: "/-stat/userpar/18/value".to_string(),
@@ -1213,32 +1312,32 @@ async fn process_single_reaper_message(
                 });
             }
         } else if msg.path.starts_with("/stop") {
-            if let Some(OscArg::Float(f)) = msg.args.first() {
-                let val = if *f > 0.5 { 127 } else { 0 };
+            if let Some(f) = msg.arg_float(0) {
+                let val = if f > 0.5 { 127 } else { 0 };
                 xb_msg = Some(OscMessage {
                     path: "/-stat/userpar/23/value".to_string(),
                     args: vec![OscArg::Int(val)],
                 });
             }
         } else if msg.path.starts_with("/repeat") {
-            if let Some(OscArg::Float(f)) = msg.args.first() {
-                let val = if *f > 0.5 { 127 } else { 0 };
+            if let Some(f) = msg.arg_float(0) {
+                let val = if f > 0.5 { 127 } else { 0 };
                 xb_msg = Some(OscMessage {
                     path: "/-stat/userpar/22/value".to_string(),
                     args: vec![OscArg::Int(val)],
                 });
             }
         } else if msg.path.starts_with("/record") {
-            if let Some(OscArg::Float(f)) = msg.args.first() {
-                let val = if *f > 0.5 { 127 } else { 0 };
+            if let Some(f) = msg.arg_float(0) {
+                let val = if f > 0.5 { 127 } else { 0 };
                 xb_msg = Some(OscMessage {
                     path: "/-stat/userpar/24/value".to_string(),
                     args: vec![OscArg::Int(val)],
                 });
             }
         } else if msg.path.starts_with("/pause") {
-            if let Some(OscArg::Float(f)) = msg.args.first() {
-                let val = if *f > 0.5 { 127 } else { 0 };
+            if let Some(f) = msg.arg_float(0) {
+                let val = if f > 0.5 { 127 } else { 0 };
                 xb_msg = Some(OscMessage {
                     path: "/-stat/userpar/19/value".to_string(),
                     args: vec![OscArg::Int(val)],
@@ -1256,7 +1355,98 @@ async fn process_single_reaper_message(
     Ok(())
 }
 
-// Simple OSC parser
+/// Maps an X32 channel strip to the Reaper track it arms for recording.
+///
+/// Only input channels are mapped. With channel banking on, the banked strips follow
+/// the current bank, as their faders do.
+///
+/// # Arguments
+///
+/// * `strip` - The X32 input channel, from 1.
+/// * `config` - The bridge configuration.
+/// * `ch_bank_offset` - The current channel bank.
+///
+/// # Returns
+///
+/// The Reaper track number, or `None` if the strip has no track.
+fn recarm_track(strip: i32, config: &Config, ch_bank_offset: i32) -> Option<i32> {
+    let last_strip = if config.ch_bank_on {
+        config.bank_size
+    } else {
+        32
+    };
+    if strip < 1 || strip > last_strip {
+        return None;
+    }
+    let mut cnum = strip;
+    if config.ch_bank_on {
+        cnum += ch_bank_offset * config.bank_size;
+    }
+    let track = cnum + config.trk_min - 1;
+    (track <= config.trk_max).then_some(track)
+}
+
+/// Returns the message that lights the record-arm button when the selected
+/// channel's track is armed, or `None` if record-arm is not configured.
+fn recarm_led(config: &Config, state: &AppState) -> Option<OscMessage> {
+    if !config.recarm_on || !(5..=12).contains(&config.recarm_btn) {
+        return None;
+    }
+    let armed = recarm_track(state.x_selected, config, state.ch_bank_offset)
+        .is_some_and(|track| state.armed_tracks.contains(&track));
+    Some(OscMessage {
+        path: format!("/-stat/userpar/{:02}/value", 12 + config.recarm_btn),
+        args: vec![OscArg::Int(if armed { 127 } else { 0 })],
+    })
+}
+
+/// Maps an X32 `/meters/1` blob to Reaper track meter levels.
+///
+/// The blob holds a little-endian int32 count followed by that many little-endian
+/// floats, the first 32 of which are the input channels. Channels map to tracks as
+/// their faders do: with channel banking on, only the banked strips are forwarded,
+/// offset by the current bank.
+///
+/// # Arguments
+///
+/// * `blob` - The blob argument of a `/meters/1` message.
+/// * `config` - The bridge configuration.
+/// * `ch_bank_offset` - The current channel bank.
+///
+/// # Returns
+///
+/// The Reaper track number and meter level of each mapped channel.
+fn meter_tracks(blob: &[u8], config: &Config, ch_bank_offset: i32) -> Vec<(i32, f32)> {
+    let count = blob
+        .get(0..4)
+        .and_then(|b| b.try_into().ok())
+        .map(i32::from_le_bytes)
+        .unwrap_or(0);
+    let channels = if config.ch_bank_on {
+        count.min(config.bank_size)
+    } else {
+        count
+    }
+    .clamp(0, 32);
+
+    let mut tracks = Vec::with_capacity(channels as usize);
+    for ch in 1..=channels {
+        let start = ch as usize * 4;
+        let Some(bytes) = blob.get(start..start + 4).and_then(|b| b.try_into().ok()) else {
+            break;
+        };
+        let mut cnum = ch;
+        if config.ch_bank_on {
+            cnum += ch_bank_offset * config.bank_size;
+        }
+        let track = cnum + config.trk_min - 1;
+        if track >= config.trk_min && track <= config.trk_max {
+            tracks.push((track, f32::from_le_bytes(bytes)));
+        }
+    }
+    tracks
+}
+
 fn parse_osc_packet(data: &[u8]) -> Result<OscMessage> {
     let path_end = data
         .iter()
@@ -1324,6 +1514,18 @@ fn parse_osc_packet(data: &[u8]) -> Result<OscMessage> {
                     args.push(OscArg::String(s));
                     arg_idx = (str_end + 4) & !3;
                 }
+                b'b' if arg_idx + 4 <= data.len() => {
+                    let size = u32::from_be_bytes([
+                        data[arg_idx],
+                        data[arg_idx + 1],
+                        data[arg_idx + 2],
+                        data[arg_idx + 3],
+                    ]) as usize;
+                    let blob_start = arg_idx + 4;
+                    let blob_end = blob_start.saturating_add(size).min(data.len());
+                    args.push(OscArg::Blob(data[blob_start..blob_end].to_vec()));
+                    arg_idx = (blob_end + 3) & !3;
+                }
                 _ => {}
             }
         }
@@ -1345,6 +1547,8 @@ mod tests {
             xx_send_mask: -1,
             xr_send_mask: -1,
             x32_ip: "127.0.0.1".to_string(),
+            x32_port: 10023,
+            x32_local_port: 0,
             reaper_ip: "127.0.0.1".to_string(),
             reaper_send_port: 8000,
             reaper_recv_port: 8000,
@@ -1354,6 +1558,8 @@ mod tests {
             bank_c_color: 0,
             eq_ctrl_on: false,
             master_on: false,
+            meters_on: false,
+            recarm_on: false,
             trk_min: 1,
             trk_max: 32,
             aux_min: 0,
@@ -1371,6 +1577,7 @@ mod tests {
             marker_btn: 0,
             ch_bank_offset: 0,
             bank_size: 8,
+            recarm_btn: 0,
         };
         let state = Arc::new(Mutex::new(AppState::new(&config)));
 
@@ -1448,6 +1655,8 @@ mod tests {
             xx_send_mask: -1,
             xr_send_mask: -1,
             x32_ip: "127.0.0.1".to_string(),
+            x32_port: 10023,
+            x32_local_port: 0,
             reaper_ip: "127.0.0.1".to_string(),
             reaper_send_port: 8000,
             reaper_recv_port: 8000,
@@ -1457,6 +1666,8 @@ mod tests {
             bank_c_color: 0,
             eq_ctrl_on: false,
             master_on: true,
+            meters_on: false,
+            recarm_on: false,
             trk_min: 1,
             trk_max: 32,
             aux_min: 0,
@@ -1474,6 +1685,7 @@ mod tests {
             marker_btn: 0,
             ch_bank_offset: 0,
             bank_size: 8,
+            recarm_btn: 0,
         };
         let state = Arc::new(Mutex::new(AppState::new(&config)));
 
@@ -1523,6 +1735,8 @@ mod tests {
             xx_send_mask: -1,
             xr_send_mask: -1,
             x32_ip: "127.0.0.1".to_string(),
+            x32_port: 10023,
+            x32_local_port: 0,
             reaper_ip: "127.0.0.1".to_string(),
             reaper_send_port: 8000,
             reaper_recv_port: 8000,
@@ -1532,6 +1746,8 @@ mod tests {
             bank_c_color: 0,
             eq_ctrl_on: false,
             master_on: false,
+            meters_on: false,
+            recarm_on: false,
             trk_min: 1,
             trk_max: 32,
             aux_min: 0,
@@ -1549,6 +1765,7 @@ mod tests {
             marker_btn: 0,
             ch_bank_offset: 0,
             bank_size: 8,
+            recarm_btn: 0,
         };
         let state = Arc::new(Mutex::new(AppState::new(&config)));
 
@@ -1604,6 +1821,8 @@ mod tests {
             xx_send_mask: -1,
             xr_send_mask: -1,
             x32_ip: "127.0.0.1".to_string(),
+            x32_port: 10023,
+            x32_local_port: 0,
             reaper_ip: "127.0.0.1".to_string(),
             reaper_send_port: 8000,
             reaper_recv_port: 8000,
@@ -1613,6 +1832,8 @@ mod tests {
             bank_c_color: 0,
             eq_ctrl_on: false,
             master_on: false,
+            meters_on: false,
+            recarm_on: false,
             trk_min: 0,
             trk_max: 0,
             aux_min: 0,
@@ -1630,6 +1851,7 @@ mod tests {
             marker_btn: 0,
             ch_bank_offset: 0,
             bank_size: 8,
+            recarm_btn: 0,
         };
         let state = Arc::new(Mutex::new(AppState::new(&config)));
 
@@ -1714,6 +1936,8 @@ mod tests {
             xx_send_mask: -1,
             xr_send_mask: -1,
             x32_ip: "127.0.0.1".to_string(),
+            x32_port: 10023,
+            x32_local_port: 0,
             reaper_ip: "127.0.0.1".to_string(),
             reaper_send_port: 8000,
             reaper_recv_port: 8000,
@@ -1723,6 +1947,8 @@ mod tests {
             bank_c_color: 0,
             eq_ctrl_on: false,
             master_on: true,
+            meters_on: false,
+            recarm_on: false,
             trk_min: 1,
             trk_max: 32,
             aux_min: 0,
@@ -1740,6 +1966,7 @@ mod tests {
             marker_btn: 0,
             ch_bank_offset: 0,
             bank_size: 8,
+            recarm_btn: 0,
         };
         let state = Arc::new(Mutex::new(AppState::new(&config)));
 
@@ -1791,6 +2018,209 @@ mod tests {
             .unwrap();
         }
     }
+
+    fn meter_config(ch_bank_on: bool) -> Config {
+        Config {
+            verbose: false,
+            delay_bank: 0,
+            delay_generic: 0,
+            xx_send_mask: -1,
+            xr_send_mask: -1,
+            x32_ip: "127.0.0.1".to_string(),
+            x32_port: 10023,
+            x32_local_port: 0,
+            reaper_ip: "127.0.0.1".to_string(),
+            reaper_send_port: 8000,
+            reaper_recv_port: 8000,
+            transport_on: false,
+            ch_bank_on,
+            marker_btn_on: false,
+            bank_c_color: 0,
+            eq_ctrl_on: false,
+            master_on: false,
+            meters_on: true,
+            recarm_on: false,
+            trk_min: 1,
+            trk_max: 24,
+            aux_min: 0,
+            aux_max: 0,
+            fxr_min: 0,
+            fxr_max: 0,
+            bus_min: 0,
+            bus_max: 0,
+            dca_min: 0,
+            dca_max: 0,
+            track_send_offset: 0,
+            rdca: vec![(0, 0); 8],
+            bank_up: 0,
+            bank_dn: 0,
+            marker_btn: 0,
+            ch_bank_offset: 0,
+            bank_size: 8,
+            recarm_btn: 0,
+        }
+    }
+
+    /// Builds a `/meters/1` blob with channel `n` at level `n / 100`.
+    fn meter_blob() -> Vec<u8> {
+        let mut blob = 96i32.to_le_bytes().to_vec();
+        for i in 0..96 {
+            let level = if i < 32 { (i + 1) as f32 / 100.0 } else { 0.0 };
+            blob.extend_from_slice(&level.to_le_bytes());
+        }
+        blob
+    }
+
+    #[test]
+    fn test_meter_tracks_mapping() {
+        let blob = meter_blob();
+
+        // Without banking, channels map straight to tracks up to trk_max.
+        let tracks = meter_tracks(&blob, &meter_config(false), 0);
+        assert_eq!(tracks.len(), 24);
+        assert_eq!(tracks[0], (1, 0.01));
+        assert_eq!(tracks[23], (24, 0.24));
+
+        // With banking, the 8 banked strips follow the current bank.
+        let tracks = meter_tracks(&blob, &meter_config(true), 1);
+        assert_eq!(tracks.len(), 8);
+        assert_eq!(tracks[0], (9, 0.01));
+        assert_eq!(tracks[7], (16, 0.08));
+
+        // Banks past trk_max are dropped.
+        assert!(meter_tracks(&blob, &meter_config(true), 3).is_empty());
+
+        // A truncated blob stops at the last complete float.
+        assert_eq!(meter_tracks(&blob[..12], &meter_config(false), 0).len(), 2);
+    }
+
+    #[test]
+    fn test_bank_bundle_for_three_track_bank() {
+        let mut config = meter_config(true);
+        config.bank_size = 3;
+        config.trk_max = 6;
+        let mut state = AppState::new(&config);
+        state.ch_bank_offset = 1;
+        for (i, track) in state.bank_tracks.iter_mut().enumerate() {
+            track.fader = i as f32 / 10.0;
+            track.scribble = format!("Track {}", i + 1);
+        }
+
+        let bundles = bank_bundles(bank_messages(&config, &state)).unwrap();
+        let messages: Vec<OscMessage> = bundles
+            .iter()
+            .flat_map(|b| {
+                let bytes = b.to_bytes().unwrap();
+                OscPacket::from_bytes(&bytes).unwrap().into_messages()
+            })
+            .collect();
+
+        // Fader, pan, mute, 16 sends, name, color and icon for each strip.
+        assert_eq!(messages.len(), 3 * 22);
+        for (strip, track) in [(1, 4), (2, 5), (3, 6)] {
+            let fader = format!("/ch/{:02}/mix/fader", strip);
+            let name = format!("/ch/{:02}/config/name", strip);
+            let find = |path: &str| messages.iter().find(|m| m.path == path).unwrap();
+            assert_eq!(
+                find(&fader).args,
+                vec![OscArg::Float((track - 1) as f32 / 10.0)]
+            );
+            assert_eq!(
+                find(&name).args,
+                vec![OscArg::String(format!("Track {}", track))]
+            );
+            for bus in 1..=16 {
+                let send = format!("/ch/{:02}/mix/{:02}/level", strip, bus);
+                assert!(messages.iter().any(|m| m.path == send));
+            }
+        }
+        assert!(!messages.iter().any(|m| m.path.starts_with("/ch/04/")));
+    }
+
+    #[test]
+    fn test_bank_bundles_fit_in_one_frame() {
+        let mut config = meter_config(true);
+        config.bank_size = 32;
+        config.trk_max = 32;
+        let mut state = AppState::new(&config);
+        for (i, track) in state.bank_tracks.iter_mut().enumerate() {
+            track.scribble = format!("Track {}", i + 1);
+        }
+
+        let messages = bank_messages(&config, &state);
+        assert_eq!(messages.len(), 32 * 22);
+        let bundles = bank_bundles(messages.clone()).unwrap();
+        assert!(bundles.len() > 1);
+
+        let mut received = Vec::new();
+        for bundle in &bundles {
+            let bytes = bundle.to_bytes().unwrap();
+            assert!(
+                bytes.len() <= MAX_BUNDLE_SIZE,
+                "bundle of {} bytes",
+                bytes.len()
+            );
+            received.extend(OscPacket::from_bytes(&bytes).unwrap().into_messages());
+        }
+        // Every message arrives, in order.
+        assert_eq!(received, messages);
+    }
+
+    #[test]
+    fn test_recarm_track_banked_channel() {
+        let mut config = meter_config(true);
+        config.trk_max = 32;
+
+        // Strip 3 in the third bank of 8 is track 19.
+        assert_eq!(recarm_track(3, &config, 2), Some(19));
+        assert_eq!(recarm_track(8, &config, 3), Some(32));
+        // Only the banked strips are mapped.
+        assert_eq!(recarm_track(9, &config, 0), None);
+        assert_eq!(recarm_track(0, &config, 0), None);
+
+        // Tracks start at trk_min.
+        config.trk_min = 5;
+        config.trk_max = 36;
+        assert_eq!(recarm_track(3, &config, 2), Some(23));
+
+        // Without banking, channels map straight through.
+        let config = meter_config(false);
+        assert_eq!(recarm_track(20, &config, 2), Some(20));
+        assert_eq!(recarm_track(25, &config, 0), None);
+    }
+
+    #[tokio::test]
+    async fn test_x32_meters_forwarded_to_reaper() {
+        let config = meter_config(true);
+        let state = Arc::new(Mutex::new(AppState::new(&config)));
+        state.lock().await.ch_bank_offset = 1;
+
+        let reaper = UdpSocket::bind("127.0.0.1:0").await.unwrap();
+        let r_addr = reaper.local_addr().unwrap();
+        let r_sock = UdpSocket::bind("127.0.0.1:0").await.unwrap();
+        let mock_x32 = UdpSocket::bind("127.0.0.1:0").await.unwrap();
+        let x_client = Arc::new(
+            MixerClient::connect(&mock_x32.local_addr().unwrap().to_string(), false)
+                .await
+                .unwrap(),
+        );
+
+        let bytes = OscMessage::new("/meters/1".to_string(), vec![OscArg::Blob(meter_blob())])
+            .to_bytes()
+            .unwrap();
+        process_x32_message(&bytes, &config, &state, &r_sock, r_addr, &x_client)
+            .await
+            .unwrap();
+
+        let mut buf = [0u8; 1024];
+        let len = reaper.recv(&mut buf).await.unwrap();
+        let bundle = osc_lib::OscBundle::from_bytes(&buf[..len]).unwrap();
+        let messages: Vec<_> = osc_lib::OscPacket::Bundle(bundle).into_messages();
+        assert_eq!(messages.len(), 8);
+        assert_eq!(messages[0].path, "/track/9/vu");
+        assert_eq!(messages[0].args, vec![OscArg::Float(0.01)]);
+        assert_eq!(messages[7].path, "/track/16/vu");
+    }
 }
 
 #[inline(always)]
@@ -1,4 +1,5 @@
 use crate::config::Config;
+use std::collections::HashSet;
 
 #[derive(Debug, Clone)]
 pub struct ChannelState {
@@ -45,6 +46,8 @@ pub struct AppState {
     pub play: bool,
     #[allow(dead_code)]
     pub play_1: bool,
+    /// Reaper tracks currently armed for recording.
+    pub armed_tracks: HashSet<i32>,
 }
 
 impl AppState {
@@ -73,6 +76,7 @@ impl AppState {
             loop_toggle: 0,
             play: false,
             play_1: false,
+            armed_tracks: HashSet::new(),
         }
     }
 }
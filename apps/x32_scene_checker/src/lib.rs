@@ -55,6 +55,9 @@ fn format_arg(arg: &OscArg) -> String {
         OscArg::Float(f) => format!("{:.3}", f),
         OscArg::String(s) => s.clone(),
         OscArg::Blob(_) => "[Blob]".to_string(),
+        OscArg::Bool(b) => b.to_string(),
+        OscArg::Nil => "[Nil]".to_string(),
+        OscArg::Infinitum => "[Infinitum]".to_string(),
     }
 }
 
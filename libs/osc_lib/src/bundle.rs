@@ -0,0 +1,197 @@
+//! OSC bundles, which group several messages (or nested bundles) under one time tag.
+//!
+//! A bundle is encoded as the string `#bundle`, a 64-bit NTP time tag, and a
+//! sequence of elements, each prefixed with its big-endian `int32` size.
+//!
+//! # Example
+//!
+//! ```
+//! use osc_lib::{OscArg, OscBundle, OscMessage, OscPacket};
+//!
+//! let bundle = OscBundle::new(vec![
+//!     OscMessage::new("/ch/01/mix/on".to_string(), vec![OscArg::Int(1)]).into(),
+//!     OscMessage::new("/ch/02/mix/on".to_string(), vec![OscArg::Int(0)]).into(),
+//! ]);
+//! let bytes = bundle.to_bytes().unwrap();
+//!
+//! let packet = OscPacket::from_bytes(&bytes).unwrap();
+//! assert_eq!(packet.messages().count(), 2);
+//! ```
+
+use crate::{OscError, OscMessage, Result};
+
+/// The marker that starts every encoded bundle.
+pub const BUNDLE_TAG: &[u8; 8] = b"#bundle\0";
+
+/// The special time tag meaning "process immediately".
+pub const IMMEDIATELY: u64 = 1;
+
+/// Bundles nested deeper than this are rejected rather than parsed recursively.
+const MAX_DEPTH: usize = 16;
+
+/// A single OSC packet: either a message or a bundle.
+#[derive(Debug, PartialEq, Clone)]
+pub enum OscPacket {
+    /// A single OSC message.
+    Message(OscMessage),
+    /// A bundle of packets sharing a time tag.
+    Bundle(OscBundle),
+}
+
+/// A group of OSC packets to be applied together.
+#[derive(Debug, PartialEq, Clone)]
+pub struct OscBundle {
+    /// The NTP time tag at which the contents should take effect.
+    pub timetag: u64,
+    /// The packets contained in the bundle, in order.
+    pub content: Vec<OscPacket>,
+}
+
+impl From<OscMessage> for OscPacket {
+    fn from(msg: OscMessage) -> Self {
+        OscPacket::Message(msg)
+    }
+}
+
+impl From<OscBundle> for OscPacket {
+    fn from(bundle: OscBundle) -> Self {
+        OscPacket::Bundle(bundle)
+    }
+}
+
+impl OscPacket {
+    /// Deserializes a packet, detecting whether it is a message or a bundle.
+    ///
+    /// # Arguments
+    ///
+    /// * `bytes` - The byte slice containing the packet.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing the deserialized `OscPacket` or an `OscError`.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self> {
+        Self::from_bytes_at_depth(bytes, 0)
+    }
+
+    fn from_bytes_at_depth(bytes: &[u8], depth: usize) -> Result<Self> {
+        if bytes.starts_with(BUNDLE_TAG) {
+            OscBundle::from_bytes_at_depth(bytes, depth).map(OscPacket::Bundle)
+        } else {
+            OscMessage::from_bytes(bytes).map(OscPacket::Message)
+        }
+    }
+
+    /// Serializes the packet to a `Vec<u8>`.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing the serialized byte vector or an `OscError`.
+    pub fn to_bytes(&self) -> Result<Vec<u8>> {
+        match self {
+            OscPacket::Message(msg) => msg.to_bytes(),
+            OscPacket::Bundle(bundle) => bundle.to_bytes(),
+        }
+    }
+
+    /// Returns every message in the packet, flattening nested bundles in order.
+    pub fn messages(&self) -> Box<dyn Iterator<Item = &OscMessage> + '_> {
+        match self {
+            OscPacket::Message(msg) => Box::new(std::iter::once(msg)),
+            OscPacket::Bundle(bundle) => Box::new(bundle.content.iter().flat_map(|p| p.messages())),
+        }
+    }
+
+    /// Consumes the packet and returns its messages, flattening nested bundles in order.
+    pub fn into_messages(self) -> Vec<OscMessage> {
+        match self {
+            OscPacket::Message(msg) => vec![msg],
+            OscPacket::Bundle(bundle) => bundle
+                .content
+                .into_iter()
+                .flat_map(OscPacket::into_messages)
+                .collect(),
+        }
+    }
+}
+
+impl OscBundle {
+    /// Creates a new bundle to be processed immediately.
+    ///
+    /// # Arguments
+    ///
+    /// * `content` - The packets to include, in order.
+    pub fn new(content: Vec<OscPacket>) -> Self {
+        Self {
+            timetag: IMMEDIATELY,
+            content,
+        }
+    }
+
+    /// Deserializes an `OscBundle` from a byte slice.
+    ///
+    /// # Arguments
+    ///
+    /// * `bytes` - The byte slice containing the bundle, starting with `#bundle`.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing the deserialized `OscBundle` or an `OscError`.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self> {
+        Self::from_bytes_at_depth(bytes, 0)
+    }
+
+    fn from_bytes_at_depth(bytes: &[u8], depth: usize) -> Result<Self> {
+        if depth >= MAX_DEPTH {
+            return Err(OscError::ParseError("Bundle nested too deeply".to_string()));
+        }
+        if !bytes.starts_with(BUNDLE_TAG) {
+            return Err(OscError::ParseError("Missing #bundle marker".to_string()));
+        }
+        let timetag = bytes
+            .get(8..16)
+            .and_then(|b| b.try_into().ok())
+            .map(u64::from_be_bytes)
+            .ok_or_else(|| OscError::ParseError("Unexpected end of buffer".to_string()))?;
+
+        let mut content = Vec::new();
+        let mut idx = 16;
+        while idx < bytes.len() {
+            let size = bytes
+                .get(idx..idx + 4)
+                .and_then(|b| b.try_into().ok())
+                .map(i32::from_be_bytes)
+                .ok_or_else(|| OscError::ParseError("Unexpected end of buffer".to_string()))?;
+            idx += 4;
+
+            // The size comes straight off the wire, so check it against the bytes remaining.
+            if size < 0 || size as usize > bytes.len() - idx {
+                return Err(OscError::ParseError(
+                    "Bundle element size exceeds remaining bytes".to_string(),
+                ));
+            }
+            let element = &bytes[idx..idx + size as usize];
+            idx += size as usize;
+
+            content.push(OscPacket::from_bytes_at_depth(element, depth + 1)?);
+        }
+
+        Ok(OscBundle { timetag, content })
+    }
+
+    /// Serializes the `OscBundle` to a `Vec<u8>`.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing the serialized byte vector or an `OscError`.
+    pub fn to_bytes(&self) -> Result<Vec<u8>> {
+        let mut bytes = Vec::with_capacity(16);
+        bytes.extend_from_slice(BUNDLE_TAG);
+        bytes.extend_from_slice(&self.timetag.to_be_bytes());
+        for packet in &self.content {
+            let element = packet.to_bytes()?;
+            bytes.extend_from_slice(&(element.len() as i32).to_be_bytes());
+            bytes.extend_from_slice(&element);
+        }
+        Ok(bytes)
+    }
+}
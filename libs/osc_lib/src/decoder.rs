@@ -0,0 +1,77 @@
+//! Decoding of datagrams that carry more than one OSC packet.
+//!
+//! A UDP datagram normally holds a single message, but some senders pack several
+//! messages back to back, and a `#bundle` takes up the rest of the datagram.
+//! [`OscDecoder`] walks a received buffer and yields each packet in order.
+//!
+//! # Example
+//!
+//! ```
+//! use osc_lib::{OscArg, OscDecoder, OscMessage, OscPacket};
+//!
+//! let mut datagram = OscMessage::new("/ch/01/mix/on".to_string(), vec![OscArg::Int(1)])
+//!     .to_bytes()
+//!     .unwrap();
+//! datagram.extend(OscMessage::new("/ch/02/mix/on".to_string(), vec![]).to_bytes().unwrap());
+//!
+//! let packets: Vec<OscPacket> = OscDecoder::new(&datagram).map(Result::unwrap).collect();
+//! assert_eq!(packets.len(), 2);
+//! ```
+
+use crate::bundle::BUNDLE_TAG;
+use crate::{OscBundle, OscMessage, OscPacket, Result};
+
+/// An iterator over the OSC packets in a byte buffer.
+///
+/// Messages are read one after another until the buffer is used up. A bundle extends
+/// to the end of the buffer, so it is always the last packet. Once a packet fails to
+/// parse, the error is yielded and the iterator ends, since the position of anything
+/// after it is unknown.
+#[derive(Debug, Clone)]
+pub struct OscDecoder<'a> {
+    bytes: &'a [u8],
+    offset: usize,
+}
+
+impl<'a> OscDecoder<'a> {
+    /// Creates a decoder over a received buffer.
+    ///
+    /// # Arguments
+    ///
+    /// * `bytes` - The datagram or buffer to decode.
+    pub fn new(bytes: &'a [u8]) -> Self {
+        Self { bytes, offset: 0 }
+    }
+
+    /// Returns the number of bytes not yet decoded.
+    pub fn remaining(&self) -> usize {
+        self.bytes.len() - self.offset
+    }
+}
+
+impl Iterator for OscDecoder<'_> {
+    type Item = Result<OscPacket>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let rest = &self.bytes[self.offset..];
+        if rest.is_empty() {
+            return None;
+        }
+
+        let result = if rest.starts_with(BUNDLE_TAG) {
+            OscBundle::from_bytes(rest).map(|bundle| (OscPacket::Bundle(bundle), rest.len()))
+        } else {
+            OscMessage::from_bytes_prefix(rest).map(|(msg, len)| (OscPacket::Message(msg), len))
+        };
+        match result {
+            Ok((packet, len)) => {
+                self.offset += len;
+                Some(Ok(packet))
+            }
+            Err(e) => {
+                self.offset = self.bytes.len();
+                Some(Err(e))
+            }
+        }
+    }
+}
@@ -38,11 +38,20 @@
 //! ```
 
 use byteorder::{BigEndian, ReadBytesExt};
-use std::fmt::Write;
 use std::io::{self, Cursor};
 use std::str::FromStr;
 use std::string::FromUtf8Error;
 
+pub mod bundle;
+pub mod decoder;
+pub mod slip;
+
+pub use bundle::{OscBundle, OscPacket};
+pub use decoder::OscDecoder;
+
+#[cfg(feature = "serde")]
+mod serde_support;
+
 #[cfg(test)]
 mod tests;
 
@@ -62,6 +71,8 @@ pub enum OscError {
     ParseError(String),
     /// An unexpected response was received from the mixer.
     UnexpectedResponse,
+    /// A message address was not a valid OSC address or address pattern.
+    InvalidAddress(String),
 }
 
 impl std::fmt::Display for OscError {
@@ -77,6 +88,7 @@ impl std::fmt::Display for OscError {
                 f.write_str(s)
             }
             OscError::UnexpectedResponse => f.write_str("Unexpected response from mixer"),
+            OscError::InvalidAddress(s) => write!(f, "Invalid OSC address: {:?}", s),
         }
     }
 }
@@ -105,16 +117,93 @@ impl From<FromUtf8Error> for OscError {
 pub type Result<T> = std::result::Result<T, OscError>;
 
 /// Represents a single argument in an OSC message.
+///
+/// With the `serde` feature enabled, arguments serialize as a tag and value, e.g.
+/// `{"type":"float","value":0.75}`. Blobs are encoded as base64 strings and
+/// non-finite floats as `"NaN"`, `"inf"` or `"-inf"`.
 #[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(tag = "type", content = "value", rename_all = "lowercase")
+)]
 pub enum OscArg {
     /// A 32-bit integer (`i` in OSC type tags).
     Int(i32),
     /// A 32-bit float (`f` in OSC type tags).
-    Float(f32),
+    Float(#[cfg_attr(feature = "serde", serde(with = "serde_support::float"))] f32),
     /// A string (`s` in OSC type tags).
     String(String),
     /// A blob of binary data (`b` in OSC type tags).
-    Blob(Vec<u8>),
+    Blob(#[cfg_attr(feature = "serde", serde(with = "serde_support::blob"))] Vec<u8>),
+    /// A boolean (`T` or `F` in OSC type tags). Carries no payload bytes.
+    Bool(bool),
+    /// Nil (`N` in OSC type tags). Carries no payload bytes.
+    Nil,
+    /// Infinitum (`I` in OSC type tags). Carries no payload bytes.
+    Infinitum,
+}
+
+impl OscArg {
+    /// Returns the value if this is an `Int` argument.
+    ///
+    /// ```
+    /// use osc_lib::OscArg;
+    ///
+    /// assert_eq!(OscArg::Int(3).as_int(), Some(3));
+    /// assert_eq!(OscArg::Float(3.0).as_int(), None);
+    /// ```
+    pub fn as_int(&self) -> Option<i32> {
+        match self {
+            OscArg::Int(i) => Some(*i),
+            _ => None,
+        }
+    }
+
+    /// Returns the value if this is a `Float` argument.
+    ///
+    /// ```
+    /// use osc_lib::OscArg;
+    ///
+    /// assert_eq!(OscArg::Float(0.75).as_float(), Some(0.75));
+    /// assert_eq!(OscArg::Int(1).as_float(), None);
+    /// ```
+    pub fn as_float(&self) -> Option<f32> {
+        match self {
+            OscArg::Float(f) => Some(*f),
+            _ => None,
+        }
+    }
+
+    /// Returns the value if this is a `String` argument.
+    ///
+    /// ```
+    /// use osc_lib::OscArg;
+    ///
+    /// assert_eq!(OscArg::String("Kick".to_string()).as_str(), Some("Kick"));
+    /// assert_eq!(OscArg::Int(1).as_str(), None);
+    /// ```
+    pub fn as_str(&self) -> Option<&str> {
+        match self {
+            OscArg::String(s) => Some(s),
+            _ => None,
+        }
+    }
+
+    /// Returns the bytes if this is a `Blob` argument.
+    ///
+    /// ```
+    /// use osc_lib::OscArg;
+    ///
+    /// assert_eq!(OscArg::Blob(vec![1, 2]).as_blob(), Some(&[1u8, 2][..]));
+    /// assert_eq!(OscArg::Int(1).as_blob(), None);
+    /// ```
+    pub fn as_blob(&self) -> Option<&[u8]> {
+        match self {
+            OscArg::Blob(b) => Some(b),
+            _ => None,
+        }
+    }
 }
 
 /// Helper function to calculate padded size.
@@ -124,6 +213,7 @@ fn padded_size(len: usize) -> usize {
 
 /// Represents a single OSC message, containing a path and a list of arguments.
 #[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct OscMessage {
     /// The OSC address pattern (e.g., `/ch/01/mix/fader`).
     pub path: String,
@@ -146,6 +236,102 @@ impl OscMessage {
         OscMessage { path, args }
     }
 
+    /// Starts building a message for `path`, adding arguments one call at a time.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use osc_lib::{OscArg, OscMessage};
+    ///
+    /// let built = OscMessage::builder("/ch/01/mix/fader").float(0.75).int(1).build();
+    /// let plain = OscMessage::new(
+    ///     "/ch/01/mix/fader".to_string(),
+    ///     vec![OscArg::Float(0.75), OscArg::Int(1)],
+    /// );
+    /// assert_eq!(built, plain);
+    /// ```
+    pub fn builder(path: impl Into<String>) -> OscMessageBuilder {
+        OscMessageBuilder {
+            msg: OscMessage::new(path.into(), Vec::new()),
+        }
+    }
+
+    /// Returns argument `idx` if it exists and is an `Int`.
+    ///
+    /// ```
+    /// use osc_lib::{OscArg, OscMessage};
+    ///
+    /// let msg = OscMessage::new("/-stat/selidx".to_string(), vec![OscArg::Int(4)]);
+    /// assert_eq!(msg.arg_int(0), Some(4));
+    /// assert_eq!(msg.arg_int(1), None);
+    /// ```
+    pub fn arg_int(&self, idx: usize) -> Option<i32> {
+        self.args.get(idx).and_then(OscArg::as_int)
+    }
+
+    /// Returns argument `idx` if it exists and is a `Float`.
+    ///
+    /// ```
+    /// use osc_lib::{OscArg, OscMessage};
+    ///
+    /// let msg = OscMessage::new("/ch/01/mix/fader".to_string(), vec![OscArg::Float(0.75)]);
+    /// assert_eq!(msg.arg_float(0), Some(0.75));
+    /// assert_eq!(msg.arg_int(0), None);
+    /// ```
+    pub fn arg_float(&self, idx: usize) -> Option<f32> {
+        self.args.get(idx).and_then(OscArg::as_float)
+    }
+
+    /// Returns argument `idx` if it exists and is a `String`.
+    ///
+    /// ```
+    /// use osc_lib::{OscArg, OscMessage};
+    ///
+    /// let msg = OscMessage::new(
+    ///     "/ch/01/config/name".to_string(),
+    ///     vec![OscArg::String("Kick".to_string())],
+    /// );
+    /// assert_eq!(msg.arg_str(0), Some("Kick"));
+    /// ```
+    pub fn arg_str(&self, idx: usize) -> Option<&str> {
+        self.args.get(idx).and_then(OscArg::as_str)
+    }
+
+    /// Checks that the path is a well-formed OSC address or address pattern.
+    ///
+    /// The path must start with `/` and contain only printable ASCII, without spaces
+    /// or `#`. The pattern characters `*`, `?`, `[`, `]`, `{` and `}` are allowed, and
+    /// `,` only between braces, as in `/{ch,bus}/01/mix/fader`.
+    ///
+    /// ```
+    /// use osc_lib::OscMessage;
+    ///
+    /// assert!(OscMessage::new("/ch/*/mix/fader".to_string(), vec![]).validate().is_ok());
+    /// assert!(OscMessage::new("/ch/01/mix fader".to_string(), vec![]).validate().is_err());
+    /// ```
+    ///
+    /// # Returns
+    ///
+    /// An `OscError::InvalidAddress` if the path is not valid.
+    pub fn validate(&self) -> Result<()> {
+        let invalid = || Err(OscError::InvalidAddress(self.path.clone()));
+        if !self.path.starts_with('/') {
+            return invalid();
+        }
+        let mut in_braces = false;
+        for b in self.path.bytes() {
+            match b {
+                b'{' => in_braces = true,
+                b'}' => in_braces = false,
+                b',' if in_braces => {}
+                b'#' | b',' => return invalid(),
+                b'!'..=b'~' => {}
+                _ => return invalid(),
+            }
+        }
+        Ok(())
+    }
+
     /// Deserializes an `OscMessage` from a byte slice.
     ///
     /// The byte slice should be a valid OSC 1.0 message, including the path,
@@ -159,6 +345,40 @@ impl OscMessage {
     ///
     /// A `Result` containing the deserialized `OscMessage` or an `OscError`.
     pub fn from_bytes(bytes: &[u8]) -> Result<Self> {
+        Self::from_bytes_prefix(bytes).map(|(msg, _)| msg)
+    }
+
+    /// Deserializes an `OscMessage` from the start of a byte slice.
+    ///
+    /// Unlike [`OscMessage::from_bytes`], this also reports where the message ended,
+    /// so a datagram holding several concatenated (unbundled) messages can be walked
+    /// one message at a time.
+    ///
+    /// # Arguments
+    ///
+    /// * `bytes` - The byte slice beginning with an OSC message.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing the deserialized `OscMessage` and the number of bytes it
+    /// occupied, including padding, or an `OscError`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use osc_lib::{OscArg, OscMessage};
+    ///
+    /// let first = OscMessage::new("/ch/01/mix/on".to_string(), vec![OscArg::Int(1)]);
+    /// let second = OscMessage::new("/ch/02/mix/on".to_string(), vec![OscArg::Int(0)]);
+    /// let mut datagram = first.to_bytes().unwrap();
+    /// datagram.extend(second.to_bytes().unwrap());
+    ///
+    /// let (msg, consumed) = OscMessage::from_bytes_prefix(&datagram).unwrap();
+    /// assert_eq!(msg, first);
+    /// let (msg, _) = OscMessage::from_bytes_prefix(&datagram[consumed..]).unwrap();
+    /// assert_eq!(msg, second);
+    /// ```
+    pub fn from_bytes_prefix(bytes: &[u8]) -> Result<(Self, usize)> {
         let mut cursor = Cursor::new(bytes);
 
         let path = read_osc_string(&mut cursor)?;
@@ -200,8 +420,12 @@ impl OscMessage {
                     let current_pos = cursor.position() as usize;
                     let buf_ref = cursor.get_ref();
 
+                    // The declared length comes straight off the wire, so never trust it beyond
+                    // the bytes actually remaining; this also bounds the allocation below.
                     if len > buf_ref.len().saturating_sub(current_pos) {
-                        return Err(OscError::ParseError("Unexpected end of buffer".to_string()));
+                        return Err(OscError::ParseError(
+                            "Blob length exceeds remaining bytes".to_string(),
+                        ));
                     }
                     let end_pos = current_pos.saturating_add(len);
 
@@ -220,11 +444,18 @@ impl OscMessage {
                     let next_aligned_pos = (end_pos + 3) & !3;
                     cursor.set_position(next_aligned_pos as u64);
                 }
+                'T' => args.push(OscArg::Bool(true)),
+                'F' => args.push(OscArg::Bool(false)),
+                'N' => args.push(OscArg::Nil),
+                'I' => args.push(OscArg::Infinitum),
                 _ => return Err(OscError::UnsupportedTypeTag(tag_byte as char)),
             }
         }
 
-        Ok(OscMessage { path, args })
+        // A trailing blob's padding may be missing from the buffer, so never report more
+        // bytes than were actually available.
+        let consumed = std::cmp::min(cursor.position() as usize, bytes.len());
+        Ok((OscMessage { path, args }, consumed))
     }
 
     /// Serializes an OSC message directly from a path and an iterator of argument references.
@@ -261,6 +492,7 @@ impl OscMessage {
                 OscArg::Blob(b) => {
                     args_size += 4 + padded_size(b.len());
                 }
+                OscArg::Bool(_) | OscArg::Nil | OscArg::Infinitum => {}
             }
         }
         let type_tags_size = padded_size(args_count + 2); // comma + tags + null
@@ -285,6 +517,10 @@ impl OscMessage {
                 OscArg::Float(_) => bytes.push(b'f'),
                 OscArg::String(_) => bytes.push(b's'),
                 OscArg::Blob(_) => bytes.push(b'b'),
+                OscArg::Bool(true) => bytes.push(b'T'),
+                OscArg::Bool(false) => bytes.push(b'F'),
+                OscArg::Nil => bytes.push(b'N'),
+                OscArg::Infinitum => bytes.push(b'I'),
             }
         }
         bytes.push(0); // Null terminator
@@ -313,6 +549,7 @@ impl OscMessage {
                         bytes.extend_from_slice(&[0, 0, 0][..pad_len]);
                     }
                 }
+                OscArg::Bool(_) | OscArg::Nil | OscArg::Infinitum => {}
             }
         }
 
@@ -332,13 +569,90 @@ impl OscMessage {
     }
 }
 
+/// Builds an [`OscMessage`] argument by argument. Created by [`OscMessage::builder`].
+///
+/// # Example
+///
+/// ```
+/// use osc_lib::{OscArg, OscMessage};
+///
+/// let built = OscMessage::builder("/ch/01/config")
+///     .string("Kick")
+///     .int(1)
+///     .bool(true)
+///     .blob(vec![0x01, 0x02])
+///     .build();
+/// let plain = OscMessage::new(
+///     "/ch/01/config".to_string(),
+///     vec![
+///         OscArg::String("Kick".to_string()),
+///         OscArg::Int(1),
+///         OscArg::Bool(true),
+///         OscArg::Blob(vec![0x01, 0x02]),
+///     ],
+/// );
+/// assert_eq!(built, plain);
+/// ```
+#[derive(Debug, Clone)]
+pub struct OscMessageBuilder {
+    msg: OscMessage,
+}
+
+impl OscMessageBuilder {
+    /// Appends an `Int` argument.
+    pub fn int(mut self, value: i32) -> Self {
+        self.msg.args.push(OscArg::Int(value));
+        self
+    }
+
+    /// Appends a `Float` argument.
+    pub fn float(mut self, value: f32) -> Self {
+        self.msg.args.push(OscArg::Float(value));
+        self
+    }
+
+    /// Appends a `String` argument.
+    pub fn string(mut self, value: impl Into<String>) -> Self {
+        self.msg.args.push(OscArg::String(value.into()));
+        self
+    }
+
+    /// Appends a `Blob` argument.
+    pub fn blob(mut self, value: impl Into<Vec<u8>>) -> Self {
+        self.msg.args.push(OscArg::Blob(value.into()));
+        self
+    }
+
+    /// Appends a `Bool` argument.
+    pub fn bool(mut self, value: bool) -> Self {
+        self.msg.args.push(OscArg::Bool(value));
+        self
+    }
+
+    /// Appends any argument, e.g. `OscArg::Nil`.
+    pub fn arg(mut self, arg: OscArg) -> Self {
+        self.msg.args.push(arg);
+        self
+    }
+
+    /// Returns the finished message.
+    pub fn build(self) -> OscMessage {
+        self.msg
+    }
+}
+
 impl FromStr for OscMessage {
     type Err = OscError;
     /// Creates an `OscMessage` from a string representation.
     ///
     /// The string format is the OSC path followed by a space, then the type tag string,
     /// and then a space-separated list of arguments. String arguments with spaces
-    /// should be enclosed in double quotes.
+    /// should be enclosed in double quotes. Blob arguments are hex tokens, optionally
+    /// prefixed with `0x` (e.g. `0x00ff`).
+    ///
+    /// The X32 writes `-oo` for faders and levels at negative infinity. A float token
+    /// of `-oo` (case-insensitive) is parsed as `0.0`, the bottom of the console's
+    /// normalized 0.0-1.0 fader range, which is what the X32 itself reports over OSC.
     ///
     /// # Arguments
     ///
@@ -379,6 +693,26 @@ impl FromStr for OscMessage {
             // OPTIMIZATION: Use .bytes() instead of .chars() to bypass UTF-8 decoding
             // overhead since OSC type tags are guaranteed to be ASCII.
             for tag in type_tags[1..].bytes() {
+                // Tags without a payload do not consume a value token.
+                match tag {
+                    b'T' => {
+                        args.push(OscArg::Bool(true));
+                        continue;
+                    }
+                    b'F' => {
+                        args.push(OscArg::Bool(false));
+                        continue;
+                    }
+                    b'N' => {
+                        args.push(OscArg::Nil);
+                        continue;
+                    }
+                    b'I' => {
+                        args.push(OscArg::Infinitum);
+                        continue;
+                    }
+                    _ => {}
+                }
                 let val_str = it.next().ok_or(OscError::ParseError(format!(
                     "Missing value for type tag '{}'",
                     tag as char
@@ -390,14 +724,24 @@ impl FromStr for OscMessage {
                         args.push(OscArg::Int(val));
                     }
                     b'f' => {
-                        let val = f32::from_str(val_str)
-                            .map_err(|e| OscError::ParseError(e.to_string()))?;
+                        let val = if val_str.eq_ignore_ascii_case("-oo") {
+                            0.0
+                        } else {
+                            f32::from_str(val_str)
+                                .map_err(|e| OscError::ParseError(e.to_string()))?
+                        };
                         args.push(OscArg::Float(val));
                     }
                     b's' => {
                         args.push(OscArg::String(val_str.to_string()));
                     }
                     b'b' => {
+                        // Blobs are written as `0x`-prefixed hex by `Display`; a bare
+                        // hex token is accepted as well.
+                        let val_str = val_str
+                            .strip_prefix("0x")
+                            .or_else(|| val_str.strip_prefix("0X"))
+                            .unwrap_or(val_str);
                         if val_str.len() % 2 != 0 {
                             return Err(OscError::ParseError(format!(
                                 "Invalid hex string length for blob: {}",
@@ -477,6 +821,79 @@ impl std::fmt::Display for OscMessage {
     /// assert_eq!(msg_str, "/ch/01/mix/fader ,f 0.75");
     /// ```
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.write_text(f, FloatStyle::Exact)
+    }
+}
+
+/// How `OscMessage::write_text` renders float arguments.
+#[derive(Clone, Copy)]
+enum FloatStyle {
+    /// The shortest representation that parses back to the same `f32`.
+    Exact,
+    /// The fixed precision the console and the C tools use.
+    X32,
+}
+
+/// Writes a float with the precision the X32 and the original C tools print.
+///
+/// The number of decimals shrinks as the magnitude grows (`0.7500`, `20.000`,
+/// `124.70`, `1000.0`), so values line up with the console's own output. The
+/// decimal point is never dropped. Non-finite values are written as `inf`,
+/// `-inf` or `NaN`.
+///
+/// # Arguments
+///
+/// * `w` - The writer to append to.
+/// * `val` - The float to write.
+///
+/// # Example
+///
+/// ```
+/// let mut s = String::new();
+/// osc_lib::write_x32_float(&mut s, 0.75).unwrap();
+/// assert_eq!(s, "0.7500");
+/// ```
+pub fn write_x32_float<W: std::fmt::Write>(w: &mut W, val: f32) -> std::fmt::Result {
+    if !val.is_finite() {
+        return write!(w, "{}", val);
+    }
+    let magnitude = val.abs();
+    let decimals = if magnitude < 10.0 {
+        4
+    } else if magnitude < 100.0 {
+        3
+    } else if magnitude < 1000.0 {
+        2
+    } else {
+        1
+    };
+    write!(w, "{:.*}", decimals, val)
+}
+
+impl OscMessage {
+    /// Converts the message to text using the console's float precision.
+    ///
+    /// This is the same as `to_string()` except that floats are written by
+    /// [`write_x32_float`], so saved snippets compare cleanly against values
+    /// read back from the console.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use osc_lib::{OscArg, OscMessage};
+    ///
+    /// let msg = OscMessage::new("/ch/01/mix/fader".to_string(), vec![OscArg::Float(0.75)]);
+    /// assert_eq!(msg.to_string_x32(), "/ch/01/mix/fader ,f 0.7500");
+    /// ```
+    pub fn to_string_x32(&self) -> String {
+        let mut s = String::new();
+        // Writing to a String cannot fail.
+        let _ = self.write_text(&mut s, FloatStyle::X32);
+        s
+    }
+
+    /// Writes the text form of the message shared by `Display` and `to_string_x32`.
+    fn write_text<W: std::fmt::Write>(&self, f: &mut W, style: FloatStyle) -> std::fmt::Result {
         f.write_str(&self.path)?;
         if !self.args.is_empty() {
             f.write_str(" ,")?;
@@ -486,16 +903,40 @@ impl std::fmt::Display for OscMessage {
                     OscArg::Float(_) => f.write_str("f")?,
                     OscArg::String(_) => f.write_str("s")?,
                     OscArg::Blob(_) => f.write_str("b")?,
+                    OscArg::Bool(true) => f.write_str("T")?,
+                    OscArg::Bool(false) => f.write_str("F")?,
+                    OscArg::Nil => f.write_str("N")?,
+                    OscArg::Infinitum => f.write_str("I")?,
                 }
             }
             for arg in &self.args {
+                // Payload-less arguments are fully described by their type tag.
+                if matches!(arg, OscArg::Bool(_) | OscArg::Nil | OscArg::Infinitum) {
+                    continue;
+                }
                 f.write_str(" ")?;
                 match arg {
                     OscArg::Int(val) => write!(f, "{}", val)?,
-                    OscArg::Float(val) => write!(f, "{}", val)?,
+                    OscArg::Float(val) => match style {
+                        // Always keep the decimal point so `1.0` doesn't read back as an int.
+                        FloatStyle::Exact if val.fract() == 0.0 => write!(f, "{:.1}", val)?,
+                        FloatStyle::Exact => write!(f, "{}", val)?,
+                        FloatStyle::X32 => write_x32_float(f, *val)?,
+                    },
                     OscArg::String(val) => {
                         f.write_str("\"")?;
-                        f.write_str(val)?;
+                        if val.contains(['"', '\\']) {
+                            // Escape embedded quotes and backslashes so that `tokenize`
+                            // reads the string back as a single, unchanged token.
+                            for c in val.chars() {
+                                if c == '"' || c == '\\' {
+                                    f.write_char('\\')?;
+                                }
+                                f.write_char(c)?;
+                            }
+                        } else {
+                            f.write_str(val)?;
+                        }
                         f.write_str("\"")?;
                     }
                     OscArg::Blob(val) => {
@@ -503,12 +944,14 @@ impl std::fmt::Display for OscMessage {
                         // with formatting `{:02x}`. This avoids the machinery of std::fmt and is
                         // significantly faster for large binary blobs in hot paths.
                         static HEX: &[u8; 16] = b"0123456789abcdef";
+                        f.write_str("0x")?;
                         for byte in val {
                             f.write_char(HEX[(byte >> 4) as usize] as char)?;
                             f.write_char(HEX[(byte & 0x0f) as usize] as char)?;
                         }
                         Ok(())
                     }?,
+                    OscArg::Bool(_) | OscArg::Nil | OscArg::Infinitum => {}
                 }
             }
         }
@@ -589,6 +1032,155 @@ pub fn tokenize(s: &str) -> Result<Vec<String>> {
     Ok(tokens)
 }
 
+/// Returns `true` if the given OSC address contains any pattern-matching characters.
+///
+/// # Arguments
+///
+/// * `address` - The OSC address or address pattern to inspect.
+///
+/// # Returns
+///
+/// `true` if the address contains `?`, `*`, `[` or `{`.
+pub fn is_address_pattern(address: &str) -> bool {
+    address
+        .bytes()
+        .any(|b| matches!(b, b'?' | b'*' | b'[' | b'{'))
+}
+
+/// Matches an OSC address against an OSC 1.0 address pattern.
+///
+/// The pattern is matched part by part (split on `/`), using the following rules:
+///
+/// * `?` matches any single character.
+/// * `*` matches any sequence of zero or more characters.
+/// * `[abc]`, `[a-z]` match any character in the set or range; `[!...]` negates it.
+/// * `{foo,bar}` matches any of the comma-separated alternatives.
+///
+/// # Arguments
+///
+/// * `pattern` - The OSC address pattern (e.g., `/ch/[0-9][0-9]/mix/fader`).
+/// * `address` - The concrete OSC address to test (e.g., `/ch/07/mix/fader`).
+///
+/// # Returns
+///
+/// `true` if the address matches the pattern.
+///
+/// # Example
+///
+/// ```
+/// use osc_lib::address_matches;
+///
+/// assert!(address_matches("/ch/*/mix/fader", "/ch/07/mix/fader"));
+/// assert!(!address_matches("/ch/*/mix/fader", "/bus/07/mix/fader"));
+/// ```
+pub fn address_matches(pattern: &str, address: &str) -> bool {
+    let mut pattern_parts = pattern.split('/');
+    let mut address_parts = address.split('/');
+    loop {
+        match (pattern_parts.next(), address_parts.next()) {
+            (Some(p), Some(a)) => {
+                if !match_part(p.as_bytes(), a.as_bytes()) {
+                    return false;
+                }
+            }
+            (None, None) => return true,
+            _ => return false,
+        }
+    }
+}
+
+/// Matches a single address part (no `/`) against a single pattern part.
+///
+/// The pattern is walked once while tracking every position in `part` that the
+/// pattern so far can end at. This keeps matching at O(pattern * part) even for
+/// hostile patterns such as `*a*a*a...b`, which a naive backtracking matcher
+/// takes exponential time on. Patterns arrive straight off the network, so this
+/// matters.
+fn match_part(pattern: &[u8], part: &[u8]) -> bool {
+    let mut reach = vec![false; part.len() + 1];
+    reach[0] = true;
+    let mut next = vec![false; part.len() + 1];
+
+    let mut p = 0;
+    while p < pattern.len() {
+        next.iter_mut().for_each(|r| *r = false);
+
+        match pattern[p] {
+            b'?' => {
+                next[1..].copy_from_slice(&reach[..part.len()]);
+                p += 1;
+            }
+            b'*' => {
+                let mut seen = false;
+                for i in 0..=part.len() {
+                    seen |= reach[i];
+                    next[i] = seen;
+                }
+                p += 1;
+            }
+            b'[' => {
+                let Some(close) = pattern[p + 1..].iter().position(|&b| b == b']') else {
+                    return false;
+                };
+                let body = &pattern[p + 1..p + 1 + close];
+                let (negate, class) = match body.split_first() {
+                    Some((b'!', class)) => (true, class),
+                    _ => (false, body),
+                };
+                for i in 0..part.len() {
+                    next[i + 1] = reach[i] && class_contains(class, part[i]) != negate;
+                }
+                p += close + 2;
+            }
+            b'{' => {
+                let Some(close) = pattern[p + 1..].iter().position(|&b| b == b'}') else {
+                    return false;
+                };
+                for alt in pattern[p + 1..p + 1 + close].split(|&b| b == b',') {
+                    for i in 0..=part.len() {
+                        if reach[i] && part[i..].starts_with(alt) {
+                            next[i + alt.len()] = true;
+                        }
+                    }
+                }
+                p += close + 2;
+            }
+            c => {
+                for i in 0..part.len() {
+                    next[i + 1] = reach[i] && part[i] == c;
+                }
+                p += 1;
+            }
+        }
+
+        std::mem::swap(&mut reach, &mut next);
+        if !reach.contains(&true) {
+            return false;
+        }
+    }
+
+    reach[part.len()]
+}
+
+/// Returns `true` if `c` is in a `[...]` character class body such as `abc` or `0-9`.
+fn class_contains(class: &[u8], c: u8) -> bool {
+    let mut i = 0;
+    while i < class.len() {
+        if i + 2 < class.len() && class[i + 1] == b'-' {
+            if class[i] <= c && c <= class[i + 2] {
+                return true;
+            }
+            i += 3;
+        } else {
+            if class[i] == c {
+                return true;
+            }
+            i += 1;
+        }
+    }
+    false
+}
+
 /// Reads a null-terminated and 4-byte padded OSC string from a cursor, returning raw bytes.
 ///
 /// # Arguments
@@ -608,13 +1200,12 @@ fn read_osc_string_bytes<'a>(cursor: &mut Cursor<&'a [u8]>) -> Result<&'a [u8]>
 
     let remainder = &buf[pos..];
 
-    // Find the null terminator byte (0)
+    // Find the null terminator byte (0). The search is bounded by the end of the
+    // buffer, so a truncated final string is reported rather than read into padding.
     let null_pos = match remainder.iter().position(|&b| b == 0) {
         Some(p) => p,
         None => {
-            return Err(OscError::ParseError(
-                "Missing null terminator in string".to_string(),
-            ));
+            return Err(OscError::ParseError("unterminated OSC string".to_string()));
         }
     };
 
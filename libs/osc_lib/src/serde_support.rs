@@ -0,0 +1,73 @@
+//! Field helpers for the optional `serde` representation of [`OscArg`](crate::OscArg).
+//!
+//! Arguments serialize as `{"type": "...", "value": ...}`. Most values map directly
+//! onto the data model, but two need help to survive formats such as JSON:
+//! blobs are written as base64 strings, and non-finite floats, which JSON cannot
+//! represent, fall back to the strings `"NaN"`, `"inf"` and `"-inf"`.
+
+/// Serializes an `f32`, writing non-finite values as strings.
+pub(crate) mod float {
+    use serde::de::{self, Deserializer, Visitor};
+    use serde::Serializer;
+    use std::fmt;
+
+    pub fn serialize<S: Serializer>(val: &f32, serializer: S) -> Result<S::Ok, S::Error> {
+        if val.is_finite() {
+            serializer.serialize_f32(*val)
+        } else if val.is_nan() {
+            serializer.serialize_str("NaN")
+        } else if val.is_sign_positive() {
+            serializer.serialize_str("inf")
+        } else {
+            serializer.serialize_str("-inf")
+        }
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<f32, D::Error> {
+        deserializer.deserialize_any(FloatVisitor)
+    }
+
+    struct FloatVisitor;
+
+    impl Visitor<'_> for FloatVisitor {
+        type Value = f32;
+
+        fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+            f.write_str("a number or one of \"NaN\", \"inf\", \"-inf\"")
+        }
+
+        fn visit_f64<E: de::Error>(self, v: f64) -> Result<f32, E> {
+            Ok(v as f32)
+        }
+
+        fn visit_i64<E: de::Error>(self, v: i64) -> Result<f32, E> {
+            Ok(v as f32)
+        }
+
+        fn visit_u64<E: de::Error>(self, v: u64) -> Result<f32, E> {
+            Ok(v as f32)
+        }
+
+        fn visit_str<E: de::Error>(self, v: &str) -> Result<f32, E> {
+            // Rust's float parser accepts "NaN", "inf" and "-inf" as written above.
+            v.parse()
+                .map_err(|_| E::invalid_value(de::Unexpected::Str(v), &self))
+        }
+    }
+}
+
+/// Serializes a byte buffer as a standard base64 string.
+pub(crate) mod blob {
+    use base64::engine::general_purpose::STANDARD;
+    use base64::Engine;
+    use serde::{Deserialize, Deserializer, Serializer};
+
+    pub fn serialize<S: Serializer>(val: &[u8], serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&STANDARD.encode(val))
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Vec<u8>, D::Error> {
+        let encoded = String::deserialize(deserializer)?;
+        STANDARD.decode(encoded).map_err(serde::de::Error::custom)
+    }
+}
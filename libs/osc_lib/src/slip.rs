@@ -0,0 +1,111 @@
+//! SLIP (RFC 1055) framing for carrying OSC packets over stream transports such as TCP.
+//!
+//! UDP preserves message boundaries, but a byte stream does not. OSC 1.1 specifies
+//! SLIP framing for streams: each packet is terminated by an `END` byte, and any
+//! `END` or `ESC` bytes inside the packet are escaped.
+//!
+//! # Example
+//!
+//! ```
+//! use osc_lib::slip::{encode, SlipDecoder};
+//! use osc_lib::{OscArg, OscMessage};
+//!
+//! let msg = OscMessage::new("/ch/01/mix/fader".to_string(), vec![OscArg::Float(0.75)]);
+//! let framed = encode(&msg.to_bytes().unwrap());
+//!
+//! let mut decoder = SlipDecoder::new();
+//! let frames = decoder.decode(&framed);
+//! assert_eq!(OscMessage::from_bytes(&frames[0]).unwrap(), msg);
+//! ```
+
+/// Marks the end of a frame.
+pub const END: u8 = 0xC0;
+/// Introduces an escaped byte.
+pub const ESC: u8 = 0xDB;
+/// Follows `ESC` to represent a literal `END` byte.
+pub const ESC_END: u8 = 0xDC;
+/// Follows `ESC` to represent a literal `ESC` byte.
+pub const ESC_ESC: u8 = 0xDD;
+
+/// Encodes a packet as a single SLIP frame.
+///
+/// The frame starts and ends with `END`, so any line noise received before it is
+/// flushed as an empty frame, which [`SlipDecoder`] discards.
+///
+/// # Arguments
+///
+/// * `packet` - The raw packet bytes, e.g. from `OscMessage::to_bytes`.
+///
+/// # Returns
+///
+/// The framed bytes, ready to be written to the stream.
+pub fn encode(packet: &[u8]) -> Vec<u8> {
+    let mut framed = Vec::with_capacity(packet.len() + 2);
+    framed.push(END);
+    for &byte in packet {
+        match byte {
+            END => framed.extend_from_slice(&[ESC, ESC_END]),
+            ESC => framed.extend_from_slice(&[ESC, ESC_ESC]),
+            _ => framed.push(byte),
+        }
+    }
+    framed.push(END);
+    framed
+}
+
+/// A streaming SLIP decoder.
+///
+/// Bytes can be fed in arbitrary chunks as they arrive from the stream; partial
+/// frames are buffered until their terminating `END` byte is seen.
+#[derive(Debug, Default)]
+pub struct SlipDecoder {
+    buffer: Vec<u8>,
+    escaped: bool,
+}
+
+impl SlipDecoder {
+    /// Creates a new decoder with an empty buffer.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Feeds a single byte to the decoder.
+    ///
+    /// # Returns
+    ///
+    /// The completed frame if `byte` terminated a non-empty frame, otherwise `None`.
+    pub fn push(&mut self, byte: u8) -> Option<Vec<u8>> {
+        if self.escaped {
+            self.escaped = false;
+            // RFC 1055 leaves an invalid escape sequence as the byte itself.
+            self.buffer.push(match byte {
+                ESC_END => END,
+                ESC_ESC => ESC,
+                other => other,
+            });
+            return None;
+        }
+
+        match byte {
+            END if self.buffer.is_empty() => None,
+            END => Some(std::mem::take(&mut self.buffer)),
+            ESC => {
+                self.escaped = true;
+                None
+            }
+            _ => {
+                self.buffer.push(byte);
+                None
+            }
+        }
+    }
+
+    /// Feeds a chunk of bytes to the decoder.
+    ///
+    /// # Returns
+    ///
+    /// Every frame completed by this chunk, in order.
+    pub fn decode(&mut self, bytes: &[u8]) -> Vec<Vec<u8>> {
+        bytes.iter().filter_map(|&b| self.push(b)).collect()
+    }
+}
@@ -194,3 +194,479 @@ fn test_negative_blob_size() {
         _ => panic!("Expected ParseError, got {:?}", result),
     }
 }
+
+#[test]
+fn test_oversized_blob_length() {
+    let mut bytes = vec![];
+    write_osc_string(&mut bytes, "/test").unwrap();
+    write_osc_string(&mut bytes, ",b").unwrap();
+    bytes.extend_from_slice(&i32::MAX.to_be_bytes()); // Claims ~2 GB
+    bytes.extend_from_slice(&[1, 2, 3, 4]);
+
+    let result = OscMessage::from_bytes(&bytes);
+    match result {
+        Err(OscError::ParseError(msg)) => assert_eq!(msg, "Blob length exceeds remaining bytes"),
+        _ => panic!("Expected ParseError, got {:?}", result),
+    }
+}
+
+#[test]
+fn test_blob_length_fuzz_never_panics() {
+    let mut header = vec![];
+    write_osc_string(&mut header, "/test").unwrap();
+    write_osc_string(&mut header, ",bi").unwrap();
+
+    for declared in [0, 1, 3, 4, 5, 7, 8, 9, 255, 65536, i32::MAX] {
+        for payload_len in 0..12 {
+            let mut bytes = header.clone();
+            bytes.extend_from_slice(&declared.to_be_bytes());
+            bytes.extend(std::iter::repeat_n(0xAA, payload_len));
+
+            if let Ok(message) = OscMessage::from_bytes(&bytes) {
+                match &message.args[0] {
+                    OscArg::Blob(b) => assert_eq!(b.len(), declared as usize),
+                    other => panic!("Expected blob, got {:?}", other),
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn test_payloadless_tags_from_bytes() {
+    let mut bytes = vec![];
+    write_osc_string(&mut bytes, "/flags").unwrap();
+    write_osc_string(&mut bytes, ",TiFNI").unwrap();
+    bytes.extend_from_slice(&7i32.to_be_bytes());
+
+    let message = OscMessage::from_bytes(&bytes).unwrap();
+    assert_eq!(
+        message.args,
+        vec![
+            OscArg::Bool(true),
+            OscArg::Int(7),
+            OscArg::Bool(false),
+            OscArg::Nil,
+            OscArg::Infinitum,
+        ]
+    );
+}
+
+#[test]
+fn test_payloadless_tags_roundtrip_to_bytes() {
+    let original_message = OscMessage {
+        path: "/flags".to_string(),
+        args: vec![
+            OscArg::Bool(true),
+            OscArg::Bool(false),
+            OscArg::Nil,
+            OscArg::Infinitum,
+        ],
+    };
+
+    let bytes = original_message.to_bytes().unwrap();
+    // Path (8) + ",TFNI\0" padded to 8; no argument payload.
+    assert_eq!(bytes.len(), 16);
+    let roundtrip_message = OscMessage::from_bytes(&bytes).unwrap();
+
+    assert_eq!(original_message, roundtrip_message);
+}
+
+#[test]
+fn test_payloadless_tags_roundtrip_to_string() {
+    let original_message = OscMessage {
+        path: "/flags".to_string(),
+        args: vec![
+            OscArg::Bool(true),
+            OscArg::Int(3),
+            OscArg::Nil,
+            OscArg::Bool(false),
+            OscArg::Infinitum,
+        ],
+    };
+
+    let s = original_message.to_string();
+    assert_eq!(s, "/flags ,TiNFI 3");
+    let roundtrip_message = OscMessage::from_str(&s).unwrap();
+
+    assert_eq!(original_message, roundtrip_message);
+}
+
+#[test]
+fn test_address_matches() {
+    let cases = [
+        ("/ch/[0-9][0-9]/mix/fader", "/ch/07/mix/fader", true),
+        ("/ch/[0-9][0-9]/mix/fader", "/ch/7/mix/fader", false),
+        ("/ch/[0-9][0-9]/mix/fader", "/ch/07/mix/on", false),
+        ("/ch/*/mix/fader", "/ch/07/mix/fader", true),
+        ("/ch/*/mix/fader", "/ch/07/08/mix/fader", false),
+        ("/ch/0?/mix/fader", "/ch/07/mix/fader", true),
+        ("/ch/0?/mix/fader", "/ch/17/mix/fader", false),
+        ("/ch/[!0]7/mix/fader", "/ch/17/mix/fader", true),
+        ("/ch/[!0]7/mix/fader", "/ch/07/mix/fader", false),
+        ("/ch/0[135]/mix/on", "/ch/03/mix/on", true),
+        ("/ch/0[135]/mix/on", "/ch/04/mix/on", false),
+        ("/{ch,bus}/01/mix/fader", "/bus/01/mix/fader", true),
+        ("/{ch,bus}/01/mix/fader", "/mtx/01/mix/fader", false),
+        ("/ch/01/mix/{fader,pan}", "/ch/01/mix/pan", true),
+        ("/ch/01/mix/*", "/ch/01/mix", false),
+        ("/ch/01/mix/fader", "/ch/01/mix/fader", true),
+        ("/ch/[0-9/mix/fader", "/ch/1/mix/fader", false),
+        ("/*a*b", "/xaxxb", true),
+        ("/*a*b", "/xaxx", false),
+        ("/ch/0{,1}7/mix/fader", "/ch/07/mix/fader", true),
+        ("/ch/0{,1}7/mix/fader", "/ch/017/mix/fader", true),
+        ("/*?", "/", false),
+    ];
+
+    for (pattern, address, expected) in cases {
+        assert_eq!(
+            address_matches(pattern, address),
+            expected,
+            "pattern {} against {}",
+            pattern,
+            address
+        );
+    }
+}
+
+#[test]
+fn test_address_matches_pathological_pattern_is_fast() {
+    let start = std::time::Instant::now();
+
+    let pattern = format!("/{}b", "*a".repeat(20));
+    let address = format!("/{}", "a".repeat(40));
+    assert!(!address_matches(&pattern, &address));
+
+    let pattern = format!("/{}z", "*".repeat(40));
+    assert!(!address_matches(&pattern, "/headamp"));
+
+    let pattern = format!("/{}", "*".repeat(4000));
+    assert!(address_matches(&pattern, "/headamp"));
+
+    assert!(start.elapsed() < std::time::Duration::from_secs(1));
+}
+
+#[test]
+fn test_is_address_pattern() {
+    assert!(is_address_pattern("/ch/*/mix/fader"));
+    assert!(is_address_pattern("/ch/0[1-3]/mix/fader"));
+    assert!(is_address_pattern("/{ch,bus}/01/mix/fader"));
+    assert!(!is_address_pattern("/ch/01/mix/fader"));
+}
+
+#[test]
+fn test_validate_address() {
+    let validate = |path: &str| OscMessage::new(path.to_string(), vec![]).validate();
+
+    assert!(validate("/ch/01/mix/fader").is_ok());
+    assert!(validate("/-stat/solosw/01").is_ok());
+    assert!(validate("/ch/0[1-3]/mix/*").is_ok());
+    assert!(validate("/{ch,bus}/01/mix/fader").is_ok());
+    assert!(validate("/").is_ok());
+
+    assert!(matches!(
+        validate("/ch/01/mix fader"),
+        Err(OscError::InvalidAddress(path)) if path == "/ch/01/mix fader"
+    ));
+    assert!(matches!(
+        validate("ch/01/mix/fader"),
+        Err(OscError::InvalidAddress(_))
+    ));
+    assert!(validate("").is_err());
+    assert!(validate("/ch/01\n/mix/fader").is_err());
+    assert!(validate("/ch/01/mix/fader,f").is_err());
+    assert!(validate("/#bundle").is_err());
+    assert!(validate("/ch/é").is_err());
+}
+
+#[test]
+fn test_unterminated_string() {
+    let bytes = b"/ch/01/config/name".to_vec();
+    let mut cursor = Cursor::new(&bytes[..]);
+
+    match read_osc_string(&mut cursor) {
+        Err(OscError::ParseError(msg)) => assert_eq!(msg, "unterminated OSC string"),
+        other => panic!("Expected ParseError, got {:?}", other),
+    }
+
+    // A truncated string argument after a valid path and type tag
+    let mut bytes = vec![];
+    write_osc_string(&mut bytes, "/ch/01/config/name").unwrap();
+    write_osc_string(&mut bytes, ",s").unwrap();
+    bytes.extend_from_slice(b"Vox");
+
+    match OscMessage::from_bytes(&bytes) {
+        Err(OscError::ParseError(msg)) => assert_eq!(msg, "unterminated OSC string"),
+        other => panic!("Expected ParseError, got {:?}", other),
+    }
+}
+
+#[test]
+fn test_blob_to_string_uses_hex_prefix() {
+    let message = OscMessage {
+        path: "/blob".to_string(),
+        args: vec![OscArg::Blob(vec![0x00, 0xff])],
+    };
+    assert_eq!(message.to_string(), "/blob ,b 0x00ff");
+
+    let parsed = OscMessage::from_str("/blob ,b 0X00FF").unwrap();
+    assert_eq!(parsed, message);
+}
+
+#[test]
+fn test_empty_blob_roundtrip_to_string() {
+    let original_message = OscMessage {
+        path: "/blob".to_string(),
+        args: vec![OscArg::Blob(vec![]), OscArg::Int(1)],
+    };
+
+    let s = original_message.to_string();
+    let roundtrip_message = OscMessage::from_str(&s).unwrap();
+
+    assert_eq!(original_message, roundtrip_message);
+}
+
+#[test]
+fn test_escaped_quote_in_quoted_string() {
+    let s = r#"/ch/01/config/name ,s "He said \"hi\"""#;
+    let message = OscMessage::from_str(s).unwrap();
+    assert_eq!(
+        message.args,
+        vec![OscArg::String("He said \"hi\"".to_string())]
+    );
+
+    // Display escapes the embedded quotes so the message round-trips
+    assert_eq!(message.to_string(), s);
+    assert_eq!(OscMessage::from_str(&message.to_string()).unwrap(), message);
+}
+
+#[test]
+fn test_backslash_in_string_roundtrip() {
+    let message = OscMessage {
+        path: "/ch/01/config/name".to_string(),
+        args: vec![OscArg::String(r"C:\show".to_string())],
+    };
+    let s = message.to_string();
+    assert_eq!(s, r#"/ch/01/config/name ,s "C:\\show""#);
+    assert_eq!(OscMessage::from_str(&s).unwrap(), message);
+}
+
+#[test]
+fn test_message_from_str_minus_infinity_fader() {
+    let message = OscMessage::from_str("/ch/01/mix/fader ,f -oo").unwrap();
+    assert_eq!(message.path, "/ch/01/mix/fader");
+    assert_eq!(message.args, vec![OscArg::Float(0.0)]);
+
+    let message = OscMessage::from_str("/ch/01/mix/01/level ,f -OO").unwrap();
+    assert_eq!(message.args, vec![OscArg::Float(0.0)]);
+}
+
+#[test]
+fn test_slip_encode_escapes_end_and_esc() {
+    let packet = [0x01, slip::END, 0x02, slip::ESC, 0x03];
+    let framed = slip::encode(&packet);
+    assert_eq!(
+        framed,
+        vec![
+            slip::END,
+            0x01,
+            slip::ESC,
+            slip::ESC_END,
+            0x02,
+            slip::ESC,
+            slip::ESC_ESC,
+            0x03,
+            slip::END
+        ]
+    );
+
+    let mut decoder = slip::SlipDecoder::new();
+    assert_eq!(decoder.decode(&framed), vec![packet.to_vec()]);
+}
+
+#[test]
+fn test_slip_decoder_streams_across_chunks() {
+    let first = OscMessage::new("/ch/01/mix/fader".to_string(), vec![OscArg::Float(0.5)]);
+    let second = OscMessage::new(
+        "/blob".to_string(),
+        vec![OscArg::Blob(vec![slip::END, slip::ESC, slip::END])],
+    );
+
+    let mut stream = slip::encode(&first.to_bytes().unwrap());
+    stream.extend(slip::encode(&second.to_bytes().unwrap()));
+
+    let mut decoder = slip::SlipDecoder::new();
+    let mut frames = Vec::new();
+    for chunk in stream.chunks(3) {
+        frames.extend(decoder.decode(chunk));
+    }
+
+    assert_eq!(frames.len(), 2);
+    assert_eq!(OscMessage::from_bytes(&frames[0]).unwrap(), first);
+    assert_eq!(OscMessage::from_bytes(&frames[1]).unwrap(), second);
+}
+
+#[test]
+fn test_float_display_keeps_decimal_point() {
+    let message = OscMessage::new(
+        "/test".to_string(),
+        vec![OscArg::Float(1.0), OscArg::Float(-0.0), OscArg::Float(0.1)],
+    );
+    let s = message.to_string();
+    assert_eq!(s, "/test ,fff 1.0 -0.0 0.1");
+    assert_eq!(OscMessage::from_str(&s).unwrap(), message);
+}
+
+#[test]
+fn test_to_string_x32_float_precision() {
+    let message = OscMessage::new(
+        "/test".to_string(),
+        vec![
+            OscArg::Float(0.75),
+            OscArg::Float(-80.0),
+            OscArg::Float(124.7),
+            OscArg::Float(20000.0),
+            OscArg::Int(1),
+        ],
+    );
+    assert_eq!(
+        message.to_string_x32(),
+        "/test ,ffffi 0.7500 -80.000 124.70 20000.0 1"
+    );
+}
+
+#[test]
+fn test_from_bytes_prefix_walks_concatenated_messages() {
+    let messages = vec![
+        OscMessage::new("/ch/01/mix/fader".to_string(), vec![OscArg::Float(0.5)]),
+        OscMessage::new(
+            "/ch/02/config/name".to_string(),
+            vec![OscArg::String("Snare".to_string())],
+        ),
+        OscMessage::new("/blob".to_string(), vec![OscArg::Blob(vec![1, 2, 3])]),
+        OscMessage::new("/xremote".to_string(), vec![]),
+    ];
+    let mut datagram = Vec::new();
+    for msg in &messages {
+        datagram.extend(msg.to_bytes().unwrap());
+    }
+
+    let mut offset = 0;
+    let mut parsed = Vec::new();
+    while offset < datagram.len() {
+        let (msg, consumed) = OscMessage::from_bytes_prefix(&datagram[offset..]).unwrap();
+        assert_eq!(consumed % 4, 0);
+        parsed.push(msg);
+        offset += consumed;
+    }
+    assert_eq!(offset, datagram.len());
+    assert_eq!(parsed, messages);
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn test_serde_json_round_trip() {
+    let message = OscMessage::new(
+        "/ch/01/config/name".to_string(),
+        vec![
+            OscArg::Int(1),
+            OscArg::Float(0.75),
+            OscArg::String("Kick".to_string()),
+            OscArg::Blob(vec![0, 1, 2, 0xC0]),
+            OscArg::Bool(true),
+            OscArg::Nil,
+            OscArg::Infinitum,
+            OscArg::Float(f32::INFINITY),
+            OscArg::Float(f32::NEG_INFINITY),
+        ],
+    );
+
+    let json = serde_json::to_string(&message).unwrap();
+    assert!(json.contains(r#"{"type":"float","value":0.75}"#));
+    assert!(json.contains(r#"{"type":"blob","value":"AAECwA=="}"#));
+    assert!(json.contains(r#"{"type":"float","value":"-inf"}"#));
+    assert_eq!(serde_json::from_str::<OscMessage>(&json).unwrap(), message);
+
+    // NaN never compares equal, so check it separately.
+    let nan = OscMessage::new("/nan".to_string(), vec![OscArg::Float(f32::NAN)]);
+    let json = serde_json::to_string(&nan).unwrap();
+    assert!(json.contains(r#""value":"NaN""#));
+    let parsed: OscMessage = serde_json::from_str(&json).unwrap();
+    assert!(parsed.arg_float(0).unwrap().is_nan());
+}
+
+#[test]
+fn test_bundle_round_trip_with_nesting() {
+    let inner = OscBundle::new(vec![OscMessage::new(
+        "/ch/03/mix/on".to_string(),
+        vec![OscArg::Int(1)],
+    )
+    .into()]);
+    let bundle = OscBundle {
+        timetag: 0x0123_4567_89ab_cdef,
+        content: vec![
+            OscMessage::new("/ch/01/mix/fader".to_string(), vec![OscArg::Float(0.5)]).into(),
+            inner.into(),
+            OscMessage::new(
+                "/ch/02/config/name".to_string(),
+                vec![OscArg::String("Snare".to_string())],
+            )
+            .into(),
+        ],
+    };
+
+    let bytes = bundle.to_bytes().unwrap();
+    assert!(bytes.starts_with(b"#bundle\0"));
+    let packet = OscPacket::from_bytes(&bytes).unwrap();
+    assert_eq!(packet, OscPacket::Bundle(bundle));
+
+    let paths: Vec<_> = packet.messages().map(|m| m.path.as_str()).collect();
+    assert_eq!(
+        paths,
+        ["/ch/01/mix/fader", "/ch/03/mix/on", "/ch/02/config/name"]
+    );
+}
+
+#[test]
+fn test_bundle_rejects_oversized_element() {
+    let mut bytes = OscBundle::new(vec![]).to_bytes().unwrap();
+    bytes.extend_from_slice(&64i32.to_be_bytes());
+    bytes.extend_from_slice(b"/abc\0\0\0\0,\0\0\0");
+    assert!(OscPacket::from_bytes(&bytes).is_err());
+}
+
+#[test]
+fn test_decoder_yields_back_to_back_messages() {
+    let first = OscMessage::new("/ch/01/mix/fader".to_string(), vec![OscArg::Float(0.5)]);
+    let second = OscMessage::new("/ch/02/config/name".to_string(), vec![]);
+    let mut buf = first.to_bytes().unwrap();
+    buf.extend(second.to_bytes().unwrap());
+
+    let packets: Vec<OscPacket> = OscDecoder::new(&buf).map(Result::unwrap).collect();
+    assert_eq!(
+        packets,
+        vec![OscPacket::Message(first), OscPacket::Message(second)]
+    );
+}
+
+#[test]
+fn test_decoder_reads_trailing_bundle_and_stops_on_error() {
+    let msg = OscMessage::new("/xremote".to_string(), vec![]);
+    let bundle = OscBundle::new(vec![
+        OscMessage::new("/ch/01/mix/on".to_string(), vec![OscArg::Int(1)]).into(),
+        OscMessage::new("/ch/02/mix/on".to_string(), vec![OscArg::Int(0)]).into(),
+    ]);
+    let mut buf = msg.to_bytes().unwrap();
+    buf.extend(bundle.to_bytes().unwrap());
+
+    let mut decoder = OscDecoder::new(&buf);
+    assert_eq!(decoder.next().unwrap().unwrap(), OscPacket::Message(msg));
+    assert_eq!(decoder.next().unwrap().unwrap(), OscPacket::Bundle(bundle));
+    assert!(decoder.next().is_none());
+
+    let mut decoder = OscDecoder::new(b"/ch/01");
+    assert!(decoder.next().unwrap().is_err());
+    assert!(decoder.next().is_none());
+    assert_eq!(decoder.remaining(), 0);
+}
@@ -0,0 +1,198 @@
+//! Typed lookups for the console's enumerated parameters.
+//!
+//! The console stores an enumerated parameter, such as a channel color, as an integer and
+//! prints it as a label in `/node` replies and scene files. The tables in the crate root
+//! hold the labels in index order; the functions here convert between the two, so the
+//! emulator and label-printing tools agree on the spelling.
+
+use crate::{
+    OFF_ON, XAMXGRP, XCHMODE, XCOLORS, XDYDET, XDYENV, XDYFTYP, XDYMODE, XDYPPOS, XDYRAT, XEQTY1,
+    XGMODE, XHSLP, XISEL, XMNMODE, XMXTYPE,
+};
+
+/// Returns the label at `index` in one of the enum tables, without its leading space.
+///
+/// # Arguments
+///
+/// * `table` - The table, e.g. [`XCOLORS`].
+/// * `index` - The integer value the console stores.
+pub fn name(table: &[&'static str], index: i32) -> Option<&'static str> {
+    let label = table.get(usize::try_from(index).ok()?)?;
+    Some(label.trim_start())
+}
+
+/// Returns the index of `label` in one of the enum tables.
+///
+/// Labels are matched ignoring case and surrounding whitespace.
+///
+/// # Arguments
+///
+/// * `table` - The table, e.g. [`XCOLORS`].
+/// * `label` - The label, e.g. `RD`.
+pub fn index(table: &[&str], label: &str) -> Option<i32> {
+    let label = label.trim();
+    table
+        .iter()
+        .position(|l| l.trim_start().eq_ignore_ascii_case(label))
+        .map(|i| i as i32)
+}
+
+/// Returns the label of an on/off switch, `OFF` or `ON`.
+pub fn on_off_name(index: i32) -> Option<&'static str> {
+    name(OFF_ON, index)
+}
+
+/// Returns the index of an on/off switch label.
+pub fn on_off_index(label: &str) -> Option<i32> {
+    index(OFF_ON, label)
+}
+
+/// Returns the label of an automix group assignment, `OFF`, `X` or `Y`.
+pub fn automix_group_name(index: i32) -> Option<&'static str> {
+    name(XAMXGRP, index)
+}
+
+/// Returns the index of an automix group assignment label.
+pub fn automix_group_index(label: &str) -> Option<i32> {
+    index(XAMXGRP, label)
+}
+
+/// Returns the label of a scribble strip color, e.g. `RD` or `RDi` for inverted red.
+pub fn color_name(index: i32) -> Option<&'static str> {
+    name(XCOLORS, index)
+}
+
+/// Returns the index of a scribble strip color label.
+pub fn color_index(label: &str) -> Option<i32> {
+    index(XCOLORS, label)
+}
+
+/// Returns the label of a monitor mix mode, `LR+M` or `LCR`.
+pub fn monitor_mode_name(index: i32) -> Option<&'static str> {
+    name(XMNMODE, index)
+}
+
+/// Returns the index of a monitor mix mode label.
+pub fn monitor_mode_index(label: &str) -> Option<i32> {
+    index(XMNMODE, label)
+}
+
+/// Returns the label of a channel solo mode, `PFL` or `AFL`.
+pub fn solo_mode_name(index: i32) -> Option<&'static str> {
+    name(XCHMODE, index)
+}
+
+/// Returns the index of a channel solo mode label.
+pub fn solo_mode_index(label: &str) -> Option<i32> {
+    index(XCHMODE, label)
+}
+
+/// Returns the label of a high-pass filter slope, e.g. `24`.
+pub fn hpf_slope_name(index: i32) -> Option<&'static str> {
+    name(XHSLP, index)
+}
+
+/// Returns the index of a high-pass filter slope label.
+pub fn hpf_slope_index(label: &str) -> Option<i32> {
+    index(XHSLP, label)
+}
+
+/// Returns the label of a gate mode, e.g. `GATE` or `DUCK`.
+pub fn gate_mode_name(index: i32) -> Option<&'static str> {
+    name(XGMODE, index)
+}
+
+/// Returns the index of a gate mode label.
+pub fn gate_mode_index(label: &str) -> Option<i32> {
+    index(XGMODE, label)
+}
+
+/// Returns the label of a dynamics mode, `COMP` or `EXP`.
+pub fn dyn_mode_name(index: i32) -> Option<&'static str> {
+    name(XDYMODE, index)
+}
+
+/// Returns the index of a dynamics mode label.
+pub fn dyn_mode_index(label: &str) -> Option<i32> {
+    index(XDYMODE, label)
+}
+
+/// Returns the label of a dynamics detector, `PEAK` or `RMS`.
+pub fn dyn_detector_name(index: i32) -> Option<&'static str> {
+    name(XDYDET, index)
+}
+
+/// Returns the index of a dynamics detector label.
+pub fn dyn_detector_index(label: &str) -> Option<i32> {
+    index(XDYDET, label)
+}
+
+/// Returns the label of a dynamics envelope, `LIN` or `LOG`.
+pub fn dyn_envelope_name(index: i32) -> Option<&'static str> {
+    name(XDYENV, index)
+}
+
+/// Returns the index of a dynamics envelope label.
+pub fn dyn_envelope_index(label: &str) -> Option<i32> {
+    index(XDYENV, label)
+}
+
+/// Returns the label of a dynamics ratio, e.g. `4.0`.
+pub fn dyn_ratio_name(index: i32) -> Option<&'static str> {
+    name(XDYRAT, index)
+}
+
+/// Returns the index of a dynamics ratio label.
+pub fn dyn_ratio_index(label: &str) -> Option<i32> {
+    index(XDYRAT, label)
+}
+
+/// Returns the label of a dynamics key filter type, e.g. `LC12`.
+pub fn dyn_filter_name(index: i32) -> Option<&'static str> {
+    name(XDYFTYP, index)
+}
+
+/// Returns the index of a dynamics key filter type label.
+pub fn dyn_filter_index(label: &str) -> Option<i32> {
+    index(XDYFTYP, label)
+}
+
+/// Returns the label of a dynamics or insert position, `PRE` or `POST`.
+pub fn dyn_position_name(index: i32) -> Option<&'static str> {
+    name(XDYPPOS, index)
+}
+
+/// Returns the index of a dynamics or insert position label.
+pub fn dyn_position_index(label: &str) -> Option<i32> {
+    index(XDYPPOS, label)
+}
+
+/// Returns the label of an insert selection, e.g. `FX1L`.
+pub fn insert_sel_name(index: i32) -> Option<&'static str> {
+    name(XISEL, index)
+}
+
+/// Returns the index of an insert selection label.
+pub fn insert_sel_index(label: &str) -> Option<i32> {
+    index(XISEL, label)
+}
+
+/// Returns the label of an EQ band type, e.g. `PEQ`.
+pub fn eq_type_name(index: i32) -> Option<&'static str> {
+    name(XEQTY1, index)
+}
+
+/// Returns the index of an EQ band type label.
+pub fn eq_type_index(label: &str) -> Option<i32> {
+    index(XEQTY1, label)
+}
+
+/// Returns the label of a mix send tap point, e.g. `PRE`.
+pub fn send_type_name(index: i32) -> Option<&'static str> {
+    name(XMXTYPE, index)
+}
+
+/// Returns the index of a mix send tap point label.
+pub fn send_type_index(label: &str) -> Option<i32> {
+    index(XMXTYPE, label)
+}
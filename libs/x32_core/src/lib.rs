@@ -7,11 +7,16 @@
 //!
 //! # Key Features
 //!
-//! - **Stateful Emulation:** The `Mixer` struct uses a `HashMap` to store the current
-//!   value of every parameter, creating a persistent state that can be modified and
-//!   queried.
+//! - **Stateful Emulation:** The `Mixer` struct stores the current value of every
+//!   parameter, creating a persistent state that can be modified and queried. Strip
+//!   names, faders, pans and mutes live in typed structs (`ChannelStrip`, `MixBus`,
+//!   `Dca`); other parameters are kept in a `HashMap`.
 //! - **OSC Message Dispatching:** The `dispatch` method processes raw OSC byte messages,
 //!   interpreting them as either requests for data or commands to change a setting.
+//! - **Enum Labels:** The [`enums`] module converts enumerated parameters, such as
+//!   colors and gate modes, between the stored index and the label the console prints.
+//! - **JSON Export:** With the `json` feature, `state_json` returns the whole state
+//!   as a JSON object for dashboards and other tools that don't speak OSC.
 //! - **Seedable State:** The `seed_from_lines` method allows you to initialize the
 //!   mixer's state from a predefined set of OSC commands, making it easy to set up
 //!   specific scenarios for testing.
@@ -55,16 +60,113 @@
 //! }
 //! ```
 
-use std::collections::HashMap;
-use std::net::SocketAddr;
+use std::collections::{HashMap, HashSet};
+use std::fs;
+use std::io::{self, Write as _};
+use std::net::{IpAddr, SocketAddr};
+use std::path::PathBuf;
 use std::sync::Arc;
 use std::time::{Duration, Instant};
 
-use osc_lib::{OscArg, OscMessage};
+use osc_lib::{address_matches, is_address_pattern, OscArg, OscDecoder, OscMessage};
+use x32_fxparse::MixerModel;
+
+pub mod enums;
+mod state;
+
+pub use state::{ChannelStrip, Dca, MixBus, MixerState};
 
 #[cfg(test)]
 mod tests;
 
+/// How long an `/xremote` subscription stays active without hearing from the client.
+const CLIENT_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// How long a `/meters` subscription stays active without a `/renew`.
+const METER_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// The shortest meter update interval, used for a `/meters` time factor of 1.
+const METER_BASE_INTERVAL: Duration = Duration::from_millis(50);
+
+/// The largest `/meters` time factor the console accepts.
+const METER_MAX_TIME_FACTOR: u32 = 99;
+
+/// The most replies a GET with an address pattern, such as `/ch/*/mix/fader`, returns.
+const MAX_PATTERN_REPLIES: usize = 128;
+
+// Section bits in the `/copy` mask. A mask of -1 copies the whole channel strip.
+/// Copy the preamp section.
+const C_HA: i32 = 0x0001;
+/// Copy the channel configuration: name, icon, color and source.
+const C_CONFIG: i32 = 0x0002;
+/// Copy the gate section.
+const C_GATE: i32 = 0x0004;
+/// Copy the compressor section.
+const C_DYN: i32 = 0x0008;
+/// Copy the equalizer section.
+const C_EQ: i32 = 0x0010;
+/// Copy the fader, pan and bus sends.
+const C_SEND: i32 = 0x0020;
+
+/// An active `/meters` subscription.
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct MeterSubscription {
+    /// When the subscription lapses unless renewed.
+    expiry: Instant,
+    /// The time between two meter blobs.
+    interval: Duration,
+    /// When the next blob is due.
+    next_send: Instant,
+    /// The 1-based input channel the strip meters of `/meters/6` report.
+    channel: u8,
+}
+
+/// Returns `true` if `path` is part of the console state stored in a scene.
+///
+/// Show metadata, status, preferences and libraries live outside the scenes.
+fn is_scene_param(path: &str) -> bool {
+    !path.starts_with("/-show/")
+        && !path.starts_with("/-stat/")
+        && !path.starts_with("/-prefs/")
+        && !path.starts_with("/-libs/")
+}
+
+/// Returns `true` if setting `path` can change which channels a mute group mutes:
+/// a group switch `/config/mute/N` or a channel's `/ch/NN/grp/mute` membership.
+fn affects_group_mutes(path: &str) -> bool {
+    path.starts_with("/config/mute/") || (path.starts_with("/ch/") && path.ends_with("/grp/mute"))
+}
+
+/// Returns the power-on value of a user bank parameter, or `None` if `path` isn't one.
+///
+/// Each bank A-C has a color, encoders 1-4 and buttons 5-12 with no assignment, and
+/// `/-stat/userpar/01/value` to `/36/value` report the controls' positions.
+fn user_ctrl_default(path: &str) -> Option<OscArg> {
+    if let Some(rest) = path.strip_prefix("/-stat/userpar/") {
+        let digits = rest.strip_suffix("/value")?;
+        if digits.len() != 2 || !digits.bytes().all(|b| b.is_ascii_digit()) {
+            return None;
+        }
+        let n: u8 = digits.parse().ok()?;
+        return (1..=36).contains(&n).then_some(OscArg::Int(0));
+    }
+    let rest = path.strip_prefix("/config/userctrl/")?;
+    let (bank, control) = rest.split_once('/')?;
+    if !matches!(bank, "A" | "B" | "C") {
+        return None;
+    }
+    let (kind, n) = match control.split_once('/') {
+        Some((kind, n)) => (kind, n.parse::<u8>().ok()?),
+        None => return (control == "color").then_some(OscArg::Int(0)),
+    };
+    let valid = match kind {
+        "enc" => (1..=4).contains(&n),
+        "btn" => (5..=12).contains(&n),
+        _ => false,
+    };
+    valid.then(|| OscArg::String(String::new()))
+}
+
 // --- Static Data for Mixer Parameters ---
 
 // The following static arrays define the string representations for various
@@ -86,7 +188,7 @@ pub static XCHMODE: &[&str] = &[" PFL", " AFL"];
 /// String representations for high-pass filter slopes.
 pub static XHSLP: &[&str] = &[" 12", " 18", " 24"];
 /// String representations for gate modes.
-pub static XGMODE: &[&str] = &[" EXP2", " EXP3", " EXP", " GATE", " DUCK"];
+pub static XGMODE: &[&str] = &[" EXP2", " EXP3", " EXP4", " GATE", " DUCK"];
 /// String representations for dynamics modes (compressor/expander).
 pub static XDYMODE: &[&str] = &[" COMP", " EXP"];
 /// String representations for dynamics detector types (Peak/RMS).
@@ -112,43 +214,164 @@ pub static XISEL: &[&str] = &[
 /// String representations for EQ types.
 pub static XEQTY1: &[&str] = &[" LCut", " LShv", " PEQ", " VEQ", " HShv", " HCut"];
 
-/// Represents the internal state of the mixer.
-#[derive(Debug, Clone)]
-pub struct MixerState {
-    values: HashMap<String, OscArg>,
-}
+/// String representations for mix bus send tap points.
+pub static XMXTYPE: &[&str] = &[" IN/LC", " <-EQ", " EQ->", " PRE", " POST", " GRP"];
+
+/// Factory-default node strings for input channel 01, copied from `Ch_inistr` in
+/// `X32CustomLayer.c`. Other channels use the same values under their own `/ch/NN/`
+/// prefix, and every headamp uses the `/headamp/000` entry.
+pub static CH_INISTR: &[&str] = &[
+    "/headamp/000 +0.0 OFF",
+    "/ch/01/config \"\" 1 YE 1",
+    "/ch/01/delay OFF 0.3",
+    "/ch/01/eq OFF",
+    "/ch/01/eq/1 PEQ 124.7 +0.00 2.0",
+    "/ch/01/eq/2 PEQ 496.6 +0.00 2.0",
+    "/ch/01/eq/3 PEQ 1k97 +0.00 2.0",
+    "/ch/01/eq/4 HShv 10k02 +0.00 2.0",
+    "/ch/01/gate OFF GATE -80.0 60.0 1 502 983 0",
+    "/ch/01/gate/filter OFF 3.0 990.9",
+    "/ch/01/dyn OFF COMP PEAK LOG 0.0 3.0 1 0.00 10 10.0 151 POST 0 100 OFF",
+    "/ch/01/dyn/filter OFF 3.0 990.9",
+    "/ch/01/insert OFF POST OFF",
+    "/ch/01/grp %00000000 %000000",
+    "/ch/01/mix/fader -oo",
+    "/ch/01/mix/pan +0",
+    "/ch/01/mix/on ON",
+    "/ch/01/mix/01 ON -oo +0 EQ->",
+    "/ch/01/mix/02 ON -oo",
+    "/ch/01/mix/03 ON -oo +0 EQ->",
+    "/ch/01/mix/04 ON -oo",
+    "/ch/01/mix/05 ON -oo +0 EQ->",
+    "/ch/01/mix/06 ON -oo",
+    "/ch/01/mix/07 ON -oo +0 EQ->",
+    "/ch/01/mix/08 ON -oo",
+    "/ch/01/mix/09 ON -oo +0 POST",
+    "/ch/01/mix/10 ON -oo",
+    "/ch/01/mix/11 ON -oo +0 POST",
+    "/ch/01/mix/12 ON -oo",
+    "/ch/01/mix/13 ON -oo +0 POST",
+    "/ch/01/mix/14 ON -oo",
+    "/ch/01/mix/15 ON -oo +0 POST",
+    "/ch/01/mix/16 ON -oo",
+    "/ch/01/mix/mono OFF",
+    "/ch/01/mix/mlevel -oo",
+];
 
-impl Default for MixerState {
-    fn default() -> Self {
-        Self::new()
-    }
+/// A leaf of a node container: its name below the container (empty for a node that
+/// is itself a leaf) and, for enumerated values, the strings the console prints.
+type NodeField = (&'static str, Option<&'static [&'static str]>);
+
+static HEADAMP_FIELDS: &[NodeField] = &[("gain", None), ("phantom", Some(OFF_ON))];
+static CONFIG_FIELDS: &[NodeField] = &[
+    ("name", None),
+    ("icon", None),
+    ("color", Some(XCOLORS)),
+    ("source", None),
+];
+static DELAY_FIELDS: &[NodeField] = &[("on", Some(OFF_ON)), ("time", None)];
+static EQ_FIELDS: &[NodeField] = &[("on", Some(OFF_ON))];
+static EQ_BAND_FIELDS: &[NodeField] = &[
+    ("type", Some(XEQTY1)),
+    ("f", None),
+    ("g", None),
+    ("q", None),
+];
+static GATE_FIELDS: &[NodeField] = &[
+    ("on", Some(OFF_ON)),
+    ("mode", Some(XGMODE)),
+    ("thr", None),
+    ("range", None),
+    ("attack", None),
+    ("hold", None),
+    ("release", None),
+    ("keysrc", None),
+];
+static FILTER_FIELDS: &[NodeField] = &[("on", Some(OFF_ON)), ("type", Some(XDYFTYP)), ("f", None)];
+static DYN_FIELDS: &[NodeField] = &[
+    ("on", Some(OFF_ON)),
+    ("mode", Some(XDYMODE)),
+    ("det", Some(XDYDET)),
+    ("env", Some(XDYENV)),
+    ("thr", None),
+    ("ratio", Some(XDYRAT)),
+    ("knee", None),
+    ("mgain", None),
+    ("attack", None),
+    ("hold", None),
+    ("release", None),
+    ("pos", Some(XDYPPOS)),
+    ("keysrc", None),
+    ("mix", None),
+    ("auto", Some(OFF_ON)),
+];
+static INSERT_FIELDS: &[NodeField] = &[
+    ("on", Some(OFF_ON)),
+    ("pos", Some(XDYPPOS)),
+    ("sel", Some(XISEL)),
+];
+static GRP_FIELDS: &[NodeField] = &[("dca", None), ("mute", None)];
+static SEND_ODD_FIELDS: &[NodeField] = &[
+    ("on", Some(OFF_ON)),
+    ("level", None),
+    ("pan", None),
+    ("type", Some(XMXTYPE)),
+];
+static SEND_EVEN_FIELDS: &[NodeField] = &[("on", Some(OFF_ON)), ("level", None)];
+static MIX_FIELDS: &[NodeField] = &[
+    ("on", Some(OFF_ON)),
+    ("fader", None),
+    ("st", Some(OFF_ON)),
+    ("pan", None),
+    ("mono", Some(OFF_ON)),
+    ("mlevel", None),
+];
+static LEAF_FIELDS: &[NodeField] = &[("", None)];
+static LEAF_ON_FIELDS: &[NodeField] = &[("", Some(OFF_ON))];
+
+/// The identity the emulator reports in its `/info`, `/xinfo` and `/status` replies.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ServerInfo {
+    /// The OSC server version, e.g. `V2.07`.
+    pub version: String,
+    /// The console's network name.
+    pub server_name: String,
+    /// The console model, e.g. `X32`, `X32RACK` or `M32`.
+    pub model: String,
+    /// The firmware version, e.g. `4.06`.
+    pub firmware: String,
 }
 
-impl MixerState {
-    /// Creates a new, empty `MixerState`.
-    pub fn new() -> Self {
+impl Default for ServerInfo {
+    fn default() -> Self {
         Self {
-            values: HashMap::new(),
+            version: "V2.07".to_string(),
+            server_name: "X32 Emulator".to_string(),
+            model: "X32".to_string(),
+            firmware: "4.06".to_string(),
         }
     }
-
-    /// Sets a value in the mixer's state.
-    pub fn set(&mut self, path: &str, arg: OscArg) {
-        self.values.insert(path.to_string(), arg);
-    }
-
-    /// Gets a value from the mixer's state.
-    pub fn get(&self, path: &str) -> Option<&OscArg> {
-        self.values.get(path)
-    }
 }
 
 /// A struct that emulates the behavior of an X32 mixer.
 pub struct Mixer {
     state: MixerState,
     clients: Vec<(SocketAddr, Instant)>,
-    // Track active meters per client. Map of (client_addr, meter_idx) -> expiry time
-    active_meters: HashMap<(SocketAddr, u8), Instant>,
+    // Track active meters per client. Map of (client_addr, meter_idx) -> subscription
+    active_meters: HashMap<(SocketAddr, u8), MeterSubscription>,
+    // Synthetic input signal per channel (1-based), as a linear level before the fader
+    signal_levels: HashMap<u8, f32>,
+    // File used by `load` and `save` to persist the state between runs
+    state_path: PathBuf,
+    // Identity reported by /info, /xinfo and /status
+    info: ServerInfo,
+    // Whether the state is ready to serve; /status reports `initializing` while not
+    ready: bool,
+    // The address the server is reachable on, reported by /status and /xinfo
+    local_ip: Option<IpAddr>,
+    // The previous value of each parameter the last set, copy or scene load changed,
+    // restored by /-undo. `None` marks a parameter that was not set.
+    undo_values: Option<Vec<(String, Option<OscArg>)>>,
 }
 
 impl Default for Mixer {
@@ -159,24 +382,253 @@ impl Default for Mixer {
 
 impl Mixer {
     /// Creates a new `Mixer` with a default, empty state.
+    ///
+    /// The state is persisted to `.X32res.rc` in the current directory, as with the
+    /// original C emulator.
     pub fn new() -> Self {
+        Self::new_with_state_path(".X32res.rc")
+    }
+
+    /// Creates a new `Mixer` with a default, empty state persisted to `state_path`.
+    ///
+    /// # Arguments
+    ///
+    /// * `state_path` - The file used by [`Mixer::load`] and [`Mixer::save`].
+    pub fn new_with_state_path(state_path: impl Into<PathBuf>) -> Self {
         Self {
             state: MixerState::new(),
             clients: Vec::new(),
             active_meters: HashMap::new(),
+            signal_levels: HashMap::new(),
+            state_path: state_path.into(),
+            info: ServerInfo::default(),
+            ready: true,
+            local_ip: None,
+            undo_values: None,
         }
     }
 
-    /// Seeds the mixer's state from a vector of OSC command strings.
+    /// Returns the identity reported in `/info` and `/status` replies.
+    pub fn server_info(&self) -> &ServerInfo {
+        &self.info
+    }
+
+    /// Sets the identity reported in `/info` and `/status` replies, e.g. to emulate
+    /// an M32 or a specific firmware.
+    ///
+    /// # Arguments
+    ///
+    /// * `info` - The server version, name, model and firmware to report.
+    pub fn set_server_info(&mut self, info: ServerInfo) {
+        self.info = info;
+    }
+
+    /// Sets whether the mixer is ready to serve its state.
+    ///
+    /// A new mixer is ready. While it is not, `/status` reports `initializing`
+    /// instead of `active`, so clients can tell an emulator that is still loading its
+    /// state from one that is serving it.
+    ///
+    /// # Arguments
+    ///
+    /// * `ready` - Whether `/status` reports `active`.
+    pub fn set_ready(&mut self, ready: bool) {
+        self.ready = ready;
+    }
+
+    /// Returns whether `/status` reports `active`; see [`Mixer::set_ready`].
+    pub fn is_ready(&self) -> bool {
+        self.ready
+    }
+
+    /// Returns the IP address reported in `/status` and `/xinfo` replies, if set.
+    pub fn local_ip(&self) -> Option<IpAddr> {
+        self.local_ip
+    }
+
+    /// Sets the IP address reported in `/status` and `/xinfo` replies.
+    ///
+    /// Without it, both report `0.0.0.0`.
+    ///
+    /// # Arguments
+    ///
+    /// * `ip` - The address the server socket is bound to.
+    pub fn set_local_ip(&mut self, ip: IpAddr) {
+        self.local_ip = Some(ip);
+    }
+
+    /// Returns the IP address string reported in `/status` and `/xinfo` replies.
+    fn reported_ip(&self) -> String {
+        self.local_ip
+            .map_or_else(|| "0.0.0.0".to_string(), |ip| ip.to_string())
+    }
+
+    /// Restores the mixer's state from its state file.
+    ///
+    /// The file holds one parameter per line in the format accepted by
+    /// [`Mixer::seed_from_lines`].
+    ///
+    /// # Returns
+    ///
+    /// An `io::Result` that is an error if the file could not be read.
+    pub fn load(&mut self) -> io::Result<()> {
+        let contents = fs::read_to_string(&self.state_path)?;
+        self.seed_from_lines(contents.lines().collect());
+        Ok(())
+    }
+
+    /// Writes the mixer's state to its state file.
+    ///
+    /// Integer, float and string parameters are saved; other argument types have no
+    /// representation in the file and are skipped. The state is written to a temporary
+    /// file beside the state file and renamed over it, so a save that is interrupted,
+    /// or races another emulator sharing the file, never leaves a truncated file.
+    ///
+    /// # Returns
+    ///
+    /// An `io::Result` that is an error if the file could not be written.
+    pub fn save(&self) -> io::Result<()> {
+        use std::fmt::Write;
+
+        let mut entries: Vec<(String, OscArg)> = self.state.iter().collect();
+        entries.sort_by(|a, b| a.0.cmp(&b.0));
+
+        let mut contents = String::new();
+        for (key, value) in entries {
+            // The writes below go to a String and cannot fail.
+            let _ = match value {
+                OscArg::Int(v) => writeln!(contents, "{},i\t{}", key, v),
+                OscArg::Float(v) => writeln!(contents, "{},f\t{}", key, v),
+                OscArg::String(v) => writeln!(contents, "{},s\t{}", key, v),
+                _ => Ok(()),
+            };
+        }
+
+        let tmp_path = self.temp_state_path();
+        let result = fs::File::create(&tmp_path)
+            .and_then(|mut file| {
+                file.write_all(contents.as_bytes())?;
+                file.sync_all()
+            })
+            .and_then(|()| fs::rename(&tmp_path, &self.state_path));
+        if result.is_err() {
+            let _ = fs::remove_file(&tmp_path);
+        }
+        result
+    }
+
+    /// Returns the temporary file [`Mixer::save`] writes before renaming it into place.
+    ///
+    /// It is in the state file's directory, so the rename can't cross file systems, and
+    /// is named after the process so two emulators don't write to the same one.
+    fn temp_state_path(&self) -> PathBuf {
+        let mut name = self
+            .state_path
+            .file_name()
+            .unwrap_or_default()
+            .to_os_string();
+        name.push(format!(".{}.tmp", std::process::id()));
+        self.state_path.with_file_name(name)
+    }
+
+    /// Returns the mixer's state as a JSON object mapping each path to its value.
+    ///
+    /// Paths are sorted. Integers and floats become JSON numbers, strings become JSON
+    /// strings and booleans become JSON booleans; other argument types are skipped, as
+    /// in [`Mixer::save`]. Non-finite floats become `null`.
+    ///
+    /// Requires the `json` feature.
+    #[cfg(feature = "json")]
+    pub fn state_json(&self) -> String {
+        use serde_json::{Map, Value};
+
+        let mut entries: Vec<(String, OscArg)> = self.state.iter().collect();
+        entries.sort_by(|a, b| a.0.cmp(&b.0));
+
+        let mut map = Map::new();
+        for (key, value) in entries {
+            let value = match value {
+                OscArg::Int(v) => Value::from(v),
+                // Widen through the shortest decimal form so 0.1 stays 0.1 rather
+                // than becoming 0.10000000149011612.
+                OscArg::Float(v) => Value::from(v.to_string().parse::<f64>().unwrap_or(f64::NAN)),
+                OscArg::String(v) => Value::from(v),
+                OscArg::Bool(v) => Value::from(v),
+                _ => continue,
+            };
+            map.insert(key, value);
+        }
+        Value::Object(map).to_string()
+    }
+
+    /// Injects a synthetic input signal on a channel so its meters show activity.
+    ///
+    /// The level reported in the meter blobs is this signal scaled by the channel's
+    /// current fader position, and zero while the channel is muted.
+    ///
+    /// # Arguments
+    ///
+    /// * `channel` - The 1-based input channel number (1-32).
+    /// * `level` - The linear signal level, where 1.0 is full scale.
+    pub fn set_signal_level(&mut self, channel: u8, level: f32) {
+        if (1..=32).contains(&channel) {
+            self.signal_levels.insert(channel, level);
+        }
+    }
+
+    /// Drops `/xremote` subscriptions that have not been renewed within ten seconds.
+    ///
+    /// # Arguments
+    ///
+    /// * `now` - The current time.
+    pub fn prune_clients(&mut self, now: Instant) {
+        self.clients.retain(|&(_, expiry)| now < expiry);
+    }
+
+    /// Generates the periodic meter blobs for every active `/meters` subscription.
+    ///
+    /// Each blob follows the console's layout: a little-endian int32 count followed by
+    /// that many little-endian floats.
     pub fn tick(&mut self) -> Vec<(SocketAddr, Arc<[u8]>)> {
+        self.tick_at(Instant::now())
+    }
+
+    /// Generates the meter blobs that are due at `now`.
+    ///
+    /// Each subscription is sent at most once per call. Its next send time advances
+    /// from the previous scheduled time rather than from `now`, so calling this a
+    /// little late doesn't lower the average rate. A subscription that has fallen more
+    /// than one interval behind is rescheduled from `now` instead of sending a burst.
+    ///
+    /// # Arguments
+    ///
+    /// * `now` - The current time.
+    ///
+    /// # Returns
+    ///
+    /// The meter blobs to send, with their destination addresses.
+    pub fn tick_at(&mut self, now: Instant) -> Vec<(SocketAddr, Arc<[u8]>)> {
         let mut responses = Vec::new();
-        let now = Instant::now();
 
-        // Expire old meters
-        self.active_meters.retain(|_, expiry| now < *expiry);
+        // Expire old clients and meters
+        self.prune_clients(now);
+        self.active_meters.retain(|_, sub| now < sub.expiry);
+
+        // Pick the subscriptions that are due and schedule their next blob
+        let mut due = Vec::new();
+        for (&(addr, meter_idx), sub) in self.active_meters.iter_mut() {
+            if sub.next_send > now {
+                continue;
+            }
+            sub.next_send += sub.interval;
+            if sub.next_send <= now {
+                sub.next_send = now + sub.interval;
+            }
+            due.push((addr, meter_idx, sub.channel));
+        }
 
-        // Generate meter blobs for each active subscription
-        for &(addr, meter_idx) in self.active_meters.keys() {
+        // Generate meter blobs for each due subscription
+        for (addr, meter_idx, channel) in due {
             // Number of floats expected per meter index (based on C code)
             let num_floats = match meter_idx {
                 0 => 70,
@@ -200,9 +652,19 @@ impl Mixer {
             };
 
             if num_floats > 0 {
-                // Generate a dummy blob of 0.0 floats
-                let blob_size = num_floats * 4;
-                let blob = vec![0u8; blob_size];
+                let mut blob = Vec::with_capacity((num_floats + 1) * 4);
+                blob.extend_from_slice(&(num_floats as i32).to_le_bytes());
+                for i in 0..num_floats {
+                    // Meters 0 and 1 both start with the 32 input channels. Meter 6 is
+                    // the strip meters of one channel; without dynamics processing,
+                    // each of them shows the channel's level.
+                    let level = match meter_idx {
+                        0 | 1 if i < 32 => self.channel_meter_level(i as u8 + 1),
+                        6 => self.channel_meter_level(channel),
+                        _ => 0.0,
+                    };
+                    blob.extend_from_slice(&level.to_le_bytes());
+                }
 
                 let path = format!("/meters/{}", meter_idx);
                 if let Ok(bytes) = OscMessage::serialize_to_bytes(&path, [&OscArg::Blob(blob)]) {
@@ -214,6 +676,359 @@ impl Mixer {
         responses
     }
 
+    /// Returns the value a client reading `path` should see.
+    ///
+    /// This is the stored value, except that a channel in an engaged mute group
+    /// reports `/ch/NN/mix/on` as off while keeping its own switch untouched, and
+    /// user bank parameters that were never set read as their power-on values.
+    fn param(&self, path: &str) -> Option<OscArg> {
+        let channel = path
+            .strip_prefix("/ch/")
+            .and_then(|rest| rest.strip_suffix("/mix/on"))
+            .filter(|n| n.len() == 2)
+            .and_then(|n| n.parse::<usize>().ok());
+        match channel {
+            Some(ch) if self.state.channel_group_muted(ch) => Some(OscArg::Int(0)),
+            _ => self.state.get(path).or_else(|| user_ctrl_default(path)),
+        }
+    }
+
+    /// Returns the post-fader meter level of an input channel.
+    ///
+    /// Each DCA the channel is assigned to adds its own fader offset, and mutes the
+    /// channel when switched off, as on the console.
+    fn channel_meter_level(&self, channel: u8) -> f32 {
+        let signal = match self.signal_levels.get(&channel) {
+            Some(&signal) => signal,
+            None => return 0.0,
+        };
+
+        let strip = self.state.channel(channel as usize);
+        if strip.and_then(|s| s.on) == Some(false)
+            || self.state.channel_group_muted(channel as usize)
+        {
+            return 0.0;
+        }
+
+        let mut db = Self::fader_to_db(strip.and_then(|s| s.fader).unwrap_or(0.75));
+        for dca_number in self.state.channel_dcas(channel as usize) {
+            let dca = self.state.dca(dca_number);
+            if dca.and_then(|d| d.on) == Some(false) {
+                return 0.0;
+            }
+            db += Self::fader_to_db(dca.and_then(|d| d.fader).unwrap_or(0.75));
+        }
+        signal * 10f32.powf(db / 20.0)
+    }
+
+    /// Converts a 0.0-1.0 fader position to dB using the console's four-segment law.
+    fn fader_to_db(f: f32) -> f32 {
+        if f >= 0.5 {
+            f * 40.0 - 30.0
+        } else if f >= 0.25 {
+            f * 80.0 - 50.0
+        } else if f >= 0.0625 {
+            f * 160.0 - 70.0
+        } else if f > 0.0 {
+            f * 480.0 - 90.0
+        } else {
+            f32::NEG_INFINITY
+        }
+    }
+
+    /// Formats the values of the node at `path` as a node string suffix.
+    ///
+    /// Channel containers with known factory defaults start from those defaults, and
+    /// any values stored in the mixer's state replace the matching fields. Other nodes
+    /// list the values stored at or below `path` in key order.
+    ///
+    /// A DCA also lists a `members` leaf that the console doesn't have: the input
+    /// channels assigned to it, as a 32-bit bitmask where bit 0 is channel 1.
+    ///
+    /// Returns `None` if the node has neither stored values nor defaults.
+    fn node_values(&self, path: &str) -> Option<String> {
+        use std::fmt::Write;
+
+        if let (Some(default), Some(fields)) = (Self::node_default(path), Self::node_fields(path)) {
+            let mut result = String::with_capacity(default.len() + 16);
+            for (i, token) in default.split_whitespace().enumerate() {
+                let stored = fields.get(i).and_then(|(name, names)| {
+                    let value = if name.is_empty() {
+                        self.param(path)
+                    } else {
+                        self.param(&format!("{}/{}", path, name))
+                    };
+                    value.map(|v| (v, *names))
+                });
+                match stored {
+                    // Defaults written as `%0000` are bitmasks, such as DCA assignments.
+                    Some((OscArg::Int(bits), _)) if token.starts_with('%') => {
+                        write!(result, " %{:0width$b}", bits, width = token.len() - 1).unwrap()
+                    }
+                    Some((v, names)) => Self::write_node_arg(&mut result, &v, names),
+                    None => write!(result, " {}", token).unwrap(),
+                }
+            }
+            return Some(result);
+        }
+
+        // ⚡ Bolt: Hoist string formatting outside the filter loop to prevent O(N) allocations
+        let mut path_slash = String::with_capacity(path.len() + 1);
+        path_slash.push_str(path);
+        path_slash.push('/');
+
+        // Collect and sort matching keys
+        let mut matches: Vec<(String, OscArg)> = self
+            .state
+            .iter()
+            .filter(|(k, _)| k == path || k.starts_with(&path_slash))
+            .collect();
+        let members = Self::dca_members_path(path);
+        if let Some((members_path, dca)) = &members {
+            let bits = self
+                .state
+                .dca_members(*dca)
+                .iter()
+                .fold(0u32, |bits, ch| bits | 1 << (ch - 1));
+            matches.push((members_path.clone(), OscArg::Int(bits as i32)));
+        }
+
+        if matches.is_empty() {
+            return None;
+        }
+        matches.sort_by(|a, b| a.0.cmp(&b.0));
+
+        let mut result = String::new();
+        for (k, v) in matches {
+            match (&members, v) {
+                (Some((members_path, _)), OscArg::Int(bits)) if *members_path == k => {
+                    write!(result, " %{:032b}", bits as u32).unwrap()
+                }
+                (_, v) => Self::write_node_arg(&mut result, &v, Self::leaf_names(&k)),
+            }
+        }
+        Some(result)
+    }
+
+    /// Returns the path of the `members` leaf and the DCA number if `path` is a DCA
+    /// node, such as `/dca/1`, or its `members` leaf.
+    fn dca_members_path(path: &str) -> Option<(String, usize)> {
+        let rest = path.strip_prefix("/dca/")?;
+        let number = rest.strip_suffix("/members").unwrap_or(rest);
+        match number.as_bytes() {
+            [digit @ b'1'..=b'8'] => {
+                Some((format!("{}/members", &path[..6]), (digit - b'0') as usize))
+            }
+            _ => None,
+        }
+    }
+
+    /// Returns the labels of a leaf of a known channel container, such as
+    /// `/ch/01/config/color`, if the field is enumerated.
+    fn leaf_names(path: &str) -> Option<&'static [&'static str]> {
+        let (parent, leaf) = path.rsplit_once('/')?;
+        let fields = Self::node_fields(parent)?;
+        fields.iter().find(|(name, _)| *name == leaf)?.1
+    }
+
+    /// Appends a single value to a node string, preceded by a space.
+    ///
+    /// Integers in an enumerated field are written as the console's name for them.
+    fn write_node_arg(result: &mut String, v: &OscArg, names: Option<&[&'static str]>) {
+        use std::fmt::Write;
+        // ⚡ Bolt: Use write! to append values to result string directly without intermediate string allocations
+        match v {
+            OscArg::Int(i) => match names.and_then(|n| enums::name(n, *i)) {
+                Some(name) => write!(result, " {}", name).unwrap(),
+                None => write!(result, " {}", i).unwrap(),
+            },
+            OscArg::Float(f) => write!(result, " {}", f).unwrap(),
+            OscArg::String(s) => write!(result, " \"{}\"", s).unwrap(),
+            OscArg::Blob(_) => result.push_str(" ~blob~"),
+            OscArg::Bool(b) => result.push_str(if *b { " ON" } else { " OFF" }),
+            OscArg::Nil | OscArg::Infinitum => {}
+        }
+    }
+
+    /// Splits a node string into the parameter values it sets.
+    ///
+    /// The values of a known channel container are matched to its fields in order,
+    /// so `/ch/01/delay ON 0.3` sets `/ch/01/delay/on` and `/ch/01/delay/time`. Any
+    /// other path takes a single value.
+    ///
+    /// Values are written in display units, as in scene files, and converted the way
+    /// `x32_lib`'s `SceneParser` converts them: `-10.0` on a fader is -10 dB, `-oo`
+    /// is fully down and `1k97` is 1.97 kHz. Enumerated fields accept their labels,
+    /// and `ON`/`OFF`, `%0101` bitmasks, numbers and quoted strings are recognized.
+    ///
+    /// Returns an empty vector if the line is not a node string.
+    fn node_line_args(line: &str) -> Vec<(String, OscArg)> {
+        // Each token is its text and whether it was quoted.
+        let mut tokens: Vec<(&str, bool)> = Vec::new();
+        let mut rest = line.trim();
+        while !rest.is_empty() {
+            let ((word, tail), quoted) = match rest.strip_prefix('"') {
+                Some(quoted) => (quoted.split_once('"').unwrap_or((quoted, "")), true),
+                None => (
+                    rest.split_once(char::is_whitespace).unwrap_or((rest, "")),
+                    false,
+                ),
+            };
+            tokens.push((word, quoted));
+            rest = tail.trim_start();
+        }
+
+        let path = match tokens.first() {
+            Some(&(path, false)) if path.starts_with('/') => path,
+            _ => return Vec::new(),
+        };
+        let fields: Vec<(String, Option<&[&str]>)> = match Self::node_fields(path) {
+            Some(fields) => fields
+                .iter()
+                .map(|&(name, names)| match name {
+                    "" => (path.to_string(), names),
+                    name => (format!("{}/{}", path, name), names),
+                })
+                .collect(),
+            None => vec![(path.to_string(), Self::leaf_names(path))],
+        };
+        if tokens.len() - 1 > fields.len() {
+            return Vec::new();
+        }
+
+        fields
+            .into_iter()
+            .zip(&tokens[1..])
+            .map(|((key, names), &(word, quoted))| {
+                let int = || {
+                    names
+                        .and_then(|n| enums::index(n, word))
+                        .or_else(|| enums::on_off_index(word))
+                        .or_else(|| i32::from_str_radix(word.strip_prefix('%')?, 2).ok())
+                        .or_else(|| word.parse().ok())
+                };
+                let display = || {
+                    x32_fxparse::parse_parameter(MixerModel::X32, &key, word)
+                        .and_then(|msg| msg.args.into_iter().next())
+                        .or_else(|| Self::display_value(&key, word))
+                };
+                let arg = if quoted {
+                    OscArg::String(word.to_string())
+                } else if let Some(arg) = display() {
+                    arg
+                } else if let Some(i) = int() {
+                    OscArg::Int(i)
+                } else if let Ok(f) = word.parse() {
+                    OscArg::Float(f)
+                } else {
+                    OscArg::String(word.to_string())
+                };
+                (key, arg)
+            })
+            .collect()
+    }
+
+    /// Converts a display-unit value that `x32_fxparse` doesn't cover, such as a send
+    /// level or a gate threshold, to its 0.0-1.0 position.
+    ///
+    /// Returns `None` for parameters that aren't floats, or if `word` isn't a number.
+    fn display_value(key: &str, word: &str) -> Option<OscArg> {
+        use x32_fxparse::{parse_flin, parse_frequency, parse_level, parse_logf};
+
+        let (parent, leaf) = key.rsplit_once('/')?;
+        match leaf {
+            "fader" | "level" | "mlevel" => return parse_level(word),
+            "pan" => return parse_flin(word, -100.0, 100.0),
+            "f" => return parse_frequency(word),
+            _ => {}
+        }
+        let inistr_path = Self::ch_inistr_path(parent)?;
+        let container = match inistr_path.strip_prefix("/ch/01/") {
+            Some(container) => container.split('/').next().unwrap_or_default(),
+            None => "headamp",
+        };
+        match (container, leaf) {
+            ("headamp", "gain") => parse_flin(word, -12.0, 60.0),
+            ("delay", "time") => parse_flin(word, 0.3, 500.0),
+            ("eq", "g") => parse_flin(word, -15.0, 15.0),
+            ("eq", "q") => parse_logf(word, 0.3, 10.0),
+            ("gate", "thr") => parse_flin(word, -80.0, 0.0),
+            ("gate", "range") => parse_flin(word, 3.0, 60.0),
+            ("dyn", "thr") => parse_flin(word, -60.0, 0.0),
+            ("dyn", "knee") => parse_flin(word, 0.0, 5.0),
+            ("dyn", "mgain") => parse_flin(word, 0.0, 24.0),
+            ("dyn", "mix") => parse_flin(word, 0.0, 100.0),
+            ("gate" | "dyn", "attack") => parse_flin(word, 0.0, 120.0),
+            ("gate" | "dyn", "hold") => parse_logf(word, 0.02, 2000.0),
+            ("gate" | "dyn", "release") => parse_logf(word, 5.0, 4000.0),
+            _ => None,
+        }
+    }
+
+    /// Maps a channel node path to its `CH_INISTR` path, e.g. `/ch/05/delay` to
+    /// `/ch/01/delay` and `/headamp/012` to `/headamp/000`.
+    fn ch_inistr_path(path: &str) -> Option<String> {
+        let is_index =
+            |s: &str, len: usize| s.len() == len && s.bytes().all(|b| b.is_ascii_digit());
+
+        if let Some(index) = path.strip_prefix("/headamp/") {
+            return is_index(index, 3).then(|| "/headamp/000".to_string());
+        }
+        let (channel, container) = path.strip_prefix("/ch/")?.split_once('/')?;
+        is_index(channel, 2).then(|| format!("/ch/01/{}", container))
+    }
+
+    /// Returns the factory-default node string suffix for a channel container, if known.
+    fn node_default(path: &str) -> Option<&'static str> {
+        let inistr_path = Self::ch_inistr_path(path)?;
+        CH_INISTR.iter().find_map(|default| {
+            let (default_path, _) = default.split_once(' ')?;
+            (default_path == inistr_path).then(|| &default[default_path.len()..])
+        })
+    }
+
+    /// Returns the factory default of a channel container parameter, if known.
+    fn param_default(path: &str) -> Option<OscArg> {
+        let parent = path.rsplit_once('/')?.0;
+        [path, parent].into_iter().find_map(|node| {
+            let line = format!("{}{}", node, Self::node_default(node)?);
+            Self::node_line_args(&line)
+                .into_iter()
+                .find_map(|(key, arg)| (key == path).then_some(arg))
+        })
+    }
+
+    /// Returns the fields of a channel container, in node string order, if known.
+    fn node_fields(path: &str) -> Option<&'static [NodeField]> {
+        let inistr_path = Self::ch_inistr_path(path)?;
+        if inistr_path == "/headamp/000" {
+            return Some(HEADAMP_FIELDS);
+        }
+        let fields = match inistr_path.strip_prefix("/ch/01/")? {
+            "config" => CONFIG_FIELDS,
+            "delay" => DELAY_FIELDS,
+            "eq" => EQ_FIELDS,
+            "eq/1" | "eq/2" | "eq/3" | "eq/4" => EQ_BAND_FIELDS,
+            "gate" => GATE_FIELDS,
+            "gate/filter" | "dyn/filter" => FILTER_FIELDS,
+            "dyn" => DYN_FIELDS,
+            "insert" => INSERT_FIELDS,
+            "grp" => GRP_FIELDS,
+            "mix" => MIX_FIELDS,
+            "mix/fader" | "mix/pan" | "mix/mlevel" => LEAF_FIELDS,
+            "mix/on" | "mix/mono" => LEAF_ON_FIELDS,
+            container => {
+                let bus: u8 = container.strip_prefix("mix/")?.parse().ok()?;
+                match bus {
+                    1..=16 if bus % 2 == 1 => SEND_ODD_FIELDS,
+                    1..=16 => SEND_EVEN_FIELDS,
+                    _ => return None,
+                }
+            }
+        };
+        Some(fields)
+    }
+
     /// Seeds the mixer's state from a vector of OSC command strings.
     pub fn seed_from_lines(&mut self, lines: Vec<&str>) {
         for line in lines {
@@ -237,33 +1052,56 @@ impl Mixer {
     }
 
     /// Dispatches an incoming OSC message and returns a list of responses to send to specific clients.
+    ///
+    /// A datagram may carry several concatenated messages, optionally ending with a
+    /// `#bundle`; each message is dispatched in order and the responses are returned together. Bundles are
+    /// processed immediately regardless of their time tag. Trailing bytes that do not
+    /// form a message are ignored.
     #[allow(clippy::type_complexity)]
     pub fn dispatch(
         &mut self,
         msg: &[u8],
         remote_addr: SocketAddr,
     ) -> Result<Vec<(SocketAddr, Arc<[u8]>)>, Box<dyn std::error::Error>> {
-        let osc_msg = OscMessage::from_bytes(msg)?;
         let mut responses = Vec::new();
+        for (i, packet) in OscDecoder::new(msg).enumerate() {
+            let packet = match packet {
+                Ok(packet) => packet,
+                Err(e) if i == 0 => return Err(e.into()),
+                Err(_) => break,
+            };
+            for osc_msg in packet.into_messages() {
+                responses.extend(self.dispatch_message(osc_msg, remote_addr)?);
+            }
+        }
+        Ok(responses)
+    }
 
-        // Expire old clients before processing
+    /// Dispatches a single parsed OSC message.
+    #[allow(clippy::type_complexity)]
+    fn dispatch_message(
+        &mut self,
+        osc_msg: OscMessage,
+        remote_addr: SocketAddr,
+    ) -> Result<Vec<(SocketAddr, Arc<[u8]>)>, Box<dyn std::error::Error>> {
+        // Reject malformed addresses rather than storing them as parameters.
+        osc_msg.validate()?;
+        let mut responses = Vec::new();
+
+        // Expire old clients before processing, then treat any message from a
+        // subscribed client as proof that it is still alive.
         let now = Instant::now();
-        self.clients.retain(|&(_, expiry)| now < expiry);
+        self.prune_clients(now);
+        let mut found = false;
+        if let Some(client) = self.clients.iter_mut().find(|c| c.0 == remote_addr) {
+            client.1 = now + CLIENT_TIMEOUT;
+            found = true;
+        }
 
         if osc_msg.path == "/xremote" {
-            let mut found = false;
-            for client in &mut self.clients {
-                if client.0 == remote_addr {
-                    client.1 = now + Duration::from_secs(10);
-                    found = true;
-                    break;
-                }
-            }
-
             if !found {
                 if self.clients.len() < 4 {
-                    self.clients
-                        .push((remote_addr, now + Duration::from_secs(10)));
+                    self.clients.push((remote_addr, now + CLIENT_TIMEOUT));
                 } else {
                     eprintln!("maximum client capacity reached");
                 }
@@ -273,30 +1111,49 @@ impl Mixer {
 
         // Handle the /info command
         if osc_msg.path == "/info" {
-            let arg1 = OscArg::String("V2.07".to_string());
-            let arg2 = OscArg::String("X32 Emulator".to_string());
-            let arg3 = OscArg::String("X32".to_string());
-            let arg4 = OscArg::String("4.06".to_string());
+            let arg1 = OscArg::String(self.info.version.clone());
+            let arg2 = OscArg::String(self.info.server_name.clone());
+            let arg3 = OscArg::String(self.info.model.clone());
+            let arg4 = OscArg::String(self.info.firmware.clone());
             let bytes = OscMessage::serialize_to_bytes("/info", [&arg1, &arg2, &arg3, &arg4])?;
             responses.push((remote_addr, bytes.into()));
             return Ok(responses);
         }
 
+        // Handle the /xinfo command, which discovery tools broadcast to find consoles
+        if osc_msg.path == "/xinfo" {
+            let arg1 = OscArg::String(self.reported_ip());
+            let arg2 = OscArg::String(self.info.server_name.clone());
+            let arg3 = OscArg::String(self.info.model.clone());
+            let arg4 = OscArg::String(self.info.firmware.clone());
+            let bytes = OscMessage::serialize_to_bytes("/xinfo", [&arg1, &arg2, &arg3, &arg4])?;
+            responses.push((remote_addr, bytes.into()));
+            return Ok(responses);
+        }
+
         // Handle the /status command
         if osc_msg.path == "/status" {
-            let arg1 = OscArg::String("active".to_string());
-            let arg2 = OscArg::String("0.0.0.0".to_string());
-            let arg3 = OscArg::String("X32 Emulator".to_string());
+            let state = if self.ready { "active" } else { "initializing" };
+            let arg1 = OscArg::String(state.to_string());
+            let arg2 = OscArg::String(self.reported_ip());
+            let arg3 = OscArg::String(self.info.server_name.clone());
             let bytes = OscMessage::serialize_to_bytes("/status", [&arg1, &arg2, &arg3])?;
             responses.push((remote_addr, bytes.into()));
             return Ok(responses);
         }
 
-        // Handle the /renew command
+        // Handle the /renew command. The /xremote subscription was already refreshed
+        // above. Like the console, `/renew ,s "/meters/N"` extends that meter
+        // subscription, and `/renew` without a name (or with "") extends all of the
+        // client's meter subscriptions. Renewing does not start a new subscription.
         if osc_msg.path == "/renew" {
-            for client in &mut self.clients {
-                if client.0 == remote_addr {
-                    client.1 = now + Duration::from_secs(10);
+            let name = osc_msg.arg_str(0).unwrap_or("");
+            let meter_idx = name
+                .strip_prefix("/meters/")
+                .and_then(|idx| idx.parse::<u8>().ok());
+            for ((addr, idx), sub) in self.active_meters.iter_mut() {
+                if *addr == remote_addr && (name.is_empty() || meter_idx == Some(*idx)) {
+                    sub.expiry = now + METER_TIMEOUT;
                 }
             }
             return Ok(responses);
@@ -308,12 +1165,81 @@ impl Mixer {
             return Ok(responses);
         }
 
-        // Handle /meters subscriptions
-        if osc_msg.path.starts_with("/meters/") {
-            if let Ok(meter_idx) = osc_msg.path[8..].parse::<u8>() {
+        // Handle /meters subscriptions, either as /meters/N or as /meters ,s "/meters/N".
+        // The int arguments after the meter name are read by position. /meters/5 takes
+        // two meter ids before the time factor, and /meters/6 two unused ints and the
+        // channel (0-31), as in /meters ,siiii "/meters/6" 0 0 ch tf. For the other
+        // meters the time factor comes first. Blobs are sent every 50 ms times the
+        // factor, which is clamped to 1-99.
+        let (meter_path, params) = if osc_msg.path == "/meters" {
+            (
+                osc_msg.arg_str(0),
+                osc_msg.args.get(1..).unwrap_or_default(),
+            )
+        } else {
+            (Some(osc_msg.path.as_str()), &osc_msg.args[..])
+        };
+        let param = |i: usize| params.get(i).and_then(OscArg::as_int);
+        if let Some(meter_path) = meter_path.filter(|p| p.starts_with("/meters/")) {
+            if let Ok(meter_idx) = meter_path[8..].parse::<u8>() {
                 if meter_idx <= 16 {
+                    let (channel, time_factor) = match meter_idx {
+                        5 => (None, param(2)),
+                        6 => (param(2), param(3)),
+                        _ => (None, param(0)),
+                    };
+                    let time_factor =
+                        time_factor.map_or(1, |f| f.clamp(1, METER_MAX_TIME_FACTOR as i32) as u32);
+                    let channel = channel.map_or(1, |ch| ch.clamp(0, 31) as u8 + 1);
+                    let subscription = MeterSubscription {
+                        expiry: now + METER_TIMEOUT,
+                        interval: METER_BASE_INTERVAL * time_factor,
+                        next_send: now,
+                        channel,
+                    };
                     self.active_meters
-                        .insert((remote_addr, meter_idx), now + Duration::from_secs(10));
+                        .insert((remote_addr, meter_idx), subscription);
+                }
+            }
+            return Ok(responses);
+        }
+
+        // Handle the /-action/goscene command, which recalls a stored scene and makes it
+        // the current one in /-show/prepos/current.
+        if osc_msg.path == "/-action/goscene" {
+            if let Some(OscArg::Int(idx)) = osc_msg.args.first() {
+                if self.recall_scene(*idx, &mut responses) {
+                    let arg = OscArg::Int(*idx);
+                    self.state.set("/-show/prepos/current", arg.clone());
+                    let bytes = OscMessage::serialize_to_bytes("/-show/prepos/current", [&arg])?;
+                    let arc_bytes: Arc<[u8]> = bytes.into();
+                    for client in &self.clients {
+                        responses.push((client.0, arc_bytes.clone()));
+                    }
+                }
+            }
+            return Ok(responses);
+        }
+
+        // Handle the /-undo command. Like the console, there is a single level of undo,
+        // covering the last set, copy, bulk load or scene recall; undoing again redoes it.
+        if osc_msg.path == "/-undo" {
+            if let Some(values) = self.undo_values.take() {
+                self.undo_values = Some(self.restore_values(values, &mut responses));
+            }
+            return Ok(responses);
+        }
+
+        // Handle the /-action/selchannel command. The console treats this as a request
+        // to change the selected strip, so it updates /-stat/selidx and notifies clients.
+        if osc_msg.path == "/-action/selchannel" {
+            if let Some(OscArg::Int(idx)) = osc_msg.args.first() {
+                let arg = OscArg::Int(*idx);
+                self.state.set("/-stat/selidx", arg.clone());
+                let bytes = OscMessage::serialize_to_bytes("/-stat/selidx", [&arg])?;
+                let arc_bytes: Arc<[u8]> = bytes.into();
+                for client in &self.clients {
+                    responses.push((client.0, arc_bytes.clone()));
                 }
             }
             return Ok(responses);
@@ -322,35 +1248,12 @@ impl Mixer {
         // Handle the /node command
         if osc_msg.path == "/node" {
             if let Some(OscArg::String(node_path)) = osc_msg.args.first() {
-                let search_path = format!("/{}", node_path);
-
-                // ⚡ Bolt: Hoist string formatting outside the filter loop to prevent O(N) allocations
-                let mut search_path_slash = String::with_capacity(search_path.len() + 1);
-                search_path_slash.push_str(&search_path);
-                search_path_slash.push('/');
-
-                // Collect and sort matching keys
-                let mut matches: Vec<(&String, &OscArg)> = self
-                    .state
-                    .values
-                    .iter()
-                    .filter(|(k, _)| **k == search_path || k.starts_with(&search_path_slash))
-                    .collect();
-
-                matches.sort_by_key(|(k, _)| *k);
-
-                if !matches.is_empty() {
-                    let mut result = node_path.clone();
-                    for (_, v) in matches {
-                        use std::fmt::Write;
-                        // ⚡ Bolt: Use write! to append values to result string directly without intermediate string allocations
-                        match v {
-                            OscArg::Int(i) => write!(result, " {}", i).unwrap(),
-                            OscArg::Float(f) => write!(result, " {}", f).unwrap(),
-                            OscArg::String(s) => write!(result, " \"{}\"", s).unwrap(),
-                            OscArg::Blob(_) => result.push_str(" ~blob~"),
-                        }
-                    }
+                let search_path = format!("/{}", node_path.trim_start_matches('/'));
+
+                // Channel containers report factory defaults for any fields not in state.
+                // Like the console, the reply is the full path, the values and a newline.
+                if let Some(values) = self.node_values(&search_path) {
+                    let result = format!("{}{}\n", search_path, values);
                     if let Ok(bytes) =
                         OscMessage::serialize_to_bytes("node", [&OscArg::String(result)])
                     {
@@ -361,126 +1264,73 @@ impl Mixer {
             return Ok(responses);
         }
 
+        // Handle the /-snap/dump command, which sends one /node reply for every stored
+        // parameter at or below the root path, in path order. Backup tools use it to
+        // fetch the whole state without walking the tree one /node query at a time.
+        if osc_msg.path == "/-snap/dump" {
+            let root = match osc_msg.args.first() {
+                Some(OscArg::String(root)) => format!("/{}", root.trim_matches('/')),
+                _ => "/".to_string(),
+            };
+            let root_slash = format!("{}/", root.trim_end_matches('/'));
+
+            let mut paths: Vec<String> = self
+                .state
+                .iter()
+                .map(|(k, _)| k)
+                .filter(|k| *k == root || k.starts_with(&root_slash))
+                .collect();
+            paths.sort();
+
+            for path in paths {
+                if let Some(values) = self.node_values(&path) {
+                    let result = format!("{}{}\n", path, values);
+                    let bytes = OscMessage::serialize_to_bytes("node", [&OscArg::String(result)])?;
+                    responses.push((remote_addr, bytes.into()));
+                }
+            }
+            return Ok(responses);
+        }
+
+        // Handle the / command, which sets parameters from node strings such as
+        // `/ch/01/config "Kick" 1 RD 1`, one per line of its string argument. Each
+        // value is applied like an individual set, so subscribed clients stay in sync.
+        if osc_msg.path == "/" {
+            if let Some(text) = osc_msg.arg_str(0) {
+                let args: Vec<(String, OscArg)> =
+                    text.lines().flat_map(Self::node_line_args).collect();
+                self.undo_values = Some(self.previous_values(args.iter().map(|(p, _)| p)));
+                for (path, arg) in args {
+                    self.apply_set(&path, &arg, remote_addr, &mut responses);
+                }
+            }
+            return Ok(responses);
+        }
+
         // Handle system administration commands: /copy, /add, /load, /save, /delete
         if osc_msg.path == "/copy" {
-            let mut success = false;
-            if osc_msg.args.len() >= 4 {
-                if let (
-                    OscArg::String(item_type),
-                    OscArg::Int(src_idx),
-                    OscArg::Int(dst_idx),
-                    OscArg::Int(mask),
-                ) = (
-                    &osc_msg.args[0],
-                    &osc_msg.args[1],
-                    &osc_msg.args[2],
-                    &osc_msg.args[3],
-                ) {
-                    let mut valid = false;
-                    let mut copy_all = false;
-
-                    // ⚡ Bolt: Eliminate redundant String::new() allocations by binding directly
-                    let (src_prefix, dst_prefix) = if item_type == "libchan"
-                        && *src_idx >= 0
-                        && *src_idx < 32
-                        && *dst_idx >= 0
-                        && *dst_idx < 32
-                    {
-                        valid = true;
-                        (
-                            format!("/ch/{:02}/", src_idx + 1),
-                            format!("/ch/{:02}/", dst_idx + 1),
-                        )
-                    } else if item_type == "libfx" && *src_idx >= 0 && *dst_idx >= 0 {
-                        valid = true;
-                        copy_all = true;
-                        (
-                            format!("/-libs/fx/{:03}/", src_idx),
-                            format!("/-libs/fx/{:03}/", dst_idx),
-                        )
-                    } else if item_type == "librout" && *src_idx >= 0 && *dst_idx >= 0 {
-                        valid = true;
-                        copy_all = true;
-                        (
-                            format!("/-libs/r/{:03}/", src_idx),
-                            format!("/-libs/r/{:03}/", dst_idx),
-                        )
-                    } else if item_type == "scene" && *src_idx >= 0 && *dst_idx >= 0 {
-                        valid = true;
-                        copy_all = true;
-                        (
-                            format!("/-show/showfile/scene/{:03}/", src_idx),
-                            format!("/-show/showfile/scene/{:03}/", dst_idx),
-                        )
-                    } else {
-                        (String::new(), String::new())
+            let success = match (
+                osc_msg.args.first(),
+                osc_msg.args.get(1),
+                osc_msg.args.get(2),
+                osc_msg.args.get(3),
+            ) {
+                (
+                    Some(OscArg::String(item_type)),
+                    Some(OscArg::Int(src_idx)),
+                    Some(OscArg::Int(dst_idx)),
+                    Some(OscArg::Int(mask)),
+                ) => {
+                    // An optional fifth argument copies that many consecutive items.
+                    let count = match osc_msg.args.get(4) {
+                        None => 1,
+                        Some(OscArg::Int(count)) => *count,
+                        Some(_) => 0,
                     };
-
-                    if valid {
-                        // C_CONFIG = 0x0002
-                        // C_HA = 0x0001
-                        // C_GATE = 0x0004
-                        // C_DYN = 0x0008
-                        // C_EQ = 0x0010
-                        // C_SEND = 0x0020
-
-                        let copy_config = (mask & 0x0002) != 0 || *mask == -1 || copy_all;
-                        let copy_ha = (mask & 0x0001) != 0 || *mask == -1 || copy_all;
-                        let copy_gate = (mask & 0x0004) != 0 || *mask == -1 || copy_all;
-                        let copy_dyn = (mask & 0x0008) != 0 || *mask == -1 || copy_all;
-                        let copy_eq = (mask & 0x0010) != 0 || *mask == -1 || copy_all;
-                        let copy_send = (mask & 0x0020) != 0 || *mask == -1 || copy_all;
-
-                        // We will collect keys to clone to avoid borrow checker issues with mut state
-                        let mut to_copy = Vec::new();
-                        let mut new_key_buf = String::with_capacity(64);
-                        for (key, val) in self.state.values.iter() {
-                            if key.starts_with(&src_prefix) {
-                                let suffix = &key[src_prefix.len()..];
-
-                                let should_copy = if copy_all {
-                                    true
-                                } else if suffix.starts_with("config/") {
-                                    copy_config
-                                } else if suffix.starts_with("preamp/") {
-                                    copy_ha
-                                } else if suffix.starts_with("gate/") {
-                                    copy_gate
-                                } else if suffix.starts_with("dyn/") {
-                                    copy_dyn
-                                } else if suffix.starts_with("eq/") {
-                                    copy_eq
-                                } else if suffix.starts_with("mix/") {
-                                    copy_send
-                                }
-                                // mix includes sends, panning, fader
-                                else {
-                                    *mask == -1
-                                }; // copy all if mask is -1
-
-                                if should_copy {
-                                    new_key_buf.clear();
-                                    use std::fmt::Write;
-                                    write!(&mut new_key_buf, "{}{}", dst_prefix, suffix).unwrap();
-                                    to_copy.push((new_key_buf.clone(), val.clone()));
-                                }
-                            }
-                        }
-
-                        for (k, v) in to_copy {
-                            self.state.set(&k, v.clone());
-                            // Need to broadcast to all clients
-                            if let Ok(b) = OscMessage::serialize_to_bytes(&k, [&v]) {
-                                let arc_b: Arc<[u8]> = b.into();
-                                for client in &self.clients {
-                                    responses.push((client.0, arc_b.clone()));
-                                }
-                            }
-                        }
-                        success = true;
-                    }
+                    self.copy_items(item_type, *src_idx, *dst_idx, *mask, count, &mut responses)
                 }
-            }
+                _ => false,
+            };
 
             let arg_type = osc_msg
                 .args
@@ -545,16 +1395,12 @@ impl Mixer {
                         }
 
                         let dst_prefix = format!("/-show/showfile/{}/{:03}/", item_type, idx);
+                        self.remove_show_data(&dst_prefix);
                         let src_prefix = "/";
                         let mut to_copy = Vec::new();
                         let mut new_key_buf = String::with_capacity(64);
-                        for (key, val) in self.state.values.iter() {
-                            if key.starts_with(src_prefix)
-                                && !key.starts_with("/-show/")
-                                && !key.starts_with("/-stat/")
-                                && !key.starts_with("/-prefs/")
-                                && !key.starts_with("/-libs/")
-                            {
+                        for (key, val) in self.state.iter() {
+                            if key.starts_with(src_prefix) && is_scene_param(&key) {
                                 let suffix = &key[src_prefix.len()..];
                                 new_key_buf.clear();
                                 use std::fmt::Write;
@@ -615,7 +1461,7 @@ impl Mixer {
                         if let (Some(src), Some(dst)) = (src_prefix, dst_prefix) {
                             let mut to_copy = Vec::new();
                             let mut new_key_buf = String::with_capacity(64);
-                            for (key, val) in self.state.values.iter() {
+                            for (key, val) in self.state.iter() {
                                 if key.starts_with(src)
                                     && !key.starts_with("/-show/")
                                     && !key.starts_with("/-stat/")
@@ -667,6 +1513,10 @@ impl Mixer {
                         self.state.set(&name_path, OscArg::String("".to_string()));
                         self.state.set(&note_path, OscArg::String("".to_string()));
                         self.state.set(&hasdata_path, OscArg::Int(0));
+                        self.remove_show_data(&format!(
+                            "/-show/showfile/{}/{:03}/",
+                            item_type, idx
+                        ));
 
                         if let Ok(b) =
                             OscMessage::serialize_to_bytes(&hasdata_path, [&OscArg::Int(0)])
@@ -809,10 +1659,10 @@ impl Mixer {
                     // For load, we simulate the hardware behavior by applying the preset state.
                     // This mirrors the /copy command but moves data from the library to the root level.
                     let (src_prefix, dst_prefix) = match item_type.as_str() {
-                        "scene" => (
-                            Some(format!("/-show/showfile/scene/{:03}/", idx)),
-                            Some("/".to_string()),
-                        ),
+                        "scene" => {
+                            success = self.recall_scene(*idx, &mut responses);
+                            (None, None)
+                        }
                         "snippet" => (
                             Some(format!("/-show/showfile/snippet/{:03}/", idx)),
                             Some("/".to_string()),
@@ -834,7 +1684,7 @@ impl Mixer {
                     if let (Some(src), Some(dst)) = (src_prefix, dst_prefix) {
                         let mut to_copy = Vec::new();
                         let mut new_key_buf = String::with_capacity(64);
-                        for (key, val) in self.state.values.iter() {
+                        for (key, val) in self.state.iter() {
                             if key.starts_with(&src) {
                                 let suffix = &key[src.len()..];
                                 // Don't copy metadata like name or note or hasdata back to root
@@ -880,70 +1730,454 @@ impl Mixer {
 
         // If the message has no arguments, it's a request for a value.
         if osc_msg.args.is_empty() {
-            if let Some(arg) = self.state.get(&osc_msg.path) {
-                let bytes = OscMessage::serialize_to_bytes(&osc_msg.path, [arg])?;
+            if let Some(arg) = self.param(&osc_msg.path) {
+                let bytes = OscMessage::serialize_to_bytes(&osc_msg.path, [&arg])?;
                 responses.push((remote_addr, bytes.into()));
+            } else if is_address_pattern(&osc_msg.path) {
+                // An address pattern gets one reply per known address it matches, up
+                // to MAX_PATTERN_REPLIES so a pattern like `/*` can't flood the client.
+                let matched = self.pattern_matches(&osc_msg.path);
+                for key in matched.into_iter().take(MAX_PATTERN_REPLIES) {
+                    if let Some(arg) = self.param(&key) {
+                        let bytes = OscMessage::serialize_to_bytes(&key, [&arg])?;
+                        responses.push((remote_addr, bytes.into()));
+                    }
+                }
             }
         } else {
             // If the message has arguments, it's a command to set a value.
             if let Some(arg) = osc_msg.args.first() {
-                self.state.set(&osc_msg.path, arg.clone());
+                // An address pattern with no exact match is applied to every known
+                // address it matches, e.g. `/ch/*/mix/fader` from X32-Edit.
+                let paths = if self.state.get(&osc_msg.path).is_none()
+                    && is_address_pattern(&osc_msg.path)
+                {
+                    self.pattern_matches(&osc_msg.path)
+                } else {
+                    vec![osc_msg.path.clone()]
+                };
+                self.undo_values = Some(self.previous_values(&paths));
+                for path in &paths {
+                    self.apply_set(path, arg, remote_addr, &mut responses);
+                }
+            }
+        }
 
-                // Broadcast value change to all xremote clients
-                if let Ok(bytes) = OscMessage::serialize_to_bytes(&osc_msg.path, [arg]) {
-                    let arc_bytes: Arc<[u8]> = bytes.into();
-                    for client in &self.clients {
-                        responses.push((client.0, arc_bytes.clone()));
-                    }
+        Ok(responses)
+    }
+
+    /// Copies channel sections, library entries or scenes for the `/copy` command and
+    /// queues an update to every client for each copied parameter.
+    ///
+    /// Channels are copied section by section according to `mask`; the other item
+    /// types are always copied whole. Every source value is read before any is
+    /// written, so overlapping ranges copy the original values.
+    ///
+    /// # Arguments
+    ///
+    /// * `item_type` - `libchan`, `libfx`, `librout` or `scene`.
+    /// * `src_idx` - The first item to copy from, 0-based.
+    /// * `dst_idx` - The first item to copy to, 0-based.
+    /// * `mask` - The `C_*` section bits to copy for `libchan`, or -1 for all.
+    /// * `count` - The number of consecutive items to copy.
+    /// * `responses` - The outgoing packets for this dispatch.
+    ///
+    /// # Returns
+    ///
+    /// `true` if the item type is known and every index in both ranges is valid.
+    fn copy_items(
+        &mut self,
+        item_type: &str,
+        src_idx: i32,
+        dst_idx: i32,
+        mask: i32,
+        count: i32,
+        responses: &mut Vec<(SocketAddr, Arc<[u8]>)>,
+    ) -> bool {
+        let limit = match item_type {
+            "libchan" => 32,
+            "libfx" | "librout" | "scene" => 100,
+            _ => return false,
+        };
+        let in_range =
+            |idx: i32| idx >= 0 && idx.checked_add(count).is_some_and(|end| end <= limit);
+        if count < 1 || !in_range(src_idx) || !in_range(dst_idx) {
+            return false;
+        }
+
+        let copy_all = item_type != "libchan" || mask == -1;
+        let copies = |section: i32| copy_all || mask & section != 0;
+
+        // We will collect keys to clone to avoid borrow checker issues with mut state
+        let mut to_copy = Vec::new();
+        // A copied scene replaces the destination scene, so its other values go.
+        let mut stale = HashSet::new();
+        let mut new_key_buf = String::with_capacity(64);
+        for offset in 0..count {
+            let (src, dst) = (src_idx + offset, dst_idx + offset);
+            let (src_prefix, dst_prefix) = match item_type {
+                "libchan" => (
+                    format!("/ch/{:02}/", src + 1),
+                    format!("/ch/{:02}/", dst + 1),
+                ),
+                "libfx" => (
+                    format!("/-libs/fx/{:03}/", src),
+                    format!("/-libs/fx/{:03}/", dst),
+                ),
+                "librout" => (
+                    format!("/-libs/r/{:03}/", src),
+                    format!("/-libs/r/{:03}/", dst),
+                ),
+                _ => (
+                    format!("/-show/showfile/scene/{:03}/", src),
+                    format!("/-show/showfile/scene/{:03}/", dst),
+                ),
+            };
+            if item_type == "scene" {
+                stale.extend(self.state.iter().map(|(k, _)| k).filter(|k| {
+                    k.strip_prefix(&dst_prefix)
+                        .is_some_and(|suffix| !matches!(suffix, "name" | "note" | "hasdata"))
+                }));
+            }
+
+            for (key, val) in self.state.iter() {
+                let Some(suffix) = key.strip_prefix(&src_prefix) else {
+                    continue;
+                };
+                let should_copy = if suffix.starts_with("config/") {
+                    copies(C_CONFIG)
+                } else if suffix.starts_with("preamp/") {
+                    copies(C_HA)
+                } else if suffix.starts_with("gate/") {
+                    copies(C_GATE)
+                } else if suffix.starts_with("dyn/") {
+                    copies(C_DYN)
+                } else if suffix.starts_with("eq/") {
+                    copies(C_EQ)
+                } else if suffix.starts_with("mix/") {
+                    // mix includes sends, panning, fader
+                    copies(C_SEND)
+                } else {
+                    copy_all
+                };
+
+                if should_copy {
+                    new_key_buf.clear();
+                    use std::fmt::Write;
+                    write!(&mut new_key_buf, "{}{}", dst_prefix, suffix).unwrap();
+                    to_copy.push((new_key_buf.clone(), val));
                 }
+            }
+        }
 
-                // If a solosw was changed, update the global solo indicator
-                if osc_msg.path.starts_with("/-stat/solosw/") {
-                    let mut any_solo = 0;
-
-                    // ⚡ Bolt: Pre-allocate a string and mutate it in-place to avoid 80 heap allocations per solosw change
-                    let mut key = String::with_capacity(32);
-                    key.push_str("/-stat/solosw/");
-                    let base_len = key.len();
-
-                    // Bounded check of the 80 solosw switches to avoid O(N) map iteration
-                    for i in 1..=80 {
-                        key.truncate(base_len);
-                        if i < 10 {
-                            key.push('0');
-                            key.push((b'0' + i as u8) as char);
-                        } else {
-                            key.push((b'0' + (i / 10) as u8) as char);
-                            key.push((b'0' + (i % 10) as u8) as char);
-                        }
+        for (k, _) in &to_copy {
+            stale.remove(k);
+        }
+        let changed = to_copy.iter().map(|(k, _)| k).chain(&stale);
+        self.undo_values = Some(self.previous_values(changed));
+        for k in &stale {
+            self.state.remove(k);
+        }
+        let muted_before = self.group_mutes();
+        for (k, v) in to_copy {
+            self.state.set(&k, v.clone());
+            // Need to broadcast to all clients
+            if let Ok(b) = OscMessage::serialize_to_bytes(&k, [&v]) {
+                let arc_b: Arc<[u8]> = b.into();
+                for client in &self.clients {
+                    responses.push((client.0, arc_b.clone()));
+                }
+            }
+        }
+        self.notify_group_mutes(&muted_before, responses);
+        true
+    }
 
-                        if let Some(v) = self.state.get(&key) {
-                            match v {
-                                OscArg::Int(val) if *val != 0 => {
-                                    any_solo = 1;
-                                    break;
-                                }
-                                OscArg::Float(f) if *f > 0.0 => {
-                                    any_solo = 1;
-                                    break;
-                                }
-                                _ => {}
-                            }
+    /// Returns the stored addresses that match an OSC address pattern, in path order.
+    fn pattern_matches(&self, pattern: &str) -> Vec<String> {
+        let mut matched: Vec<String> = self
+            .state
+            .iter()
+            .map(|(k, _)| k)
+            .filter(|k| address_matches(pattern, k))
+            .collect();
+        matched.sort();
+        matched
+    }
+
+    /// Removes the parameters stored in a show file scene or snippet, keeping its
+    /// name, note and `hasdata` flag.
+    ///
+    /// # Arguments
+    ///
+    /// * `prefix` - The entry's prefix, such as `/-show/showfile/scene/003/`.
+    fn remove_show_data(&mut self, prefix: &str) {
+        let stale: Vec<String> = self
+            .state
+            .iter()
+            .map(|(k, _)| k)
+            .filter(|k| {
+                k.strip_prefix(prefix)
+                    .is_some_and(|suffix| !matches!(suffix, "name" | "note" | "hasdata"))
+            })
+            .collect();
+        for key in stale {
+            self.state.remove(&key);
+        }
+    }
+
+    /// Restores a stored scene and queues an update to every client for each
+    /// parameter the recall changed.
+    ///
+    /// Parameters outside the scene, such as show metadata and libraries, keep their
+    /// current values.
+    ///
+    /// # Arguments
+    ///
+    /// * `idx` - The scene index to recall.
+    /// * `responses` - The outgoing packets for this dispatch.
+    ///
+    /// # Returns
+    ///
+    /// `true` if the scene exists and was recalled.
+    fn recall_scene(&mut self, idx: i32, responses: &mut Vec<(SocketAddr, Arc<[u8]>)>) -> bool {
+        // Scenes are kept in the show file, so they are saved with the rest of the
+        // state, whether they were stored with /save or seeded from a file.
+        let prefix = format!("/-show/showfile/scene/{:03}/", idx);
+        let has_data = self.state.get(&format!("{}hasdata", prefix)) == Some(OscArg::Int(1));
+        let mut restored = MixerState::new();
+        for (key, val) in self.state.iter() {
+            if let Some(suffix) = key.strip_prefix(&prefix) {
+                if suffix != "name" && suffix != "note" && suffix != "hasdata" {
+                    restored.set(&format!("/{}", suffix), val);
+                }
+            }
+        }
+        if !has_data && restored.iter().next().is_none() {
+            return false;
+        }
+        for (key, val) in self.state.iter().filter(|(k, _)| !is_scene_param(k)) {
+            restored.set(&key, val);
+        }
+
+        self.undo_values = Some(self.replace_state(restored, responses));
+        true
+    }
+
+    /// Replaces the whole console state and queues an update to every client for
+    /// each parameter whose value changed, including parameters the new state lacks.
+    ///
+    /// # Arguments
+    ///
+    /// * `state` - The new state.
+    /// * `responses` - The outgoing packets for this dispatch.
+    ///
+    /// # Returns
+    ///
+    /// The previous value of each parameter that changed, as for [`Self::restore_values`].
+    fn replace_state(
+        &mut self,
+        state: MixerState,
+        responses: &mut Vec<(SocketAddr, Arc<[u8]>)>,
+    ) -> Vec<(String, Option<OscArg>)> {
+        let removed = self
+            .state
+            .iter()
+            .filter(|(k, _)| state.get(k).is_none())
+            .map(|(k, _)| (k, None));
+        let mut values: Vec<(String, Option<OscArg>)> = state
+            .iter()
+            .map(|(k, v)| (k, Some(v)))
+            .chain(removed)
+            .collect();
+        values.sort_by(|a, b| a.0.cmp(&b.0));
+        self.restore_values(values, responses)
+    }
+
+    /// Returns the current value of each parameter in `paths`, for /-undo to restore.
+    ///
+    /// Switching a solo also changes `/-stat/solo`, so its value is included too.
+    fn previous_values<S: AsRef<str>>(
+        &self,
+        paths: impl IntoIterator<Item = S>,
+    ) -> Vec<(String, Option<OscArg>)> {
+        let mut seen = HashSet::new();
+        let mut values = Vec::new();
+        for path in paths {
+            let path = path.as_ref();
+            let solo = path.starts_with("/-stat/solosw/").then_some("/-stat/solo");
+            for path in std::iter::once(path).chain(solo) {
+                if seen.insert(path.to_string()) {
+                    values.push((path.to_string(), self.state.get(path)));
+                }
+            }
+        }
+        values
+    }
+
+    /// Sets each parameter to its value, or removes it for `None`, and queues an
+    /// update to every client for each parameter that changed.
+    ///
+    /// A removed parameter is reported at its factory default where one is known.
+    ///
+    /// # Arguments
+    ///
+    /// * `values` - The parameters to restore.
+    /// * `responses` - The outgoing packets for this dispatch.
+    ///
+    /// # Returns
+    ///
+    /// The previous value of each parameter that changed, so restoring them again
+    /// reverts this call.
+    fn restore_values(
+        &mut self,
+        values: Vec<(String, Option<OscArg>)>,
+        responses: &mut Vec<(SocketAddr, Arc<[u8]>)>,
+    ) -> Vec<(String, Option<OscArg>)> {
+        let muted_before = self.group_mutes();
+        let mut previous = Vec::with_capacity(values.len());
+        for (key, val) in values {
+            let current = self.state.get(&key);
+            if current == val {
+                continue;
+            }
+            match &val {
+                Some(v) => self.state.set(&key, v.clone()),
+                None => {
+                    self.state.remove(&key);
+                }
+            }
+
+            let update = self.param(&key).or_else(|| Self::param_default(&key));
+            if let Some(Ok(b)) = update.map(|v| OscMessage::serialize_to_bytes(&key, [&v])) {
+                let arc_b: Arc<[u8]> = b.into();
+                for client in &self.clients {
+                    responses.push((client.0, arc_b.clone()));
+                }
+            }
+            previous.push((key, current));
+        }
+        self.notify_group_mutes(&muted_before, responses);
+        previous
+    }
+
+    /// Returns whether each input channel is muted by a mute group, indexed from 0.
+    fn group_mutes(&self) -> Vec<bool> {
+        (1..=32)
+            .map(|ch| self.state.channel_group_muted(ch))
+            .collect()
+    }
+
+    /// Queues an update of `/ch/NN/mix/on` to every client for each channel whose
+    /// mute group state differs from `muted_before`, as returned by
+    /// [`Self::group_mutes`].
+    fn notify_group_mutes(
+        &self,
+        muted_before: &[bool],
+        responses: &mut Vec<(SocketAddr, Arc<[u8]>)>,
+    ) {
+        for (i, &was_muted) in muted_before.iter().enumerate() {
+            let ch = i + 1;
+            if self.state.channel_group_muted(ch) == was_muted {
+                continue;
+            }
+            let on_path = format!("/ch/{:02}/mix/on", ch);
+            let on = self.param(&on_path).unwrap_or(OscArg::Int(1));
+            if let Ok(bytes) = OscMessage::serialize_to_bytes(&on_path, [&on]) {
+                let arc_bytes: Arc<[u8]> = bytes.into();
+                for client in &self.clients {
+                    responses.push((client.0, arc_bytes.clone()));
+                }
+            }
+        }
+    }
+
+    /// Stores a single parameter change and queues the resulting updates.
+    ///
+    /// The stored value, after any range clamping, is broadcast to every `/xremote`
+    /// client except `remote_addr`.
+    /// Like the console, the surface that made the change does not get its own
+    /// SET echoed back. Derived state such as `/-stat/solo` is recomputed and
+    /// sent to all clients.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - The parameter address being set.
+    /// * `arg` - The new value.
+    /// * `remote_addr` - The client that sent the change.
+    /// * `responses` - The outgoing packets for this dispatch.
+    fn apply_set(
+        &mut self,
+        path: &str,
+        arg: &OscArg,
+        remote_addr: SocketAddr,
+        responses: &mut Vec<(SocketAddr, Arc<[u8]>)>,
+    ) {
+        // The console only has userpar 01-36, so sets to any other userpar are ignored.
+        if path.starts_with("/-stat/userpar/") && user_ctrl_default(path).is_none() {
+            return;
+        }
+        let muted_before = affects_group_mutes(path).then(|| self.group_mutes());
+
+        self.state.set(path, arg.clone());
+        let stored = self.state.get(path).unwrap_or_else(|| arg.clone());
+
+        if let Ok(bytes) = OscMessage::serialize_to_bytes(path, [&stored]) {
+            let arc_bytes: Arc<[u8]> = bytes.into();
+            for client in self.clients.iter().filter(|c| c.0 != remote_addr) {
+                responses.push((client.0, arc_bytes.clone()));
+            }
+        }
+
+        // If a mute group or a channel's membership was switched, notify clients of
+        // the affected channels' new effective on/off state.
+        if let Some(muted_before) = muted_before {
+            self.notify_group_mutes(&muted_before, responses);
+        }
+
+        // If a solosw was changed, update the global solo indicator
+        if path.starts_with("/-stat/solosw/") {
+            let mut any_solo = 0;
+
+            // ⚡ Bolt: Pre-allocate a string and mutate it in-place to avoid 80 heap allocations per solosw change
+            let mut key = String::with_capacity(32);
+            key.push_str("/-stat/solosw/");
+            let base_len = key.len();
+
+            // Bounded check of the 80 solosw switches to avoid O(N) map iteration
+            for i in 1..=80 {
+                key.truncate(base_len);
+                if i < 10 {
+                    key.push('0');
+                    key.push((b'0' + i as u8) as char);
+                } else {
+                    key.push((b'0' + (i / 10) as u8) as char);
+                    key.push((b'0' + (i % 10) as u8) as char);
+                }
+
+                if let Some(v) = self.state.get(&key) {
+                    match v {
+                        OscArg::Int(val) if val != 0 => {
+                            any_solo = 1;
+                            break;
                         }
-                    }
-                    self.state.set("/-stat/solo", OscArg::Int(any_solo));
-                    if let Ok(bytes) =
-                        OscMessage::serialize_to_bytes("/-stat/solo", [&OscArg::Int(any_solo)])
-                    {
-                        let arc_bytes: Arc<[u8]> = bytes.into();
-                        for client in &self.clients {
-                            responses.push((client.0, arc_bytes.clone()));
+                        OscArg::Float(f) if f > 0.0 => {
+                            any_solo = 1;
+                            break;
                         }
+                        _ => {}
                     }
                 }
             }
+            self.state.set("/-stat/solo", OscArg::Int(any_solo));
+            if let Ok(bytes) =
+                OscMessage::serialize_to_bytes("/-stat/solo", [&OscArg::Int(any_solo)])
+            {
+                let arc_bytes: Arc<[u8]> = bytes.into();
+                for client in &self.clients {
+                    responses.push((client.0, arc_bytes.clone()));
+                }
+            }
         }
-
-        Ok(responses)
     }
 }
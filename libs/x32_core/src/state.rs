@@ -0,0 +1,359 @@
+//! The emulator's parameter store.
+//!
+//! The strip parameters most tools work with (the name, fader, pan and on/off switch
+//! of channels, buses, DCAs and the main stereo bus) are held in typed structs. Every
+//! other path is kept as a raw OSC argument in a fallback map, so any parameter a
+//! client sets can still be read back.
+//!
+//! Typed fields are `None` until set. As with the fallback map, a parameter that was
+//! never set is unknown, and a GET for it goes unanswered.
+
+use osc_lib::OscArg;
+use std::collections::HashMap;
+
+/// An input channel strip, `/ch/01` to `/ch/32`.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ChannelStrip {
+    /// The scribble strip name, `/ch/NN/config/name`.
+    pub name: Option<String>,
+    /// The fader level from 0.0 to 1.0, `/ch/NN/mix/fader`.
+    pub fader: Option<f32>,
+    /// The pan position from 0.0 (left) to 1.0 (right), `/ch/NN/mix/pan`.
+    pub pan: Option<f32>,
+    /// Whether the channel is unmuted, `/ch/NN/mix/on`.
+    pub on: Option<bool>,
+}
+
+/// A mix bus, `/bus/01` to `/bus/16`, or the main stereo bus `/main/st`.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct MixBus {
+    /// The scribble strip name, `.../config/name`.
+    pub name: Option<String>,
+    /// The fader level from 0.0 to 1.0, `.../mix/fader`.
+    pub fader: Option<f32>,
+    /// The pan position from 0.0 (left) to 1.0 (right), `.../mix/pan`.
+    pub pan: Option<f32>,
+    /// Whether the bus is unmuted, `.../mix/on`.
+    pub on: Option<bool>,
+}
+
+/// A DCA group, `/dca/1` to `/dca/8`.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Dca {
+    /// The scribble strip name, `/dca/N/config/name`.
+    pub name: Option<String>,
+    /// The fader level from 0.0 to 1.0, `/dca/N/fader`.
+    pub fader: Option<f32>,
+    /// Whether the DCA is unmuted, `/dca/N/on`.
+    pub on: Option<bool>,
+}
+
+/// The strip a typed path belongs to. Indices are 0-based.
+#[derive(Debug, Clone, Copy)]
+enum Strip {
+    Channel(usize),
+    Bus(usize),
+    Main,
+    Dca(usize),
+}
+
+/// The parameter of a strip a typed path refers to.
+#[derive(Debug, Clone, Copy)]
+enum Field {
+    Name,
+    Fader,
+    Pan,
+    On,
+}
+
+const FIELDS: [Field; 4] = [Field::Name, Field::Fader, Field::Pan, Field::On];
+
+/// Mutable references to a strip's name, fader, pan and on fields.
+type TypedFieldsMut<'a> = (
+    &'a mut Option<String>,
+    &'a mut Option<f32>,
+    Option<&'a mut Option<f32>>,
+    &'a mut Option<bool>,
+);
+
+/// Represents the internal state of the mixer.
+#[derive(Debug, Clone, Default)]
+pub struct MixerState {
+    channels: [ChannelStrip; 32],
+    buses: [MixBus; 16],
+    main: MixBus,
+    dcas: [Dca; 8],
+    /// Parameters without a typed representation, keyed by OSC path.
+    pub(crate) values: HashMap<String, OscArg>,
+}
+
+impl MixerState {
+    /// Creates a new, empty `MixerState`.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets a value in the mixer's state.
+    ///
+    /// Like the console, out-of-range values are coerced rather than rejected: fader,
+    /// level and pan floats are clamped to 0.0-1.0, on/off integers to 0 or 1, and
+    /// `/-stat/userpar` positions to 0-127.
+    ///
+    /// Values for typed parameters are stored in their strip when the argument has the
+    /// parameter's type; anything else is kept in the fallback map.
+    pub fn set(&mut self, path: &str, arg: OscArg) {
+        let arg = Self::coerce(path, arg);
+        if let Some((strip, field)) = Self::locate(path) {
+            if self.set_typed(strip, field, &arg) {
+                self.values.remove(path);
+                return;
+            }
+        }
+        self.values.insert(path.to_string(), arg);
+    }
+
+    /// Gets a value from the mixer's state.
+    pub fn get(&self, path: &str) -> Option<OscArg> {
+        if let Some(arg) = self.values.get(path) {
+            return Some(arg.clone());
+        }
+        let (strip, field) = Self::locate(path)?;
+        self.get_typed(strip, field)
+    }
+
+    /// Removes a parameter from the mixer's state, returning its value if it was set.
+    pub fn remove(&mut self, path: &str) -> Option<OscArg> {
+        let value = self.values.remove(path);
+        let typed = Self::locate(path).and_then(|(strip, field)| {
+            let arg = self.get_typed(strip, field);
+            self.clear_typed(strip, field);
+            arg
+        });
+        value.or(typed)
+    }
+
+    /// Returns every stored parameter as a path and value, in no particular order.
+    pub fn iter(&self) -> impl Iterator<Item = (String, OscArg)> + '_ {
+        let strips = (0..32)
+            .map(Strip::Channel)
+            .chain((0..16).map(Strip::Bus))
+            .chain(std::iter::once(Strip::Main))
+            .chain((0..8).map(Strip::Dca));
+        let typed = strips
+            .flat_map(|strip| FIELDS.iter().map(move |&field| (strip, field)))
+            .filter_map(move |(strip, field)| {
+                let arg = self.get_typed(strip, field)?;
+                let path = Self::path_of(strip, field)?;
+                // A fallback entry for the same path is newer and is yielded below.
+                (!self.values.contains_key(&path)).then_some((path, arg))
+            });
+        typed.chain(self.values.iter().map(|(k, v)| (k.clone(), v.clone())))
+    }
+
+    /// Returns the input channel with the given 1-based number.
+    pub fn channel(&self, number: usize) -> Option<&ChannelStrip> {
+        self.channels.get(number.checked_sub(1)?)
+    }
+
+    /// Returns the mix bus with the given 1-based number.
+    pub fn bus(&self, number: usize) -> Option<&MixBus> {
+        self.buses.get(number.checked_sub(1)?)
+    }
+
+    /// Returns the main stereo bus.
+    pub fn main(&self) -> &MixBus {
+        &self.main
+    }
+
+    /// Returns the DCA group with the given 1-based number.
+    pub fn dca(&self, number: usize) -> Option<&Dca> {
+        self.dcas.get(number.checked_sub(1)?)
+    }
+
+    /// Clamps a value into the range the console allows for its parameter.
+    fn coerce(path: &str, arg: OscArg) -> OscArg {
+        let leaf = path.rsplit('/').next().unwrap_or_default();
+        match (leaf, arg) {
+            ("fader" | "level" | "pan", OscArg::Float(f)) => OscArg::Float(f.clamp(0.0, 1.0)),
+            ("on", OscArg::Int(i)) => OscArg::Int(i.clamp(0, 1)),
+            ("value", OscArg::Int(i)) if path.starts_with("/-stat/userpar/") => {
+                OscArg::Int(i.clamp(0, 127))
+            }
+            (_, arg) => arg,
+        }
+    }
+
+    /// Returns the 1-based numbers of the DCAs an input channel is assigned to.
+    ///
+    /// Assignments come from the `/ch/NN/grp/dca` bitmask, where bit 0 is DCA 1.
+    pub fn channel_dcas(&self, channel: usize) -> Vec<usize> {
+        let bits = match self.get(&format!("/ch/{:02}/grp/dca", channel)) {
+            Some(OscArg::Int(bits)) => bits,
+            _ => return Vec::new(),
+        };
+        (1..=8).filter(|n| bits & (1 << (n - 1)) != 0).collect()
+    }
+
+    /// Returns the 1-based numbers of the input channels assigned to a DCA.
+    pub fn dca_members(&self, dca: usize) -> Vec<usize> {
+        (1..=32)
+            .filter(|&ch| self.channel_dcas(ch).contains(&dca))
+            .collect()
+    }
+
+    /// Returns `true` if an input channel belongs to an engaged mute group.
+    ///
+    /// Group membership comes from the `/ch/NN/grp/mute` bitmask, where bit 0 is
+    /// group 1, and a group is engaged while `/config/mute/N` is 1.
+    pub fn channel_group_muted(&self, channel: usize) -> bool {
+        let bits = match self.get(&format!("/ch/{:02}/grp/mute", channel)) {
+            Some(OscArg::Int(bits)) => bits,
+            _ => return false,
+        };
+        (1..=6).any(|n| {
+            bits & (1 << (n - 1)) != 0
+                && matches!(self.get(&format!("/config/mute/{}", n)), Some(OscArg::Int(on)) if on != 0)
+        })
+    }
+
+    /// Maps an OSC path to the typed parameter it addresses, if any.
+    fn locate(path: &str) -> Option<(Strip, Field)> {
+        let index = |digits: &str, len: usize, count: usize| -> Option<usize> {
+            if digits.len() != len || !digits.bytes().all(|b| b.is_ascii_digit()) {
+                return None;
+            }
+            let n: usize = digits.parse().ok()?;
+            (1..=count).contains(&n).then(|| n - 1)
+        };
+        let strip_field = |rest: &str| match rest {
+            "/config/name" => Some(Field::Name),
+            "/mix/fader" => Some(Field::Fader),
+            "/mix/pan" => Some(Field::Pan),
+            "/mix/on" => Some(Field::On),
+            _ => None,
+        };
+
+        if let Some(rest) = path.strip_prefix("/ch/") {
+            let (digits, rest) = rest.split_at_checked(2)?;
+            Some((Strip::Channel(index(digits, 2, 32)?), strip_field(rest)?))
+        } else if let Some(rest) = path.strip_prefix("/bus/") {
+            let (digits, rest) = rest.split_at_checked(2)?;
+            Some((Strip::Bus(index(digits, 2, 16)?), strip_field(rest)?))
+        } else if let Some(rest) = path.strip_prefix("/main/st") {
+            Some((Strip::Main, strip_field(rest)?))
+        } else if let Some(rest) = path.strip_prefix("/dca/") {
+            let (digits, rest) = rest.split_at_checked(1)?;
+            let field = match rest {
+                "/config/name" => Field::Name,
+                "/fader" => Field::Fader,
+                "/on" => Field::On,
+                _ => return None,
+            };
+            Some((Strip::Dca(index(digits, 1, 8)?), field))
+        } else {
+            None
+        }
+    }
+
+    /// Builds the OSC path of a typed parameter.
+    fn path_of(strip: Strip, field: Field) -> Option<String> {
+        let suffix = match (strip, field) {
+            (Strip::Dca(_), Field::Name) => "config/name",
+            (Strip::Dca(_), Field::Fader) => "fader",
+            (Strip::Dca(_), Field::On) => "on",
+            (Strip::Dca(_), Field::Pan) => return None,
+            (_, Field::Name) => "config/name",
+            (_, Field::Fader) => "mix/fader",
+            (_, Field::Pan) => "mix/pan",
+            (_, Field::On) => "mix/on",
+        };
+        Some(match strip {
+            Strip::Channel(i) => format!("/ch/{:02}/{}", i + 1, suffix),
+            Strip::Bus(i) => format!("/bus/{:02}/{}", i + 1, suffix),
+            Strip::Main => format!("/main/st/{}", suffix),
+            Strip::Dca(i) => format!("/dca/{}/{}", i + 1, suffix),
+        })
+    }
+
+    fn get_typed(&self, strip: Strip, field: Field) -> Option<OscArg> {
+        let (name, fader, pan, on) = match strip {
+            Strip::Channel(i) => {
+                let s = &self.channels[i];
+                (&s.name, s.fader, s.pan, s.on)
+            }
+            Strip::Bus(i) => {
+                let b = &self.buses[i];
+                (&b.name, b.fader, b.pan, b.on)
+            }
+            Strip::Main => (
+                &self.main.name,
+                self.main.fader,
+                self.main.pan,
+                self.main.on,
+            ),
+            Strip::Dca(i) => {
+                let d = &self.dcas[i];
+                (&d.name, d.fader, None, d.on)
+            }
+        };
+        match field {
+            Field::Name => name.clone().map(OscArg::String),
+            Field::Fader => fader.map(OscArg::Float),
+            Field::Pan => pan.map(OscArg::Float),
+            Field::On => on.map(|on| OscArg::Int(on as i32)),
+        }
+    }
+
+    /// Returns a strip's name, fader, pan and on fields. DCAs have no pan.
+    fn typed_mut(&mut self, strip: Strip) -> TypedFieldsMut<'_> {
+        match strip {
+            Strip::Channel(i) => {
+                let s = &mut self.channels[i];
+                (&mut s.name, &mut s.fader, Some(&mut s.pan), &mut s.on)
+            }
+            Strip::Bus(i) => {
+                let b = &mut self.buses[i];
+                (&mut b.name, &mut b.fader, Some(&mut b.pan), &mut b.on)
+            }
+            Strip::Main => {
+                let m = &mut self.main;
+                (&mut m.name, &mut m.fader, Some(&mut m.pan), &mut m.on)
+            }
+            Strip::Dca(i) => {
+                let d = &mut self.dcas[i];
+                (&mut d.name, &mut d.fader, None, &mut d.on)
+            }
+        }
+    }
+
+    /// Resets a typed field to unknown.
+    fn clear_typed(&mut self, strip: Strip, field: Field) {
+        let (name, fader, pan, on) = self.typed_mut(strip);
+        match field {
+            Field::Name => *name = None,
+            Field::Fader => *fader = None,
+            Field::Pan => {
+                if let Some(pan) = pan {
+                    *pan = None;
+                }
+            }
+            Field::On => *on = None,
+        }
+    }
+
+    /// Stores `arg` in a typed field, returning `false` if it has the wrong type.
+    fn set_typed(&mut self, strip: Strip, field: Field, arg: &OscArg) -> bool {
+        let (name, fader, pan, on) = self.typed_mut(strip);
+        match (field, arg) {
+            (Field::Name, OscArg::String(s)) => *name = Some(s.clone()),
+            (Field::Fader, OscArg::Float(f)) => *fader = Some(*f),
+            (Field::Pan, OscArg::Float(f)) => match pan {
+                Some(pan) => *pan = Some(*f),
+                None => return false,
+            },
+            (Field::On, OscArg::Int(i)) => *on = Some(*i != 0),
+            _ => return false,
+        }
+        true
+    }
+}
@@ -1,9 +1,11 @@
 #[cfg(test)]
 #[allow(clippy::module_inception)]
 mod tests {
-    use crate::{Mixer, MixerState};
-    use osc_lib::{OscArg, OscMessage};
+    use crate::{Mixer, MixerState, ServerInfo};
+    use osc_lib::{OscArg, OscBundle, OscMessage};
+    use std::collections::HashMap;
     use std::net::{IpAddr, Ipv4Addr, SocketAddr};
+    use std::time::{Duration, Instant};
 
     fn test_addr(port: u16) -> SocketAddr {
         SocketAddr::new(IpAddr::V4(Ipv4Addr::new(127, 0, 0, 1)), port)
@@ -22,10 +24,57 @@ mod tests {
         let arg = OscArg::Float(0.75);
 
         state.set(path, arg.clone());
-        assert_eq!(state.get(path), Some(&arg));
+        assert_eq!(state.get(path), Some(arg));
         assert_eq!(state.get("/non/existent"), None);
     }
 
+    #[test]
+    fn test_mixer_state_typed_strips() {
+        let mut state = MixerState::new();
+        state.set("/ch/03/config/name", OscArg::String("Vox".to_string()));
+        state.set("/ch/03/mix/fader", OscArg::Float(0.6));
+        state.set("/ch/03/mix/on", OscArg::Int(0));
+        state.set("/bus/16/mix/pan", OscArg::Float(0.25));
+        state.set("/main/st/mix/fader", OscArg::Float(0.7));
+        state.set("/dca/8/fader", OscArg::Float(0.4));
+        state.set("/ch/03/eq/1/g", OscArg::Float(0.5));
+
+        let ch = state.channel(3).unwrap();
+        assert_eq!(ch.name.as_deref(), Some("Vox"));
+        assert_eq!(ch.fader, Some(0.6));
+        assert_eq!(ch.pan, None);
+        assert_eq!(ch.on, Some(false));
+        assert_eq!(state.bus(16).unwrap().pan, Some(0.25));
+        assert_eq!(state.main().fader, Some(0.7));
+        assert_eq!(state.dca(8).unwrap().fader, Some(0.4));
+        assert!(state.channel(33).is_none());
+
+        // Typed and unmodeled parameters read back through the same paths
+        assert_eq!(state.get("/ch/03/mix/on"), Some(OscArg::Int(0)));
+        assert_eq!(state.get("/dca/8/fader"), Some(OscArg::Float(0.4)));
+        assert_eq!(state.get("/ch/03/eq/1/g"), Some(OscArg::Float(0.5)));
+        assert_eq!(state.values.len(), 1);
+
+        // A value of an unexpected type is kept verbatim rather than dropped
+        state.set("/ch/03/mix/fader", OscArg::Int(1));
+        assert_eq!(state.get("/ch/03/mix/fader"), Some(OscArg::Int(1)));
+
+        let mut paths: Vec<String> = state.iter().map(|(k, _)| k).collect();
+        paths.sort();
+        assert_eq!(
+            paths,
+            [
+                "/bus/16/mix/pan",
+                "/ch/03/config/name",
+                "/ch/03/eq/1/g",
+                "/ch/03/mix/fader",
+                "/ch/03/mix/on",
+                "/dca/8/fader",
+                "/main/st/mix/fader",
+            ]
+        );
+    }
+
     #[test]
     fn test_mixer_seed_from_lines() {
         let mut mixer = Mixer::new();
@@ -39,13 +88,13 @@ mod tests {
 
         assert_eq!(
             mixer.state.get("/ch/01/mix/fader"),
-            Some(&OscArg::Float(0.75))
+            Some(OscArg::Float(0.75))
         );
         assert_eq!(
             mixer.state.get("/ch/01/config/name"),
-            Some(&OscArg::String("MyChannel".to_string()))
+            Some(OscArg::String("MyChannel".to_string()))
         );
-        assert_eq!(mixer.state.get("/ch/01/mix/on"), Some(&OscArg::Int(1)));
+        assert_eq!(mixer.state.get("/ch/01/mix/on"), Some(OscArg::Int(1)));
     }
 
     #[test]
@@ -65,7 +114,7 @@ mod tests {
         // The valid line should be processed
         assert_eq!(
             mixer.state.get("/ch/01/mix/fader"),
-            Some(&OscArg::Float(0.5))
+            Some(OscArg::Float(0.5))
         );
     }
 
@@ -110,6 +159,152 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_mixer_dispatch_snap_dump() {
+        let mut mixer = Mixer::new();
+        mixer.seed_from_lines(vec![
+            "/ch/02/mix/fader,f\t0.5",
+            "/ch/01/mix/fader,f\t0.75",
+            "/ch/01/config/name,s\tKick",
+            "/bus/01/mix/on,i\t0",
+        ]);
+
+        let bytes = OscMessage::new(
+            "/-snap/dump".to_string(),
+            vec![OscArg::String("/ch".to_string())],
+        )
+        .to_bytes()
+        .unwrap();
+        let responses = mixer.dispatch(&bytes, test_addr(1234)).unwrap();
+        let lines: Vec<String> = responses
+            .iter()
+            .map(|(_, bytes)| {
+                let msg = OscMessage::from_bytes(bytes).unwrap();
+                assert_eq!(msg.path, "node");
+                msg.args[0].as_str().unwrap().to_string()
+            })
+            .collect();
+
+        let position = |line: &str| lines.iter().position(|l| l == line);
+        let name = position("/ch/01/config/name \"Kick\"\n").unwrap();
+        let fader1 = position("/ch/01/mix/fader 0.75\n").unwrap();
+        let fader2 = position("/ch/02/mix/fader 0.5\n").unwrap();
+        assert!(name < fader1 && fader1 < fader2);
+        assert!(lines.iter().all(|l| l.starts_with("/ch/")));
+
+        let mut sorted = lines.clone();
+        sorted.sort();
+        assert_eq!(lines, sorted);
+    }
+
+    #[cfg(feature = "json")]
+    #[test]
+    fn test_mixer_state_json() {
+        let mut mixer = Mixer::new();
+        mixer.seed_from_lines(vec![
+            "/ch/01/mix/fader,f\t0.1",
+            "/ch/01/config/name,s\tKick",
+            "/bus/01/mix/on,i\t0",
+        ]);
+
+        let json: serde_json::Value = serde_json::from_str(&mixer.state_json()).unwrap();
+        assert_eq!(json["/ch/01/mix/fader"], serde_json::json!(0.1));
+        assert_eq!(json["/ch/01/config/name"], "Kick");
+        assert_eq!(json["/bus/01/mix/on"], 0);
+    }
+
+    #[test]
+    fn test_mixer_dispatch_xinfo() {
+        let mut mixer = Mixer::new();
+        let bytes = OscMessage::new("/xinfo".to_string(), vec![])
+            .to_bytes()
+            .unwrap();
+
+        let responses = mixer.dispatch(&bytes, test_addr(1234)).unwrap();
+        let response_msg = OscMessage::from_bytes(&responses.last().unwrap().1).unwrap();
+        assert_eq!(response_msg.path, "/xinfo");
+        assert_eq!(
+            response_msg.args,
+            vec![
+                OscArg::String("0.0.0.0".to_string()),
+                OscArg::String("X32 Emulator".to_string()),
+                OscArg::String("X32".to_string()),
+                OscArg::String("4.06".to_string()),
+            ]
+        );
+
+        mixer.set_local_ip(IpAddr::V4(Ipv4Addr::new(192, 168, 1, 20)));
+        let responses = mixer.dispatch(&bytes, test_addr(1234)).unwrap();
+        let response_msg = OscMessage::from_bytes(&responses.last().unwrap().1).unwrap();
+        assert_eq!(
+            response_msg.args[0],
+            OscArg::String("192.168.1.20".to_string())
+        );
+    }
+
+    #[test]
+    fn test_mixer_status_active_after_seeding() {
+        let mut mixer = Mixer::new();
+        assert!(mixer.is_ready());
+        mixer.set_ready(false);
+        let bytes = OscMessage::new("/status".to_string(), vec![])
+            .to_bytes()
+            .unwrap();
+
+        let responses = mixer.dispatch(&bytes, test_addr(1234)).unwrap();
+        let response_msg = OscMessage::from_bytes(&responses.last().unwrap().1).unwrap();
+        assert_eq!(
+            response_msg.args[0],
+            OscArg::String("initializing".to_string())
+        );
+
+        mixer.seed_from_lines(vec!["/ch/01/mix/fader,f\t0.75"]);
+        mixer.set_local_ip(IpAddr::V4(Ipv4Addr::new(192, 168, 1, 20)));
+        mixer.set_ready(true);
+
+        let responses = mixer.dispatch(&bytes, test_addr(1234)).unwrap();
+        let response_msg = OscMessage::from_bytes(&responses.last().unwrap().1).unwrap();
+        assert_eq!(response_msg.args[0], OscArg::String("active".to_string()));
+        assert_eq!(
+            response_msg.args[1],
+            OscArg::String("192.168.1.20".to_string())
+        );
+    }
+
+    #[test]
+    fn test_mixer_dispatch_info_custom_model() {
+        let mut mixer = Mixer::new();
+        mixer.set_server_info(ServerInfo {
+            server_name: "FOH".to_string(),
+            model: "M32".to_string(),
+            firmware: "4.11".to_string(),
+            ..ServerInfo::default()
+        });
+
+        let bytes = OscMessage::new("/info".to_string(), vec![])
+            .to_bytes()
+            .unwrap();
+        let responses = mixer.dispatch(&bytes, test_addr(1234)).unwrap();
+        let response_msg = OscMessage::from_bytes(&responses.last().unwrap().1).unwrap();
+        assert!(response_msg.path.starts_with("/info"));
+        assert_eq!(
+            response_msg.args,
+            vec![
+                OscArg::String("V2.07".to_string()),
+                OscArg::String("FOH".to_string()),
+                OscArg::String("M32".to_string()),
+                OscArg::String("4.11".to_string()),
+            ]
+        );
+
+        let bytes = OscMessage::new("/status".to_string(), vec![])
+            .to_bytes()
+            .unwrap();
+        let responses = mixer.dispatch(&bytes, test_addr(1234)).unwrap();
+        let response_msg = OscMessage::from_bytes(&responses.last().unwrap().1).unwrap();
+        assert_eq!(response_msg.args[2], OscArg::String("FOH".to_string()));
+    }
+
     #[test]
     fn test_mixer_dispatch_renew() {
         let mut mixer = Mixer::new();
@@ -123,6 +318,149 @@ mod tests {
         assert!(responses.is_empty());
     }
 
+    #[test]
+    fn test_mixer_meter_resubscribe_does_not_duplicate() {
+        let mut mixer = Mixer::new();
+        let bytes = OscMessage::new(
+            "/meters".to_string(),
+            vec![OscArg::String("/meters/1".to_string())],
+        )
+        .to_bytes()
+        .unwrap();
+        mixer.dispatch(&bytes, test_addr(1234)).unwrap();
+        mixer.dispatch(&bytes, test_addr(1234)).unwrap();
+
+        assert_eq!(mixer.active_meters.len(), 1);
+        let responses = mixer.tick();
+        assert_eq!(responses.len(), 1);
+        assert_eq!(responses[0].0, test_addr(1234));
+    }
+
+    #[test]
+    fn test_mixer_meter_time_factor_sets_rate() {
+        let mut mixer = Mixer::new();
+        let subscribe = |mixer: &mut Mixer, port: u16, factor: i32| {
+            let bytes = OscMessage::new(
+                "/meters".to_string(),
+                vec![OscArg::String("/meters/1".to_string()), OscArg::Int(factor)],
+            )
+            .to_bytes()
+            .unwrap();
+            mixer.dispatch(&bytes, test_addr(port)).unwrap();
+        };
+        subscribe(&mut mixer, 1111, 1);
+        subscribe(&mut mixer, 2222, 4);
+        // Out of range factors are clamped to 1-99.
+        subscribe(&mut mixer, 3333, 0);
+        subscribe(&mut mixer, 4444, 500);
+        assert_eq!(
+            mixer.active_meters[&(test_addr(3333), 1)].interval,
+            Duration::from_millis(50)
+        );
+        assert_eq!(
+            mixer.active_meters[&(test_addr(4444), 1)].interval,
+            Duration::from_millis(50 * 99)
+        );
+
+        // Simulate one second of ticks that come every 30 ms, off the 50 ms grid.
+        let start = Instant::now();
+        let mut frames = HashMap::new();
+        for i in 0..34 {
+            for (addr, _) in mixer.tick_at(start + Duration::from_millis(30 * i)) {
+                *frames.entry(addr.port()).or_insert(0) += 1;
+            }
+        }
+        assert_eq!(frames[&1111], 20);
+        assert_eq!(frames[&2222], 5);
+        assert_eq!(frames[&4444], 1);
+    }
+
+    #[test]
+    fn test_mixer_meter_time_factor_position_depends_on_meter() {
+        let mut mixer = Mixer::new();
+        let mut interval = |meter: &str, ints: &[i32]| {
+            let mut args = vec![OscArg::String(meter.to_string())];
+            args.extend(ints.iter().copied().map(OscArg::Int));
+            let bytes = OscMessage::new("/meters".to_string(), args)
+                .to_bytes()
+                .unwrap();
+            mixer.dispatch(&bytes, test_addr(1234)).unwrap();
+            let idx = meter[8..].parse::<u8>().unwrap();
+            mixer.active_meters[&(test_addr(1234), idx)].interval
+        };
+
+        assert_eq!(interval("/meters/2", &[4]), Duration::from_millis(200));
+        // /meters/5 takes two meter ids before the time factor.
+        assert_eq!(
+            interval("/meters/5", &[3, 7, 2]),
+            Duration::from_millis(100)
+        );
+        assert_eq!(interval("/meters/5", &[3, 7]), Duration::from_millis(50));
+        // /meters/6 takes two unused ints and the channel before it.
+        assert_eq!(
+            interval("/meters/6", &[0, 0, 5, 3]),
+            Duration::from_millis(150)
+        );
+        assert_eq!(interval("/meters/6", &[0, 0, 5]), Duration::from_millis(50));
+    }
+
+    #[test]
+    fn test_mixer_renew_extends_meter_subscription() {
+        let mut mixer = Mixer::new();
+        for meter in ["/meters/1", "/meters/2"] {
+            let bytes = OscMessage::new(
+                "/meters".to_string(),
+                vec![OscArg::String(meter.to_string())],
+            )
+            .to_bytes()
+            .unwrap();
+            mixer.dispatch(&bytes, test_addr(1234)).unwrap();
+        }
+
+        // Both subscriptions are about to expire.
+        let soon = Instant::now() + Duration::from_millis(100);
+        for sub in mixer.active_meters.values_mut() {
+            sub.expiry = soon;
+        }
+
+        let renew = OscMessage::new(
+            "/renew".to_string(),
+            vec![OscArg::String("/meters/1".to_string())],
+        )
+        .to_bytes()
+        .unwrap();
+        let responses = mixer.dispatch(&renew, test_addr(1234)).unwrap();
+        assert!(responses.is_empty());
+        assert!(mixer.active_meters[&(test_addr(1234), 1)].expiry > soon + Duration::from_secs(9));
+        assert_eq!(mixer.active_meters[&(test_addr(1234), 2)].expiry, soon);
+
+        // After the original expiry only the renewed subscription still streams.
+        std::thread::sleep(Duration::from_millis(150));
+        let responses = mixer.tick();
+        assert_eq!(responses.len(), 1);
+        let msg = OscMessage::from_bytes(&responses[0].1).unwrap();
+        assert_eq!(msg.path, "/meters/1");
+
+        // A bare /renew extends every meter subscription of the client, but not
+        // those of other clients.
+        let bytes = OscMessage::new(
+            "/meters".to_string(),
+            vec![OscArg::String("/meters/2".to_string())],
+        )
+        .to_bytes()
+        .unwrap();
+        mixer.dispatch(&bytes, test_addr(5678)).unwrap();
+        for sub in mixer.active_meters.values_mut() {
+            sub.expiry = soon;
+        }
+        let renew = OscMessage::new("/renew".to_string(), vec![])
+            .to_bytes()
+            .unwrap();
+        mixer.dispatch(&renew, test_addr(1234)).unwrap();
+        assert!(mixer.active_meters[&(test_addr(1234), 1)].expiry > soon);
+        assert_eq!(mixer.active_meters[&(test_addr(5678), 2)].expiry, soon);
+    }
+
     #[test]
     fn test_mixer_dispatch_unsubscribe() {
         let mut mixer = Mixer::new();
@@ -161,7 +499,7 @@ mod tests {
 
         assert_eq!(
             mixer.state.get("/ch/01/mix/fader"),
-            Some(&OscArg::Float(0.5))
+            Some(OscArg::Float(0.5))
         );
     }
 
@@ -184,6 +522,17 @@ mod tests {
         assert_eq!(response_msg.args, vec![OscArg::Float(0.8)]);
     }
 
+    #[test]
+    fn test_mixer_dispatch_rejects_invalid_address() {
+        let mut mixer = Mixer::new();
+        let msg = OscMessage::new("/ch/01/mix fader".to_string(), vec![OscArg::Float(0.5)]);
+
+        assert!(mixer
+            .dispatch(&msg.to_bytes().unwrap(), test_addr(1234))
+            .is_err());
+        assert_eq!(mixer.state.get("/ch/01/mix fader"), None);
+    }
+
     #[test]
     fn test_mixer_dispatch_get_non_existent_value() {
         let mut mixer = Mixer::new();
@@ -224,6 +573,599 @@ mod tests {
         assert_eq!(response_msg.args, vec![OscArg::Float(0.5)]);
     }
 
+    #[test]
+    fn test_mixer_dispatch_slash_command_propagates_sets() {
+        let mut mixer = Mixer::new();
+        let msg_xremote = OscMessage::new("/xremote".to_string(), vec![])
+            .to_bytes()
+            .unwrap();
+        mixer.dispatch(&msg_xremote, test_addr(1111)).unwrap();
+
+        let text = "/ch/01/config/name \"Kick\"\n/ch/02/mix/on OFF\n/ch/03/config/color RD\n";
+        let msg_bulk = OscMessage::new("/".to_string(), vec![OscArg::String(text.to_string())])
+            .to_bytes()
+            .unwrap();
+        let responses = mixer.dispatch(&msg_bulk, test_addr(2222)).unwrap();
+
+        let received: Vec<OscMessage> = responses
+            .iter()
+            .filter(|(addr, _)| *addr == test_addr(1111))
+            .map(|(_, bytes)| OscMessage::from_bytes(bytes).unwrap())
+            .collect();
+        assert_eq!(received.len(), 3);
+        assert_eq!(received[0].path, "/ch/01/config/name");
+        assert_eq!(received[0].args, vec![OscArg::String("Kick".to_string())]);
+        assert_eq!(received[1].path, "/ch/02/mix/on");
+        assert_eq!(received[1].args, vec![OscArg::Int(0)]);
+        assert_eq!(received[2].path, "/ch/03/config/color");
+        assert_eq!(received[2].args, vec![OscArg::Int(1)]);
+        assert_eq!(
+            mixer.state.get("/ch/01/config/name"),
+            Some(OscArg::String("Kick".to_string()))
+        );
+    }
+
+    /// Sends `text` as the string argument of `/`.
+    fn send_node_lines(mixer: &mut Mixer, text: &str) {
+        let msg_bulk = OscMessage::new("/".to_string(), vec![OscArg::String(text.to_string())])
+            .to_bytes()
+            .unwrap();
+        mixer.dispatch(&msg_bulk, test_addr(2222)).unwrap();
+    }
+
+    fn assert_float(arg: Option<OscArg>, expected: f32) {
+        match arg {
+            Some(OscArg::Float(f)) => {
+                assert!(
+                    (f - expected).abs() < 0.01,
+                    "expected {}, got {}",
+                    expected,
+                    f
+                )
+            }
+            other => panic!("expected a float near {}, got {:?}", expected, other),
+        }
+    }
+
+    #[test]
+    fn test_mixer_dispatch_slash_command_converts_db_values() {
+        let mut mixer = Mixer::new();
+        send_node_lines(
+            &mut mixer,
+            "/ch/01/mix/fader -10.0\n/ch/02/mix/fader 0\n/ch/01/mix/01 ON -20.0 +0 POST\n",
+        );
+
+        assert_float(mixer.state.get("/ch/01/mix/fader"), 0.5);
+        // A whole number of dB is still a fader position, not an integer.
+        assert_eq!(
+            mixer.state.get("/ch/02/mix/fader"),
+            Some(OscArg::Float(0.75))
+        );
+        assert_float(mixer.state.get("/ch/01/mix/01/level"), 0.375);
+        assert_float(mixer.state.get("/ch/01/mix/01/pan"), 0.5);
+        assert_eq!(mixer.state.get("/ch/01/mix/01/type"), Some(OscArg::Int(4)));
+    }
+
+    #[test]
+    fn test_mixer_dispatch_slash_command_converts_minus_infinity() {
+        let mut mixer = Mixer::new();
+        send_node_lines(&mut mixer, "/ch/03/mix ON -oo OFF +0 OFF -oo\n");
+
+        assert_eq!(mixer.state.get("/ch/03/mix/on"), Some(OscArg::Int(1)));
+        assert_eq!(
+            mixer.state.get("/ch/03/mix/fader"),
+            Some(OscArg::Float(0.0))
+        );
+        assert_eq!(mixer.state.get("/ch/03/mix/st"), Some(OscArg::Int(0)));
+        assert_eq!(
+            mixer.state.get("/ch/03/mix/mlevel"),
+            Some(OscArg::Float(0.0))
+        );
+
+        let msg_get = OscMessage::new("/ch/03/mix/fader".to_string(), vec![])
+            .to_bytes()
+            .unwrap();
+        let responses = mixer.dispatch(&msg_get, test_addr(2222)).unwrap();
+        assert_eq!(responses.len(), 1);
+        let reply = OscMessage::from_bytes(&responses[0].1).unwrap();
+        assert_eq!(reply.args, vec![OscArg::Float(0.0)]);
+    }
+
+    #[test]
+    fn test_mixer_dispatch_slash_command_converts_enum_tokens() {
+        let mut mixer = Mixer::new();
+        send_node_lines(
+            &mut mixer,
+            "/ch/01/eq/1 PEQ 124.7 +3.00 2.0\n/ch/01/gate ON DUCK -40.0 30.0 1 502 983 0\n",
+        );
+
+        assert_eq!(mixer.state.get("/ch/01/eq/1/type"), Some(OscArg::Int(2)));
+        assert_float(mixer.state.get("/ch/01/eq/1/f"), 0.265);
+        assert_float(mixer.state.get("/ch/01/eq/1/g"), 0.6);
+        assert_float(mixer.state.get("/ch/01/eq/1/q"), 0.541);
+        assert_eq!(mixer.state.get("/ch/01/gate/on"), Some(OscArg::Int(1)));
+        assert_eq!(mixer.state.get("/ch/01/gate/mode"), Some(OscArg::Int(4)));
+        assert_float(mixer.state.get("/ch/01/gate/thr"), 0.5);
+        assert_float(mixer.state.get("/ch/01/gate/attack"), 1.0 / 120.0);
+        assert_eq!(mixer.state.get("/ch/01/gate/keysrc"), Some(OscArg::Int(0)));
+    }
+
+    #[test]
+    fn test_mixer_dispatch_set_address_pattern_updates_solo() {
+        let mut mixer = Mixer::new();
+        mixer.seed_from_lines(vec![
+            "/-stat/solosw/01,i\t0",
+            "/-stat/solosw/02,i\t0",
+            "/-stat/solo,i\t0",
+        ]);
+
+        let msg_xremote = OscMessage::new("/xremote".to_string(), vec![])
+            .to_bytes()
+            .unwrap();
+        mixer.dispatch(&msg_xremote, test_addr(1111)).unwrap();
+
+        let msg_set = OscMessage::new("/-stat/solosw/*".to_string(), vec![OscArg::Int(1)])
+            .to_bytes()
+            .unwrap();
+        let responses = mixer.dispatch(&msg_set, test_addr(2222)).unwrap();
+
+        assert_eq!(mixer.state.get("/-stat/solosw/01"), Some(OscArg::Int(1)));
+        assert_eq!(mixer.state.get("/-stat/solosw/02"), Some(OscArg::Int(1)));
+        assert_eq!(mixer.state.get("/-stat/solo"), Some(OscArg::Int(1)));
+
+        let solo = responses
+            .iter()
+            .map(|(_, b)| OscMessage::from_bytes(b).unwrap())
+            .find(|m| m.path == "/-stat/solo")
+            .expect("/-stat/solo should be broadcast");
+        assert_eq!(solo.args, vec![OscArg::Int(1)]);
+    }
+
+    #[test]
+    fn test_mixer_userpar_set_is_read_back_and_propagated() {
+        let mut mixer = Mixer::new();
+
+        let msg_xremote = OscMessage::new("/xremote".to_string(), vec![])
+            .to_bytes()
+            .unwrap();
+        mixer.dispatch(&msg_xremote, test_addr(1111)).unwrap();
+
+        // Before any set, user bank parameters read as their power-on values.
+        let get = |mixer: &mut Mixer, path: &str| {
+            let msg = OscMessage::new(path.to_string(), vec![])
+                .to_bytes()
+                .unwrap();
+            let responses = mixer.dispatch(&msg, test_addr(2222)).unwrap();
+            assert_eq!(responses.len(), 1, "{} should get one reply", path);
+            OscMessage::from_bytes(&responses[0].1).unwrap().args
+        };
+        assert_eq!(
+            get(&mut mixer, "/-stat/userpar/18/value"),
+            vec![OscArg::Int(0)]
+        );
+        assert_eq!(
+            get(&mut mixer, "/config/userctrl/C/btn/6"),
+            vec![OscArg::String(String::new())]
+        );
+
+        let msg_set = OscMessage::new(
+            "/-stat/userpar/18/value".to_string(),
+            vec![OscArg::Int(127)],
+        )
+        .to_bytes()
+        .unwrap();
+        let responses = mixer.dispatch(&msg_set, test_addr(2222)).unwrap();
+        assert_eq!(responses.len(), 1);
+        assert_eq!(responses[0].0, test_addr(1111));
+        let update = OscMessage::from_bytes(&responses[0].1).unwrap();
+        assert_eq!(update.path, "/-stat/userpar/18/value");
+        assert_eq!(update.args, vec![OscArg::Int(127)]);
+
+        assert_eq!(
+            get(&mut mixer, "/-stat/userpar/18/value"),
+            vec![OscArg::Int(127)]
+        );
+
+        let msg_assign = OscMessage::new(
+            "/config/userctrl/C/btn/6".to_string(),
+            vec![OscArg::String("MN16001".to_string())],
+        )
+        .to_bytes()
+        .unwrap();
+        mixer.dispatch(&msg_assign, test_addr(2222)).unwrap();
+        assert_eq!(
+            get(&mut mixer, "/config/userctrl/C/btn/6"),
+            vec![OscArg::String("MN16001".to_string())]
+        );
+
+        // Paths outside the user banks are neither stored nor answered.
+        for path in ["/-stat/userpar/37/value", "/-stat/userpar/5/value"] {
+            let msg_set = OscMessage::new(path.to_string(), vec![OscArg::Int(127)])
+                .to_bytes()
+                .unwrap();
+            assert!(mixer
+                .dispatch(&msg_set, test_addr(2222))
+                .unwrap()
+                .is_empty());
+            let msg_get = OscMessage::new(path.to_string(), vec![])
+                .to_bytes()
+                .unwrap();
+            assert!(mixer
+                .dispatch(&msg_get, test_addr(2222))
+                .unwrap()
+                .is_empty());
+        }
+    }
+
+    #[test]
+    fn test_mixer_set_clamps_out_of_range_values() {
+        let mut mixer = Mixer::new();
+
+        let msg_xremote = OscMessage::new("/xremote".to_string(), vec![])
+            .to_bytes()
+            .unwrap();
+        mixer.dispatch(&msg_xremote, test_addr(1111)).unwrap();
+
+        let mut set = |path: &str, arg: OscArg| {
+            let msg = OscMessage::new(path.to_string(), vec![arg])
+                .to_bytes()
+                .unwrap();
+            let responses = mixer.dispatch(&msg, test_addr(2222)).unwrap();
+            assert_eq!(responses.len(), 1);
+            OscMessage::from_bytes(&responses[0].1).unwrap().args[0].clone()
+        };
+
+        assert_eq!(
+            set("/ch/01/mix/fader", OscArg::Float(2.0)),
+            OscArg::Float(1.0)
+        );
+        assert_eq!(
+            set("/ch/02/mix/fader", OscArg::Float(-1.0)),
+            OscArg::Float(0.0)
+        );
+        assert_eq!(
+            set("/ch/01/mix/pan", OscArg::Float(-0.5)),
+            OscArg::Float(0.0)
+        );
+        assert_eq!(
+            set("/ch/01/mix/03/level", OscArg::Float(1.5)),
+            OscArg::Float(1.0)
+        );
+        assert_eq!(set("/ch/01/mix/on", OscArg::Int(5)), OscArg::Int(1));
+        assert_eq!(set("/ch/01/mix/on", OscArg::Int(-1)), OscArg::Int(0));
+
+        assert_eq!(
+            mixer.state.get("/ch/01/mix/fader"),
+            Some(OscArg::Float(1.0))
+        );
+        assert_eq!(
+            mixer.state.get("/ch/02/mix/fader"),
+            Some(OscArg::Float(0.0))
+        );
+    }
+
+    #[test]
+    fn test_mixer_undo_restores_previous_value() {
+        let mut mixer = Mixer::new();
+        mixer.seed_from_lines(vec!["/ch/01/mix/fader,f\t0.25"]);
+
+        let msg_xremote = OscMessage::new("/xremote".to_string(), vec![])
+            .to_bytes()
+            .unwrap();
+        mixer.dispatch(&msg_xremote, test_addr(1111)).unwrap();
+
+        let msg_set = OscMessage::new("/ch/01/mix/fader".to_string(), vec![OscArg::Float(0.5)])
+            .to_bytes()
+            .unwrap();
+        mixer.dispatch(&msg_set, test_addr(2222)).unwrap();
+        assert_eq!(
+            mixer.state.get("/ch/01/mix/fader"),
+            Some(OscArg::Float(0.5))
+        );
+
+        let msg_undo = OscMessage::new("/-undo".to_string(), vec![])
+            .to_bytes()
+            .unwrap();
+        let responses = mixer.dispatch(&msg_undo, test_addr(2222)).unwrap();
+
+        assert_eq!(
+            mixer.state.get("/ch/01/mix/fader"),
+            Some(OscArg::Float(0.25))
+        );
+        assert_eq!(responses.len(), 1);
+        assert_eq!(responses[0].0, test_addr(1111));
+        let update = OscMessage::from_bytes(&responses[0].1).unwrap();
+        assert_eq!(update.path, "/ch/01/mix/fader");
+        assert_eq!(update.args, vec![OscArg::Float(0.25)]);
+
+        // A second undo reverts the undo.
+        mixer.dispatch(&msg_undo, test_addr(2222)).unwrap();
+        assert_eq!(
+            mixer.state.get("/ch/01/mix/fader"),
+            Some(OscArg::Float(0.5))
+        );
+    }
+
+    #[test]
+    fn test_mixer_undo_unsets_new_parameter() {
+        let mut mixer = Mixer::new();
+        let msg_xremote = OscMessage::new("/xremote".to_string(), vec![])
+            .to_bytes()
+            .unwrap();
+        mixer.dispatch(&msg_xremote, test_addr(1111)).unwrap();
+
+        let msg_set = OscMessage::new("/ch/02/delay/on".to_string(), vec![OscArg::Int(1)])
+            .to_bytes()
+            .unwrap();
+        mixer.dispatch(&msg_set, test_addr(2222)).unwrap();
+
+        // The parameter was never set, so undo removes it and reports its default.
+        let msg_undo = OscMessage::new("/-undo".to_string(), vec![])
+            .to_bytes()
+            .unwrap();
+        let responses = mixer.dispatch(&msg_undo, test_addr(2222)).unwrap();
+        assert_eq!(mixer.state.get("/ch/02/delay/on"), None);
+        assert_eq!(responses.len(), 1);
+        let update = OscMessage::from_bytes(&responses[0].1).unwrap();
+        assert_eq!(update.path, "/ch/02/delay/on");
+        assert_eq!(update.args, vec![OscArg::Int(0)]);
+
+        mixer.dispatch(&msg_undo, test_addr(2222)).unwrap();
+        assert_eq!(mixer.state.get("/ch/02/delay/on"), Some(OscArg::Int(1)));
+    }
+
+    #[test]
+    fn test_mixer_undo_reverts_copy() {
+        let mut mixer = Mixer::new();
+        mixer.seed_from_lines(vec![
+            "/ch/01/mix/fader,f\t0.75",
+            "/ch/02/mix/fader,f\t0.25",
+            "/ch/03/mix/fader,f\t0.5",
+        ]);
+
+        let copy = OscMessage::new(
+            "/copy".to_string(),
+            vec![
+                OscArg::String("libchan".to_string()),
+                OscArg::Int(0),
+                OscArg::Int(1),
+                OscArg::Int(-1),
+            ],
+        );
+        mixer
+            .dispatch(&copy.to_bytes().unwrap(), test_addr(2222))
+            .unwrap();
+        assert_eq!(
+            mixer.state.get("/ch/02/mix/fader"),
+            Some(OscArg::Float(0.75))
+        );
+
+        let msg_undo = OscMessage::new("/-undo".to_string(), vec![])
+            .to_bytes()
+            .unwrap();
+        mixer.dispatch(&msg_undo, test_addr(2222)).unwrap();
+        assert_eq!(
+            mixer.state.get("/ch/02/mix/fader"),
+            Some(OscArg::Float(0.25))
+        );
+        assert_eq!(
+            mixer.state.get("/ch/03/mix/fader"),
+            Some(OscArg::Float(0.5))
+        );
+    }
+
+    #[test]
+    fn test_mixer_set_not_echoed_to_originator() {
+        let mut mixer = Mixer::new();
+
+        let msg_xremote = OscMessage::new("/xremote".to_string(), vec![])
+            .to_bytes()
+            .unwrap();
+        mixer.dispatch(&msg_xremote, test_addr(1111)).unwrap();
+        mixer.dispatch(&msg_xremote, test_addr(2222)).unwrap();
+
+        let msg_set = OscMessage::new("/ch/01/mix/fader".to_string(), vec![OscArg::Float(0.5)])
+            .to_bytes()
+            .unwrap();
+        let responses = mixer.dispatch(&msg_set, test_addr(2222)).unwrap();
+
+        assert_eq!(responses.len(), 1);
+        assert_eq!(responses[0].0, test_addr(1111));
+        assert!(responses.iter().all(|(addr, _)| *addr != test_addr(2222)));
+    }
+
+    #[test]
+    fn test_mixer_dispatch_concatenated_messages() {
+        let mut mixer = Mixer::new();
+
+        let mut datagram =
+            OscMessage::new("/ch/01/mix/fader".to_string(), vec![OscArg::Float(0.25)])
+                .to_bytes()
+                .unwrap();
+        datagram.extend(
+            OscMessage::new("/info".to_string(), vec![])
+                .to_bytes()
+                .unwrap(),
+        );
+        let responses = mixer.dispatch(&datagram, test_addr(1111)).unwrap();
+
+        assert_eq!(
+            mixer.state.get("/ch/01/mix/fader"),
+            Some(OscArg::Float(0.25))
+        );
+        assert_eq!(responses.len(), 1);
+        let response_msg = OscMessage::from_bytes(&responses[0].1).unwrap();
+        assert_eq!(response_msg.path, "/info");
+    }
+
+    #[test]
+    fn test_mixer_dispatch_bundle() {
+        let mut mixer = Mixer::new();
+
+        let bundle = OscBundle::new(vec![
+            OscMessage::new(
+                "/ch/01/config/name".to_string(),
+                vec![OscArg::String("Kick".to_string())],
+            )
+            .into(),
+            OscMessage::new(
+                "/ch/02/config/name".to_string(),
+                vec![OscArg::String("Snare".to_string())],
+            )
+            .into(),
+            OscMessage::new("/ch/02/config/name".to_string(), vec![]).into(),
+        ]);
+        let responses = mixer
+            .dispatch(&bundle.to_bytes().unwrap(), test_addr(1111))
+            .unwrap();
+
+        assert_eq!(
+            mixer.state.get("/ch/01/config/name"),
+            Some(OscArg::String("Kick".to_string()))
+        );
+        assert_eq!(
+            mixer.state.get("/ch/02/config/name"),
+            Some(OscArg::String("Snare".to_string()))
+        );
+        // The query at the end of the bundle sees the set before it.
+        assert_eq!(responses.len(), 1);
+        let response_msg = OscMessage::from_bytes(&responses[0].1).unwrap();
+        assert_eq!(response_msg.args, vec![OscArg::String("Snare".to_string())]);
+    }
+
+    #[test]
+    fn test_mixer_stale_client_stops_receiving_updates() {
+        let mut mixer = Mixer::new();
+
+        let start = Instant::now();
+        let msg_xremote = OscMessage::new("/xremote".to_string(), vec![])
+            .to_bytes()
+            .unwrap();
+        mixer.dispatch(&msg_xremote, test_addr(1111)).unwrap();
+
+        // Any message from a subscribed client keeps it alive past the original expiry.
+        std::thread::sleep(Duration::from_millis(100));
+        let msg_info = OscMessage::new("/info".to_string(), vec![])
+            .to_bytes()
+            .unwrap();
+        mixer.dispatch(&msg_info, test_addr(1111)).unwrap();
+        mixer.prune_clients(start + Duration::from_millis(10_050));
+
+        let msg_set = OscMessage::new("/ch/01/mix/fader".to_string(), vec![OscArg::Float(0.5)])
+            .to_bytes()
+            .unwrap();
+        let responses = mixer.dispatch(&msg_set, test_addr(2222)).unwrap();
+        assert_eq!(responses.len(), 1);
+        assert_eq!(responses[0].0, test_addr(1111));
+
+        // Without renewal, the subscription lapses after ten seconds.
+        mixer.prune_clients(Instant::now() + Duration::from_secs(11));
+        let responses = mixer.dispatch(&msg_set, test_addr(2222)).unwrap();
+        assert!(responses.is_empty());
+    }
+
+    #[test]
+    fn test_mixer_selchannel_updates_selidx() {
+        let mut mixer = Mixer::new();
+
+        let msg_xremote = OscMessage::new("/xremote".to_string(), vec![])
+            .to_bytes()
+            .unwrap();
+        mixer.dispatch(&msg_xremote, test_addr(1111)).unwrap();
+
+        let msg_sel = OscMessage::new("/-action/selchannel".to_string(), vec![OscArg::Int(5)])
+            .to_bytes()
+            .unwrap();
+        let responses = mixer.dispatch(&msg_sel, test_addr(2222)).unwrap();
+
+        assert_eq!(mixer.state.get("/-stat/selidx"), Some(OscArg::Int(5)));
+        assert_eq!(responses.len(), 1);
+        assert_eq!(responses[0].0, test_addr(1111));
+
+        let response_msg = OscMessage::from_bytes(&responses[0].1).unwrap();
+        assert_eq!(response_msg.path, "/-stat/selidx");
+        assert_eq!(response_msg.args, vec![OscArg::Int(5)]);
+
+        // A subsequent query of selidx reflects the new selection
+        let msg_get = OscMessage::new("/-stat/selidx".to_string(), vec![])
+            .to_bytes()
+            .unwrap();
+        let responses = mixer.dispatch(&msg_get, test_addr(2222)).unwrap();
+        let response_msg = OscMessage::from_bytes(&responses[0].1).unwrap();
+        assert_eq!(response_msg.args, vec![OscArg::Int(5)]);
+    }
+
+    #[test]
+    fn test_mixer_dispatch_set_address_pattern() {
+        let mut mixer = Mixer::new();
+        mixer.seed_from_lines(vec![
+            "/ch/01/mix/fader,f\t0.1",
+            "/ch/02/mix/fader,f\t0.2",
+            "/ch/12/mix/fader,f\t0.3",
+            "/ch/01/mix/on,i\t1",
+        ]);
+
+        let msg_xremote = OscMessage::new("/xremote".to_string(), vec![])
+            .to_bytes()
+            .unwrap();
+        mixer.dispatch(&msg_xremote, test_addr(1111)).unwrap();
+
+        let msg_set = OscMessage::new("/ch/0[0-9]/mix/fader".to_string(), vec![OscArg::Float(0.5)])
+            .to_bytes()
+            .unwrap();
+        let responses = mixer.dispatch(&msg_set, test_addr(2222)).unwrap();
+
+        assert_eq!(
+            mixer.state.get("/ch/01/mix/fader"),
+            Some(OscArg::Float(0.5))
+        );
+        assert_eq!(
+            mixer.state.get("/ch/02/mix/fader"),
+            Some(OscArg::Float(0.5))
+        );
+        assert_eq!(
+            mixer.state.get("/ch/12/mix/fader"),
+            Some(OscArg::Float(0.3))
+        );
+        assert_eq!(mixer.state.get("/ch/01/mix/on"), Some(OscArg::Int(1)));
+        // The pattern itself must not be stored as a parameter
+        assert_eq!(mixer.state.get("/ch/0[0-9]/mix/fader"), None);
+
+        let paths: Vec<String> = responses
+            .iter()
+            .map(|(_, b)| OscMessage::from_bytes(b).unwrap().path)
+            .collect();
+        assert_eq!(paths, vec!["/ch/01/mix/fader", "/ch/02/mix/fader"]);
+    }
+
+    #[test]
+    fn test_mixer_dispatch_get_address_pattern() {
+        let mut mixer = Mixer::new();
+        mixer.seed_from_lines(vec![
+            "/ch/01/mix/fader,f\t0.1",
+            "/ch/02/mix/fader,f\t0.2",
+            "/ch/03/mix/fader,f\t0.3",
+            "/ch/04/mix/fader,f\t0.4",
+            "/ch/02/mix/on,i\t1",
+        ]);
+
+        let msg_get = OscMessage::new("/ch/0[1-3]/mix/fader".to_string(), vec![])
+            .to_bytes()
+            .unwrap();
+        let responses = mixer.dispatch(&msg_get, test_addr(1234)).unwrap();
+
+        assert!(responses.iter().all(|(addr, _)| *addr == test_addr(1234)));
+        let replies: Vec<OscMessage> = responses
+            .iter()
+            .map(|(_, b)| OscMessage::from_bytes(b).unwrap())
+            .collect();
+        assert_eq!(replies.len(), 3);
+        for (i, reply) in replies.iter().enumerate() {
+            assert_eq!(reply.path, format!("/ch/{:02}/mix/fader", i + 1));
+            assert_eq!(reply.args, vec![OscArg::Float((i + 1) as f32 / 10.0)]);
+        }
+    }
+
     #[test]
     fn test_mixer_xremote_max_clients() {
         let mut mixer = Mixer::new();
@@ -284,23 +1226,111 @@ mod tests {
 
         let responses = mixer.dispatch(&bytes, test_addr(1234)).unwrap();
 
-        // We expect the destination to be updated
+        // We expect the destination to be updated
+        assert_eq!(
+            mixer.state.get("/ch/02/config/name"),
+            Some(OscArg::String("Source".to_string()))
+        );
+        assert_eq!(
+            mixer.state.get("/ch/02/mix/fader"),
+            Some(OscArg::Float(0.75))
+        );
+
+        // We expect a response acknowledging the copy
+        assert!(responses.len() >= 1);
+        let response_msg = OscMessage::from_bytes(&responses.last().unwrap().1).unwrap();
+        assert_eq!(response_msg.path, "/copy");
+        assert_eq!(response_msg.args.len(), 2);
+        assert_eq!(response_msg.args[0], OscArg::String("libchan".to_string()));
+        assert_eq!(response_msg.args[1], OscArg::Int(1));
+    }
+
+    #[test]
+    fn test_mixer_dispatch_copy_libchan_eq_only() {
+        let mut mixer = Mixer::new();
+        mixer.seed_from_lines(vec![
+            "/ch/01/config/name,s\tSource",
+            "/ch/01/eq/1/g,f\t0.75",
+            "/ch/01/dyn/thr,f\t0.25",
+            "/ch/02/config/name,s\tDest",
+        ]);
+
+        // format: /copy ,siii "libchan" source dest mask, with C_EQ = 0x0010
+        let msg = OscMessage::new(
+            "/copy".to_string(),
+            vec![
+                OscArg::String("libchan".to_string()),
+                OscArg::Int(0),
+                OscArg::Int(1),
+                OscArg::Int(0x0010),
+            ],
+        );
+        let responses = mixer
+            .dispatch(&msg.to_bytes().unwrap(), test_addr(1234))
+            .unwrap();
+
+        assert_eq!(mixer.state.get("/ch/02/eq/1/g"), Some(OscArg::Float(0.75)));
+        assert_eq!(mixer.state.get("/ch/02/dyn/thr"), None);
         assert_eq!(
             mixer.state.get("/ch/02/config/name"),
-            Some(&OscArg::String("Source".to_string()))
+            Some(OscArg::String("Dest".to_string()))
         );
+
+        let response_msg = OscMessage::from_bytes(&responses.last().unwrap().1).unwrap();
         assert_eq!(
-            mixer.state.get("/ch/02/mix/fader"),
-            Some(&OscArg::Float(0.75))
+            response_msg.args,
+            vec![OscArg::String("libchan".to_string()), OscArg::Int(1)]
         );
+    }
 
-        // We expect a response acknowledging the copy
-        assert!(responses.len() >= 1);
-        let response_msg = OscMessage::from_bytes(&responses.last().unwrap().1).unwrap();
-        assert_eq!(response_msg.path, "/copy");
-        assert_eq!(response_msg.args.len(), 2);
-        assert_eq!(response_msg.args[0], OscArg::String("libchan".to_string()));
-        assert_eq!(response_msg.args[1], OscArg::Int(1));
+    #[test]
+    fn test_mixer_dispatch_copy_libchan_range() {
+        let mut mixer = Mixer::new();
+        mixer.seed_from_lines(vec![
+            "/ch/01/config/name,s\tKick",
+            "/ch/02/config/name,s\tSnare",
+            "/ch/03/config/name,s\tHat",
+            "/ch/01/mix/fader,f\t0.5",
+        ]);
+
+        // Copy channels 01-03 to 11-13: /copy ,siiii "libchan" source dest mask count
+        let copy = |mixer: &mut Mixer, src: i32, dst: i32, count: i32| {
+            let msg = OscMessage::new(
+                "/copy".to_string(),
+                vec![
+                    OscArg::String("libchan".to_string()),
+                    OscArg::Int(src),
+                    OscArg::Int(dst),
+                    OscArg::Int(-1),
+                    OscArg::Int(count),
+                ],
+            );
+            let responses = mixer
+                .dispatch(&msg.to_bytes().unwrap(), test_addr(1234))
+                .unwrap();
+            OscMessage::from_bytes(&responses.last().unwrap().1)
+                .unwrap()
+                .args[1]
+                .clone()
+        };
+        assert_eq!(copy(&mut mixer, 0, 10, 3), OscArg::Int(1));
+
+        for (ch, name) in [(11, "Kick"), (12, "Snare"), (13, "Hat")] {
+            assert_eq!(
+                mixer.state.get(&format!("/ch/{}/config/name", ch)),
+                Some(OscArg::String(name.to_string()))
+            );
+        }
+        assert_eq!(
+            mixer.state.get("/ch/11/mix/fader"),
+            Some(OscArg::Float(0.5))
+        );
+        assert_eq!(mixer.state.get("/ch/14/config/name"), None);
+
+        // A range running past channel 32 is rejected without copying anything.
+        assert_eq!(copy(&mut mixer, 0, 30, 3), OscArg::Int(0));
+        assert_eq!(mixer.state.get("/ch/31/config/name"), None);
+        assert_eq!(copy(&mut mixer, 0, 1, 0), OscArg::Int(0));
     }
 
     #[test]
@@ -330,11 +1360,11 @@ mod tests {
 
         assert_eq!(
             mixer.state.get("/-libs/fx/002/name"),
-            Some(&OscArg::String("SourceFX".to_string()))
+            Some(OscArg::String("SourceFX".to_string()))
         );
         assert_eq!(
             mixer.state.get("/-libs/fx/002/hasdata"),
-            Some(&OscArg::Int(1))
+            Some(OscArg::Int(1))
         );
 
         assert!(responses.len() >= 1);
@@ -373,11 +1403,11 @@ mod tests {
 
         assert_eq!(
             mixer.state.get("/-libs/r/010/name"),
-            Some(&OscArg::String("SourceRout".to_string()))
+            Some(OscArg::String("SourceRout".to_string()))
         );
         assert_eq!(
             mixer.state.get("/-libs/r/010/hasdata"),
-            Some(&OscArg::Int(1))
+            Some(OscArg::Int(1))
         );
 
         assert!(responses.len() >= 1);
@@ -420,11 +1450,11 @@ mod tests {
 
         assert_eq!(
             mixer.state.get("/-show/showfile/scene/020/name"),
-            Some(&OscArg::String("SourceScene".to_string()))
+            Some(OscArg::String("SourceScene".to_string()))
         );
         assert_eq!(
             mixer.state.get("/-show/showfile/scene/020/note"),
-            Some(&OscArg::String("SourceNote".to_string()))
+            Some(OscArg::String("SourceNote".to_string()))
         );
 
         assert!(responses.len() >= 1);
@@ -435,6 +1465,56 @@ mod tests {
         assert_eq!(response_msg.args[1], OscArg::Int(1));
     }
 
+    #[test]
+    fn test_mixer_dispatch_copy_overlapping_scenes() {
+        let mut mixer = Mixer::new();
+        let send = |mixer: &mut Mixer, path: &str, args: Vec<OscArg>| {
+            let msg = OscMessage::new(path.to_string(), args);
+            let responses = mixer
+                .dispatch(&msg.to_bytes().unwrap(), test_addr(1234))
+                .unwrap();
+            responses
+                .last()
+                .map(|(_, bytes)| OscMessage::from_bytes(bytes).unwrap())
+        };
+        for (idx, fader) in [(1, 0.25), (2, 0.75)] {
+            send(&mut mixer, "/ch/01/mix/fader", vec![OscArg::Float(fader)]);
+            send(
+                &mut mixer,
+                "/save",
+                vec![
+                    OscArg::String("scene".to_string()),
+                    OscArg::Int(idx),
+                    OscArg::String(format!("Scene {}", idx)),
+                    OscArg::String(String::new()),
+                ],
+            );
+        }
+        let copy = |mixer: &mut Mixer, src: i32, dst: i32, count: i32| {
+            let args = vec![
+                OscArg::String("scene".to_string()),
+                OscArg::Int(src),
+                OscArg::Int(dst),
+                OscArg::Int(-1),
+                OscArg::Int(count),
+            ];
+            send(mixer, "/copy", args).unwrap().args[1].clone()
+        };
+
+        // Scenes 1-2 to 2-3: scene 3 gets scene 2 as it was before the copy.
+        assert_eq!(copy(&mut mixer, 1, 2, 2), OscArg::Int(1));
+        let recall = vec![OscArg::String("scene".to_string()), OscArg::Int(3)];
+        send(&mut mixer, "/load", recall);
+        assert_eq!(
+            mixer.state.get("/ch/01/mix/fader"),
+            Some(OscArg::Float(0.75))
+        );
+
+        // The show file holds 100 scenes; a range running past the last is rejected.
+        assert_eq!(copy(&mut mixer, 1, 98, 3), OscArg::Int(0));
+        assert_eq!(copy(&mut mixer, 1, 99, 1), OscArg::Int(1));
+    }
+
     #[test]
     fn test_mixer_dispatch_add() {
         let mut mixer = Mixer::new();
@@ -452,11 +1532,11 @@ mod tests {
 
         assert_eq!(
             mixer.state.get("/-show/showfile/cue/005/name"),
-            Some(&OscArg::String("My Cue".to_string()))
+            Some(OscArg::String("My Cue".to_string()))
         );
         assert_eq!(
             mixer.state.get("/-show/showfile/cue/005/hasdata"),
-            Some(&OscArg::Int(1))
+            Some(OscArg::Int(1))
         );
 
         assert!(responses.len() >= 1);
@@ -497,11 +1577,11 @@ mod tests {
         // Check that state was copied (with "/" prefix replacing the scene prefix)
         assert_eq!(
             mixer.state.get("/ch/01/mix/fader"),
-            Some(&OscArg::Float(0.5))
+            Some(OscArg::Float(0.5))
         );
         assert_eq!(
             mixer.state.get("/ch/02/mix/fader"),
-            Some(&OscArg::Float(0.75))
+            Some(OscArg::Float(0.75))
         );
 
         // Name and hasdata should not be copied to root
@@ -547,11 +1627,11 @@ mod tests {
 
         assert_eq!(
             mixer.state.get("/-libs/ch/001/name"),
-            Some(&OscArg::String("".to_string()))
+            Some(OscArg::String("".to_string()))
         );
         assert_eq!(
             mixer.state.get("/-libs/ch/001/hasdata"),
-            Some(&OscArg::Int(0))
+            Some(OscArg::Int(0))
         );
 
         let mut found_response = false;
@@ -575,11 +1655,11 @@ mod tests {
 
         assert_eq!(
             mixer.state.get("/-libs/fx/002/name"),
-            Some(&OscArg::String("".to_string()))
+            Some(OscArg::String("".to_string()))
         );
         assert_eq!(
             mixer.state.get("/-libs/fx/002/hasdata"),
-            Some(&OscArg::Int(0))
+            Some(OscArg::Int(0))
         );
 
         let mut found_response2 = false;
@@ -603,11 +1683,11 @@ mod tests {
 
         assert_eq!(
             mixer.state.get("/-libs/r/003/name"),
-            Some(&OscArg::String("".to_string()))
+            Some(OscArg::String("".to_string()))
         );
         assert_eq!(
             mixer.state.get("/-libs/r/003/hasdata"),
-            Some(&OscArg::Int(0))
+            Some(OscArg::Int(0))
         );
 
         let mut found_response3 = false;
@@ -655,15 +1735,15 @@ mod tests {
         // Check state is cleared
         assert_eq!(
             mixer.state.get("/-show/showfile/scene/005/name"),
-            Some(&OscArg::String("".to_string()))
+            Some(OscArg::String("".to_string()))
         );
         assert_eq!(
             mixer.state.get("/-show/showfile/scene/005/note"),
-            Some(&OscArg::String("".to_string()))
+            Some(OscArg::String("".to_string()))
         );
         assert_eq!(
             mixer.state.get("/-show/showfile/scene/005/hasdata"),
-            Some(&OscArg::Int(0))
+            Some(OscArg::Int(0))
         );
 
         // Check response (delete command status)
@@ -689,15 +1769,15 @@ mod tests {
         // Check state is cleared
         assert_eq!(
             mixer.state.get("/-show/showfile/snippet/010/name"),
-            Some(&OscArg::String("".to_string()))
+            Some(OscArg::String("".to_string()))
         );
         assert_eq!(
             mixer.state.get("/-show/showfile/snippet/010/note"),
-            Some(&OscArg::String("".to_string()))
+            Some(OscArg::String("".to_string()))
         );
         assert_eq!(
             mixer.state.get("/-show/showfile/snippet/010/hasdata"),
-            Some(&OscArg::Int(0))
+            Some(OscArg::Int(0))
         );
 
         // Check response
@@ -734,15 +1814,15 @@ mod tests {
         // We expect the state to have "My Scene" at /-show/showfile/scene/005/name
         assert_eq!(
             mixer.state.get("/-show/showfile/scene/005/name"),
-            Some(&OscArg::String("My Scene".to_string()))
+            Some(OscArg::String("My Scene".to_string()))
         );
         assert_eq!(
             mixer.state.get("/-show/showfile/scene/005/note"),
-            Some(&OscArg::String("My Note".to_string()))
+            Some(OscArg::String("My Note".to_string()))
         );
         assert_eq!(
             mixer.state.get("/-show/showfile/scene/005/hasdata"),
-            Some(&OscArg::Int(1))
+            Some(OscArg::Int(1))
         );
         // The original C code puts the note at the next index, but doesn't explicitly mention the path for note in save.
         // I will assume standard format /note for it. Let's just check name for now to see if basic implementation works.
@@ -756,6 +1836,132 @@ mod tests {
         assert_eq!(response_msg.args[1], OscArg::Int(1)); // Success
     }
 
+    #[test]
+    fn test_scene_recall_restores_faders() {
+        let mut mixer = Mixer::new();
+        let surface = test_addr(1234);
+        let subscriber = test_addr(5678);
+        mixer
+            .dispatch(
+                &OscMessage::new("/xremote".to_string(), vec![])
+                    .to_bytes()
+                    .unwrap(),
+                subscriber,
+            )
+            .unwrap();
+
+        mixer.state.set("/ch/01/mix/fader", OscArg::Float(0.75));
+        mixer.state.set("/ch/02/mix/fader", OscArg::Float(0.5));
+        let save = OscMessage::new(
+            "/save".to_string(),
+            vec![
+                OscArg::String("scene".to_string()),
+                OscArg::Int(3),
+                OscArg::String("Verse".to_string()),
+                OscArg::String("".to_string()),
+            ],
+        );
+        mixer.dispatch(&save.to_bytes().unwrap(), surface).unwrap();
+
+        mixer.state.set("/ch/01/mix/fader", OscArg::Float(0.1));
+        mixer.state.set("/ch/02/mix/fader", OscArg::Float(0.2));
+
+        let load = OscMessage::new(
+            "/load".to_string(),
+            vec![OscArg::String("scene".to_string()), OscArg::Int(3)],
+        );
+        let responses = mixer.dispatch(&load.to_bytes().unwrap(), surface).unwrap();
+        assert_eq!(
+            mixer.state.get("/ch/01/mix/fader"),
+            Some(OscArg::Float(0.75))
+        );
+        assert_eq!(
+            mixer.state.get("/ch/02/mix/fader"),
+            Some(OscArg::Float(0.5))
+        );
+
+        // Only the parameters the recall changed are sent to subscribers.
+        let updates: Vec<OscMessage> = responses
+            .iter()
+            .filter(|(addr, _)| *addr == subscriber)
+            .map(|(_, b)| OscMessage::from_bytes(b).unwrap())
+            .collect();
+        assert_eq!(
+            updates,
+            vec![
+                OscMessage::new("/ch/01/mix/fader".to_string(), vec![OscArg::Float(0.75)]),
+                OscMessage::new("/ch/02/mix/fader".to_string(), vec![OscArg::Float(0.5)]),
+            ]
+        );
+        let reply = OscMessage::from_bytes(&responses.last().unwrap().1).unwrap();
+        assert_eq!(reply.args[1], OscArg::Int(1));
+
+        // /-action/goscene recalls the same store and updates the current scene.
+        mixer.state.set("/ch/01/mix/fader", OscArg::Float(0.3));
+        let go = OscMessage::new("/-action/goscene".to_string(), vec![OscArg::Int(3)]);
+        mixer.dispatch(&go.to_bytes().unwrap(), surface).unwrap();
+        assert_eq!(
+            mixer.state.get("/ch/01/mix/fader"),
+            Some(OscArg::Float(0.75))
+        );
+        assert_eq!(
+            mixer.state.get("/-show/prepos/current"),
+            Some(OscArg::Int(3))
+        );
+    }
+
+    #[test]
+    fn test_scene_recall_reports_parameters_the_scene_lacks() {
+        let mut mixer = Mixer::new();
+        let subscriber = test_addr(5678);
+        mixer
+            .dispatch(
+                &OscMessage::new("/xremote".to_string(), vec![])
+                    .to_bytes()
+                    .unwrap(),
+                subscriber,
+            )
+            .unwrap();
+
+        mixer.state.set("/ch/01/mix/fader", OscArg::Float(0.75));
+        let save = OscMessage::new(
+            "/save".to_string(),
+            vec![
+                OscArg::String("scene".to_string()),
+                OscArg::Int(3),
+                OscArg::String("Verse".to_string()),
+                OscArg::String("".to_string()),
+            ],
+        );
+        mixer
+            .dispatch(&save.to_bytes().unwrap(), test_addr(1234))
+            .unwrap();
+
+        // The gate was switched on after the scene was stored, so recalling the
+        // scene unsets it and subscribers see its default.
+        mixer.state.set("/ch/01/gate/on", OscArg::Int(1));
+        let load = OscMessage::new(
+            "/load".to_string(),
+            vec![OscArg::String("scene".to_string()), OscArg::Int(3)],
+        );
+        let responses = mixer
+            .dispatch(&load.to_bytes().unwrap(), test_addr(1234))
+            .unwrap();
+        assert_eq!(mixer.state.get("/ch/01/gate/on"), None);
+        let updates: Vec<OscMessage> = responses
+            .iter()
+            .filter(|(addr, _)| *addr == subscriber)
+            .map(|(_, b)| OscMessage::from_bytes(b).unwrap())
+            .collect();
+        assert_eq!(
+            updates,
+            vec![OscMessage::new(
+                "/ch/01/gate/on".to_string(),
+                vec![OscArg::Int(0)]
+            )]
+        );
+    }
+
     #[test]
     fn test_mixer_dispatch_save_scene_snapshot() {
         let mut mixer = Mixer::new();
@@ -781,17 +1987,17 @@ mod tests {
         // Ensure state is correctly snapshotted into scene
         assert_eq!(
             mixer.state.get("/-show/showfile/scene/005/ch/01/mix/fader"),
-            Some(&OscArg::Float(0.75))
+            Some(OscArg::Float(0.75))
         );
         assert_eq!(
             mixer
                 .state
                 .get("/-show/showfile/scene/005/bus/01/mix/fader"),
-            Some(&OscArg::Float(0.5))
+            Some(OscArg::Float(0.5))
         );
         assert_eq!(
             mixer.state.get("/-show/showfile/scene/005/fx/1/type"),
-            Some(&OscArg::Int(3))
+            Some(OscArg::Int(3))
         );
     }
 
@@ -814,11 +2020,11 @@ mod tests {
 
         assert_eq!(
             mixer.state.get("/-libs/ch/010/name"),
-            Some(&OscArg::String("My Channel Preset".to_string()))
+            Some(OscArg::String("My Channel Preset".to_string()))
         );
         assert_eq!(
             mixer.state.get("/-libs/ch/010/hasdata"),
-            Some(&OscArg::Int(1))
+            Some(OscArg::Int(1))
         );
 
         assert!(responses.len() >= 1);
@@ -852,11 +2058,11 @@ mod tests {
 
         assert_eq!(
             mixer.state.get("/-libs/ch/010/mix/fader"),
-            Some(&OscArg::Float(0.75))
+            Some(OscArg::Float(0.75))
         );
         assert_eq!(
             mixer.state.get("/-libs/ch/010/config/name"),
-            Some(&OscArg::String("Kick".to_string()))
+            Some(OscArg::String("Kick".to_string()))
         );
     }
 
@@ -879,11 +2085,11 @@ mod tests {
 
         assert_eq!(
             mixer.state.get("/-libs/fx/015/name"),
-            Some(&OscArg::String("My FX Preset".to_string()))
+            Some(OscArg::String("My FX Preset".to_string()))
         );
         assert_eq!(
             mixer.state.get("/-libs/fx/015/hasdata"),
-            Some(&OscArg::Int(1))
+            Some(OscArg::Int(1))
         );
 
         assert!(responses.len() >= 1);
@@ -913,10 +2119,10 @@ mod tests {
 
         mixer.dispatch(&bytes, test_addr(1234)).unwrap();
 
-        assert_eq!(mixer.state.get("/-libs/fx/015/type"), Some(&OscArg::Int(3)));
+        assert_eq!(mixer.state.get("/-libs/fx/015/type"), Some(OscArg::Int(3)));
         assert_eq!(
             mixer.state.get("/-libs/fx/015/par/01"),
-            Some(&OscArg::Float(0.5))
+            Some(OscArg::Float(0.5))
         );
     }
 
@@ -939,11 +2145,11 @@ mod tests {
 
         assert_eq!(
             mixer.state.get("/-libs/r/005/name"),
-            Some(&OscArg::String("My Routing Preset".to_string()))
+            Some(OscArg::String("My Routing Preset".to_string()))
         );
         assert_eq!(
             mixer.state.get("/-libs/r/005/hasdata"),
-            Some(&OscArg::Int(1))
+            Some(OscArg::Int(1))
         );
 
         assert!(responses.len() >= 1);
@@ -975,11 +2181,11 @@ mod tests {
 
         assert_eq!(
             mixer.state.get("/-libs/r/005/config/routing/IN/01"),
-            Some(&OscArg::Int(2))
+            Some(OscArg::Int(2))
         );
         assert_eq!(
             mixer.state.get("/-libs/r/005/config/routing/OUT/01"),
-            Some(&OscArg::Int(4))
+            Some(OscArg::Int(4))
         );
     }
 
@@ -1003,15 +2209,15 @@ mod tests {
 
         assert_eq!(
             mixer.state.get("/-show/showfile/snippet/002/name"),
-            Some(&OscArg::String("My Snippet".to_string()))
+            Some(OscArg::String("My Snippet".to_string()))
         );
         assert_eq!(
             mixer.state.get("/-show/showfile/snippet/002/note"),
-            Some(&OscArg::String("My Note".to_string()))
+            Some(OscArg::String("My Note".to_string()))
         );
         assert_eq!(
             mixer.state.get("/-show/showfile/snippet/002/hasdata"),
-            Some(&OscArg::Int(1))
+            Some(OscArg::Int(1))
         );
 
         assert!(responses.len() >= 1);
@@ -1045,7 +2251,280 @@ mod tests {
         assert_eq!(response_msg.args.len(), 1);
         assert_eq!(
             response_msg.args[0],
-            OscArg::String("ch/01/config 3 \"MyName\"".to_string())
+            OscArg::String("/ch/01/config \"MyName\" 1 YE 1\n".to_string())
+        );
+    }
+
+    #[test]
+    fn test_mixer_dispatch_node_renders_color_label() {
+        let mut mixer = Mixer::new();
+        let msg_set = OscMessage::new("/ch/01/config/color".to_string(), vec![OscArg::Int(1)])
+            .to_bytes()
+            .unwrap();
+        mixer.dispatch(&msg_set, test_addr(1234)).unwrap();
+
+        let mut query = |path: &str, args: Vec<OscArg>| {
+            let msg = OscMessage::new(path.to_string(), args).to_bytes().unwrap();
+            let responses = mixer.dispatch(&msg, test_addr(1234)).unwrap();
+            OscMessage::from_bytes(&responses.last().unwrap().1)
+                .unwrap()
+                .args
+        };
+
+        let node = |path: &str| vec![OscArg::String(path.to_string())];
+        assert_eq!(
+            query("/node", node("ch/01/config")),
+            vec![OscArg::String("/ch/01/config \"\" 1 RD 1\n".to_string())]
+        );
+        assert_eq!(
+            query("/node", node("ch/01/config/color")),
+            vec![OscArg::String("/ch/01/config/color RD\n".to_string())]
+        );
+        // The OSC GET still returns the stored index.
+        assert_eq!(query("/ch/01/config/color", vec![]), vec![OscArg::Int(1)]);
+    }
+
+    #[test]
+    fn test_mixer_dispatch_node_defaults() {
+        let mut mixer = Mixer::new();
+
+        let msg = OscMessage {
+            path: "/node".to_string(),
+            args: vec![OscArg::String("ch/02/delay".to_string())],
+        };
+        let bytes = msg.to_bytes().unwrap();
+        let responses = mixer.dispatch(&bytes, test_addr(1234)).unwrap();
+
+        let response_msg = OscMessage::from_bytes(&responses.last().unwrap().1).unwrap();
+        assert_eq!(
+            response_msg.args[0],
+            OscArg::String("/ch/02/delay OFF 0.3\n".to_string())
+        );
+    }
+
+    #[test]
+    fn test_mixer_dispatch_node_channel_defaults() {
+        let mut mixer = Mixer::new();
+        mixer
+            .state
+            .set("/ch/05/config/name", OscArg::String("Kick".to_string()));
+        mixer.state.set("/ch/05/gate/on", OscArg::Int(1));
+        mixer.state.set("/ch/05/gate/mode", OscArg::Int(4));
+
+        let mut query = |node: &str| {
+            let msg = OscMessage::new("/node".to_string(), vec![OscArg::String(node.to_string())])
+                .to_bytes()
+                .unwrap();
+            let responses = mixer.dispatch(&msg, test_addr(1234)).unwrap();
+            let response_msg = OscMessage::from_bytes(&responses.last().unwrap().1).unwrap();
+            match &response_msg.args[0] {
+                OscArg::String(s) => s.clone(),
+                other => panic!("Expected string, got {:?}", other),
+            }
+        };
+
+        assert_eq!(query("ch/05/delay"), "/ch/05/delay OFF 0.3\n");
+        assert_eq!(
+            query("ch/05/dyn"),
+            "/ch/05/dyn OFF COMP PEAK LOG 0.0 3.0 1 0.00 10 10.0 151 POST 0 100 OFF\n"
+        );
+        assert_eq!(query("headamp/005"), "/headamp/005 +0.0 OFF\n");
+        assert_eq!(query("/headamp/005"), "/headamp/005 +0.0 OFF\n");
+
+        // Stored values replace only their own fields; the rest keep their defaults
+        assert_eq!(query("ch/05/config"), "/ch/05/config \"Kick\" 1 YE 1\n");
+        assert_eq!(
+            query("ch/05/gate"),
+            "/ch/05/gate ON DUCK -80.0 60.0 1 502 983 0\n"
+        );
+    }
+
+    #[test]
+    fn test_mixer_dca_assignments() {
+        let mut mixer = Mixer::new();
+        mixer.seed_from_lines(vec![
+            "/ch/01/grp/dca,i\t1",
+            "/ch/02/grp/dca,i\t3",
+            "/dca/1/fader,f\t0.5",
+        ]);
+        mixer.set_signal_level(1, 0.5);
+        mixer.set_signal_level(3, 0.5);
+
+        assert_eq!(mixer.state.dca_members(1), vec![1, 2]);
+        assert_eq!(mixer.state.dca_members(2), vec![2]);
+        assert_eq!(mixer.state.channel_dcas(2), vec![1, 2]);
+
+        // The DCA node reports its members as a channel bitmask
+        let msg = OscMessage::new("/node".to_string(), vec![OscArg::String("dca/1".into())])
+            .to_bytes()
+            .unwrap();
+        let responses = mixer.dispatch(&msg, test_addr(1234)).unwrap();
+        let response_msg = OscMessage::from_bytes(&responses[0].1).unwrap();
+        let node = match &response_msg.args[0] {
+            OscArg::String(s) => s.clone(),
+            other => panic!("Expected string, got {:?}", other),
+        };
+        assert_eq!(node, format!("/dca/1 0.5 %{:032b}\n", 0b11));
+        let bits = node.split_whitespace().last().unwrap();
+        let bits = u32::from_str_radix(bits.trim_start_matches('%'), 2).unwrap();
+        let members: Vec<u32> = (1..=32).filter(|ch| bits & 1 << (ch - 1) != 0).collect();
+        assert_eq!(members, vec![1, 2]);
+
+        let msg = OscMessage::new(
+            "/node".to_string(),
+            vec![OscArg::String("ch/02/grp".into())],
+        )
+        .to_bytes()
+        .unwrap();
+        let responses = mixer.dispatch(&msg, test_addr(1234)).unwrap();
+        let response_msg = OscMessage::from_bytes(&responses[0].1).unwrap();
+        assert_eq!(
+            response_msg.args[0],
+            OscArg::String("/ch/02/grp %00000011 %000000\n".to_string())
+        );
+
+        // DCA 1 at -10 dB pulls down channel 1 but not the unassigned channel 3
+        let msg = OscMessage::new("/meters/1".to_string(), vec![])
+            .to_bytes()
+            .unwrap();
+        mixer.dispatch(&msg, test_addr(1234)).unwrap();
+        // Each reading is one meter interval after the last, so a blob is due.
+        let start = Instant::now();
+        let mut ticks = 0;
+        let mut level = |mixer: &mut Mixer, ch: usize| {
+            let responses = mixer.tick_at(start + Duration::from_millis(50 * ticks));
+            ticks += 1;
+            let msg_out = OscMessage::from_bytes(&responses[0].1).unwrap();
+            let blob = msg_out.args[0].as_blob().unwrap().to_vec();
+            let start = 4 + (ch - 1) * 4;
+            f32::from_le_bytes(blob[start..start + 4].try_into().unwrap())
+        };
+        assert!((level(&mut mixer, 1) - 0.5 * 10f32.powf(-0.5)).abs() < 1e-6);
+        assert!((level(&mut mixer, 3) - 0.5).abs() < 1e-6);
+
+        mixer.seed_from_lines(vec!["/dca/1/on,i\t0"]);
+        assert_eq!(level(&mut mixer, 1), 0.0);
+    }
+
+    #[test]
+    fn test_mixer_mute_group() {
+        let mut mixer = Mixer::new();
+        mixer.seed_from_lines(vec![
+            "/ch/01/grp/mute,i\t1",
+            "/ch/02/grp/mute,i\t3",
+            "/ch/03/grp/mute,i\t2",
+            "/ch/01/mix/on,i\t1",
+            "/ch/02/mix/on,i\t1",
+            "/ch/03/mix/on,i\t1",
+        ]);
+
+        let msg_xremote = OscMessage::new("/xremote".to_string(), vec![])
+            .to_bytes()
+            .unwrap();
+        mixer.dispatch(&msg_xremote, test_addr(1111)).unwrap();
+
+        let get_on = |mixer: &mut Mixer, ch: u8| {
+            let msg = OscMessage::new(format!("/ch/{:02}/mix/on", ch), vec![])
+                .to_bytes()
+                .unwrap();
+            let responses = mixer.dispatch(&msg, test_addr(2222)).unwrap();
+            OscMessage::from_bytes(&responses[0].1).unwrap().args[0].clone()
+        };
+        let set_group = |mixer: &mut Mixer, on: i32| {
+            let msg = OscMessage::new("/config/mute/1".to_string(), vec![OscArg::Int(on)])
+                .to_bytes()
+                .unwrap();
+            let responses = mixer.dispatch(&msg, test_addr(2222)).unwrap();
+            let mut updates: Vec<OscMessage> = responses
+                .iter()
+                .map(|(_, bytes)| OscMessage::from_bytes(bytes).unwrap())
+                .filter(|m| m.path.ends_with("/mix/on"))
+                .collect();
+            updates.sort_by(|a, b| a.path.cmp(&b.path));
+            updates
+        };
+
+        // Engaging group 1 mutes channels 1 and 2 and notifies subscribers
+        let updates = set_group(&mut mixer, 1);
+        assert_eq!(
+            updates,
+            vec![
+                OscMessage::new("/ch/01/mix/on".to_string(), vec![OscArg::Int(0)]),
+                OscMessage::new("/ch/02/mix/on".to_string(), vec![OscArg::Int(0)]),
+            ]
+        );
+        assert_eq!(get_on(&mut mixer, 1), OscArg::Int(0));
+        assert_eq!(get_on(&mut mixer, 2), OscArg::Int(0));
+        assert_eq!(get_on(&mut mixer, 3), OscArg::Int(1));
+
+        // Releasing it restores each channel's own switch
+        let updates = set_group(&mut mixer, 0);
+        assert_eq!(updates.len(), 2);
+        assert!(updates.iter().all(|m| m.args == vec![OscArg::Int(1)]));
+        assert_eq!(get_on(&mut mixer, 1), OscArg::Int(1));
+        assert_eq!(get_on(&mut mixer, 2), OscArg::Int(1));
+        assert_eq!(mixer.state.get("/ch/01/mix/on"), Some(OscArg::Int(1)));
+    }
+
+    #[test]
+    fn test_mixer_mute_group_membership_change() {
+        let mut mixer = Mixer::new();
+        mixer.seed_from_lines(vec!["/config/mute/1,i\t1", "/ch/05/mix/on,i\t1"]);
+
+        let msg_xremote = OscMessage::new("/xremote".to_string(), vec![])
+            .to_bytes()
+            .unwrap();
+        mixer.dispatch(&msg_xremote, test_addr(1111)).unwrap();
+
+        let on_updates = |responses: Vec<(SocketAddr, std::sync::Arc<[u8]>)>| -> Vec<OscMessage> {
+            responses
+                .iter()
+                .map(|(_, bytes)| OscMessage::from_bytes(bytes).unwrap())
+                .filter(|m| m.path == "/ch/05/mix/on")
+                .collect()
+        };
+
+        // Adding channel 5 to the engaged group mutes it.
+        let msg = OscMessage::new("/ch/05/grp/mute".to_string(), vec![OscArg::Int(1)])
+            .to_bytes()
+            .unwrap();
+        let responses = mixer.dispatch(&msg, test_addr(2222)).unwrap();
+        assert_eq!(
+            on_updates(responses),
+            vec![OscMessage::new(
+                "/ch/05/mix/on".to_string(),
+                vec![OscArg::Int(0)]
+            )]
+        );
+
+        // Undoing the change unmutes it again.
+        let msg_undo = OscMessage::new("/-undo".to_string(), vec![])
+            .to_bytes()
+            .unwrap();
+        let responses = mixer.dispatch(&msg_undo, test_addr(2222)).unwrap();
+        assert_eq!(
+            on_updates(responses),
+            vec![OscMessage::new(
+                "/ch/05/mix/on".to_string(),
+                vec![OscArg::Int(1)]
+            )]
+        );
+
+        // So does a node string that clears the membership.
+        mixer.dispatch(&msg_undo, test_addr(2222)).unwrap();
+        let msg = OscMessage::new(
+            "/".to_string(),
+            vec![OscArg::String("/ch/05/grp %00000000 %000000".to_string())],
+        )
+        .to_bytes()
+        .unwrap();
+        let responses = mixer.dispatch(&msg, test_addr(2222)).unwrap();
+        assert_eq!(
+            on_updates(responses),
+            vec![OscMessage::new(
+                "/ch/05/mix/on".to_string(),
+                vec![OscArg::Int(1)]
+            )]
         );
     }
 
@@ -1067,7 +2546,7 @@ mod tests {
         let bytes1 = msg1.to_bytes().unwrap();
         let responses1 = mixer.dispatch(&bytes1, test_addr(1234)).unwrap();
 
-        assert_eq!(mixer.state.get("/-stat/solo"), Some(&OscArg::Int(1)));
+        assert_eq!(mixer.state.get("/-stat/solo"), Some(OscArg::Int(1)));
 
         let mut found_solo = false;
         for (_, resp_bytes) in &responses1 {
@@ -1087,7 +2566,7 @@ mod tests {
         let bytes2 = msg2.to_bytes().unwrap();
         mixer.dispatch(&bytes2, test_addr(1234)).unwrap();
 
-        assert_eq!(mixer.state.get("/-stat/solo"), Some(&OscArg::Int(1)));
+        assert_eq!(mixer.state.get("/-stat/solo"), Some(OscArg::Int(1)));
 
         // Set solosw 01 to 0
         let msg3 = OscMessage {
@@ -1097,7 +2576,7 @@ mod tests {
         let bytes3 = msg3.to_bytes().unwrap();
         mixer.dispatch(&bytes3, test_addr(1234)).unwrap();
 
-        assert_eq!(mixer.state.get("/-stat/solo"), Some(&OscArg::Int(1))); // 02 is still on
+        assert_eq!(mixer.state.get("/-stat/solo"), Some(OscArg::Int(1))); // 02 is still on
 
         // Set solosw 02 to 0
         let msg4 = OscMessage {
@@ -1107,7 +2586,7 @@ mod tests {
         let bytes4 = msg4.to_bytes().unwrap();
         let responses4 = mixer.dispatch(&bytes4, test_addr(1234)).unwrap();
 
-        assert_eq!(mixer.state.get("/-stat/solo"), Some(&OscArg::Int(0)));
+        assert_eq!(mixer.state.get("/-stat/solo"), Some(OscArg::Int(0)));
 
         let mut found_solo_off = false;
         for (_, resp_bytes) in &responses4 {
@@ -1148,10 +2627,232 @@ mod tests {
         // /meters/1 args should be a blob
         assert_eq!(msg_out.args.len(), 1);
         if let OscArg::Blob(blob) = &msg_out.args[0] {
-            // /meters/1 expects 96 floats (96 * 4 = 384 bytes, plus the length header in standard OSC which we don't count here, but the data length)
-            assert_eq!(blob.len(), 384);
+            // /meters/1 carries an int32 count of 96 followed by 96 floats (4 + 96 * 4 = 388 bytes)
+            assert_eq!(blob.len(), 388);
+            assert_eq!(i32::from_le_bytes(blob[0..4].try_into().unwrap()), 96);
         } else {
             panic!("Expected blob argument");
         }
     }
+
+    #[test]
+    fn test_mixer_meters_report_seeded_signal() {
+        let mut mixer = Mixer::new();
+        mixer.seed_from_lines(vec!["/ch/03/mix/fader,f\t0.5", "/ch/04/mix/on,i\t0"]);
+        mixer.set_signal_level(2, 0.5);
+        mixer.set_signal_level(3, 0.5);
+        mixer.set_signal_level(4, 0.5);
+
+        let msg = OscMessage::new(
+            "/meters".to_string(),
+            vec![OscArg::String("/meters/1".to_string())],
+        );
+        mixer
+            .dispatch(&msg.to_bytes().unwrap(), test_addr(1234))
+            .unwrap();
+
+        let responses = mixer.tick();
+        assert_eq!(responses.len(), 1);
+        let msg_out = OscMessage::from_bytes(&responses[0].1).unwrap();
+        assert_eq!(msg_out.path, "/meters/1");
+        let blob = msg_out.args[0].as_blob().unwrap();
+        let level = |ch: usize| {
+            let start = 4 + (ch - 1) * 4;
+            f32::from_le_bytes(blob[start..start + 4].try_into().unwrap())
+        };
+
+        // Channel 2 sits at the default unity fader, channel 3 at -10 dB.
+        assert_eq!(level(1), 0.0);
+        assert!((level(2) - 0.5).abs() < 1e-6);
+        assert!((level(3) - 0.5 * 10f32.powf(-0.5)).abs() < 1e-6);
+        // Channel 4 is muted.
+        assert_eq!(level(4), 0.0);
+    }
+
+    #[test]
+    fn test_mixer_strip_meters_report_selected_channel() {
+        let mut mixer = Mixer::new();
+        mixer.set_signal_level(5, 0.5);
+
+        // /meters ,siii "/meters/6" 0 0 4 selects channel 5.
+        let msg = OscMessage::new(
+            "/meters".to_string(),
+            vec![
+                OscArg::String("/meters/6".to_string()),
+                OscArg::Int(0),
+                OscArg::Int(0),
+                OscArg::Int(4),
+            ],
+        );
+        mixer
+            .dispatch(&msg.to_bytes().unwrap(), test_addr(1234))
+            .unwrap();
+
+        let responses = mixer.tick();
+        assert_eq!(responses.len(), 1);
+        let msg_out = OscMessage::from_bytes(&responses[0].1).unwrap();
+        assert_eq!(msg_out.path, "/meters/6");
+        let blob = msg_out.args[0].as_blob().unwrap();
+        assert_eq!(blob.len(), 4 + 4 * 4);
+        for start in (4..blob.len()).step_by(4) {
+            let level = f32::from_le_bytes(blob[start..start + 4].try_into().unwrap());
+            assert!((level - 0.5).abs() < 1e-6);
+        }
+    }
+
+    #[test]
+    fn test_mixer_state_paths_persist_independently() {
+        let dir = std::env::temp_dir();
+        let path_a = dir.join(format!("x32_core_state_a_{}.rc", std::process::id()));
+        let path_b = dir.join(format!("x32_core_state_b_{}.rc", std::process::id()));
+
+        let mut mixer_a = Mixer::new_with_state_path(&path_a);
+        mixer_a.seed_from_lines(vec![
+            "/ch/01/config/name,s\tKick",
+            "/ch/01/mix/fader,f\t0.5",
+        ]);
+        mixer_a.save().unwrap();
+
+        let mut mixer_b = Mixer::new_with_state_path(&path_b);
+        mixer_b.seed_from_lines(vec!["/ch/01/config/name,s\tSnare", "/ch/01/mix/on,i\t0"]);
+        mixer_b.save().unwrap();
+
+        let mut restored_a = Mixer::new_with_state_path(&path_a);
+        restored_a.load().unwrap();
+        let mut restored_b = Mixer::new_with_state_path(&path_b);
+        restored_b.load().unwrap();
+
+        let _ = std::fs::remove_file(&path_a);
+        let _ = std::fs::remove_file(&path_b);
+
+        assert_eq!(
+            restored_a.state.get("/ch/01/config/name"),
+            Some(OscArg::String("Kick".to_string()))
+        );
+        assert_eq!(
+            restored_a.state.get("/ch/01/mix/fader"),
+            Some(OscArg::Float(0.5))
+        );
+        assert_eq!(restored_a.state.get("/ch/01/mix/on"), None);
+        assert_eq!(
+            restored_b.state.get("/ch/01/config/name"),
+            Some(OscArg::String("Snare".to_string()))
+        );
+        assert_eq!(restored_b.state.get("/ch/01/mix/on"), Some(OscArg::Int(0)));
+        assert_eq!(restored_b.state.get("/ch/01/mix/fader"), None);
+    }
+
+    #[test]
+    fn test_mixer_interrupted_save_keeps_previous_state() {
+        let path = std::env::temp_dir().join(format!("x32_core_atomic_{}.rc", std::process::id()));
+
+        let mut mixer = Mixer::new_with_state_path(&path);
+        mixer.seed_from_lines(vec!["/ch/01/config/name,s\tKick"]);
+        mixer.save().unwrap();
+
+        // A directory in place of the temporary file makes the next write fail partway.
+        let tmp_path = mixer.temp_state_path();
+        std::fs::create_dir(&tmp_path).unwrap();
+        mixer.seed_from_lines(vec!["/ch/01/config/name,s\tSnare"]);
+        let result = mixer.save();
+        std::fs::remove_dir(&tmp_path).unwrap();
+
+        let mut restored = Mixer::new_with_state_path(&path);
+        restored.load().unwrap();
+        let _ = std::fs::remove_file(&path);
+
+        assert!(result.is_err());
+        assert_eq!(
+            restored.state.get("/ch/01/config/name"),
+            Some(OscArg::String("Kick".to_string()))
+        );
+    }
+
+    #[test]
+    fn test_stored_scene_survives_restart() {
+        let path = std::env::temp_dir().join(format!("x32_core_scene_{}.rc", std::process::id()));
+        let save = |idx: i32| {
+            OscMessage::new(
+                "/save".to_string(),
+                vec![
+                    OscArg::String("scene".to_string()),
+                    OscArg::Int(idx),
+                    OscArg::String("Verse".to_string()),
+                    OscArg::String("".to_string()),
+                ],
+            )
+            .to_bytes()
+            .unwrap()
+        };
+        let load = OscMessage::new(
+            "/load".to_string(),
+            vec![OscArg::String("scene".to_string()), OscArg::Int(4)],
+        )
+        .to_bytes()
+        .unwrap();
+
+        let mut mixer = Mixer::new_with_state_path(&path);
+        mixer.state.set("/ch/01/gate/on", OscArg::Int(1));
+        mixer.dispatch(&save(4), test_addr(1234)).unwrap();
+        // Storing the scene again replaces it, so the gate is no longer part of it.
+        mixer.state.remove("/ch/01/gate/on");
+        mixer.state.set("/ch/01/mix/fader", OscArg::Float(0.75));
+        mixer.dispatch(&save(4), test_addr(1234)).unwrap();
+        mixer.save().unwrap();
+
+        let mut restored = Mixer::new_with_state_path(&path);
+        restored.load().unwrap();
+        let _ = std::fs::remove_file(&path);
+        restored.state.set("/ch/01/mix/fader", OscArg::Float(0.1));
+        restored.state.set("/ch/01/gate/on", OscArg::Int(1));
+        let responses = restored.dispatch(&load, test_addr(1234)).unwrap();
+        let reply = OscMessage::from_bytes(&responses.last().unwrap().1).unwrap();
+        assert_eq!(reply.args[1], OscArg::Int(1));
+        assert_eq!(
+            restored.state.get("/ch/01/mix/fader"),
+            Some(OscArg::Float(0.75))
+        );
+        assert_eq!(restored.state.get("/ch/01/gate/on"), None);
+
+        // A deleted scene can no longer be recalled.
+        let delete = OscMessage::new(
+            "/delete".to_string(),
+            vec![OscArg::String("scene".to_string()), OscArg::Int(4)],
+        );
+        restored
+            .dispatch(&delete.to_bytes().unwrap(), test_addr(1234))
+            .unwrap();
+        let responses = restored.dispatch(&load, test_addr(1234)).unwrap();
+        let reply = OscMessage::from_bytes(&responses.last().unwrap().1).unwrap();
+        assert_eq!(reply.args[1], OscArg::Int(0));
+    }
+
+    #[test]
+    fn test_enum_color_round_trip() {
+        use crate::enums::{color_index, color_name};
+
+        for i in 0..16 {
+            let name = color_name(i).unwrap();
+            assert_eq!(color_index(name), Some(i));
+        }
+        assert_eq!(color_name(1), Some("RD"));
+        assert_eq!(color_name(9), Some("RDi"));
+        assert_eq!(color_index("rdi"), Some(9));
+        assert_eq!(color_name(16), None);
+        assert_eq!(color_name(-1), None);
+        assert_eq!(color_index("PK"), None);
+    }
+
+    #[test]
+    fn test_enum_gate_mode_round_trip() {
+        use crate::enums::{gate_mode_index, gate_mode_name};
+
+        for i in 0..5 {
+            let name = gate_mode_name(i).unwrap();
+            assert_eq!(gate_mode_index(name), Some(i));
+        }
+        assert_eq!(gate_mode_name(2), Some("EXP4"));
+        assert_eq!(gate_mode_index(" GATE"), Some(3));
+        assert_eq!(gate_mode_name(5), None);
+    }
 }
@@ -86,7 +86,8 @@ fn parse_enum_fx(s: Option<&str>, range: &[&str]) -> i32 {
         .unwrap_or(0)
 }
 
-fn parse_level(val: &str) -> Option<OscArg> {
+/// Converts a fader or send level in dB, or `-oo`, to its 0.0-1.0 fader position.
+pub fn parse_level(val: &str) -> Option<OscArg> {
     let val = val.trim();
     if val == "-oo" {
         return Some(OscArg::Float(0.0));
@@ -113,20 +114,24 @@ fn parse_level(val: &str) -> Option<OscArg> {
     Some(OscArg::Float(fval))
 }
 
-fn parse_frequency(val: &str) -> Option<OscArg> {
+/// Converts a frequency in Hz, such as `990.9` or `1k97`, to its 0.0-1.0 position on
+/// the 20 Hz-20 kHz log scale.
+pub fn parse_frequency(val: &str) -> Option<OscArg> {
     let fval = parse_float_raw(val)?;
     let mut res = (fval / 20.0).ln() / 6.907_755_4;
     res = (res * 200.0).round() / 200.0;
     Some(OscArg::Float(res.clamp(0.0, 1.0)))
 }
 
-fn parse_flin(val: &str, min: f32, max: f32) -> Option<OscArg> {
+/// Converts a value on the linear scale `min..=max` to its 0.0-1.0 position.
+pub fn parse_flin(val: &str, min: f32, max: f32) -> Option<OscArg> {
     let fval = parse_float_raw(val)?;
     let res = (fval - min) / (max - min);
     Some(OscArg::Float(res.clamp(0.0, 1.0)))
 }
 
-fn parse_logf(val: &str, min: f32, max: f32) -> Option<OscArg> {
+/// Converts a value on the log scale `min..=max` to its 0.0-1.0 position.
+pub fn parse_logf(val: &str, min: f32, max: f32) -> Option<OscArg> {
     let fval = parse_float_raw(val)?;
     if fval <= min {
         Some(OscArg::Float(0.0))
@@ -1,6 +1,6 @@
-use crate::error::Result;
+use crate::error::{Result, X32Error};
 use crate::transport::MixerTransport;
-use osc_lib::{OscArg, OscError, OscMessage};
+use osc_lib::{OscArg, OscBundle, OscMessage};
 use std::sync::Arc;
 use tokio::sync::{broadcast, watch};
 use tokio::time::{self, Duration};
@@ -28,15 +28,17 @@ impl MixerClient {
             match time::timeout(timeout_dur - start.elapsed(), rx.recv()).await {
                 Ok(Ok(msg)) => {
                     if msg.path == "/node" || msg.path == "node" {
-                        if let Some(OscArg::String(response_str)) = msg.args.first() {
-                            return Ok(response_str.clone());
+                        if let Some(response_str) = msg.arg_str(0) {
+                            return Ok(response_str.to_string());
                         }
                     }
                 }
                 _ => continue,
             }
         }
-        Err(OscError::ParseError("Query /node timeout".to_string()).into())
+        Err(X32Error::Timeout {
+            path: format!("/node {}", node_path),
+        })
     }
 
     /// Creates a new `MixerClient` instance using the provided transport.
@@ -85,6 +87,19 @@ impl MixerClient {
         Ok(Self::new(transport, heartbeat))
     }
 
+    /// Connects to a mixer at the given IP address from a fixed local UDP port.
+    ///
+    /// # Arguments
+    ///
+    /// * `ip` - The IP address of the mixer, with an optional port (default 10023).
+    /// * `local_port` - The local UDP port to send from, or 0 for a dynamic port.
+    /// * `heartbeat` - Whether to send `/xremote` periodically.
+    pub async fn connect_from(ip: &str, local_port: u16, heartbeat: bool) -> Result<Self> {
+        let transport =
+            Arc::new(crate::transport::udp::UdpTransport::connect_from(ip, local_port).await?);
+        Ok(Self::new(transport, heartbeat))
+    }
+
     /// Connects to a mixer via USB MIDI Sysex.
     pub fn connect_midi(port_name: &str, heartbeat: bool) -> Result<Self> {
         let transport = Arc::new(crate::transport::midi::MidiTransport::connect(port_name)?);
@@ -206,12 +221,32 @@ impl MixerClient {
         Ok(())
     }
 
+    /// Sends a bundle of OSC messages to the mixer.
+    ///
+    /// Over UDP the whole bundle is sent in a single datagram.
+    pub async fn send_bundle(&self, bundle: OscBundle) -> Result<()> {
+        self.transport.send_bundle(bundle).await
+    }
+
     /// Queries a value from the mixer.
     ///
     /// Note: This waits for the next message matching the path.
     /// In a multi-threaded environment, this might catch a message intended for someone else
     /// if they are querying the same path, but for X32 it's generally safe.
     pub async fn query_value(&self, path: &str) -> Result<OscArg> {
+        self.query_message(path)
+            .await?
+            .args
+            .into_iter()
+            .next()
+            .ok_or_else(|| X32Error::type_mismatch("value", None))
+    }
+
+    /// Queries a parameter from the mixer and returns the whole reply.
+    ///
+    /// Like [`MixerClient::query_value`], this waits for the next message matching the
+    /// path, but keeps every argument of it.
+    pub async fn query_message(&self, path: &str) -> Result<OscMessage> {
         let mut rx = self.msg_tx.subscribe();
         self.send_message(path, vec![]).await?;
 
@@ -223,15 +258,15 @@ impl MixerClient {
             match time::timeout(timeout - start.elapsed(), rx.recv()).await {
                 Ok(Ok(msg)) => {
                     if msg.path == path {
-                        return msg.args.first().cloned().ok_or_else(|| {
-                            OscError::ParseError("Empty response".to_string()).into()
-                        });
+                        return Ok(msg);
                     }
                 }
                 _ => break,
             }
         }
-        Err(OscError::ParseError("Query timeout".to_string()).into())
+        Err(X32Error::Timeout {
+            path: path.to_string(),
+        })
     }
 
     /// Subscribes to all incoming OSC messages.
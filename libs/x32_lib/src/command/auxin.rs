@@ -88,6 +88,16 @@ pub fn on(channel_num: u8) -> String {
     format!("/auxin/{:02}/mix/on", channel_num)
 }
 
+/// Returns the OSC address for an auxin channel's pan position.
+pub fn pan(channel_num: u8) -> String {
+    format!("/auxin/{:02}/mix/pan", channel_num)
+}
+
+/// Returns the OSC address for an auxin channel's solo switch.
+pub fn solo(channel_num: u8) -> String {
+    format!("/-stat/solosw/{:02}", channel_num + 32)
+}
+
 // --- OSC Message Setters ---
 
 /// Creates an OSC message to set the name of an auxin channel.
@@ -187,6 +197,60 @@ pub fn set_on(channel_num: u8, on: i32) -> (String, Vec<OscArg>) {
     (self::on(channel_num), vec![OscArg::Int(on)])
 }
 
+/// Creates an OSC message to mute or unmute an auxin channel.
+///
+/// # Arguments
+///
+/// * `channel_num` - The channel number (1-8).
+/// * `muted` - `true` to mute the channel, `false` to unmute it.
+///
+/// ```
+/// use x32_lib::command::auxin;
+///
+/// let (address, args) = auxin::set_mute(1, true);
+/// assert_eq!(address, "/auxin/01/mix/on");
+/// assert_eq!(args, vec![osc_lib::OscArg::Int(0)]);
+/// ```
+pub fn set_mute(channel_num: u8, muted: bool) -> (String, Vec<OscArg>) {
+    (self::on(channel_num), vec![OscArg::Int(i32::from(!muted))])
+}
+
+/// Creates an OSC message to set the pan position of an auxin channel.
+///
+/// # Arguments
+///
+/// * `channel_num` - The channel number (1-8).
+/// * `pan` - The pan position (0.0 for hard left, 0.5 for center, 1.0 for hard right).
+///
+/// ```
+/// use x32_lib::command::auxin;
+///
+/// let (address, args) = auxin::set_pan(1, 0.5);
+/// assert_eq!(address, "/auxin/01/mix/pan");
+/// assert_eq!(args, vec![osc_lib::OscArg::Float(0.5)]);
+/// ```
+pub fn set_pan(channel_num: u8, pan: f32) -> (String, Vec<OscArg>) {
+    (self::pan(channel_num), vec![OscArg::Float(pan)])
+}
+
+/// Creates an OSC message to solo or unsolo an auxin channel.
+///
+/// # Arguments
+///
+/// * `channel_num` - The channel number (1-8).
+/// * `on` - `true` to solo the channel, `false` to clear its solo.
+///
+/// ```
+/// use x32_lib::command::auxin;
+///
+/// let (address, args) = auxin::set_solo(1, true);
+/// assert_eq!(address, "/-stat/solosw/33");
+/// assert_eq!(args, vec![osc_lib::OscArg::Int(1)]);
+/// ```
+pub fn set_solo(channel_num: u8, on: bool) -> (String, Vec<OscArg>) {
+    (self::solo(channel_num), vec![OscArg::Int(i32::from(on))])
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -222,4 +286,29 @@ mod tests {
         assert_eq!(address, "/auxin/02/mix/on");
         assert_eq!(args, vec![OscArg::Int(1)]);
     }
+
+    #[test]
+    fn test_set_mute() {
+        let (address, args) = set_mute(8, true);
+        assert_eq!(address, "/auxin/08/mix/on");
+        assert_eq!(args, vec![OscArg::Int(0)]);
+        let (_, args) = set_mute(8, false);
+        assert_eq!(args, vec![OscArg::Int(1)]);
+    }
+
+    #[test]
+    fn test_set_pan() {
+        let (address, args) = set_pan(2, 0.25);
+        assert_eq!(address, "/auxin/02/mix/pan");
+        assert_eq!(args, vec![OscArg::Float(0.25)]);
+    }
+
+    #[test]
+    fn test_set_solo() {
+        let (address, args) = set_solo(8, true);
+        assert_eq!(address, "/-stat/solosw/40");
+        assert_eq!(args, vec![OscArg::Int(1)]);
+        let (_, args) = set_solo(8, false);
+        assert_eq!(args, vec![OscArg::Int(0)]);
+    }
 }
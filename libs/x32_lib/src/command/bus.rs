@@ -3,6 +3,8 @@
 //! Mix buses are used for creating monitor mixes, subgroup mixes, or feeding effects processors.
 //! This module provides functions to control their configuration, dynamics, EQ, and fader levels.
 use super::{Command, CommandFlags, CommandType};
+use crate::common::valid_bus;
+use crate::error::Result;
 use osc_lib::OscArg;
 
 /// A static array of all available commands for the mix bus channels.
@@ -137,6 +139,16 @@ pub fn on(channel_num: u8) -> String {
     format!("/bus/{:02}/mix/on", channel_num)
 }
 
+/// Returns the OSC address for a bus channel's pan position.
+pub fn pan(channel_num: u8) -> String {
+    format!("/bus/{:02}/mix/pan", channel_num)
+}
+
+/// Returns the OSC address for a bus channel's solo switch.
+pub fn solo(channel_num: u8) -> String {
+    format!("/-stat/solosw/{:02}", channel_num + 48)
+}
+
 // --- OSC Message Setters ---
 
 /// Creates an OSC message to set the name of a bus channel.
@@ -149,15 +161,16 @@ pub fn on(channel_num: u8) -> String {
 /// ```
 /// use x32_lib::command::bus;
 ///
-/// let (address, args) = bus::set_name(1, "Test");
+/// let (address, args) = bus::set_name(1, "Test").unwrap();
 /// assert_eq!(address, "/bus/01/config/name");
 /// assert_eq!(args, vec![osc_lib::OscArg::String("Test".to_string())]);
 /// ```
-pub fn set_name(channel_num: u8, name: &str) -> (String, Vec<OscArg>) {
-    (
+pub fn set_name(channel_num: u8, name: &str) -> Result<(String, Vec<OscArg>)> {
+    valid_bus(channel_num)?;
+    Ok((
         self::name(channel_num),
         vec![OscArg::String(name.to_string())],
-    )
+    ))
 }
 
 /// Creates an OSC message to set the color of a bus channel.
@@ -170,12 +183,13 @@ pub fn set_name(channel_num: u8, name: &str) -> (String, Vec<OscArg>) {
 /// ```
 /// use x32_lib::command::bus;
 ///
-/// let (address, args) = bus::set_color(1, 2);
+/// let (address, args) = bus::set_color(1, 2).unwrap();
 /// assert_eq!(address, "/bus/01/config/color");
 /// assert_eq!(args, vec![osc_lib::OscArg::Int(2)]);
 /// ```
-pub fn set_color(channel_num: u8, color: i32) -> (String, Vec<OscArg>) {
-    (self::color(channel_num), vec![OscArg::Int(color)])
+pub fn set_color(channel_num: u8, color: i32) -> Result<(String, Vec<OscArg>)> {
+    valid_bus(channel_num)?;
+    Ok((self::color(channel_num), vec![OscArg::Int(color)]))
 }
 
 /// Creates an OSC message to set the dyn on state of a bus channel.
@@ -188,12 +202,13 @@ pub fn set_color(channel_num: u8, color: i32) -> (String, Vec<OscArg>) {
 /// ```
 /// use x32_lib::command::bus;
 ///
-/// let (address, args) = bus::set_dyn_on(1, 1);
+/// let (address, args) = bus::set_dyn_on(1, 1).unwrap();
 /// assert_eq!(address, "/bus/01/dyn/on");
 /// assert_eq!(args, vec![osc_lib::OscArg::Int(1)]);
 /// ```
-pub fn set_dyn_on(channel_num: u8, on: i32) -> (String, Vec<OscArg>) {
-    (self::dyn_on(channel_num), vec![OscArg::Int(on)])
+pub fn set_dyn_on(channel_num: u8, on: i32) -> Result<(String, Vec<OscArg>)> {
+    valid_bus(channel_num)?;
+    Ok((self::dyn_on(channel_num), vec![OscArg::Int(on)]))
 }
 
 /// Creates an OSC message to set the eq band type of a bus channel.
@@ -207,15 +222,16 @@ pub fn set_dyn_on(channel_num: u8, on: i32) -> (String, Vec<OscArg>) {
 /// ```
 /// use x32_lib::command::bus;
 ///
-/// let (address, args) = bus::set_eq_band_type(1, 1, 2);
+/// let (address, args) = bus::set_eq_band_type(1, 1, 2).unwrap();
 /// assert_eq!(address, "/bus/01/eq/1/type");
 /// assert_eq!(args, vec![osc_lib::OscArg::Int(2)]);
 /// ```
-pub fn set_eq_band_type(channel_num: u8, band: u8, eq_type: i32) -> (String, Vec<OscArg>) {
-    (
+pub fn set_eq_band_type(channel_num: u8, band: u8, eq_type: i32) -> Result<(String, Vec<OscArg>)> {
+    valid_bus(channel_num)?;
+    Ok((
         self::eq_band_type(channel_num, band),
         vec![OscArg::Int(eq_type)],
-    )
+    ))
 }
 
 /// Creates an OSC message to set the fader level of a bus channel.
@@ -228,12 +244,13 @@ pub fn set_eq_band_type(channel_num: u8, band: u8, eq_type: i32) -> (String, Vec
 /// ```
 /// use x32_lib::command::bus;
 ///
-/// let (address, args) = bus::set_fader(1, 0.75);
+/// let (address, args) = bus::set_fader(1, 0.75).unwrap();
 /// assert_eq!(address, "/bus/01/mix/fader");
 /// assert_eq!(args, vec![osc_lib::OscArg::Float(0.75)]);
 /// ```
-pub fn set_fader(channel_num: u8, level: f32) -> (String, Vec<OscArg>) {
-    (fader_level(channel_num), vec![OscArg::Float(level)])
+pub fn set_fader(channel_num: u8, level: f32) -> Result<(String, Vec<OscArg>)> {
+    valid_bus(channel_num)?;
+    Ok((fader_level(channel_num), vec![OscArg::Float(level)]))
 }
 
 /// Creates an OSC message to set the on state of a bus channel.
@@ -246,12 +263,70 @@ pub fn set_fader(channel_num: u8, level: f32) -> (String, Vec<OscArg>) {
 /// ```
 /// use x32_lib::command::bus;
 ///
-/// let (address, args) = bus::set_on(1, 1);
+/// let (address, args) = bus::set_on(1, 1).unwrap();
 /// assert_eq!(address, "/bus/01/mix/on");
 /// assert_eq!(args, vec![osc_lib::OscArg::Int(1)]);
 /// ```
-pub fn set_on(channel_num: u8, on: i32) -> (String, Vec<OscArg>) {
-    (self::on(channel_num), vec![OscArg::Int(on)])
+pub fn set_on(channel_num: u8, on: i32) -> Result<(String, Vec<OscArg>)> {
+    valid_bus(channel_num)?;
+    Ok((self::on(channel_num), vec![OscArg::Int(on)]))
+}
+
+/// Creates an OSC message to mute or unmute a bus channel.
+///
+/// # Arguments
+///
+/// * `channel_num` - The channel number (1-16).
+/// * `muted` - `true` to mute the channel, `false` to unmute it.
+///
+/// ```
+/// use x32_lib::command::bus;
+///
+/// let (address, args) = bus::set_mute(1, true).unwrap();
+/// assert_eq!(address, "/bus/01/mix/on");
+/// assert_eq!(args, vec![osc_lib::OscArg::Int(0)]);
+/// ```
+pub fn set_mute(channel_num: u8, muted: bool) -> Result<(String, Vec<OscArg>)> {
+    valid_bus(channel_num)?;
+    Ok((self::on(channel_num), vec![OscArg::Int(i32::from(!muted))]))
+}
+
+/// Creates an OSC message to set the pan position of a bus channel.
+///
+/// # Arguments
+///
+/// * `channel_num` - The channel number (1-16).
+/// * `pan` - The pan position (0.0 for hard left, 0.5 for center, 1.0 for hard right).
+///
+/// ```
+/// use x32_lib::command::bus;
+///
+/// let (address, args) = bus::set_pan(1, 0.5).unwrap();
+/// assert_eq!(address, "/bus/01/mix/pan");
+/// assert_eq!(args, vec![osc_lib::OscArg::Float(0.5)]);
+/// ```
+pub fn set_pan(channel_num: u8, pan: f32) -> Result<(String, Vec<OscArg>)> {
+    valid_bus(channel_num)?;
+    Ok((self::pan(channel_num), vec![OscArg::Float(pan)]))
+}
+
+/// Creates an OSC message to solo or unsolo a bus channel.
+///
+/// # Arguments
+///
+/// * `channel_num` - The channel number (1-16).
+/// * `on` - `true` to solo the channel, `false` to clear its solo.
+///
+/// ```
+/// use x32_lib::command::bus;
+///
+/// let (address, args) = bus::set_solo(1, true).unwrap();
+/// assert_eq!(address, "/-stat/solosw/49");
+/// assert_eq!(args, vec![osc_lib::OscArg::Int(1)]);
+/// ```
+pub fn set_solo(channel_num: u8, on: bool) -> Result<(String, Vec<OscArg>)> {
+    valid_bus(channel_num)?;
+    Ok((self::solo(channel_num), vec![OscArg::Int(i32::from(on))]))
 }
 
 #[cfg(test)]
@@ -260,15 +335,40 @@ mod tests {
 
     #[test]
     fn test_set_fader() {
-        let (address, args) = set_fader(1, 0.5);
+        let (address, args) = set_fader(1, 0.5).unwrap();
         assert_eq!(address, "/bus/01/mix/fader");
         assert_eq!(args, vec![OscArg::Float(0.5)]);
     }
 
     #[test]
     fn test_set_on() {
-        let (address, args) = set_on(2, 1);
+        let (address, args) = set_on(2, 1).unwrap();
         assert_eq!(address, "/bus/02/mix/on");
         assert_eq!(args, vec![OscArg::Int(1)]);
     }
+
+    #[test]
+    fn test_set_mute() {
+        let (address, args) = set_mute(16, true).unwrap();
+        assert_eq!(address, "/bus/16/mix/on");
+        assert_eq!(args, vec![OscArg::Int(0)]);
+        let (_, args) = set_mute(16, false).unwrap();
+        assert_eq!(args, vec![OscArg::Int(1)]);
+    }
+
+    #[test]
+    fn test_set_pan() {
+        let (address, args) = set_pan(2, 0.25).unwrap();
+        assert_eq!(address, "/bus/02/mix/pan");
+        assert_eq!(args, vec![OscArg::Float(0.25)]);
+    }
+
+    #[test]
+    fn test_set_solo() {
+        let (address, args) = set_solo(16, true).unwrap();
+        assert_eq!(address, "/-stat/solosw/64");
+        assert_eq!(args, vec![OscArg::Int(1)]);
+        let (_, args) = set_solo(16, false).unwrap();
+        assert_eq!(args, vec![OscArg::Int(0)]);
+    }
 }
@@ -24,7 +24,7 @@
 //!     let socket = create_socket("192.168.1.64", 1000)?;
 //!
 //!     // Set the channel fader level
-//!     let (address, args) = channel::set_fader(1, 0.75);
+//!     let (address, args) = channel::set_fader(1, 0.75)?;
 //!     let msg = OscMessage::new(address, args);
 //!     socket.send(&msg.to_bytes()?)?;
 //!
@@ -39,6 +39,10 @@ use super::{
     Command, CommandFlags, CommandType, OFF_ON, XAMXGRP, XCOLORS, XDYDET, XDYENV, XDYFTYP, XDYMODE,
     XDYPPOS, XDYRAT, XEQTY1, XGMODE, XHSLOP, XISEL, XMTYPE,
 };
+use crate::common::{
+    format_frequency, format_time, valid_bus, valid_channel, DynParams, EqType, GateParams, SendTap,
+};
+use crate::error::{Result, X32Error};
 use lazy_static::lazy_static;
 use osc_lib::OscArg;
 
@@ -100,11 +104,46 @@ pub fn insert_sel(channel_num: u8) -> String {
     format!("/ch/{:02}/insert/sel", channel_num)
 }
 
+/// Returns the OSC address for a channel's gate.
+pub fn gate(channel_num: u8) -> String {
+    format!("/ch/{:02}/gate", channel_num)
+}
+
+/// Returns the OSC address for a channel's compressor/expander.
+pub fn dynamics(channel_num: u8) -> String {
+    format!("/ch/{:02}/dyn", channel_num)
+}
+
+/// Returns the OSC address for a channel's EQ band.
+pub fn eq_band(channel_num: u8, band: u8) -> String {
+    format!("/ch/{:02}/eq/{}", channel_num, band)
+}
+
 /// Returns the OSC address for a channel's EQ band type.
 pub fn eq_band_type(channel_num: u8, band: u8) -> String {
     format!("/ch/{:02}/eq/{}/type", channel_num, band)
 }
 
+/// Returns the OSC address for a channel's pan position.
+pub fn pan(channel_num: u8) -> String {
+    format!("/ch/{:02}/mix/pan", channel_num)
+}
+
+/// Returns the OSC address for a channel's solo switch.
+pub fn solo(channel_num: u8) -> String {
+    format!("/-stat/solosw/{:02}", channel_num)
+}
+
+/// Returns the OSC address for the level of a channel's send to a mix bus.
+pub fn send_level(channel_num: u8, bus: u8) -> String {
+    format!("/ch/{:02}/mix/{:02}/level", channel_num, bus)
+}
+
+/// Returns the OSC address for the tap point of a channel's send to a mix bus.
+pub fn send_tap(channel_num: u8, bus: u8) -> String {
+    format!("/ch/{:02}/mix/{:02}/type", channel_num, bus)
+}
+
 // --- OSC Message Setters ---
 
 /// Creates an OSC message to set the name of a channel.
@@ -118,15 +157,16 @@ pub fn eq_band_type(channel_num: u8, band: u8) -> String {
 /// use x32_lib::command::channel;
 /// use osc_lib::OscArg;
 ///
-/// let (address, args) = channel::set_name(1, "Test");
+/// let (address, args) = channel::set_name(1, "Test").unwrap();
 /// assert_eq!(address, "/ch/01/config/name");
 /// assert_eq!(args, vec![OscArg::String("Test".to_string())]);
 /// ```
-pub fn set_name(channel_num: u8, name: &str) -> (String, Vec<OscArg>) {
-    (
+pub fn set_name(channel_num: u8, name: &str) -> Result<(String, Vec<OscArg>)> {
+    valid_channel(channel_num)?;
+    Ok((
         self::name(channel_num),
         vec![OscArg::String(name.to_string())],
-    )
+    ))
 }
 
 /// Creates an OSC message to set the color of a channel.
@@ -140,12 +180,13 @@ pub fn set_name(channel_num: u8, name: &str) -> (String, Vec<OscArg>) {
 /// use x32_lib::command::channel;
 /// use osc_lib::OscArg;
 ///
-/// let (address, args) = channel::set_color(1, 2);
+/// let (address, args) = channel::set_color(1, 2).unwrap();
 /// assert_eq!(address, "/ch/01/config/color");
 /// assert_eq!(args, vec![OscArg::Int(2)]);
 /// ```
-pub fn set_color(channel_num: u8, color: i32) -> (String, Vec<OscArg>) {
-    (self::color(channel_num), vec![OscArg::Int(color)])
+pub fn set_color(channel_num: u8, color: i32) -> Result<(String, Vec<OscArg>)> {
+    valid_channel(channel_num)?;
+    Ok((self::color(channel_num), vec![OscArg::Int(color)]))
 }
 
 /// Creates an OSC message to set the input source of a channel.
@@ -159,12 +200,13 @@ pub fn set_color(channel_num: u8, color: i32) -> (String, Vec<OscArg>) {
 /// use x32_lib::command::channel;
 /// use osc_lib::OscArg;
 ///
-/// let (address, args) = channel::set_input_source(1, 2);
+/// let (address, args) = channel::set_input_source(1, 2).unwrap();
 /// assert_eq!(address, "/ch/01/config/source");
 /// assert_eq!(args, vec![OscArg::Int(2)]);
 /// ```
-pub fn set_input_source(channel_num: u8, source: i32) -> (String, Vec<OscArg>) {
-    (self::input_source(channel_num), vec![OscArg::Int(source)])
+pub fn set_input_source(channel_num: u8, source: i32) -> Result<(String, Vec<OscArg>)> {
+    valid_channel(channel_num)?;
+    Ok((self::input_source(channel_num), vec![OscArg::Int(source)]))
 }
 
 /// Creates an OSC message to set the delay on state of a channel.
@@ -178,12 +220,13 @@ pub fn set_input_source(channel_num: u8, source: i32) -> (String, Vec<OscArg>) {
 /// use x32_lib::command::channel;
 /// use osc_lib::OscArg;
 ///
-/// let (address, args) = channel::set_delay_on(1, 1);
+/// let (address, args) = channel::set_delay_on(1, 1).unwrap();
 /// assert_eq!(address, "/ch/01/delay/on");
 /// assert_eq!(args, vec![OscArg::Int(1)]);
 /// ```
-pub fn set_delay_on(channel_num: u8, on: i32) -> (String, Vec<OscArg>) {
-    (self::delay_on(channel_num), vec![OscArg::Int(on)])
+pub fn set_delay_on(channel_num: u8, on: i32) -> Result<(String, Vec<OscArg>)> {
+    valid_channel(channel_num)?;
+    Ok((self::delay_on(channel_num), vec![OscArg::Int(on)]))
 }
 
 /// Creates an OSC message to set the delay time of a channel.
@@ -197,12 +240,13 @@ pub fn set_delay_on(channel_num: u8, on: i32) -> (String, Vec<OscArg>) {
 /// use x32_lib::command::channel;
 /// use osc_lib::OscArg;
 ///
-/// let (address, args) = channel::set_delay_time(1, 0.5);
+/// let (address, args) = channel::set_delay_time(1, 0.5).unwrap();
 /// assert_eq!(address, "/ch/01/delay/time");
 /// assert_eq!(args, vec![OscArg::Float(0.5)]);
 /// ```
-pub fn set_delay_time(channel_num: u8, time: f32) -> (String, Vec<OscArg>) {
-    (self::delay_time(channel_num), vec![OscArg::Float(time)])
+pub fn set_delay_time(channel_num: u8, time: f32) -> Result<(String, Vec<OscArg>)> {
+    valid_channel(channel_num)?;
+    Ok((self::delay_time(channel_num), vec![OscArg::Float(time)]))
 }
 
 /// Creates an OSC message to set the fader level of a channel.
@@ -216,12 +260,13 @@ pub fn set_delay_time(channel_num: u8, time: f32) -> (String, Vec<OscArg>) {
 /// use x32_lib::command::channel;
 /// use osc_lib::OscArg;
 ///
-/// let (address, args) = channel::set_fader(1, 0.75);
+/// let (address, args) = channel::set_fader(1, 0.75).unwrap();
 /// assert_eq!(address, "/ch/01/mix/fader");
 /// assert_eq!(args, vec![OscArg::Float(0.75)]);
 /// ```
-pub fn set_fader(channel_num: u8, level: f32) -> (String, Vec<OscArg>) {
-    (fader_level(channel_num), vec![OscArg::Float(level)])
+pub fn set_fader(channel_num: u8, level: f32) -> Result<(String, Vec<OscArg>)> {
+    valid_channel(channel_num)?;
+    Ok((fader_level(channel_num), vec![OscArg::Float(level)]))
 }
 
 /// Creates an OSC message to set the on state (mute) of a channel.
@@ -235,12 +280,13 @@ pub fn set_fader(channel_num: u8, level: f32) -> (String, Vec<OscArg>) {
 /// use x32_lib::command::channel;
 /// use osc_lib::OscArg;
 ///
-/// let (address, args) = channel::set_on(1, 1);
+/// let (address, args) = channel::set_on(1, 1).unwrap();
 /// assert_eq!(address, "/ch/01/mix/on");
 /// assert_eq!(args, vec![OscArg::Int(1)]);
 /// ```
-pub fn set_on(channel_num: u8, on: i32) -> (String, Vec<OscArg>) {
-    (self::on(channel_num), vec![OscArg::Int(on)])
+pub fn set_on(channel_num: u8, on: i32) -> Result<(String, Vec<OscArg>)> {
+    valid_channel(channel_num)?;
+    Ok((self::on(channel_num), vec![OscArg::Int(on)]))
 }
 
 /// Creates an OSC message to set the insert on state of a channel.
@@ -254,12 +300,13 @@ pub fn set_on(channel_num: u8, on: i32) -> (String, Vec<OscArg>) {
 /// use x32_lib::command::channel;
 /// use osc_lib::OscArg;
 ///
-/// let (address, args) = channel::set_insert_on(1, 1);
+/// let (address, args) = channel::set_insert_on(1, 1).unwrap();
 /// assert_eq!(address, "/ch/01/insert/on");
 /// assert_eq!(args, vec![OscArg::Int(1)]);
 /// ```
-pub fn set_insert_on(channel_num: u8, on: i32) -> (String, Vec<OscArg>) {
-    (self::insert_on(channel_num), vec![OscArg::Int(on)])
+pub fn set_insert_on(channel_num: u8, on: i32) -> Result<(String, Vec<OscArg>)> {
+    valid_channel(channel_num)?;
+    Ok((self::insert_on(channel_num), vec![OscArg::Int(on)]))
 }
 
 /// Creates an OSC message to set the insert position of a channel.
@@ -273,12 +320,13 @@ pub fn set_insert_on(channel_num: u8, on: i32) -> (String, Vec<OscArg>) {
 /// use x32_lib::command::channel;
 /// use osc_lib::OscArg;
 ///
-/// let (address, args) = channel::set_insert_pos(1, 1);
+/// let (address, args) = channel::set_insert_pos(1, 1).unwrap();
 /// assert_eq!(address, "/ch/01/insert/pos");
 /// assert_eq!(args, vec![OscArg::Int(1)]);
 /// ```
-pub fn set_insert_pos(channel_num: u8, pos: i32) -> (String, Vec<OscArg>) {
-    (self::insert_pos(channel_num), vec![OscArg::Int(pos)])
+pub fn set_insert_pos(channel_num: u8, pos: i32) -> Result<(String, Vec<OscArg>)> {
+    valid_channel(channel_num)?;
+    Ok((self::insert_pos(channel_num), vec![OscArg::Int(pos)]))
 }
 
 /// Creates an OSC message to set the insert selection of a channel.
@@ -292,12 +340,13 @@ pub fn set_insert_pos(channel_num: u8, pos: i32) -> (String, Vec<OscArg>) {
 /// use x32_lib::command::channel;
 /// use osc_lib::OscArg;
 ///
-/// let (address, args) = channel::set_insert_sel(1, 2);
+/// let (address, args) = channel::set_insert_sel(1, 2).unwrap();
 /// assert_eq!(address, "/ch/01/insert/sel");
 /// assert_eq!(args, vec![OscArg::Int(2)]);
 /// ```
-pub fn set_insert_sel(channel_num: u8, sel: i32) -> (String, Vec<OscArg>) {
-    (self::insert_sel(channel_num), vec![OscArg::Int(sel)])
+pub fn set_insert_sel(channel_num: u8, sel: i32) -> Result<(String, Vec<OscArg>)> {
+    valid_channel(channel_num)?;
+    Ok((self::insert_sel(channel_num), vec![OscArg::Int(sel)]))
 }
 
 /// Creates an OSC message to set the eq band type of a channel.
@@ -312,15 +361,169 @@ pub fn set_insert_sel(channel_num: u8, sel: i32) -> (String, Vec<OscArg>) {
 /// use x32_lib::command::channel;
 /// use osc_lib::OscArg;
 ///
-/// let (address, args) = channel::set_eq_band_type(1, 1, 2);
+/// let (address, args) = channel::set_eq_band_type(1, 1, 2).unwrap();
 /// assert_eq!(address, "/ch/01/eq/1/type");
 /// assert_eq!(args, vec![OscArg::Int(2)]);
 /// ```
-pub fn set_eq_band_type(channel_num: u8, band: u8, eq_type: i32) -> (String, Vec<OscArg>) {
-    (
+pub fn set_eq_band_type(channel_num: u8, band: u8, eq_type: i32) -> Result<(String, Vec<OscArg>)> {
+    valid_channel(channel_num)?;
+    Ok((
         self::eq_band_type(channel_num, band),
         vec![OscArg::Int(eq_type)],
-    )
+    ))
+}
+
+/// Creates an OSC message to set all the parameters of a channel's EQ band at once.
+///
+/// The arguments are the strings the console prints for the band in `/node` replies,
+/// so the message is equivalent to the node line `/ch/01/eq/3 PEQ 1k97 +0.00 2.0`.
+///
+/// # Arguments
+///
+/// * `channel_num` - The channel number (1-32).
+/// * `band` - The eq band (1-4).
+/// * `eq_type` - The filter type of the band.
+/// * `freq_hz` - The band frequency in Hz (20 to 20000).
+/// * `gain_db` - The band gain in dB (-15 to +15).
+/// * `q` - The band quality factor (0.3 to 10).
+///
+/// ```
+/// use x32_lib::command::channel;
+/// use x32_lib::common::EqType;
+/// use osc_lib::OscArg;
+///
+/// let (address, args) = channel::set_eq_band(1, 3, EqType::Peq, 1970.0, 0.0, 2.0).unwrap();
+/// assert_eq!(address, "/ch/01/eq/3");
+/// assert_eq!(
+///     args,
+///     vec![
+///         OscArg::String("PEQ".to_string()),
+///         OscArg::String("1k97".to_string()),
+///         OscArg::String("+0.00".to_string()),
+///         OscArg::String("2.0".to_string()),
+///     ]
+/// );
+/// ```
+pub fn set_eq_band(
+    channel_num: u8,
+    band: u8,
+    eq_type: EqType,
+    freq_hz: f32,
+    gain_db: f32,
+    q: f32,
+) -> Result<(String, Vec<OscArg>)> {
+    valid_channel(channel_num)?;
+    Ok((
+        self::eq_band(channel_num, band),
+        vec![
+            OscArg::String(crate::common::XEQTY1[eq_type as usize].to_string()),
+            OscArg::String(format_frequency(freq_hz)),
+            OscArg::String(format!("{:+.2}", gain_db)),
+            OscArg::String(format!("{:.1}", q)),
+        ],
+    ))
+}
+
+/// Creates an OSC message to set all the parameters of a channel's gate at once.
+///
+/// Like [`set_eq_band`], the arguments are the strings the console prints in the
+/// gate's node line.
+///
+/// # Arguments
+///
+/// * `channel_num` - The channel number (1-32).
+/// * `params` - The gate settings.
+///
+/// ```
+/// use x32_lib::command::channel;
+/// use x32_lib::common::{GateMode, GateParams};
+///
+/// let params = GateParams {
+///     on: true,
+///     mode: GateMode::Exp2,
+///     threshold_db: -40.0,
+///     ..GateParams::default()
+/// };
+/// let (address, args) = channel::set_gate(1, params).unwrap();
+/// assert_eq!(address, "/ch/01/gate");
+/// assert_eq!(args.len(), 8);
+/// ```
+pub fn set_gate(channel_num: u8, params: GateParams) -> Result<(String, Vec<OscArg>)> {
+    use crate::common::OFF_ON;
+    use crate::enums::gate_mode_name;
+
+    valid_channel(channel_num)?;
+    let values = [
+        OFF_ON[usize::from(params.on)].to_string(),
+        gate_mode_name(params.mode as i32)
+            .unwrap_or_default()
+            .to_string(),
+        format!("{:.1}", params.threshold_db),
+        format!("{:.1}", params.range_db),
+        format!("{:.0}", params.attack_ms),
+        format_time(params.hold_ms),
+        format_time(params.release_ms),
+        params.key_source.to_string(),
+    ];
+    Ok((
+        self::gate(channel_num),
+        values.into_iter().map(OscArg::String).collect(),
+    ))
+}
+
+/// Creates an OSC message to set all the parameters of a channel's compressor/expander
+/// at once.
+///
+/// Like [`set_eq_band`], the arguments are the strings the console prints in the
+/// processor's node line.
+///
+/// # Arguments
+///
+/// * `channel_num` - The channel number (1-32).
+/// * `params` - The compressor/expander settings.
+///
+/// ```
+/// use x32_lib::command::channel;
+/// use x32_lib::common::{DynParams, DynRatio};
+///
+/// let params = DynParams {
+///     on: true,
+///     threshold_db: -20.0,
+///     ratio: DynRatio::R4_0,
+///     ..DynParams::default()
+/// };
+/// let (address, args) = channel::set_dyn(1, params).unwrap();
+/// assert_eq!(address, "/ch/01/dyn");
+/// assert_eq!(args.len(), 15);
+/// ```
+pub fn set_dyn(channel_num: u8, params: DynParams) -> Result<(String, Vec<OscArg>)> {
+    use crate::common::{OFF_ON, XDYDET, XDYENV, XDYMODE, XDYPPOS};
+    use crate::enums::dyn_ratio_name;
+
+    valid_channel(channel_num)?;
+    let values = [
+        OFF_ON[usize::from(params.on)].to_string(),
+        XDYMODE[params.mode as usize].to_string(),
+        XDYDET[params.detector as usize].to_string(),
+        XDYENV[params.envelope as usize].to_string(),
+        format!("{:.1}", params.threshold_db),
+        dyn_ratio_name(params.ratio as i32)
+            .unwrap_or_default()
+            .to_string(),
+        format!("{:.0}", params.knee),
+        format!("{:.2}", params.makeup_gain_db),
+        format!("{:.0}", params.attack_ms),
+        format_time(params.hold_ms),
+        format_time(params.release_ms),
+        XDYPPOS[params.position as usize].to_string(),
+        params.key_source.to_string(),
+        format!("{:.0}", params.mix_percent),
+        OFF_ON[usize::from(params.auto_gain)].to_string(),
+    ];
+    Ok((
+        self::dynamics(channel_num),
+        values.into_iter().map(OscArg::String).collect(),
+    ))
 }
 
 /// Programmatically generates a vector of all available OSC commands for a single channel.
@@ -589,6 +792,118 @@ lazy_static! {
         (1..=32).map(get_channel_commands).collect();
 }
 
+/// Creates an OSC message to mute or unmute a channel.
+///
+/// The console has no separate mute parameter: muting sets the channel's on state to 0.
+///
+/// # Arguments
+///
+/// * `channel_num` - The channel number (1-32).
+/// * `muted` - `true` to mute the channel, `false` to unmute it.
+///
+/// ```
+/// use x32_lib::command::channel;
+///
+/// let (address, args) = channel::set_mute(1, true).unwrap();
+/// assert_eq!(address, "/ch/01/mix/on");
+/// assert_eq!(args, vec![osc_lib::OscArg::Int(0)]);
+/// ```
+pub fn set_mute(channel_num: u8, muted: bool) -> Result<(String, Vec<OscArg>)> {
+    valid_channel(channel_num)?;
+    Ok((self::on(channel_num), vec![OscArg::Int(i32::from(!muted))]))
+}
+
+/// Creates an OSC message to set the pan position of a channel.
+///
+/// # Arguments
+///
+/// * `channel_num` - The channel number (1-32).
+/// * `pan` - The pan position (0.0 for hard left, 0.5 for center, 1.0 for hard right).
+///
+/// ```
+/// use x32_lib::command::channel;
+///
+/// let (address, args) = channel::set_pan(1, 0.5).unwrap();
+/// assert_eq!(address, "/ch/01/mix/pan");
+/// assert_eq!(args, vec![osc_lib::OscArg::Float(0.5)]);
+/// ```
+pub fn set_pan(channel_num: u8, pan: f32) -> Result<(String, Vec<OscArg>)> {
+    valid_channel(channel_num)?;
+    Ok((self::pan(channel_num), vec![OscArg::Float(pan)]))
+}
+
+/// Creates an OSC message to solo or unsolo a channel.
+///
+/// # Arguments
+///
+/// * `channel_num` - The channel number (1-32).
+/// * `on` - `true` to solo the channel, `false` to clear its solo.
+///
+/// ```
+/// use x32_lib::command::channel;
+///
+/// let (address, args) = channel::set_solo(1, true).unwrap();
+/// assert_eq!(address, "/-stat/solosw/01");
+/// assert_eq!(args, vec![osc_lib::OscArg::Int(1)]);
+/// ```
+pub fn set_solo(channel_num: u8, on: bool) -> Result<(String, Vec<OscArg>)> {
+    valid_channel(channel_num)?;
+    Ok((self::solo(channel_num), vec![OscArg::Int(i32::from(on))]))
+}
+
+/// Creates an OSC message to set the level of a channel's send to a mix bus.
+///
+/// # Arguments
+///
+/// * `channel_num` - The channel number (1-32).
+/// * `bus` - The mix bus number (1-16).
+/// * `level` - The send level (0.0 to 1.0).
+///
+/// ```
+/// use x32_lib::command::channel;
+///
+/// let (address, args) = channel::set_send(5, 3, 0.75).unwrap();
+/// assert_eq!(address, "/ch/05/mix/03/level");
+/// assert_eq!(args, vec![osc_lib::OscArg::Float(0.75)]);
+/// ```
+pub fn set_send(channel_num: u8, bus: u8, level: f32) -> Result<(String, Vec<OscArg>)> {
+    valid_channel(channel_num)?;
+    valid_bus(bus)?;
+    Ok((send_level(channel_num, bus), vec![OscArg::Float(level)]))
+}
+
+/// Creates an OSC message to set where a channel's send to a mix bus is tapped.
+///
+/// The console keeps one tap point for each pair of buses, on the odd bus, so an
+/// even bus number is rejected.
+///
+/// # Arguments
+///
+/// * `channel_num` - The channel number (1-32).
+/// * `bus` - The odd mix bus number (1-15).
+/// * `tap` - The tap point.
+///
+/// ```
+/// use x32_lib::command::channel;
+/// use x32_lib::common::SendTap;
+///
+/// let (address, args) = channel::set_send_tap(5, 3, SendTap::Pre).unwrap();
+/// assert_eq!(address, "/ch/05/mix/03/type");
+/// assert_eq!(args, vec![osc_lib::OscArg::Int(3)]);
+/// ```
+pub fn set_send_tap(channel_num: u8, bus: u8, tap: SendTap) -> Result<(String, Vec<OscArg>)> {
+    valid_channel(channel_num)?;
+    valid_bus(bus)?;
+    if bus.is_multiple_of(2) {
+        return Err(X32Error::Custom(format!(
+            "Bus {} shares its tap point with bus {}",
+            bus,
+            bus - 1
+        )));
+    }
+    Ok((send_tap(channel_num, bus), vec![OscArg::Int(tap as i32)]))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -616,6 +931,15 @@ mod tests {
         get_channel_commands(0);
     }
 
+    #[test]
+    fn test_setters_reject_out_of_range_channels() {
+        assert!(set_fader(0, 0.5).is_err());
+        assert!(set_fader(33, 0.5).is_err());
+        assert!(set_mute(33, true).is_err());
+        assert!(set_gate(0, GateParams::default()).is_err());
+        assert!(set_fader(32, 0.5).is_ok());
+    }
+
     #[test]
     fn test_xchannel_commands_array() {
         assert_eq!(XCHANNEL_COMMANDS.len(), 32);
@@ -624,6 +948,92 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_set_eq_band() {
+        let (address, args) = set_eq_band(1, 1, EqType::Peq, 124.7, 0.0, 2.0).unwrap();
+        assert_eq!(address, "/ch/01/eq/1");
+        assert_eq!(
+            args,
+            vec![
+                OscArg::String("PEQ".to_string()),
+                OscArg::String("124.7".to_string()),
+                OscArg::String("+0.00".to_string()),
+                OscArg::String("2.0".to_string()),
+            ]
+        );
+
+        let (address, args) = set_eq_band(32, 4, EqType::HShv, 10020.0, -3.5, 0.7).unwrap();
+        assert_eq!(address, "/ch/32/eq/4");
+        assert_eq!(
+            args,
+            vec![
+                OscArg::String("HShv".to_string()),
+                OscArg::String("10k02".to_string()),
+                OscArg::String("-3.50".to_string()),
+                OscArg::String("0.7".to_string()),
+            ]
+        );
+    }
+
+    /// Renders a setter's output as a node line, e.g. `/ch/01/gate OFF GATE ...`.
+    fn node_line((address, args): (String, Vec<OscArg>)) -> String {
+        let values: Vec<&str> = args.iter().filter_map(OscArg::as_str).collect();
+        format!("{} {}", address, values.join(" "))
+    }
+
+    #[test]
+    fn test_default_gate_and_dyn_match_factory_settings() {
+        let inistr = x32_core::CH_INISTR;
+        let gate = node_line(set_gate(1, GateParams::default()).unwrap());
+        assert!(inistr.contains(&gate.as_str()), "{}", gate);
+        let dynamics = node_line(set_dyn(1, DynParams::default()).unwrap());
+        assert!(inistr.contains(&dynamics.as_str()), "{}", dynamics);
+    }
+
+    #[test]
+    fn test_gate_mode_ids_match_the_console() {
+        use crate::common::GateMode;
+        use crate::enums::gate_mode_index;
+
+        for (label, mode) in [("EXP2", GateMode::Exp2), ("GATE", GateMode::Gate)] {
+            let id = gate_mode_index(label).unwrap();
+            assert_eq!(id, mode as i32);
+            assert_eq!(GateMode::from_id(id as u8), Some(mode));
+        }
+    }
+
+    #[test]
+    fn test_set_gate_and_dyn_values() {
+        use crate::common::{DynMode, DynRatio, GateMode, InsertPosition};
+
+        let gate = GateParams {
+            on: true,
+            mode: GateMode::Duck,
+            threshold_db: -35.5,
+            hold_ms: 0.5,
+            release_ms: 25.0,
+            ..GateParams::default()
+        };
+        assert_eq!(
+            node_line(set_gate(32, gate).unwrap()),
+            "/ch/32/gate ON DUCK -35.5 60.0 1 0.50 25.0 0"
+        );
+
+        let dynamics = DynParams {
+            on: true,
+            mode: DynMode::Exp,
+            ratio: DynRatio::R100,
+            makeup_gain_db: 6.0,
+            position: InsertPosition::Pre,
+            auto_gain: true,
+            ..DynParams::default()
+        };
+        assert_eq!(
+            node_line(set_dyn(2, dynamics).unwrap()),
+            "/ch/02/dyn ON EXP PEAK LOG 0.0 100 1 6.00 10 10.0 151 PRE 0 100 ON"
+        );
+    }
+
     #[test]
     fn test_address_getters() {
         assert_eq!(name(1), "/ch/01/config/name");
@@ -631,4 +1041,52 @@ mod tests {
         assert_eq!(color(1), "/ch/01/config/color");
         assert_eq!(color(32), "/ch/32/config/color");
     }
+
+    #[test]
+    fn test_set_mute() {
+        let (address, args) = set_mute(32, true).unwrap();
+        assert_eq!(address, "/ch/32/mix/on");
+        assert_eq!(args, vec![OscArg::Int(0)]);
+        let (_, args) = set_mute(32, false).unwrap();
+        assert_eq!(args, vec![OscArg::Int(1)]);
+    }
+
+    #[test]
+    fn test_set_pan() {
+        let (address, args) = set_pan(2, 0.25).unwrap();
+        assert_eq!(address, "/ch/02/mix/pan");
+        assert_eq!(args, vec![OscArg::Float(0.25)]);
+    }
+
+    #[test]
+    fn test_set_solo() {
+        let (address, args) = set_solo(32, true).unwrap();
+        assert_eq!(address, "/-stat/solosw/32");
+        assert_eq!(args, vec![OscArg::Int(1)]);
+        let (_, args) = set_solo(32, false).unwrap();
+        assert_eq!(args, vec![OscArg::Int(0)]);
+    }
+
+    #[test]
+    fn test_set_send() {
+        let (address, args) = set_send(5, 3, 0.5).unwrap();
+        assert_eq!(address, "/ch/05/mix/03/level");
+        assert_eq!(args, vec![OscArg::Float(0.5)]);
+        assert!(set_send(5, 0, 0.5).is_err());
+        assert!(set_send(5, 17, 0.5).is_err());
+        assert!(set_send(33, 1, 0.5).is_err());
+        assert!(set_send(32, 16, 0.5).is_ok());
+    }
+
+    #[test]
+    fn test_set_send_tap() {
+        let (address, args) = set_send_tap(5, 3, SendTap::PostEq).unwrap();
+        assert_eq!(address, "/ch/05/mix/03/type");
+        assert_eq!(args, vec![OscArg::Int(2)]);
+        let (_, args) = set_send_tap(1, 15, SendTap::Group).unwrap();
+        assert_eq!(args, vec![OscArg::Int(5)]);
+        assert_eq!(SendTap::from_id(4), Some(SendTap::Post));
+        assert!(set_send_tap(5, 4, SendTap::Pre).is_err());
+        assert!(set_send_tap(5, 17, SendTap::Pre).is_err());
+    }
 }
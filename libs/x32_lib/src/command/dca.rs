@@ -4,6 +4,8 @@
 //! This module provides functions for controlling the fader level, on/off state, and configuration
 //! of the 8 DCAs.
 use super::{Command, CommandFlags, CommandType, OFF_ON, XCOLORS};
+use crate::common::valid_dca;
+use crate::error::Result;
 use lazy_static::lazy_static;
 use osc_lib::OscArg;
 
@@ -49,24 +51,94 @@ lazy_static! {
 // --- OSC Message Setters ---
 
 /// Creates an OSC message to set the fader level of a DCA.
-pub fn set_fader(dca_num: u8, level: f32) -> (String, Vec<OscArg>) {
-    (
+///
+/// # Arguments
+///
+/// * `dca_num` - The DCA number (1-8).
+/// * `level` - The new fader level (0.0-1.0).
+///
+/// ```
+/// use x32_lib::command::dca;
+///
+/// let (address, args) = dca::set_fader(1, 0.75).unwrap();
+/// assert_eq!(address, "/dca/1/fader");
+/// assert_eq!(args, vec![osc_lib::OscArg::Float(0.75)]);
+/// ```
+pub fn set_fader(dca_num: u8, level: f32) -> Result<(String, Vec<OscArg>)> {
+    valid_dca(dca_num)?;
+    Ok((
         format!("/dca/{}/fader", dca_num),
         vec![OscArg::Float(level)],
-    )
+    ))
 }
 
 /// Creates an OSC message to set the on state of a DCA.
-pub fn set_on(dca_num: u8, on: i32) -> (String, Vec<OscArg>) {
-    (format!("/dca/{}/on", dca_num), vec![OscArg::Int(on)])
+///
+/// # Arguments
+///
+/// * `dca_num` - The DCA number (1-8).
+/// * `on` - The new on state (0 for off, 1 for on).
+pub fn set_on(dca_num: u8, on: i32) -> Result<(String, Vec<OscArg>)> {
+    valid_dca(dca_num)?;
+    Ok((format!("/dca/{}/on", dca_num), vec![OscArg::Int(on)]))
 }
 
 /// Creates an OSC message to set the name of a DCA.
-pub fn set_name(dca_num: u8, name: &str) -> (String, Vec<OscArg>) {
-    (
+///
+/// # Arguments
+///
+/// * `dca_num` - The DCA number (1-8).
+/// * `name` - The new scribble strip name.
+pub fn set_name(dca_num: u8, name: &str) -> Result<(String, Vec<OscArg>)> {
+    valid_dca(dca_num)?;
+    Ok((
         format!("/dca/{}/config/name", dca_num),
         vec![OscArg::String(name.to_string())],
-    )
+    ))
+}
+
+/// Creates an OSC message to mute or unmute a DCA.
+///
+/// Muting a DCA switches it off, which also mutes every channel assigned to it.
+///
+/// # Arguments
+///
+/// * `dca_num` - The DCA number (1-8).
+/// * `muted` - `true` to mute the DCA, `false` to unmute it.
+///
+/// ```
+/// use x32_lib::command::dca;
+///
+/// let (address, args) = dca::set_mute(1, true).unwrap();
+/// assert_eq!(address, "/dca/1/on");
+/// assert_eq!(args, vec![osc_lib::OscArg::Int(0)]);
+/// ```
+pub fn set_mute(dca_num: u8, muted: bool) -> Result<(String, Vec<OscArg>)> {
+    set_on(dca_num, i32::from(!muted))
+}
+
+/// Creates an OSC message to solo or unsolo a DCA.
+///
+/// DCA solo switches follow the 72 channel and bus switches in `/-stat/solosw`.
+///
+/// # Arguments
+///
+/// * `dca_num` - The DCA number (1-8).
+/// * `on` - `true` to solo the DCA, `false` to clear its solo.
+///
+/// ```
+/// use x32_lib::command::dca;
+///
+/// let (address, args) = dca::set_solo(1, true).unwrap();
+/// assert_eq!(address, "/-stat/solosw/73");
+/// assert_eq!(args, vec![osc_lib::OscArg::Int(1)]);
+/// ```
+pub fn set_solo(dca_num: u8, on: bool) -> Result<(String, Vec<OscArg>)> {
+    valid_dca(dca_num)?;
+    Ok((
+        format!("/-stat/solosw/{:02}", dca_num + 72),
+        vec![OscArg::Int(i32::from(on))],
+    ))
 }
 
 #[cfg(test)]
@@ -80,4 +152,26 @@ mod tests {
             assert_eq!(XDCA_COMMANDS[i].len(), 5);
         }
     }
+
+    #[test]
+    fn test_setters_reject_out_of_range_dcas() {
+        assert!(set_fader(0, 0.5).is_err());
+        assert!(set_mute(9, true).is_err());
+    }
+
+    #[test]
+    fn test_set_mute() {
+        let (address, args) = set_mute(8, true).unwrap();
+        assert_eq!(address, "/dca/8/on");
+        assert_eq!(args, vec![OscArg::Int(0)]);
+    }
+
+    #[test]
+    fn test_set_solo() {
+        let (address, args) = set_solo(1, true).unwrap();
+        assert_eq!(address, "/-stat/solosw/73");
+        assert_eq!(args, vec![OscArg::Int(1)]);
+        let (address, _) = set_solo(8, false).unwrap();
+        assert_eq!(address, "/-stat/solosw/80");
+    }
 }
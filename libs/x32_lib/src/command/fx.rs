@@ -4,6 +4,7 @@
 //! from reverbs and delays to EQs and compressors. This module provides the functions
 //! needed to control the type, source, and parameters of these effects.
 use super::{Command, CommandFlags, CommandType};
+use crate::common::valid_fx;
 use crate::error::Result;
 use lazy_static::lazy_static;
 use osc_lib::{OscArg, OscMessage};
@@ -43,16 +44,18 @@ lazy_static! {
 // --- OSC Message Setters ---
 
 /// Creates an OSC message to set the type of an effects processor.
-pub fn set_type(fx_num: u8, fx_type: i32) -> (String, Vec<OscArg>) {
-    (format!("/fx/{}/type", fx_num), vec![OscArg::Int(fx_type)])
+pub fn set_type(fx_num: u8, fx_type: i32) -> Result<(String, Vec<OscArg>)> {
+    valid_fx(fx_num)?;
+    Ok((format!("/fx/{}/type", fx_num), vec![OscArg::Int(fx_type)]))
 }
 
 /// Creates an OSC message to set a parameter of an effects processor.
-pub fn set_param(fx_num: u8, param_num: u8, value: f32) -> (String, Vec<OscArg>) {
-    (
+pub fn set_param(fx_num: u8, param_num: u8, value: f32) -> Result<(String, Vec<OscArg>)> {
+    valid_fx(fx_num)?;
+    Ok((
         format!("/fx/{}/par/{:02}", fx_num, param_num),
         vec![OscArg::Float(value)],
-    )
+    ))
 }
 
 /// Sends an OSC message to set a parameter of an effects processor.
@@ -62,7 +65,7 @@ pub fn set_fx_param(
     param_num: u8,
     value: f32,
 ) -> Result<(String, Vec<OscArg>)> {
-    let (address, args) = set_param(fx_num, param_num, value);
+    let (address, args) = set_param(fx_num, param_num, value)?;
     let msg = OscMessage::new(address.clone(), args);
     socket.send(&msg.to_bytes()?)?;
     Ok((address, msg.args))
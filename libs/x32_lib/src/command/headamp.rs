@@ -2,56 +2,106 @@
 //!
 //! The headamp controls are responsible for the analog gain of the microphone preamplifiers
 //! and for switching 48V phantom power on or off.
+//!
+//! Headamps are addressed by their index (0-127) with three digits, as the console does:
+//! `/headamp/000` is the first local input. Which headamp feeds a channel depends on the
+//! input routing, so the index is not the channel number.
 use osc_lib::OscArg;
 
+/// The lowest headamp gain, in dB.
+pub const GAIN_MIN_DB: f32 = -12.0;
+/// The highest headamp gain, in dB.
+pub const GAIN_MAX_DB: f32 = 60.0;
+/// The size of one headamp gain step, in dB.
+pub const GAIN_STEP_DB: f32 = 0.5;
+
 // --- Address String Getters ---
 
-/// Returns the OSC address for a headamp channel's gain.
-pub fn gain(channel_num: u8) -> String {
-    format!("/headamp/{:02}/gain", channel_num)
+/// Returns the OSC address for a headamp's gain.
+///
+/// ```
+/// use x32_lib::command::headamp;
+///
+/// assert_eq!(headamp::gain(0), "/headamp/000/gain");
+/// ```
+pub fn gain(headamp: u8) -> String {
+    format!("/headamp/{:03}/gain", headamp)
+}
+
+/// Returns the OSC address for a headamp's phantom power state.
+pub fn phantom(headamp: u8) -> String {
+    format!("/headamp/{:03}/phantom", headamp)
+}
+
+// --- Gain Conversion ---
+
+/// Converts a gain in dB to the float the console uses for `/headamp/NNN/gain`.
+///
+/// The float maps linearly onto the -12 dB to +60 dB range. The gain is rounded to the
+/// console's 0.5 dB steps and clamped to the range.
+///
+/// # Arguments
+///
+/// * `db` - The gain in dB.
+///
+/// # Returns
+///
+/// The gain as a float from 0.0 to 1.0.
+pub fn db_to_gain(db: f32) -> f32 {
+    let steps = (GAIN_MAX_DB - GAIN_MIN_DB) / GAIN_STEP_DB;
+    let step = ((db.clamp(GAIN_MIN_DB, GAIN_MAX_DB) - GAIN_MIN_DB) / GAIN_STEP_DB).round();
+    step / steps
 }
 
-/// Returns the OSC address for a headamp channel's phantom power state.
-pub fn phantom(channel_num: u8) -> String {
-    format!("/headamp/{:02}/phantom", channel_num)
+/// Converts a `/headamp/NNN/gain` float to a gain in dB.
+///
+/// # Arguments
+///
+/// * `gain` - The gain as a float from 0.0 to 1.0. Out-of-range values are clamped.
+///
+/// # Returns
+///
+/// The gain in dB, from -12.0 to +60.0.
+pub fn gain_to_db(gain: f32) -> f32 {
+    GAIN_MIN_DB + gain.clamp(0.0, 1.0) * (GAIN_MAX_DB - GAIN_MIN_DB)
 }
 
 // --- OSC Message Setters ---
 
-/// Creates an OSC message to set the gain of a headamp channel.
+/// Creates an OSC message to set the gain of a headamp.
 ///
 /// # Arguments
 ///
-/// * `channel_num` - The headamp channel number (1-32).
-/// * `gain` - The new gain for the channel (0.0-1.0).
+/// * `headamp` - The headamp index (0-127).
+/// * `db` - The new gain in dB (-12 to +60).
 ///
 /// ```
 /// use x32_lib::command::headamp;
 ///
-/// let (address, args) = headamp::set_gain(1, 0.75);
-/// assert_eq!(address, "/headamp/01/gain");
-/// assert_eq!(args, vec![osc_lib::OscArg::Float(0.75)]);
+/// let (address, args) = headamp::set_gain(0, 24.0);
+/// assert_eq!(address, "/headamp/000/gain");
+/// assert_eq!(args, vec![osc_lib::OscArg::Float(0.5)]);
 /// ```
-pub fn set_gain(channel_num: u8, gain: f32) -> (String, Vec<OscArg>) {
-    (self::gain(channel_num), vec![OscArg::Float(gain)])
+pub fn set_gain(headamp: u8, db: f32) -> (String, Vec<OscArg>) {
+    (self::gain(headamp), vec![OscArg::Float(db_to_gain(db))])
 }
 
-/// Creates an OSC message to set the phantom power of a headamp channel.
+/// Creates an OSC message to switch the phantom power of a headamp.
 ///
 /// # Arguments
 ///
-/// * `channel_num` - The headamp channel number (1-32).
-/// * `on` - The new phantom power state for the channel (0 for Off, 1 for On).
+/// * `headamp` - The headamp index (0-127).
+/// * `on` - Whether 48V phantom power should be on.
 ///
 /// ```
 /// use x32_lib::command::headamp;
 ///
-/// let (address, args) = headamp::set_phantom(1, 1);
-/// assert_eq!(address, "/headamp/01/phantom");
+/// let (address, args) = headamp::set_phantom(1, true);
+/// assert_eq!(address, "/headamp/001/phantom");
 /// assert_eq!(args, vec![osc_lib::OscArg::Int(1)]);
 /// ```
-pub fn set_phantom(channel_num: u8, on: i32) -> (String, Vec<OscArg>) {
-    (self::phantom(channel_num), vec![OscArg::Int(on)])
+pub fn set_phantom(headamp: u8, on: bool) -> (String, Vec<OscArg>) {
+    (self::phantom(headamp), vec![OscArg::Int(i32::from(on))])
 }
 
 #[cfg(test)]
@@ -60,15 +110,36 @@ mod tests {
 
     #[test]
     fn test_set_gain() {
-        let (address, args) = set_gain(1, 0.5);
-        assert_eq!(address, "/headamp/01/gain");
+        let (address, args) = set_gain(0, 24.0);
+        assert_eq!(address, "/headamp/000/gain");
         assert_eq!(args, vec![OscArg::Float(0.5)]);
+
+        let (address, args) = set_gain(127, 60.0);
+        assert_eq!(address, "/headamp/127/gain");
+        assert_eq!(args, vec![OscArg::Float(1.0)]);
+    }
+
+    #[test]
+    fn test_gain_conversion() {
+        assert_eq!(db_to_gain(-12.0), 0.0);
+        assert_eq!(db_to_gain(-20.0), 0.0);
+        assert_eq!(db_to_gain(80.0), 1.0);
+        // 30 dB is step 84 of 144; 30.2 dB rounds to the same step.
+        assert_eq!(db_to_gain(30.0), 84.0 / 144.0);
+        assert_eq!(db_to_gain(30.2), db_to_gain(30.0));
+        for step in 0..=144 {
+            let db = GAIN_MIN_DB + step as f32 * GAIN_STEP_DB;
+            assert!((gain_to_db(db_to_gain(db)) - db).abs() < 1e-4, "{} dB", db);
+        }
     }
 
     #[test]
     fn test_set_phantom() {
-        let (address, args) = set_phantom(2, 1);
-        assert_eq!(address, "/headamp/02/phantom");
+        let (address, args) = set_phantom(2, true);
+        assert_eq!(address, "/headamp/002/phantom");
         assert_eq!(args, vec![OscArg::Int(1)]);
+
+        let (_, args) = set_phantom(2, false);
+        assert_eq!(args, vec![OscArg::Int(0)]);
     }
 }
@@ -1,9 +1,68 @@
 //! Provides functions for generating OSC commands for miscellaneous X32/M32 settings.
 //!
 //! This module covers a range of settings that don't fit into the other categories,
-//! such as USB playback routing, auxiliary input inserts, and headamp routing.
+//! such as USB playback routing, auxiliary input inserts, headamp routing, the
+//! talkback switches and the test-tone oscillator.
+use crate::common::{db_to_level, quantize_fader};
 use osc_lib::OscArg;
 
+/// The lowest oscillator frequency, in Hz.
+pub const OSC_FREQ_MIN_HZ: f32 = 20.0;
+/// The highest oscillator frequency, in Hz.
+pub const OSC_FREQ_MAX_HZ: f32 = 20000.0;
+/// The number of positions of the oscillator frequency controls.
+pub const OSC_FREQ_STEPS: u32 = 121;
+
+/// The signal the oscillator generates, in the order of `/config/osc/type`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum OscType {
+    /// A sine wave at the selected frequency.
+    #[default]
+    Sine = 0,
+    /// Pink noise.
+    Pink = 1,
+    /// White noise.
+    White = 2,
+}
+
+/// A talkback switch.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Talkback {
+    /// Talkback A.
+    A,
+    /// Talkback B.
+    B,
+}
+
+/// Settings for the oscillator, as sent by [`set_oscillator`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct OscGenParams {
+    /// Whether the oscillator is running.
+    pub on: bool,
+    /// The output level in dB (-90 to +10).
+    pub level_db: f32,
+    /// The sine frequency in Hz (20 to 20000).
+    pub frequency_hz: f32,
+    /// The signal type.
+    pub osc_type: OscType,
+    /// The destination: 0-15 for mix buses 1-16, 16 L, 17 R, 18 L+R, 19 M/C and
+    /// 20-25 for matrices 1-6.
+    pub destination: i32,
+}
+
+impl Default for OscGenParams {
+    /// A 1 kHz sine at -20 dB on mix bus 1, switched off.
+    fn default() -> Self {
+        Self {
+            on: false,
+            level_db: -20.0,
+            frequency_hz: 1000.0,
+            osc_type: OscType::Sine,
+            destination: 0,
+        }
+    }
+}
+
 // --- Address String Getters ---
 
 /// Returns the OSC address for the USB player path.
@@ -21,6 +80,55 @@ pub fn ha_routing(ha_num: u8) -> String {
     format!("/config/routing/p/{:02}", ha_num)
 }
 
+/// Returns the OSC address of a talkback switch.
+///
+/// The switches live under `/-stat`; `/config/talk` holds the talkback settings.
+pub fn talkback(bank: Talkback) -> String {
+    match bank {
+        Talkback::A => "/-stat/talk/A".to_string(),
+        Talkback::B => "/-stat/talk/B".to_string(),
+    }
+}
+
+/// Returns the OSC address of the oscillator on/off switch.
+pub fn oscillator_on() -> String {
+    "/-stat/osc/on".to_string()
+}
+
+// --- Oscillator Frequency Conversion ---
+
+/// Converts a frequency to the float the console uses for `/config/osc/f1` and `f2`.
+///
+/// The float maps logarithmically onto 20 Hz to 20 kHz and is rounded to the
+/// control's [`OSC_FREQ_STEPS`] positions.
+///
+/// # Arguments
+///
+/// * `freq_hz` - The frequency in Hz. Out-of-range values are clamped.
+///
+/// # Returns
+///
+/// The frequency as a float from 0.0 to 1.0.
+pub fn frequency_to_float(freq_hz: f32) -> f32 {
+    let freq = freq_hz.clamp(OSC_FREQ_MIN_HZ, OSC_FREQ_MAX_HZ);
+    let value = (freq / OSC_FREQ_MIN_HZ).ln() / (OSC_FREQ_MAX_HZ / OSC_FREQ_MIN_HZ).ln();
+    let max = (OSC_FREQ_STEPS - 1) as f32;
+    (value * max).round() / max
+}
+
+/// Converts a `/config/osc/f1` or `f2` float to a frequency in Hz.
+///
+/// # Arguments
+///
+/// * `value` - The frequency as a float from 0.0 to 1.0.
+///
+/// # Returns
+///
+/// The frequency in Hz, from 20 to 20000.
+pub fn float_to_frequency(value: f32) -> f32 {
+    OSC_FREQ_MIN_HZ * (OSC_FREQ_MAX_HZ / OSC_FREQ_MIN_HZ).powf(value.clamp(0.0, 1.0))
+}
+
 // --- OSC Message Setters ---
 
 /// Creates an OSC message to set the usb path of a misc channel.
@@ -76,6 +184,64 @@ pub fn set_ha_routing(ha_num: u8, routing: i32) -> (String, Vec<OscArg>) {
     (ha_routing(ha_num), vec![OscArg::Int(routing)])
 }
 
+/// Creates an OSC message to switch a talkback on or off.
+///
+/// # Arguments
+///
+/// * `bank` - The talkback switch, A or B.
+/// * `on` - Whether talkback should be on.
+///
+/// ```
+/// use x32_lib::command::misc::{self, Talkback};
+///
+/// let (address, args) = misc::set_talkback(Talkback::A, true);
+/// assert_eq!(address, "/-stat/talk/A");
+/// assert_eq!(args, vec![osc_lib::OscArg::Int(1)]);
+/// ```
+pub fn set_talkback(bank: Talkback, on: bool) -> (String, Vec<OscArg>) {
+    (talkback(bank), vec![OscArg::Int(i32::from(on))])
+}
+
+/// Creates the OSC messages to set up and switch the oscillator.
+///
+/// The frequency is sent as F1 and F1 is selected, so the messages fully define the tone.
+/// The on/off switch comes last, so the oscillator never starts with stale settings.
+///
+/// # Arguments
+///
+/// * `params` - The oscillator settings.
+///
+/// ```
+/// use x32_lib::command::misc::{self, OscGenParams};
+///
+/// let params = OscGenParams { on: true, ..OscGenParams::default() };
+/// let messages = misc::set_oscillator(params);
+/// assert_eq!(messages[0].0, "/config/osc/level");
+/// assert_eq!(messages.last().unwrap().0, "/-stat/osc/on");
+/// ```
+pub fn set_oscillator(params: OscGenParams) -> Vec<(String, Vec<OscArg>)> {
+    vec![
+        (
+            "/config/osc/level".to_string(),
+            vec![OscArg::Float(quantize_fader(db_to_level(params.level_db)))],
+        ),
+        (
+            "/config/osc/f1".to_string(),
+            vec![OscArg::Float(frequency_to_float(params.frequency_hz))],
+        ),
+        ("/config/osc/fsel".to_string(), vec![OscArg::Int(0)]),
+        (
+            "/config/osc/type".to_string(),
+            vec![OscArg::Int(params.osc_type as i32)],
+        ),
+        (
+            "/config/osc/dest".to_string(),
+            vec![OscArg::Int(params.destination)],
+        ),
+        (oscillator_on(), vec![OscArg::Int(i32::from(params.on))]),
+    ]
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -101,6 +267,52 @@ mod tests {
         assert_eq!(args, vec![OscArg::Int(2)]);
     }
 
+    #[test]
+    fn test_set_talkback() {
+        let (address, args) = set_talkback(Talkback::A, true);
+        assert_eq!(address, "/-stat/talk/A");
+        assert_eq!(args, vec![OscArg::Int(1)]);
+
+        let (address, args) = set_talkback(Talkback::B, false);
+        assert_eq!(address, "/-stat/talk/B");
+        assert_eq!(args, vec![OscArg::Int(0)]);
+    }
+
+    #[test]
+    fn test_oscillator_frequency_encoding() {
+        assert_eq!(frequency_to_float(20.0), 0.0);
+        assert_eq!(frequency_to_float(20000.0), 1.0);
+        assert_eq!(frequency_to_float(5.0), 0.0);
+        // 1 kHz is 0.566 of the way up the log scale, position 68 of 120.
+        assert_eq!(frequency_to_float(1000.0), 68.0 / 120.0);
+        assert!((float_to_frequency(0.5) - 632.46).abs() < 0.01);
+        assert!((float_to_frequency(frequency_to_float(440.0)) - 440.0).abs() < 440.0 * 0.03);
+    }
+
+    #[test]
+    fn test_set_oscillator() {
+        let params = OscGenParams {
+            on: true,
+            level_db: 0.0,
+            frequency_hz: 1000.0,
+            osc_type: OscType::Pink,
+            destination: 18,
+        };
+        let messages = set_oscillator(params);
+        let find = |path: &str| &messages.iter().find(|(a, _)| a == path).unwrap().1;
+
+        assert_eq!(
+            find("/config/osc/level"),
+            &vec![OscArg::Float(quantize_fader(0.75))]
+        );
+        assert_eq!(find("/config/osc/f1"), &vec![OscArg::Float(68.0 / 120.0)]);
+        assert_eq!(find("/config/osc/fsel"), &vec![OscArg::Int(0)]);
+        assert_eq!(find("/config/osc/type"), &vec![OscArg::Int(1)]);
+        assert_eq!(find("/config/osc/dest"), &vec![OscArg::Int(18)]);
+        assert_eq!(messages.last().unwrap().0, "/-stat/osc/on");
+        assert_eq!(messages.last().unwrap().1, vec![OscArg::Int(1)]);
+    }
+
     #[test]
     fn test_usb_path() {
         assert_eq!(usb_path(), "/-usb/path");
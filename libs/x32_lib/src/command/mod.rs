@@ -4,6 +4,10 @@
 //! to a specific section of the X32 console (e.g., `channel`, `bus`, `fx`). The
 //! primary goal is to provide a type-safe and idiomatic Rust interface for generating
 //! OSC messages that can be sent to the mixer.
+//!
+//! The `set_*` builders for channels, buses, DCAs, matrices and effects check the strip
+//! number with the `valid_*` helpers in [`crate::common`] and return an error rather than
+//! an address the console would ignore, such as `/ch/33/mix/fader`.
 
 use bitflags::bitflags;
 
@@ -83,8 +87,8 @@ pub const XISEL: &[&str] = &[
     "OFF", "Ins", "FX1L", "FX1R", "FX2L", "FX2R", "FX3L", "FX3R", "FX4L", "FX4R", "FX5L", "FX5R",
     "FX6L", "FX6R", "FX7L", "FX7R", "FX8L", "FX8R", "Aux1", "Aux2", "Aux3", "Aux4", "Aux5", "Aux6",
 ];
-/// String representations for gate modes.
-pub const XGMODE: &[&str] = &["GATE", "EXP2", "EXP3", "EXP4", "DUCK"];
+/// String representations for gate modes, shared with the emulator.
+pub use x32_core::XGMODE;
 /// String representations for dynamics filter types.
 pub const XDYFTYP: &[&str] = &["LC6", "LC12", "HC6", "HC12", "BP", "NOTCH"];
 /// String representations for dynamics modes (compressor/limiter).
@@ -93,10 +97,8 @@ pub const XDYMODE: &[&str] = &["COMP", "LIMIT"];
 pub const XDYDET: &[&str] = &["PEAK", "RMS"];
 /// String representations for dynamics envelope types.
 pub const XDYENV: &[&str] = &["LIN", "LOG"];
-/// String representations for dynamics ratio settings.
-pub const XDYRAT: &[&str] = &[
-    "1.1", "1.3", "1.5", "2.0", "2.5", "3.0", "4.0", "5.0", "7.0", "10.0", "20.0", "100.0",
-];
+/// String representations for dynamics ratio settings, shared with the emulator.
+pub use x32_core::XDYRAT;
 /// String representations for EQ types.
 pub const XEQTY1: &[&str] = &["LCut", "LShv", "PEQ", "VEQ", "HCut", "HShv"];
 /// String representations for bus mix types.
@@ -2,6 +2,8 @@
 //!
 //! Matrix channels are used for creating custom mixes of mix buses and main outputs, often
 //! for sending to different speaker zones, recording devices, or broadcast feeds.
+use crate::common::valid_mtx;
+use crate::error::Result;
 use osc_lib::OscArg;
 
 // --- Address String Getters ---
@@ -26,6 +28,11 @@ pub fn on(channel_num: u8) -> String {
     format!("/mtx/{:02}/mix/on", channel_num)
 }
 
+/// Returns the OSC address for a matrix channel's solo switch.
+pub fn solo(channel_num: u8) -> String {
+    format!("/-stat/solosw/{:02}", channel_num + 64)
+}
+
 // --- OSC Message Setters ---
 
 /// Creates an OSC message to set the name of a mtx channel.
@@ -38,15 +45,16 @@ pub fn on(channel_num: u8) -> String {
 /// ```
 /// use x32_lib::command::mtx;
 ///
-/// let (address, args) = mtx::set_name(1, "Test");
+/// let (address, args) = mtx::set_name(1, "Test").unwrap();
 /// assert_eq!(address, "/mtx/01/config/name");
 /// assert_eq!(args, vec![osc_lib::OscArg::String("Test".to_string())]);
 /// ```
-pub fn set_name(channel_num: u8, name: &str) -> (String, Vec<OscArg>) {
-    (
+pub fn set_name(channel_num: u8, name: &str) -> Result<(String, Vec<OscArg>)> {
+    valid_mtx(channel_num)?;
+    Ok((
         self::name(channel_num),
         vec![OscArg::String(name.to_string())],
-    )
+    ))
 }
 
 /// Creates an OSC message to set the color of a mtx channel.
@@ -59,12 +67,13 @@ pub fn set_name(channel_num: u8, name: &str) -> (String, Vec<OscArg>) {
 /// ```
 /// use x32_lib::command::mtx;
 ///
-/// let (address, args) = mtx::set_color(1, 2);
+/// let (address, args) = mtx::set_color(1, 2).unwrap();
 /// assert_eq!(address, "/mtx/01/config/color");
 /// assert_eq!(args, vec![osc_lib::OscArg::Int(2)]);
 /// ```
-pub fn set_color(channel_num: u8, color: i32) -> (String, Vec<OscArg>) {
-    (self::color(channel_num), vec![OscArg::Int(color)])
+pub fn set_color(channel_num: u8, color: i32) -> Result<(String, Vec<OscArg>)> {
+    valid_mtx(channel_num)?;
+    Ok((self::color(channel_num), vec![OscArg::Int(color)]))
 }
 
 /// Creates an OSC message to set the fader level of a mtx channel.
@@ -77,12 +86,13 @@ pub fn set_color(channel_num: u8, color: i32) -> (String, Vec<OscArg>) {
 /// ```
 /// use x32_lib::command::mtx;
 ///
-/// let (address, args) = mtx::set_fader(1, 0.75);
+/// let (address, args) = mtx::set_fader(1, 0.75).unwrap();
 /// assert_eq!(address, "/mtx/01/mix/fader");
 /// assert_eq!(args, vec![osc_lib::OscArg::Float(0.75)]);
 /// ```
-pub fn set_fader(channel_num: u8, level: f32) -> (String, Vec<OscArg>) {
-    (fader_level(channel_num), vec![OscArg::Float(level)])
+pub fn set_fader(channel_num: u8, level: f32) -> Result<(String, Vec<OscArg>)> {
+    valid_mtx(channel_num)?;
+    Ok((fader_level(channel_num), vec![OscArg::Float(level)]))
 }
 
 /// Creates an OSC message to set the on state of a mtx channel.
@@ -95,12 +105,51 @@ pub fn set_fader(channel_num: u8, level: f32) -> (String, Vec<OscArg>) {
 /// ```
 /// use x32_lib::command::mtx;
 ///
-/// let (address, args) = mtx::set_on(1, 1);
+/// let (address, args) = mtx::set_on(1, 1).unwrap();
+/// assert_eq!(address, "/mtx/01/mix/on");
+/// assert_eq!(args, vec![osc_lib::OscArg::Int(1)]);
+/// ```
+pub fn set_on(channel_num: u8, on: i32) -> Result<(String, Vec<OscArg>)> {
+    valid_mtx(channel_num)?;
+    Ok((self::on(channel_num), vec![OscArg::Int(on)]))
+}
+
+/// Creates an OSC message to mute or unmute a matrix channel.
+///
+/// # Arguments
+///
+/// * `channel_num` - The channel number (1-6).
+/// * `muted` - `true` to mute the channel, `false` to unmute it.
+///
+/// ```
+/// use x32_lib::command::mtx;
+///
+/// let (address, args) = mtx::set_mute(1, true).unwrap();
 /// assert_eq!(address, "/mtx/01/mix/on");
+/// assert_eq!(args, vec![osc_lib::OscArg::Int(0)]);
+/// ```
+pub fn set_mute(channel_num: u8, muted: bool) -> Result<(String, Vec<OscArg>)> {
+    valid_mtx(channel_num)?;
+    Ok((self::on(channel_num), vec![OscArg::Int(i32::from(!muted))]))
+}
+
+/// Creates an OSC message to solo or unsolo a matrix channel.
+///
+/// # Arguments
+///
+/// * `channel_num` - The channel number (1-6).
+/// * `on` - `true` to solo the channel, `false` to clear its solo.
+///
+/// ```
+/// use x32_lib::command::mtx;
+///
+/// let (address, args) = mtx::set_solo(1, true).unwrap();
+/// assert_eq!(address, "/-stat/solosw/65");
 /// assert_eq!(args, vec![osc_lib::OscArg::Int(1)]);
 /// ```
-pub fn set_on(channel_num: u8, on: i32) -> (String, Vec<OscArg>) {
-    (self::on(channel_num), vec![OscArg::Int(on)])
+pub fn set_solo(channel_num: u8, on: bool) -> Result<(String, Vec<OscArg>)> {
+    valid_mtx(channel_num)?;
+    Ok((self::solo(channel_num), vec![OscArg::Int(i32::from(on))]))
 }
 
 #[cfg(test)]
@@ -133,29 +182,47 @@ mod tests {
 
     #[test]
     fn test_set_name() {
-        let (address, args) = set_name(1, "Test");
+        let (address, args) = set_name(1, "Test").unwrap();
         assert_eq!(address, "/mtx/01/config/name");
         assert_eq!(args, vec![OscArg::String("Test".to_string())]);
     }
 
     #[test]
     fn test_set_color() {
-        let (address, args) = set_color(1, 2);
+        let (address, args) = set_color(1, 2).unwrap();
         assert_eq!(address, "/mtx/01/config/color");
         assert_eq!(args, vec![OscArg::Int(2)]);
     }
 
     #[test]
     fn test_set_fader() {
-        let (address, args) = set_fader(1, 0.5);
+        let (address, args) = set_fader(1, 0.5).unwrap();
         assert_eq!(address, "/mtx/01/mix/fader");
         assert_eq!(args, vec![OscArg::Float(0.5)]);
     }
 
     #[test]
     fn test_set_on() {
-        let (address, args) = set_on(2, 1);
+        let (address, args) = set_on(2, 1).unwrap();
         assert_eq!(address, "/mtx/02/mix/on");
         assert_eq!(args, vec![OscArg::Int(1)]);
     }
+
+    #[test]
+    fn test_set_mute() {
+        let (address, args) = set_mute(6, true).unwrap();
+        assert_eq!(address, "/mtx/06/mix/on");
+        assert_eq!(args, vec![OscArg::Int(0)]);
+        let (_, args) = set_mute(6, false).unwrap();
+        assert_eq!(args, vec![OscArg::Int(1)]);
+    }
+
+    #[test]
+    fn test_set_solo() {
+        let (address, args) = set_solo(6, true).unwrap();
+        assert_eq!(address, "/-stat/solosw/70");
+        assert_eq!(args, vec![OscArg::Int(1)]);
+        let (_, args) = set_solo(6, false).unwrap();
+        assert_eq!(args, vec![OscArg::Int(0)]);
+    }
 }
@@ -2,8 +2,78 @@
 //!
 //! This module is responsible for routing signals to the physical output connectors on the
 //! back of the console, such as the main XLR outputs, auxiliary outputs, and AES/EBU outputs.
+//!
+//! Each of the 16 main outputs has a source, a tap point that selects where in the source's
+//! signal path the output is fed from, a phase invert switch and a delay.
 use osc_lib::OscArg;
 
+/// The tap point names, as the console prints them, in the order of `/outputs/main/NN/pos`.
+pub static XOUTPOS: [&str; 9] = [
+    "IN/LC", "IN/LC+M", "<EQ", "<EQ+M", "EQ>", "EQ>+M", "PRE", "PRE+M", "POST",
+];
+
+/// The shortest output delay, in milliseconds.
+pub const DELAY_MIN_MS: f32 = 0.3;
+/// The longest output delay, in milliseconds.
+pub const DELAY_MAX_MS: f32 = 500.0;
+
+/// The point in a source's signal path that feeds an output.
+///
+/// The `M` variants follow the source's mute.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TapPoint {
+    /// After the input and low cut.
+    InLc = 0,
+    /// After the input and low cut, muted with the source.
+    InLcM = 1,
+    /// Before the EQ.
+    PreEq = 2,
+    /// Before the EQ, muted with the source.
+    PreEqM = 3,
+    /// After the EQ.
+    PostEq = 4,
+    /// After the EQ, muted with the source.
+    PostEqM = 5,
+    /// Before the fader.
+    Pre = 6,
+    /// Before the fader, muted with the source.
+    PreM = 7,
+    /// After the fader.
+    Post = 8,
+}
+
+impl TapPoint {
+    /// Every tap point, in the order of [`XOUTPOS`].
+    pub const ALL: [TapPoint; 9] = [
+        TapPoint::InLc,
+        TapPoint::InLcM,
+        TapPoint::PreEq,
+        TapPoint::PreEqM,
+        TapPoint::PostEq,
+        TapPoint::PostEqM,
+        TapPoint::Pre,
+        TapPoint::PreM,
+        TapPoint::Post,
+    ];
+
+    /// Returns the tap point with the given `/outputs/main/NN/pos` value.
+    pub fn from_id(id: u8) -> Option<Self> {
+        Self::ALL.get(usize::from(id)).copied()
+    }
+
+    /// Returns the tap point with the given name, such as `"PRE+M"`, ignoring case.
+    pub fn from_name(name: &str) -> Option<Self> {
+        Self::ALL
+            .into_iter()
+            .find(|tap| tap.name().eq_ignore_ascii_case(name.trim()))
+    }
+
+    /// Returns the name the console prints for this tap point.
+    pub fn name(&self) -> &'static str {
+        XOUTPOS[*self as usize]
+    }
+}
+
 // --- Address String Getters ---
 
 /// Returns the OSC address for a main output's source.
@@ -11,6 +81,26 @@ pub fn main_output_source(output_num: u8) -> String {
     format!("/outputs/main/{:02}/src", output_num)
 }
 
+/// Returns the OSC address for a main output's tap point.
+pub fn tap(output_num: u8) -> String {
+    format!("/outputs/main/{:02}/pos", output_num)
+}
+
+/// Returns the OSC address for a main output's phase invert switch.
+pub fn phase(output_num: u8) -> String {
+    format!("/outputs/main/{:02}/invert", output_num)
+}
+
+/// Returns the OSC address for a main output's delay time.
+pub fn delay_time(output_num: u8) -> String {
+    format!("/outputs/main/{:02}/delay/time", output_num)
+}
+
+/// Returns the OSC address for a main output's delay on/off state.
+pub fn delay_on(output_num: u8) -> String {
+    format!("/outputs/main/{:02}/delay/on", output_num)
+}
+
 // --- OSC Message Setters ---
 
 /// Creates an OSC message to set the main output source.
@@ -30,3 +120,63 @@ pub fn main_output_source(output_num: u8) -> String {
 pub fn set_main_output_source(output_num: u8, source: i32) -> (String, Vec<OscArg>) {
     (main_output_source(output_num), vec![OscArg::Int(source)])
 }
+
+/// Creates an OSC message to set the source of a main output.
+///
+/// # Arguments
+///
+/// * `output_num` - The output number (1-16).
+/// * `source_id` - The source, as the index of the console's output source list.
+///
+/// ```
+/// use x32_lib::command::output;
+///
+/// let (address, args) = output::set_source(3, 4);
+/// assert_eq!(address, "/outputs/main/03/src");
+/// assert_eq!(args, vec![osc_lib::OscArg::Int(4)]);
+/// ```
+pub fn set_source(output_num: u8, source_id: i32) -> (String, Vec<OscArg>) {
+    set_main_output_source(output_num, source_id)
+}
+
+/// Creates an OSC message to set the tap point of a main output.
+///
+/// # Arguments
+///
+/// * `output_num` - The output number (1-16).
+/// * `tap_point` - Where in the source's signal path the output is fed from.
+///
+/// ```
+/// use x32_lib::command::output::{self, TapPoint};
+///
+/// let (address, args) = output::set_tap(1, TapPoint::PreM);
+/// assert_eq!(address, "/outputs/main/01/pos");
+/// assert_eq!(args, vec![osc_lib::OscArg::Int(7)]);
+/// ```
+pub fn set_tap(output_num: u8, tap_point: TapPoint) -> (String, Vec<OscArg>) {
+    (tap(output_num), vec![OscArg::Int(tap_point as i32)])
+}
+
+/// Creates an OSC message to set the phase invert switch of a main output.
+///
+/// # Arguments
+///
+/// * `output_num` - The output number (1-16).
+/// * `inv` - Whether the output's polarity is inverted.
+pub fn set_phase(output_num: u8, inv: bool) -> (String, Vec<OscArg>) {
+    (phase(output_num), vec![OscArg::Int(i32::from(inv))])
+}
+
+/// Creates an OSC message to set the delay time of a main output.
+///
+/// The delay is only applied while `/outputs/main/NN/delay/on` is switched on.
+///
+/// # Arguments
+///
+/// * `output_num` - The output number (1-16).
+/// * `ms` - The delay in milliseconds (0.3 to 500). Out-of-range values are clamped.
+pub fn set_delay(output_num: u8, ms: f32) -> (String, Vec<OscArg>) {
+    let value =
+        (ms.clamp(DELAY_MIN_MS, DELAY_MAX_MS) - DELAY_MIN_MS) / (DELAY_MAX_MS - DELAY_MIN_MS);
+    (delay_time(output_num), vec![OscArg::Float(value)])
+}
@@ -4,6 +4,7 @@
 //! parameters, ensuring that only valid values are used for settings like
 //! channel color, EQ type, and insert positions.
 
+use crate::error::{Result, X32Error};
 use bitflags::bitflags;
 
 #[derive(Debug, Clone)]
@@ -143,6 +144,129 @@ impl EqType {
     }
 }
 
+/// Represents the mode of a channel gate.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[repr(u8)]
+pub enum GateMode {
+    /// Expander, 1:2
+    Exp2 = 0,
+    /// Expander, 1:3
+    Exp3,
+    /// Expander, 1:4
+    Exp4,
+    /// Gate
+    Gate,
+    /// Ducker
+    Duck,
+}
+
+impl GateMode {
+    pub fn from_id(id: u8) -> Option<Self> {
+        match id {
+            0 => Some(GateMode::Exp2),
+            1 => Some(GateMode::Exp3),
+            2 => Some(GateMode::Exp4),
+            3 => Some(GateMode::Gate),
+            4 => Some(GateMode::Duck),
+            _ => None,
+        }
+    }
+}
+
+/// Represents the mode of a dynamics processor.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[repr(u8)]
+pub enum DynMode {
+    /// Compressor
+    Comp = 0,
+    /// Expander
+    Exp,
+}
+
+impl DynMode {
+    pub fn from_id(id: u8) -> Option<Self> {
+        match id {
+            0 => Some(DynMode::Comp),
+            1 => Some(DynMode::Exp),
+            _ => None,
+        }
+    }
+}
+
+/// Represents the level detector of a dynamics processor.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[repr(u8)]
+pub enum DynDetector {
+    Peak = 0,
+    Rms,
+}
+
+impl DynDetector {
+    pub fn from_id(id: u8) -> Option<Self> {
+        match id {
+            0 => Some(DynDetector::Peak),
+            1 => Some(DynDetector::Rms),
+            _ => None,
+        }
+    }
+}
+
+/// Represents the envelope of a dynamics processor.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[repr(u8)]
+pub enum DynEnvelope {
+    Lin = 0,
+    Log,
+}
+
+impl DynEnvelope {
+    pub fn from_id(id: u8) -> Option<Self> {
+        match id {
+            0 => Some(DynEnvelope::Lin),
+            1 => Some(DynEnvelope::Log),
+            _ => None,
+        }
+    }
+}
+
+/// Represents the ratio of a dynamics processor, from 1.1:1 to 100:1.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[repr(u8)]
+pub enum DynRatio {
+    R1_1 = 0,
+    R1_3,
+    R1_5,
+    R2_0,
+    R2_5,
+    R3_0,
+    R4_0,
+    R5_0,
+    R7_0,
+    R10,
+    R20,
+    R100,
+}
+
+impl DynRatio {
+    pub fn from_id(id: u8) -> Option<Self> {
+        match id {
+            0 => Some(DynRatio::R1_1),
+            1 => Some(DynRatio::R1_3),
+            2 => Some(DynRatio::R1_5),
+            3 => Some(DynRatio::R2_0),
+            4 => Some(DynRatio::R2_5),
+            5 => Some(DynRatio::R3_0),
+            6 => Some(DynRatio::R4_0),
+            7 => Some(DynRatio::R5_0),
+            8 => Some(DynRatio::R7_0),
+            9 => Some(DynRatio::R10),
+            10 => Some(DynRatio::R20),
+            11 => Some(DynRatio::R100),
+            _ => None,
+        }
+    }
+}
+
 /// Represents the position of an insert (`Pre` or `Post` fader).
 #[derive(Debug, Clone, Copy, PartialEq)]
 #[repr(u8)]
@@ -161,6 +285,39 @@ impl InsertPosition {
     }
 }
 
+/// Represents where a bus send is tapped from the channel (`IN/LC` to `GRP`).
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[repr(u8)]
+pub enum SendTap {
+    /// Input, after the low cut
+    InLc = 0,
+    /// Before the EQ
+    PreEq,
+    /// After the EQ
+    PostEq,
+    /// Before the fader
+    Pre,
+    /// After the fader
+    Post,
+    /// Follows the bus's subgroup setting
+    Group,
+}
+
+impl SendTap {
+    /// Returns the tap point with the given `/ch/NN/mix/NN/type` value, if valid.
+    pub fn from_id(id: u8) -> Option<Self> {
+        match id {
+            0 => Some(SendTap::InLc),
+            1 => Some(SendTap::PreEq),
+            2 => Some(SendTap::PostEq),
+            3 => Some(SendTap::Pre),
+            4 => Some(SendTap::Post),
+            5 => Some(SendTap::Group),
+            _ => None,
+        }
+    }
+}
+
 /// Represents the selection for an insert.
 #[derive(Debug, Clone, Copy, PartialEq)]
 #[repr(u8)]
@@ -257,6 +414,145 @@ impl FxSource {
     }
 }
 
+/// The settings of a channel gate, as set with `channel::set_gate`.
+///
+/// The default is the console's factory setting for an input channel.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct GateParams {
+    /// Whether the gate is switched in.
+    pub on: bool,
+    /// The gate mode.
+    pub mode: GateMode,
+    /// The threshold in dB (-80 to 0).
+    pub threshold_db: f32,
+    /// The range in dB (3 to 60).
+    pub range_db: f32,
+    /// The attack time in ms (0 to 120).
+    pub attack_ms: f32,
+    /// The hold time in ms (0.02 to 2000).
+    pub hold_ms: f32,
+    /// The release time in ms (5 to 4000).
+    pub release_ms: f32,
+    /// The key source (0 for the channel itself).
+    pub key_source: i32,
+}
+
+impl Default for GateParams {
+    fn default() -> Self {
+        Self {
+            on: false,
+            mode: GateMode::Gate,
+            threshold_db: -80.0,
+            range_db: 60.0,
+            attack_ms: 1.0,
+            hold_ms: 502.0,
+            release_ms: 983.0,
+            key_source: 0,
+        }
+    }
+}
+
+/// The settings of a channel compressor/expander, as set with `channel::set_dyn`.
+///
+/// The default is the console's factory setting for an input channel.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct DynParams {
+    /// Whether the processor is switched in.
+    pub on: bool,
+    /// Compressor or expander.
+    pub mode: DynMode,
+    /// The level detector.
+    pub detector: DynDetector,
+    /// The envelope.
+    pub envelope: DynEnvelope,
+    /// The threshold in dB (-60 to 0).
+    pub threshold_db: f32,
+    /// The ratio.
+    pub ratio: DynRatio,
+    /// The knee (0 to 5).
+    pub knee: f32,
+    /// The make-up gain in dB (0 to 24).
+    pub makeup_gain_db: f32,
+    /// The attack time in ms (0 to 120).
+    pub attack_ms: f32,
+    /// The hold time in ms (0.02 to 2000).
+    pub hold_ms: f32,
+    /// The release time in ms (5 to 4000).
+    pub release_ms: f32,
+    /// Whether the processor comes before or after the EQ.
+    pub position: InsertPosition,
+    /// The key source (0 for the channel itself).
+    pub key_source: i32,
+    /// The wet/dry mix in percent (0 to 100).
+    pub mix_percent: f32,
+    /// Whether automatic make-up gain is enabled.
+    pub auto_gain: bool,
+}
+
+impl Default for DynParams {
+    fn default() -> Self {
+        Self {
+            on: false,
+            mode: DynMode::Comp,
+            detector: DynDetector::Peak,
+            envelope: DynEnvelope::Log,
+            threshold_db: 0.0,
+            ratio: DynRatio::R3_0,
+            knee: 1.0,
+            makeup_gain_db: 0.0,
+            attack_ms: 10.0,
+            hold_ms: 10.0,
+            release_ms: 151.0,
+            position: InsertPosition::Post,
+            key_source: 0,
+            mix_percent: 100.0,
+            auto_gain: false,
+        }
+    }
+}
+
+/// Formats a time the way the console prints it in node strings: whole
+/// milliseconds from 100 ms, otherwise one decimal from 10 ms, otherwise two.
+///
+/// # Arguments
+///
+/// * `ms` - The time in milliseconds.
+///
+/// # Returns
+///
+/// The time in the console's text notation, e.g. `983`, `10.0` or `0.02`.
+pub fn format_time(ms: f32) -> String {
+    if ms >= 99.95 {
+        format!("{:.0}", ms)
+    } else if ms >= 9.995 {
+        format!("{:.1}", ms)
+    } else {
+        format!("{:.2}", ms)
+    }
+}
+
+/// Formats a frequency the way the console prints it in node strings.
+///
+/// Frequencies below 1 kHz are printed with one decimal (`124.7`). From 1 kHz up,
+/// the value is printed in kHz with two decimals and `k` in place of the decimal
+/// point (`1k97`, `10k02`).
+///
+/// # Arguments
+///
+/// * `freq_hz` - The frequency in Hz.
+///
+/// # Returns
+///
+/// The frequency in the console's text notation.
+pub fn format_frequency(freq_hz: f32) -> String {
+    // Compare after rounding so 999.96 Hz becomes 1k00 rather than 1000.0.
+    if (freq_hz * 10.0).round() < 10000.0 {
+        format!("{:.1}", freq_hz)
+    } else {
+        format!("{:.2}", freq_hz / 1000.0).replace('.', "k")
+    }
+}
+
 /// A list of scribble strip color names.
 pub static XCOLORS: [&str; 16] = [
     "OFF", "RD", "GN", "YE", "BL", "MG", "CY", "WH", "OFFi", "RDi", "GNi", "YEi", "BLi", "MGi",
@@ -284,3 +580,215 @@ pub static XISEL: [&str; 23] = [
 ];
 /// A list of EQ types.
 pub static XEQTY1: [&str; 6] = ["LCut", "LShv", "PEQ", "VEQ", "HShv", "HCut"];
+
+/// The number of positions of a fader parameter such as `/ch/01/mix/fader`.
+pub const FADER_STEPS: u32 = 1024;
+/// The number of positions of the console's coarse 161-step level law.
+pub const LEVEL_STEPS: u32 = 161;
+
+/// Converts a linear fader level (0.0 to 1.0) to decibels.
+///
+/// The console's fader law is made of four linear segments, meeting at -10 dB
+/// (level 0.5), -30 dB (level 0.25) and -60 dB (level 0.0625). Level 0.0 is -90 dB,
+/// which the console displays as -oo.
+///
+/// # Arguments
+///
+/// * `level` - The linear fader level.
+///
+/// # Returns
+///
+/// The level in dB, from -90.0 to +10.0.
+pub fn level_to_db(level: f32) -> f32 {
+    if level >= 0.5 {
+        40.0 * level - 30.0
+    } else if level >= 0.25 {
+        80.0 * level - 50.0
+    } else if level >= 0.0625 {
+        160.0 * level - 70.0
+    } else {
+        480.0 * level - 90.0
+    }
+}
+
+/// Converts a decibel value to a linear fader level (0.0 to 1.0).
+///
+/// This is the inverse of [`level_to_db`]. Values outside -90 dB to +10 dB are clamped.
+///
+/// # Arguments
+///
+/// * `db` - The level in dB.
+///
+/// # Returns
+///
+/// The linear fader level.
+pub fn db_to_level(db: f32) -> f32 {
+    let level = if db > -10.0 {
+        (db + 30.0) / 40.0
+    } else if db > -30.0 {
+        (db + 50.0) / 80.0
+    } else if db > -60.0 {
+        (db + 70.0) / 160.0
+    } else {
+        (db + 90.0) / 480.0
+    };
+    level.clamp(0.0, 1.0)
+}
+
+/// Converts a linear level to the nearest of `steps` detent positions.
+///
+/// # Arguments
+///
+/// * `level` - The linear level (0.0 to 1.0). Out-of-range values are clamped.
+/// * `steps` - The number of positions, e.g. [`FADER_STEPS`] or [`LEVEL_STEPS`].
+///
+/// # Returns
+///
+/// The position, from 0 to `steps - 1`.
+pub fn level_to_step(level: f32, steps: u32) -> u32 {
+    let max = steps.saturating_sub(1);
+    (level.clamp(0.0, 1.0) * max as f32).round() as u32
+}
+
+/// Converts a detent position back to the linear level the console sends for it.
+///
+/// # Arguments
+///
+/// * `step` - The position, from 0 to `steps - 1`. Larger values are clamped.
+/// * `steps` - The number of positions, e.g. [`FADER_STEPS`] or [`LEVEL_STEPS`].
+///
+/// # Returns
+///
+/// The linear level (0.0 to 1.0).
+pub fn step_to_level(step: u32, steps: u32) -> f32 {
+    let max = steps.saturating_sub(1);
+    if max == 0 {
+        return 0.0;
+    }
+    step.min(max) as f32 / max as f32
+}
+
+/// Converts a fader level to the nearest of the console's [`FADER_STEPS`] fader positions.
+///
+/// # Arguments
+///
+/// * `level` - The linear fader level (0.0 to 1.0). Out-of-range values are clamped.
+///
+/// # Returns
+///
+/// The fader position, from 0 to 1023.
+pub fn fader_float_to_step(level: f32) -> u16 {
+    level_to_step(level, FADER_STEPS) as u16
+}
+
+/// Converts a fader position back to the level the console reports for it.
+///
+/// # Arguments
+///
+/// * `step` - The fader position, from 0 to 1023. Larger values are clamped.
+///
+/// # Returns
+///
+/// The linear fader level (0.0 to 1.0).
+pub fn fader_step_to_float(step: u16) -> f32 {
+    step_to_level(u32::from(step), FADER_STEPS)
+}
+
+/// Snaps a fader level to the value the console will store and echo back for it.
+///
+/// Sending the snapped value means a level that comes back from the console compares
+/// equal to the one that was sent, so a two-way sync does not drift or loop.
+///
+/// # Arguments
+///
+/// * `level` - The linear fader level (0.0 to 1.0).
+///
+/// # Returns
+///
+/// The level of the nearest fader position.
+pub fn quantize_fader(level: f32) -> f32 {
+    fader_step_to_float(fader_float_to_step(level))
+}
+
+/// Converts a decibel value to the nearest fader position.
+///
+/// # Arguments
+///
+/// * `db` - The level in dB. Values outside -90 dB to +10 dB are clamped.
+///
+/// # Returns
+///
+/// The fader position, from 0 to 1023.
+pub fn db_to_fader_step(db: f32) -> u16 {
+    fader_float_to_step(db_to_level(db))
+}
+
+/// Converts a fader position to the level in dB the console displays for it.
+///
+/// # Arguments
+///
+/// * `step` - The fader position, from 0 to 1023.
+///
+/// # Returns
+///
+/// The level in dB, from -90.0 (-oo) to +10.0.
+pub fn fader_step_to_db(step: u16) -> f32 {
+    level_to_db(fader_step_to_float(step))
+}
+
+/// Checks that `num` lies in `1..=max`, naming the strip kind in the error.
+fn valid_number(kind: &str, num: u8, max: u8) -> Result<u8> {
+    if (1..=max).contains(&num) {
+        Ok(num)
+    } else {
+        Err(X32Error::Custom(format!(
+            "{} {} out of range (1-{})",
+            kind, num, max
+        )))
+    }
+}
+
+/// Checks that `ch` is an input channel number (1-32).
+///
+/// # Returns
+///
+/// A `Result` containing `ch`, or an `X32Error` if it is out of range.
+pub fn valid_channel(ch: u8) -> Result<u8> {
+    valid_number("Channel", ch, 32)
+}
+
+/// Checks that `bus` is a mix bus number (1-16).
+///
+/// # Returns
+///
+/// A `Result` containing `bus`, or an `X32Error` if it is out of range.
+pub fn valid_bus(bus: u8) -> Result<u8> {
+    valid_number("Bus", bus, 16)
+}
+
+/// Checks that `dca` is a DCA number (1-8).
+///
+/// # Returns
+///
+/// A `Result` containing `dca`, or an `X32Error` if it is out of range.
+pub fn valid_dca(dca: u8) -> Result<u8> {
+    valid_number("DCA", dca, 8)
+}
+
+/// Checks that `mtx` is a matrix number (1-6).
+///
+/// # Returns
+///
+/// A `Result` containing `mtx`, or an `X32Error` if it is out of range.
+pub fn valid_mtx(mtx: u8) -> Result<u8> {
+    valid_number("Matrix", mtx, 6)
+}
+
+/// Checks that `fx` is an effects slot number (1-8).
+///
+/// # Returns
+///
+/// A `Result` containing `fx`, or an `X32Error` if it is out of range.
+pub fn valid_fx(fx: u8) -> Result<u8> {
+    valid_number("FX slot", fx, 8)
+}
@@ -0,0 +1,350 @@
+//! A blocking connection to an X32/M32 console.
+//!
+//! Command-line tools that only need to send a few messages and read the replies
+//! can use [`Console`] instead of the asynchronous [`MixerClient`](crate::MixerClient).
+//! It owns the UDP socket and handles retries, timeouts and unrelated
+//! packets arriving between a request and its reply.
+//!
+//! With the `async` feature, [`AsyncConsole`] offers the same calls on a
+//! `tokio` socket.
+//!
+//! # Example
+//!
+//! ```no_run
+//! use x32_lib::Console;
+//! use osc_lib::OscArg;
+//!
+//! fn main() -> x32_lib::Result<()> {
+//!     let console = Console::connect("192.168.1.64")?;
+//!     console.set("/ch/01/mix/fader", vec![OscArg::Float(0.75)])?;
+//!     let reply = console.query("/ch/01/mix/fader", vec![])?;
+//!     println!("Channel 1 fader: {:?}", reply.arg_float(0));
+//!     Ok(())
+//! }
+//! ```
+
+use crate::error::{Result, X32Error};
+use osc_lib::{OscArg, OscMessage};
+use std::net::UdpSocket;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+/// How long to wait for a reply before resending a query.
+pub const DEFAULT_TIMEOUT: Duration = Duration::from_millis(500);
+
+/// How many times a query is resent after the first attempt times out.
+pub const DEFAULT_RETRIES: u32 = 2;
+
+/// The size of a receive buffer, enough for the largest `/node` reply.
+pub const RECV_BUF_SIZE: usize = 8192;
+
+/// A blocking connection to an X32/M32 console.
+#[derive(Debug)]
+pub struct Console {
+    socket: UdpSocket,
+    timeout: Duration,
+    retries: u32,
+    /// Reused by every query, so polling loops do not allocate per reply.
+    buf: Mutex<Vec<u8>>,
+}
+
+impl Console {
+    /// Connects to a console.
+    ///
+    /// # Arguments
+    ///
+    /// * `ip` - The IP address of the console, with an optional port (default 10023).
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing the `Console` or an `X32Error` if the socket could not be set up.
+    pub fn connect(ip: &str) -> Result<Self> {
+        Self::connect_from(ip, 0)
+    }
+
+    /// Connects to a console from a fixed local port, e.g. to get through a firewall
+    /// that only allows known ports.
+    ///
+    /// # Arguments
+    ///
+    /// * `ip` - The IP address of the console, with an optional port (default 10023).
+    /// * `local_port` - The local UDP port to send from, or 0 for a dynamic port.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing the `Console` or an `X32Error` if the socket could not be set up.
+    pub fn connect_from(ip: &str, local_port: u16) -> Result<Self> {
+        Ok(Self::from_socket(crate::connect_socket(ip, local_port)?))
+    }
+
+    /// Wraps a UDP socket that is already connected to a console.
+    ///
+    /// # Arguments
+    ///
+    /// * `socket` - The connected socket. Its read timeout is managed by the `Console`.
+    pub fn from_socket(socket: UdpSocket) -> Self {
+        Self {
+            socket,
+            timeout: DEFAULT_TIMEOUT,
+            retries: DEFAULT_RETRIES,
+            buf: Mutex::new(vec![0; RECV_BUF_SIZE]),
+        }
+    }
+
+    /// Sets how long each attempt of a query waits for its reply.
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = timeout;
+        self
+    }
+
+    /// Sets how many times a query is resent after the first attempt times out.
+    pub fn with_retries(mut self, retries: u32) -> Self {
+        self.retries = retries;
+        self
+    }
+
+    /// Returns the underlying socket.
+    pub fn socket(&self) -> &UdpSocket {
+        &self.socket
+    }
+
+    /// Returns the address of the receive buffer, to check that it is reused.
+    #[cfg(test)]
+    pub(crate) fn recv_buf_ptr(&self) -> *const u8 {
+        self.buf.lock().unwrap_or_else(|e| e.into_inner()).as_ptr()
+    }
+
+    /// Sends a message without waiting for a reply.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - The OSC address, e.g. `/ch/01/mix/fader`.
+    /// * `args` - The values to set.
+    pub fn set(&self, path: &str, args: Vec<OscArg>) -> Result<()> {
+        let bytes = OscMessage::new(path.to_string(), args).to_bytes()?;
+        self.socket.send(&bytes)?;
+        Ok(())
+    }
+
+    /// Subscribes to parameter updates by sending `/xremote`.
+    ///
+    /// The console drops the subscription after 10 seconds, so long-running tools
+    /// must call this again periodically.
+    pub fn subscribe(&self) -> Result<()> {
+        self.set("/xremote", vec![])
+    }
+
+    /// Sends a message and waits for the reply with the same address.
+    ///
+    /// Packets for other addresses, such as `/xremote` updates, are skipped. If no
+    /// reply arrives within the timeout, the message is resent up to the configured
+    /// number of retries.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - The OSC address to query, e.g. `/ch/01/mix/fader` or `/node`.
+    /// * `args` - The query arguments, usually empty.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing the reply, or an `X32Error` if every attempt timed out.
+    pub fn query(&self, path: &str, args: Vec<OscArg>) -> Result<OscMessage> {
+        let bytes = OscMessage::new(path.to_string(), args).to_bytes()?;
+        // Take the buffer out rather than holding the lock while waiting, so a
+        // concurrent query on another thread is not blocked behind this one.
+        let mut buf = std::mem::take(&mut *self.buf.lock().unwrap_or_else(|e| e.into_inner()));
+        if buf.is_empty() {
+            buf = vec![0; RECV_BUF_SIZE];
+        }
+        let result = self.query_into(path, &bytes, &mut buf);
+        *self.buf.lock().unwrap_or_else(|e| e.into_inner()) = buf;
+        result
+    }
+
+    /// Sends `bytes` and waits for the reply to `path`, receiving into `buf`.
+    fn query_into(&self, path: &str, bytes: &[u8], buf: &mut [u8]) -> Result<OscMessage> {
+        for _ in 0..=self.retries {
+            self.socket.send(bytes)?;
+            let deadline = Instant::now() + self.timeout;
+
+            while let Some(remaining) = deadline
+                .checked_duration_since(Instant::now())
+                .filter(|d| !d.is_zero())
+            {
+                self.socket.set_read_timeout(Some(remaining))?;
+                let len = match self.socket.recv(buf) {
+                    Ok(len) => len,
+                    Err(e)
+                        if e.kind() == std::io::ErrorKind::WouldBlock
+                            || e.kind() == std::io::ErrorKind::TimedOut =>
+                    {
+                        break;
+                    }
+                    Err(e) => return Err(e.into()),
+                };
+                if let Ok(reply) = OscMessage::from_bytes(&buf[..len]) {
+                    if is_reply(&reply, path) {
+                        return Ok(reply);
+                    }
+                }
+            }
+        }
+
+        Err(X32Error::Timeout {
+            path: path.to_string(),
+        })
+    }
+}
+
+/// Sends a message on `socket` and parses the next packet that arrives.
+///
+/// Unlike [`Console::query`], this makes a single attempt and uses the socket's own read
+/// timeout. The socket-level helpers in the crate root are built on it.
+///
+/// # Arguments
+///
+/// * `socket` - A socket connected to the console.
+/// * `msg` - The message to send.
+/// * `buf` - The buffer to receive into, reused across calls by the caller.
+///
+/// # Returns
+///
+/// A `Result` containing the first message received, `X32Error::Timeout` if none arrived,
+/// or `X32Error::PathMismatch` if it answers another address.
+pub(crate) fn exchange(socket: &UdpSocket, msg: &OscMessage, buf: &mut [u8]) -> Result<OscMessage> {
+    socket.send(&msg.to_bytes()?)?;
+    let len = match socket.recv(buf) {
+        Ok(len) => len,
+        Err(e)
+            if e.kind() == std::io::ErrorKind::WouldBlock
+                || e.kind() == std::io::ErrorKind::TimedOut =>
+        {
+            return Err(X32Error::Timeout {
+                path: msg.path.clone(),
+            });
+        }
+        Err(e) => return Err(e.into()),
+    };
+    let reply = OscMessage::from_bytes(&buf[..len])?;
+    if !is_reply(&reply, &msg.path) {
+        return Err(X32Error::PathMismatch {
+            expected: msg.path.clone(),
+            got: reply.path,
+        });
+    }
+    Ok(reply)
+}
+
+/// A connection to an X32/M32 console for use from `tokio` tasks.
+///
+/// This mirrors [`Console`], with `async` methods.
+#[cfg(feature = "async")]
+#[derive(Debug)]
+pub struct AsyncConsole {
+    socket: tokio::net::UdpSocket,
+    timeout: Duration,
+    retries: u32,
+}
+
+#[cfg(feature = "async")]
+impl AsyncConsole {
+    /// Connects to a console.
+    ///
+    /// # Arguments
+    ///
+    /// * `ip` - The IP address of the console, with an optional port (default 10023).
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing the `AsyncConsole` or an `X32Error` if the socket could not be set up.
+    pub async fn connect(ip: &str) -> Result<Self> {
+        Ok(Self::from_socket(crate::create_socket_async(ip).await?))
+    }
+
+    /// Wraps a `tokio` UDP socket that is already connected to a console.
+    pub fn from_socket(socket: tokio::net::UdpSocket) -> Self {
+        Self {
+            socket,
+            timeout: DEFAULT_TIMEOUT,
+            retries: DEFAULT_RETRIES,
+        }
+    }
+
+    /// Sets how long each attempt of a query waits for its reply.
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = timeout;
+        self
+    }
+
+    /// Sets how many times a query is resent after the first attempt times out.
+    pub fn with_retries(mut self, retries: u32) -> Self {
+        self.retries = retries;
+        self
+    }
+
+    /// Returns the underlying socket.
+    pub fn socket(&self) -> &tokio::net::UdpSocket {
+        &self.socket
+    }
+
+    /// Sends a message without waiting for a reply.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - The OSC address, e.g. `/ch/01/mix/fader`.
+    /// * `args` - The values to set.
+    pub async fn set(&self, path: &str, args: Vec<OscArg>) -> Result<()> {
+        let bytes = OscMessage::new(path.to_string(), args).to_bytes()?;
+        self.socket.send(&bytes).await?;
+        Ok(())
+    }
+
+    /// Subscribes to parameter updates by sending `/xremote`.
+    pub async fn subscribe(&self) -> Result<()> {
+        self.set("/xremote", vec![]).await
+    }
+
+    /// Sends a message and waits for the reply with the same address.
+    ///
+    /// See [`Console::query`] for how unrelated packets and retries are handled.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - The OSC address to query, e.g. `/ch/01/mix/fader` or `/node`.
+    /// * `args` - The query arguments, usually empty.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing the reply, or an `X32Error` if every attempt timed out.
+    pub async fn query(&self, path: &str, args: Vec<OscArg>) -> Result<OscMessage> {
+        let bytes = OscMessage::new(path.to_string(), args).to_bytes()?;
+        let mut buf = vec![0; RECV_BUF_SIZE];
+
+        for _ in 0..=self.retries {
+            self.socket.send(&bytes).await?;
+            let deadline = tokio::time::Instant::now() + self.timeout;
+
+            while let Ok(received) =
+                tokio::time::timeout_at(deadline, self.socket.recv(&mut buf)).await
+            {
+                let len = received?;
+                if let Ok(reply) = OscMessage::from_bytes(&buf[..len]) {
+                    if is_reply(&reply, path) {
+                        return Ok(reply);
+                    }
+                }
+            }
+        }
+
+        Err(X32Error::Timeout {
+            path: path.to_string(),
+        })
+    }
+}
+
+/// Returns `true` if `reply` answers a query sent to `path`.
+///
+/// The console answers `/node` on the address `node`, without the slash.
+fn is_reply(reply: &OscMessage, path: &str) -> bool {
+    reply.path.trim_start_matches('/') == path.trim_start_matches('/')
+}
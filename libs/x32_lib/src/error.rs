@@ -1,6 +1,6 @@
 //! `x32_lib`'s custom `Error` and `Result` types.
 
-use osc_lib::OscError;
+use osc_lib::{OscArg, OscError};
 use std::fmt;
 use std::io;
 use std::net::AddrParseError;
@@ -22,16 +22,59 @@ pub enum X32Error {
     /// An error from the underlying `osc_lib`, such as a malformed
     /// OSC message or an unsupported type tag.
     Osc(OscError),
+    /// No reply to a query arrived in time.
+    Timeout {
+        /// The address that was queried.
+        path: String,
+    },
+    /// A reply carried a different type of value than the caller asked for.
+    TypeMismatch {
+        /// The type asked for, e.g. `"float"`.
+        expected: &'static str,
+        /// The type received, or `"none"` for a reply without arguments.
+        got: &'static str,
+    },
+    /// A reply arrived for another address than the one queried.
+    PathMismatch {
+        /// The address that was queried.
+        expected: String,
+        /// The address of the reply.
+        got: String,
+    },
     /// A custom, application-level error.
     Custom(String),
 }
 
+impl X32Error {
+    /// Creates a `TypeMismatch` for a reply argument, or for a missing one.
+    pub fn type_mismatch(expected: &'static str, got: Option<&OscArg>) -> Self {
+        let got = match got {
+            Some(OscArg::Int(_)) => "int",
+            Some(OscArg::Float(_)) => "float",
+            Some(OscArg::String(_)) => "string",
+            Some(OscArg::Blob(_)) => "blob",
+            Some(OscArg::Bool(_)) => "bool",
+            Some(OscArg::Nil) => "nil",
+            Some(OscArg::Infinitum) => "infinitum",
+            None => "none",
+        };
+        X32Error::TypeMismatch { expected, got }
+    }
+}
+
 impl fmt::Display for X32Error {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match self {
             X32Error::Io(e) => write!(f, "IO error: {}", e),
             X32Error::AddrParse(e) => write!(f, "Address parse error: {}", e),
             X32Error::Osc(e) => write!(f, "OSC error: {}", e),
+            X32Error::Timeout { path } => write!(f, "Timeout waiting for {}", path),
+            X32Error::TypeMismatch { expected, got } => {
+                write!(f, "Type mismatch: expected {}, got {}", expected, got)
+            }
+            X32Error::PathMismatch { expected, got } => {
+                write!(f, "Reply for {} while waiting for {}", got, expected)
+            }
             X32Error::Custom(s) => {
                 f.write_str("X32 error: ")?;
                 f.write_str(s)
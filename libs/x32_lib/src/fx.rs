@@ -0,0 +1,395 @@
+//! Effect types and their ids.
+//!
+//! The console reports the effect loaded in a slot as an index into the list of effects
+//! that slot can hold, so the same id names different effects in slots 1-4 and 5-8.
+//! [`FxType1`] lists the effects of slots 1-4 and [`FxType2`] the insert-only effects
+//! of slots 5-8, in the order of `/fx/[1..8]/type`.
+
+use crate::scene_parse::{XFXTYP4, XFXTYP5};
+
+/// An effect that can be loaded in FX slots 1-4.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum FxType1 {
+    /// HALL: Hall Reverb.
+    Hall = 0,
+    /// AMBI: Ambience.
+    Ambience = 1,
+    /// RPLT: Rich Plate Reverb.
+    RichPlate = 2,
+    /// ROOM: Room Reverb.
+    Room = 3,
+    /// CHAM: Chamber Reverb.
+    Chamber = 4,
+    /// PLAT: Plate Reverb.
+    Plate = 5,
+    /// VREV: Vintage Reverb.
+    VintageReverb = 6,
+    /// VRM: Vintage Room.
+    VintageRoom = 7,
+    /// GATE: Gated Reverb.
+    GatedReverb = 8,
+    /// RVRS: Reverse Reverb.
+    ReverseReverb = 9,
+    /// DLY: Stereo Delay.
+    StereoDelay = 10,
+    /// 3TAP: 3-Tap Delay.
+    ThreeTapDelay = 11,
+    /// 4TAP: Rhythm Delay.
+    RhythmDelay = 12,
+    /// CRS: Stereo Chorus.
+    StereoChorus = 13,
+    /// FLNG: Stereo Flanger.
+    StereoFlanger = 14,
+    /// PHAS: Stereo Phaser.
+    StereoPhaser = 15,
+    /// DIMC: Dimensional Chorus.
+    DimensionalChorus = 16,
+    /// FILT: Mood Filter.
+    MoodFilter = 17,
+    /// ROTA: Rotary Speaker.
+    RotarySpeaker = 18,
+    /// PAN: Tremolo/Panner.
+    TremoloPanner = 19,
+    /// SUB: Sub Octaver.
+    SubOctaver = 20,
+    /// D/RV: Delay + Chamber.
+    DelayReverb = 21,
+    /// CR/R: Chorus + Chamber.
+    ChorusReverb = 22,
+    /// FL/R: Flanger + Chamber.
+    FlangerReverb = 23,
+    /// D/CR: Delay + Chorus.
+    DelayChorus = 24,
+    /// D/FL: Delay + Flanger.
+    DelayFlanger = 25,
+    /// MODD: Modulation Delay.
+    ModulationDelay = 26,
+    /// GEQ2: Dual Graphic EQ.
+    DualGeq = 27,
+    /// GEQ: Stereo Graphic EQ.
+    StereoGeq = 28,
+    /// TEQ2: Dual TruEQ.
+    DualTrueEq = 29,
+    /// TEQ: Stereo TruEQ.
+    StereoTrueEq = 30,
+    /// DES2: Dual De-Esser.
+    DualDeEsser = 31,
+    /// DES: Stereo De-Esser.
+    StereoDeEsser = 32,
+    /// P1A: Stereo Xtec EQ1.
+    StereoXtecEq1 = 33,
+    /// P1A2: Dual Xtec EQ1.
+    DualXtecEq1 = 34,
+    /// PQ5: Stereo Xtec EQ5.
+    StereoXtecEq5 = 35,
+    /// PQ5S: Dual Xtec EQ5.
+    DualXtecEq5 = 36,
+    /// WAVD: Wave Designer.
+    WaveDesigner = 37,
+    /// LIM: Precision Limiter.
+    PrecisionLimiter = 38,
+    /// CMB: Stereo Combinator.
+    StereoCombinator = 39,
+    /// CMB2: Dual Combinator.
+    DualCombinator = 40,
+    /// FAC: Stereo Fair Compressor.
+    StereoFairComp = 41,
+    /// FAC1M: M/S Fair Compressor.
+    MsFairComp = 42,
+    /// FAC2: Dual Fair Compressor.
+    DualFairComp = 43,
+    /// LEC: Stereo Leisure Compressor.
+    StereoLeisureComp = 44,
+    /// LEC2: Dual Leisure Compressor.
+    DualLeisureComp = 45,
+    /// ULC: Stereo Ultimo Compressor.
+    StereoUltimoComp = 46,
+    /// ULC2: Dual Ultimo Compressor.
+    DualUltimoComp = 47,
+    /// ENH2: Dual Enhancer.
+    DualEnhancer = 48,
+    /// ENH: Stereo Enhancer.
+    StereoEnhancer = 49,
+    /// EXC2: Dual Exciter.
+    DualExciter = 50,
+    /// EXC: Stereo Exciter.
+    StereoExciter = 51,
+    /// IMG: Stereo Imager.
+    StereoImager = 52,
+    /// EDI: Edison EX1.
+    EdisonEx1 = 53,
+    /// SON: Sound Maxer.
+    SoundMaxer = 54,
+    /// AMP2: Dual Guitar Amp.
+    DualGuitarAmp = 55,
+    /// AMP: Stereo Guitar Amp.
+    StereoGuitarAmp = 56,
+    /// DRV2: Dual Tube Stage.
+    DualTubeStage = 57,
+    /// DRV: Stereo Tube Stage.
+    StereoTubeStage = 58,
+    /// PIT2: Dual Pitch Shifter.
+    DualPitch = 59,
+    /// PIT: Stereo Pitch Shifter.
+    StereoPitch = 60,
+}
+
+impl FxType1 {
+    /// Every effect type, in id order.
+    pub const ALL: [FxType1; 61] = [
+        FxType1::Hall,
+        FxType1::Ambience,
+        FxType1::RichPlate,
+        FxType1::Room,
+        FxType1::Chamber,
+        FxType1::Plate,
+        FxType1::VintageReverb,
+        FxType1::VintageRoom,
+        FxType1::GatedReverb,
+        FxType1::ReverseReverb,
+        FxType1::StereoDelay,
+        FxType1::ThreeTapDelay,
+        FxType1::RhythmDelay,
+        FxType1::StereoChorus,
+        FxType1::StereoFlanger,
+        FxType1::StereoPhaser,
+        FxType1::DimensionalChorus,
+        FxType1::MoodFilter,
+        FxType1::RotarySpeaker,
+        FxType1::TremoloPanner,
+        FxType1::SubOctaver,
+        FxType1::DelayReverb,
+        FxType1::ChorusReverb,
+        FxType1::FlangerReverb,
+        FxType1::DelayChorus,
+        FxType1::DelayFlanger,
+        FxType1::ModulationDelay,
+        FxType1::DualGeq,
+        FxType1::StereoGeq,
+        FxType1::DualTrueEq,
+        FxType1::StereoTrueEq,
+        FxType1::DualDeEsser,
+        FxType1::StereoDeEsser,
+        FxType1::StereoXtecEq1,
+        FxType1::DualXtecEq1,
+        FxType1::StereoXtecEq5,
+        FxType1::DualXtecEq5,
+        FxType1::WaveDesigner,
+        FxType1::PrecisionLimiter,
+        FxType1::StereoCombinator,
+        FxType1::DualCombinator,
+        FxType1::StereoFairComp,
+        FxType1::MsFairComp,
+        FxType1::DualFairComp,
+        FxType1::StereoLeisureComp,
+        FxType1::DualLeisureComp,
+        FxType1::StereoUltimoComp,
+        FxType1::DualUltimoComp,
+        FxType1::DualEnhancer,
+        FxType1::StereoEnhancer,
+        FxType1::DualExciter,
+        FxType1::StereoExciter,
+        FxType1::StereoImager,
+        FxType1::EdisonEx1,
+        FxType1::SoundMaxer,
+        FxType1::DualGuitarAmp,
+        FxType1::StereoGuitarAmp,
+        FxType1::DualTubeStage,
+        FxType1::StereoTubeStage,
+        FxType1::DualPitch,
+        FxType1::StereoPitch,
+    ];
+
+    /// Returns the effect type with the given `/fx/[1..4]/type` id.
+    pub fn from_id(id: i32) -> Option<FxType1> {
+        usize::try_from(id).ok().and_then(|i| Self::ALL.get(i)).copied()
+    }
+
+    /// Returns the effect type with the given short name, such as `"HALL"` or `"D/RV"`.
+    ///
+    /// Names are matched case-insensitively.
+    pub fn from_name(name: &str) -> Option<FxType1> {
+        Self::ALL
+            .into_iter()
+            .find(|fx| fx.name().eq_ignore_ascii_case(name))
+    }
+
+    /// Returns the `/fx/[1..4]/type` id of this effect type.
+    pub fn id(&self) -> i32 {
+        *self as i32
+    }
+
+    /// Returns the short name the console and scene files use for this effect type.
+    pub fn name(&self) -> &'static str {
+        XFXTYP4[*self as usize]
+    }
+}
+
+/// An effect that can be loaded in FX slots 5-8.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum FxType2 {
+    /// GEQ2: Dual Graphic EQ.
+    DualGeq = 0,
+    /// GEQ: Stereo Graphic EQ.
+    StereoGeq = 1,
+    /// TEQ2: Dual TruEQ.
+    DualTrueEq = 2,
+    /// TEQ: Stereo TruEQ.
+    StereoTrueEq = 3,
+    /// DES2: Dual De-Esser.
+    DualDeEsser = 4,
+    /// DES: Stereo De-Esser.
+    StereoDeEsser = 5,
+    /// P1A: Stereo Xtec EQ1.
+    StereoXtecEq1 = 6,
+    /// P1A2: Dual Xtec EQ1.
+    DualXtecEq1 = 7,
+    /// PQ5: Stereo Xtec EQ5.
+    StereoXtecEq5 = 8,
+    /// PQ5S: Dual Xtec EQ5.
+    DualXtecEq5 = 9,
+    /// WAVD: Wave Designer.
+    WaveDesigner = 10,
+    /// LIM: Precision Limiter.
+    PrecisionLimiter = 11,
+    /// FAC: Stereo Fair Compressor.
+    StereoFairComp = 12,
+    /// FAC1M: M/S Fair Compressor.
+    MsFairComp = 13,
+    /// FAC2: Dual Fair Compressor.
+    DualFairComp = 14,
+    /// LEC: Stereo Leisure Compressor.
+    StereoLeisureComp = 15,
+    /// LEC2: Dual Leisure Compressor.
+    DualLeisureComp = 16,
+    /// ULC: Stereo Ultimo Compressor.
+    StereoUltimoComp = 17,
+    /// ULC2: Dual Ultimo Compressor.
+    DualUltimoComp = 18,
+    /// ENH2: Dual Enhancer.
+    DualEnhancer = 19,
+    /// ENH: Stereo Enhancer.
+    StereoEnhancer = 20,
+    /// EXC2: Dual Exciter.
+    DualExciter = 21,
+    /// EXC: Stereo Exciter.
+    StereoExciter = 22,
+    /// IMG: Stereo Imager.
+    StereoImager = 23,
+    /// EDI: Edison EX1.
+    EdisonEx1 = 24,
+    /// SON: Sound Maxer.
+    SoundMaxer = 25,
+    /// AMP2: Dual Guitar Amp.
+    DualGuitarAmp = 26,
+    /// AMP: Stereo Guitar Amp.
+    StereoGuitarAmp = 27,
+    /// DRV2: Dual Tube Stage.
+    DualTubeStage = 28,
+    /// DRV: Stereo Tube Stage.
+    StereoTubeStage = 29,
+    /// PHAS: Stereo Phaser.
+    StereoPhaser = 30,
+    /// FILT: Mood Filter.
+    MoodFilter = 31,
+    /// PAN: Tremolo/Panner.
+    TremoloPanner = 32,
+    /// SUB: Sub Octaver.
+    SubOctaver = 33,
+}
+
+impl FxType2 {
+    /// Every effect type, in id order.
+    pub const ALL: [FxType2; 34] = [
+        FxType2::DualGeq,
+        FxType2::StereoGeq,
+        FxType2::DualTrueEq,
+        FxType2::StereoTrueEq,
+        FxType2::DualDeEsser,
+        FxType2::StereoDeEsser,
+        FxType2::StereoXtecEq1,
+        FxType2::DualXtecEq1,
+        FxType2::StereoXtecEq5,
+        FxType2::DualXtecEq5,
+        FxType2::WaveDesigner,
+        FxType2::PrecisionLimiter,
+        FxType2::StereoFairComp,
+        FxType2::MsFairComp,
+        FxType2::DualFairComp,
+        FxType2::StereoLeisureComp,
+        FxType2::DualLeisureComp,
+        FxType2::StereoUltimoComp,
+        FxType2::DualUltimoComp,
+        FxType2::DualEnhancer,
+        FxType2::StereoEnhancer,
+        FxType2::DualExciter,
+        FxType2::StereoExciter,
+        FxType2::StereoImager,
+        FxType2::EdisonEx1,
+        FxType2::SoundMaxer,
+        FxType2::DualGuitarAmp,
+        FxType2::StereoGuitarAmp,
+        FxType2::DualTubeStage,
+        FxType2::StereoTubeStage,
+        FxType2::StereoPhaser,
+        FxType2::MoodFilter,
+        FxType2::TremoloPanner,
+        FxType2::SubOctaver,
+    ];
+
+    /// Returns the effect type with the given `/fx/[5..8]/type` id.
+    pub fn from_id(id: i32) -> Option<FxType2> {
+        usize::try_from(id).ok().and_then(|i| Self::ALL.get(i)).copied()
+    }
+
+    /// Returns the effect type with the given short name, such as `"GEQ2"`.
+    ///
+    /// Names are matched case-insensitively.
+    pub fn from_name(name: &str) -> Option<FxType2> {
+        Self::ALL
+            .into_iter()
+            .find(|fx| fx.name().eq_ignore_ascii_case(name))
+    }
+
+    /// Returns the `/fx/[5..8]/type` id of this effect type.
+    pub fn id(&self) -> i32 {
+        *self as i32
+    }
+
+    /// Returns the short name the console and scene files use for this effect type.
+    pub fn name(&self) -> &'static str {
+        XFXTYP5[*self as usize]
+    }
+}
+
+/// Returns the id a named effect type has in the given FX slot.
+///
+/// # Arguments
+///
+/// * `slot` - The FX slot number (1-8).
+/// * `name` - The effect's short name, such as `"HALL"`.
+///
+/// # Returns
+///
+/// The `/fx/[slot]/type` id, or `None` if the slot cannot hold that effect.
+pub fn type_id(slot: u8, name: &str) -> Option<i32> {
+    match slot {
+        1..=4 => FxType1::from_name(name).map(|fx| fx.id()),
+        5..=8 => FxType2::from_name(name).map(|fx| fx.id()),
+        _ => None,
+    }
+}
+
+/// Returns the short name of the effect with the given id in an FX slot.
+///
+/// # Arguments
+///
+/// * `slot` - The FX slot number (1-8).
+/// * `id` - The value of `/fx/[slot]/type`.
+pub fn type_name(slot: u8, id: i32) -> Option<&'static str> {
+    match slot {
+        1..=4 => FxType1::from_id(id).map(|fx| fx.name()),
+        5..=8 => FxType2::from_id(id).map(|fx| fx.name()),
+        _ => None,
+    }
+}
@@ -0,0 +1,86 @@
+//! Helpers for keeping an `/xremote` subscription alive.
+//!
+//! The X32 only pushes parameter updates to clients that have sent `/xremote`
+//! within the last 10 seconds. Tools that run their own event loop use
+//! [`Keepalive`] to renew the subscription from that loop rather than
+//! maintaining a separate timer.
+
+use crate::client::MixerClient;
+use crate::error::Result;
+use std::time::{Duration, Instant};
+
+/// The interval at which `/xremote` is renewed, safely inside the mixer's 10 second window.
+pub const KEEPALIVE_INTERVAL: Duration = Duration::from_secs(9);
+
+/// Sends a single `/xremote` keepalive to the mixer.
+///
+/// # Arguments
+///
+/// * `client` - The `MixerClient` connected to the mixer.
+pub async fn keepalive(client: &MixerClient) -> Result<()> {
+    client.send_message("/xremote", vec![]).await
+}
+
+/// Tracks when `/xremote` was last sent so it is only renewed when due.
+pub struct Keepalive {
+    interval: Duration,
+    last_sent: Option<Instant>,
+    clock: Box<dyn Fn() -> Instant + Send + Sync>,
+}
+
+impl Keepalive {
+    /// Creates a new `Keepalive` using [`KEEPALIVE_INTERVAL`] and the system clock.
+    pub fn new() -> Self {
+        Self::with_clock(KEEPALIVE_INTERVAL, Instant::now)
+    }
+
+    /// Creates a new `Keepalive` with a custom interval and clock.
+    ///
+    /// # Arguments
+    ///
+    /// * `interval` - The minimum time between two keepalives.
+    /// * `clock` - A function returning the current time.
+    pub fn with_clock(
+        interval: Duration,
+        clock: impl Fn() -> Instant + Send + Sync + 'static,
+    ) -> Self {
+        Self {
+            interval,
+            last_sent: None,
+            clock: Box::new(clock),
+        }
+    }
+
+    /// Returns `true` if a keepalive should be sent now.
+    pub fn is_due(&self) -> bool {
+        match self.last_sent {
+            Some(last) => (self.clock)().saturating_duration_since(last) >= self.interval,
+            None => true,
+        }
+    }
+
+    /// Sends `/xremote` if it has not been sent within the interval.
+    ///
+    /// # Arguments
+    ///
+    /// * `client` - The `MixerClient` connected to the mixer.
+    ///
+    /// # Returns
+    ///
+    /// `Ok(true)` if a keepalive was sent, `Ok(false)` if it was not yet due.
+    pub async fn tick(&mut self, client: &MixerClient) -> Result<bool> {
+        if !self.is_due() {
+            return Ok(false);
+        }
+        // Record the attempt before sending so a failing socket is not retried on every tick.
+        self.last_sent = Some((self.clock)());
+        keepalive(client).await?;
+        Ok(true)
+    }
+}
+
+impl Default for Keepalive {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -25,7 +25,7 @@
 //!     let ip_address = "192.168.1.64";
 //!     let socket = create_socket(ip_address, 1000)?;
 //!
-//!     let (address, args) = channel::set_fader(1, 0.75);
+//!     let (address, args) = channel::set_fader(1, 0.75)?;
 //!     let msg = OscMessage::new(address, args);
 //!
 //!     socket.send(&msg.to_bytes()?)?;
@@ -46,7 +46,12 @@
 //!   various mixer controls.
 //! - [`error`]: Defines the custom `X32Error` type and `Result` alias for robust error
 //!   handling.
+//! - [`console`]: A blocking connection for tools that send a message and wait for its reply.
+//! - [`fx`]: Maps effect type ids to and from their names.
 //! - [`common`]: Provides common utilities and helper functions used throughout the library.
+//! - [`keepalive`]: Renews the `/xremote` subscription from a tool's own event loop.
+//! - [`subscription`]: Streams single parameters with `/subscribe` and `/renew`.
+//! - [`user_ctrl`]: Builds the encoder and button assignments of a user bank.
 //!
 //! # Credits
 //!
@@ -60,15 +65,27 @@ mod tests;
 pub mod client;
 pub mod command;
 pub mod common;
+pub mod console;
 pub mod error;
+pub mod fx;
+pub mod keepalive;
 pub mod main_bus;
 pub mod scene_parse;
+pub mod subscription;
 pub mod transport;
+pub mod user_ctrl;
 
 pub use crate::client::MixerClient;
+#[cfg(feature = "async")]
+pub use crate::console::AsyncConsole;
+pub use crate::console::Console;
+pub use crate::keepalive::{keepalive, Keepalive};
+pub use crate::subscription::{renew, subscribe};
 pub use crate::transport::MixerTransport;
+use std::cell::RefCell;
 use std::net::{SocketAddr, UdpSocket};
 use std::time::Duration;
+pub use x32_core::enums;
 pub use x32_fxparse::MixerModel;
 
 pub use crate::error::{Result, X32Error};
@@ -92,6 +109,48 @@ pub use osc_lib::{OscArg, OscError, OscMessage};
 /// connection fails.
 #[deprecated(since = "0.1.0", note = "Use MixerClient and async methods instead")]
 pub fn create_socket(ip: &str, timeout: u64) -> Result<UdpSocket> {
+    let socket = connect_socket(ip, 0)?;
+    socket.set_read_timeout(Some(Duration::from_millis(timeout)))?;
+    Ok(socket)
+}
+
+/// Binds a UDP socket to `local_port` on all interfaces (0 for a dynamic port) and
+/// connects it to a console, defaulting to port 10023.
+pub(crate) fn connect_socket(ip: &str, local_port: u16) -> Result<UdpSocket> {
+    let (mut local_addr, remote_addr) = socket_addrs(ip)?;
+    local_addr.set_port(local_port);
+    let socket = UdpSocket::bind(local_addr)?;
+    socket.connect(remote_addr)?;
+
+    Ok(socket)
+}
+
+/// Creates a `tokio` UDP socket and connects to an X32/M32 console.
+///
+/// This is the asynchronous counterpart of `create_socket`. Reads are not given a
+/// timeout; wrap them in `tokio::time::timeout` instead.
+///
+/// # Arguments
+///
+/// * `ip` - The IP address of the console, with an optional port (default 10023).
+///
+/// # Returns
+///
+/// A `Result` containing the connected socket or an `X32Error` if the connection fails.
+#[cfg(feature = "async")]
+pub async fn create_socket_async(ip: &str) -> Result<tokio::net::UdpSocket> {
+    let (local_addr, remote_addr) = socket_addrs(ip)?;
+    let socket = tokio::net::UdpSocket::bind(local_addr).await?;
+    socket.connect(remote_addr).await?;
+
+    Ok(socket)
+}
+
+/// Resolves the local and remote addresses for a connection to a console.
+///
+/// The local address is always the unspecified address of the remote's family with a
+/// dynamic port: binding to the console's own IP would fail on any real network.
+fn socket_addrs(ip: &str) -> Result<(SocketAddr, SocketAddr)> {
     // If the IP address does not contain a port, add the default X32 port.
     let full_ip = if (ip.contains(':') && !ip.starts_with('[')) || ip.contains("]:") {
         ip.to_string()
@@ -107,11 +166,17 @@ pub fn create_socket(ip: &str, timeout: u64) -> Result<UdpSocket> {
         "[::]:0".parse()?
     };
 
-    let socket = UdpSocket::bind(local_addr)?;
-    socket.connect(remote_addr)?;
-    socket.set_read_timeout(Some(Duration::from_millis(timeout)))?;
+    Ok((local_addr, remote_addr))
+}
 
-    Ok(socket)
+thread_local! {
+    /// The receive buffer shared by the blocking query helpers on this thread.
+    static RECV_BUF: RefCell<Vec<u8>> = RefCell::new(vec![0; console::RECV_BUF_SIZE]);
+}
+
+/// Sends a query on `socket` and parses the reply, reusing this thread's receive buffer.
+fn exchange(socket: &UdpSocket, msg: &OscMessage) -> Result<OscMessage> {
+    RECV_BUF.with(|buf| console::exchange(socket, msg, &mut buf.borrow_mut()))
 }
 
 /// Queries the mixer for the type of effect in a given FX slot.
@@ -126,16 +191,16 @@ pub fn create_socket(ip: &str, timeout: u64) -> Result<UdpSocket> {
 /// A `Result` containing the effect type as an integer, or an `X32Error` on failure.
 #[deprecated(since = "0.1.0", note = "Use MixerClient and async methods instead")]
 pub fn get_fx_type(socket: &UdpSocket, slot: u8) -> Result<i32> {
+    query_fx_type(socket, slot)
+}
+
+/// Queries `/fx/[slot]/type` and returns the effect type id.
+fn query_fx_type(socket: &UdpSocket, slot: u8) -> Result<i32> {
     let msg = OscMessage::new(format!("/fx/{}/type", slot), vec![]);
-    socket.send(&msg.to_bytes()?)?;
-    let mut buf = [0; 512];
-    let len = socket.recv(&mut buf)?;
-    let response = OscMessage::from_bytes(&buf[..len])?;
-    if let Some(OscArg::Int(fx_type)) = response.args.first() {
-        Ok(*fx_type)
-    } else {
-        Err(OscError::ParseError("Unexpected response from mixer".to_string()).into())
-    }
+    let response = exchange(socket, &msg)?;
+    response
+        .arg_int(0)
+        .ok_or_else(|| X32Error::type_mismatch("int", response.args.first()))
 }
 
 /// Queries the mixer for the current level of a fader.
@@ -151,15 +216,10 @@ pub fn get_fx_type(socket: &UdpSocket, slot: u8) -> Result<i32> {
 #[deprecated(since = "0.1.0", note = "Use MixerClient and async methods instead")]
 pub fn get_fader_level(socket: &UdpSocket, fader_addr: &str) -> Result<f32> {
     let msg = OscMessage::new(fader_addr.to_string(), vec![]);
-    socket.send(&msg.to_bytes()?)?;
-    let mut buf = [0; 512];
-    let len = socket.recv(&mut buf)?;
-    let response = OscMessage::from_bytes(&buf[..len])?;
-    if let Some(OscArg::Float(level)) = response.args.first() {
-        Ok(*level)
-    } else {
-        Err(OscError::ParseError("Unexpected response from mixer".to_string()).into())
-    }
+    let response = exchange(socket, &msg)?;
+    response
+        .arg_float(0)
+        .ok_or_else(|| X32Error::type_mismatch("float", response.args.first()))
 }
 
 /// Verifies that a specific FX slot contains the expected effect type.
@@ -171,26 +231,21 @@ pub fn get_fader_level(socket: &UdpSocket, fader_addr: &str) -> Result<f32> {
 ///
 /// * `socket` - A `UdpSocket` connected to the mixer.
 /// * `slot` - The FX slot number (1-8).
-/// * `expected_type` - A string representing the expected effect type (e.g., "GEQ2").
+/// * `expected_type` - The short name of the expected effect type (e.g., "GEQ2").
 ///
 /// # Returns
 ///
-/// A `Result` containing `true` if the effect type matches, or `false` otherwise.
+/// A `Result` containing `true` if the effect type matches, or `false` otherwise. Names
+/// the slot cannot hold are reported as an error rather than a mismatch.
 #[deprecated(since = "0.1.0", note = "Use MixerClient and async methods instead")]
 pub fn verify_fx_type(socket: &UdpSocket, slot: u8, expected_type: &str) -> Result<bool> {
-    let msg = OscMessage::new(
-        "/node".to_string(),
-        vec![OscArg::String(format!("fx/{}", slot))],
-    );
-    socket.send(&msg.to_bytes()?)?;
-    let mut buf = [0; 512];
-    let len = socket.recv(&mut buf)?;
-    let response = OscMessage::from_bytes(&buf[..len])?;
-    if let Some(OscArg::String(response_str)) = response.args.first() {
-        Ok(response_str.contains(expected_type))
-    } else {
-        Ok(false)
-    }
+    let expected_id = fx::type_id(slot, expected_type).ok_or_else(|| {
+        X32Error::Custom(format!(
+            "Unknown FX type {} for slot {}",
+            expected_type, slot
+        ))
+    })?;
+    Ok(query_fx_type(socket, slot)? == expected_id)
 }
 
 /// Gets the value of a floating-point parameter from the mixer.
@@ -206,15 +261,55 @@ pub fn verify_fx_type(socket: &UdpSocket, slot: u8, expected_type: &str) -> Resu
 #[deprecated(since = "0.1.0", note = "Use MixerClient and async methods instead")]
 pub fn get_parameter(socket: &UdpSocket, address: &str) -> Result<f32> {
     let msg = OscMessage::new(address.to_string(), vec![]);
-    socket.send(&msg.to_bytes()?)?;
-    let mut buf = [0; 512];
-    let len = socket.recv(&mut buf)?;
-    let response = OscMessage::from_bytes(&buf[..len])?;
-    if let Some(OscArg::Float(value)) = response.args.first() {
-        Ok(*value)
-    } else {
-        Err(OscError::ParseError("Unexpected response from mixer".to_string()).into())
-    }
+    let response = exchange(socket, &msg)?;
+    response
+        .arg_float(0)
+        .ok_or_else(|| X32Error::type_mismatch("float", response.args.first()))
+}
+
+/// Gets the value of a string parameter, such as a channel name, from the mixer.
+///
+/// # Arguments
+///
+/// * `socket` - A `UdpSocket` connected to the mixer.
+/// * `address` - The OSC address of the parameter to get.
+///
+/// # Returns
+///
+/// A `Result` containing the parameter's value, or `X32Error::TypeMismatch`
+/// if the mixer replied with another type.
+pub fn get_string(socket: &UdpSocket, address: &str) -> Result<String> {
+    let arg = query_arg(socket, address)?;
+    arg.as_ref()
+        .and_then(OscArg::as_str)
+        .map(str::to_string)
+        .ok_or_else(|| X32Error::type_mismatch("string", arg.as_ref()))
+}
+
+/// Gets the value of an integer parameter, such as an on/off switch or an enum
+/// index, from the mixer.
+///
+/// # Arguments
+///
+/// * `socket` - A `UdpSocket` connected to the mixer.
+/// * `address` - The OSC address of the parameter to get.
+///
+/// # Returns
+///
+/// A `Result` containing the parameter's value, or `X32Error::TypeMismatch`
+/// if the mixer replied with another type.
+pub fn get_int(socket: &UdpSocket, address: &str) -> Result<i32> {
+    let arg = query_arg(socket, address)?;
+    arg.as_ref()
+        .and_then(OscArg::as_int)
+        .ok_or_else(|| X32Error::type_mismatch("int", arg.as_ref()))
+}
+
+/// Sends a query and returns the first argument of the reply, if it has one.
+fn query_arg(socket: &UdpSocket, address: &str) -> Result<Option<OscArg>> {
+    let msg = OscMessage::new(address.to_string(), vec![]);
+    let response = exchange(socket, &msg)?;
+    Ok(response.args.into_iter().next())
 }
 
 /// Sets the value of a floating-point parameter on the mixer.
@@ -264,11 +359,8 @@ pub async fn set_parameter_async(client: &MixerClient, address: &str, value: f32
 /// A `Result` containing the parameter's value as a float.
 pub async fn get_parameter_async(client: &MixerClient, address: &str) -> Result<f32> {
     let arg = query_value_async(client, address).await?;
-    if let OscArg::Float(value) = arg {
-        Ok(value)
-    } else {
-        Err(OscError::ParseError("Unexpected response from mixer".to_string()).into())
-    }
+    arg.as_float()
+        .ok_or_else(|| X32Error::type_mismatch("float", Some(&arg)))
 }
 
 /// Queries a value from the mixer asynchronously with a bounded timeout (500ms).
@@ -280,11 +372,48 @@ pub async fn get_parameter_async(client: &MixerClient, address: &str) -> Result<
 ///
 /// # Returns
 ///
-/// A `Result` containing the first argument of the response as an `OscArg`.
+/// A `Result` containing the first argument of the response as an `OscArg`,
+/// or `X32Error::Timeout` if no reply arrived.
 pub async fn query_value_async(client: &MixerClient, address: &str) -> Result<OscArg> {
     match tokio::time::timeout(Duration::from_millis(500), client.query_value(address)).await {
         Ok(res) => res,
-        Err(_) => Err(OscError::ParseError("Query timeout".to_string()).into()),
+        Err(_) => Err(X32Error::Timeout {
+            path: address.to_string(),
+        }),
+    }
+}
+
+/// Queries a parameter from the mixer, retrying when a query times out.
+///
+/// Each attempt waits at most 500ms, as [`query_value_async`] does.
+///
+/// # Arguments
+///
+/// * `client` - A `MixerClient` connected to the mixer.
+/// * `address` - The OSC address to query.
+/// * `retries` - How many more attempts to make after the first one fails.
+///
+/// # Returns
+///
+/// A `Result` containing the whole response, or the last error.
+pub async fn query_message_retry(
+    client: &MixerClient,
+    address: &str,
+    retries: u32,
+) -> Result<OscMessage> {
+    let mut attempt = 0;
+    loop {
+        let query = client.query_message(address);
+        let result = match tokio::time::timeout(Duration::from_millis(500), query).await {
+            Ok(result) => result,
+            Err(_) => Err(X32Error::Timeout {
+                path: address.to_string(),
+            }),
+        };
+        match result {
+            Err(X32Error::Timeout { .. }) if attempt < retries => attempt += 1,
+            result => return result,
+        }
     }
 }
 
@@ -0,0 +1,84 @@
+//! Helpers for `/subscribe`, which streams a single parameter to the client.
+//!
+//! Unlike `/xremote`, which pushes every change made on the console, a
+//! subscription repeatedly sends the current value of one address. Like
+//! `/xremote`, it lapses after 10 seconds unless it is renewed with `/renew`.
+//!
+//! # Example
+//!
+//! ```no_run
+//! use std::time::Duration;
+//! use x32_lib::{renew, subscribe, Console};
+//!
+//! fn main() -> x32_lib::Result<()> {
+//!     let console = Console::connect("192.168.1.64")?;
+//!     subscribe(console.socket(), "/ch/01/mix/fader", Duration::from_millis(100))?;
+//!     loop {
+//!         // ... read updates from console.socket() ...
+//!         renew(console.socket(), "/ch/01/mix/fader")?;
+//!         # break Ok(());
+//!     }
+//! }
+//! ```
+
+use crate::error::Result;
+use osc_lib::{OscArg, OscMessage};
+use std::net::UdpSocket;
+use std::time::Duration;
+
+/// How long a subscription lasts without being renewed.
+pub const SUBSCRIPTION_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// The console's update period; subscription intervals are multiples of it.
+const TICK: Duration = Duration::from_millis(50);
+
+/// The largest time factor the console accepts.
+const MAX_TIME_FACTOR: i32 = 24;
+
+/// Builds a `/subscribe ,si` message for `path`.
+///
+/// The console expresses the interval as a time factor in 50 ms ticks, from 0 to
+/// 24, so `interval` is rounded to the nearest tick and capped at 1.2 seconds.
+///
+/// # Arguments
+///
+/// * `path` - The OSC address to stream, e.g. `/ch/01/mix/fader`.
+/// * `interval` - How often the value should be sent.
+pub fn subscribe_message(path: &str, interval: Duration) -> OscMessage {
+    let ticks = (interval.as_secs_f64() / TICK.as_secs_f64()).round();
+    let time_factor = (ticks as i32).clamp(0, MAX_TIME_FACTOR);
+    OscMessage::new(
+        "/subscribe".to_string(),
+        vec![OscArg::String(path.to_string()), OscArg::Int(time_factor)],
+    )
+}
+
+/// Builds a `/renew ,s` message for `path`.
+pub fn renew_message(path: &str) -> OscMessage {
+    OscMessage::new("/renew".to_string(), vec![OscArg::String(path.to_string())])
+}
+
+/// Subscribes to updates of a single parameter.
+///
+/// The subscription must be renewed with [`renew`] within [`SUBSCRIPTION_TIMEOUT`].
+///
+/// # Arguments
+///
+/// * `socket` - A `UdpSocket` connected to the mixer.
+/// * `path` - The OSC address to stream, e.g. `/ch/01/mix/fader`.
+/// * `interval` - How often the value should be sent. See [`subscribe_message`].
+pub fn subscribe(socket: &UdpSocket, path: &str, interval: Duration) -> Result<()> {
+    socket.send(&subscribe_message(path, interval).to_bytes()?)?;
+    Ok(())
+}
+
+/// Renews a subscription made with [`subscribe`].
+///
+/// # Arguments
+///
+/// * `socket` - A `UdpSocket` connected to the mixer.
+/// * `path` - The subscribed OSC address.
+pub fn renew(socket: &UdpSocket, path: &str) -> Result<()> {
+    socket.send(&renew_message(path).to_bytes()?)?;
+    Ok(())
+}
@@ -7,12 +7,22 @@ mod async_methods;
 mod client;
 #[path = "tests/common.rs"]
 mod common;
+#[path = "tests/console.rs"]
+mod console;
 #[path = "tests/error.rs"]
 mod error;
+#[path = "tests/fx.rs"]
+mod fx;
+#[path = "tests/keepalive.rs"]
+mod keepalive;
 #[path = "tests/main_bus.rs"]
 mod main_bus;
 #[path = "tests/output.rs"]
 mod output;
+#[path = "tests/subscription.rs"]
+mod subscription;
+#[path = "tests/user_ctrl.rs"]
+mod user_ctrl;
 
 #[test]
 fn test_create_socket_with_port() {
@@ -20,8 +30,56 @@ fn test_create_socket_with_port() {
     assert_eq!(socket.peer_addr().unwrap().port(), 10024);
 }
 
+#[test]
+fn test_create_socket_with_non_local_mixer_ip() {
+    // 198.51.100.0/24 is reserved for documentation, so it is never a local interface.
+    // Without a route to it the connect may fail, but never because of the local bind.
+    match create_socket("198.51.100.7", 1000) {
+        Ok(socket) => {
+            assert_eq!(
+                socket.peer_addr().unwrap().to_string(),
+                "198.51.100.7:10023"
+            );
+            assert!(socket.local_addr().unwrap().ip() != socket.peer_addr().unwrap().ip());
+        }
+        Err(X32Error::Io(e)) => assert_ne!(e.kind(), std::io::ErrorKind::AddrNotAvailable),
+        Err(e) => panic!("unexpected error: {}", e),
+    }
+}
+
 #[test]
 fn test_create_socket_default_port() {
     let socket = create_socket("127.0.0.1", 1000).unwrap();
     assert_eq!(socket.peer_addr().unwrap().port(), 10023);
 }
+
+#[test]
+fn test_get_string_and_int() {
+    let emulator = x32_emulator::testing::X32Emulator::start_with(|mixer| {
+        mixer.seed_from_lines(vec![
+            "/ch/05/config/name,s\tVocal",
+            "/ch/05/config/color,i\t3",
+        ]);
+    })
+    .unwrap();
+    let addr = emulator.addr().to_string();
+
+    let socket = create_socket(&addr, 1000).unwrap();
+    assert_eq!(get_string(&socket, "/ch/05/config/name").unwrap(), "Vocal");
+    assert_eq!(get_int(&socket, "/ch/05/config/color").unwrap(), 3);
+
+    assert!(matches!(
+        get_int(&socket, "/ch/05/config/name"),
+        Err(X32Error::TypeMismatch {
+            expected: "int",
+            got: "string"
+        })
+    ));
+    assert!(matches!(
+        get_string(&socket, "/ch/05/config/color"),
+        Err(X32Error::TypeMismatch {
+            expected: "string",
+            got: "int"
+        })
+    ));
+}
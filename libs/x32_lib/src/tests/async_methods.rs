@@ -1,24 +1,11 @@
 use crate::*;
 use osc_lib::OscArg;
-use std::thread;
-use tokio::time::Duration;
+use x32_emulator::testing::X32Emulator;
 
 #[tokio::test]
 async fn test_async_methods() {
-    let mock_x32_socket = std::net::UdpSocket::bind("127.0.0.1:0").unwrap();
-    let udp_port = mock_x32_socket.local_addr().unwrap().port();
-    drop(mock_x32_socket); // Free the port so the emulator can use it
-
-    // Start the emulator in a separate thread
-    let bind_addr = format!("127.0.0.1:{}", udp_port);
-    thread::spawn(move || {
-        x32_emulator::server::run(&bind_addr, None, None).unwrap();
-    });
-
-    // Give emulator a moment to start
-    tokio::time::sleep(Duration::from_millis(100)).await;
-
-    let client = MixerClient::connect(&format!("127.0.0.1:{}", udp_port), false)
+    let emulator = X32Emulator::start().unwrap();
+    let client = MixerClient::connect(&emulator.addr().to_string(), false)
         .await
         .unwrap();
 
@@ -30,9 +17,6 @@ async fn test_async_methods() {
     let test_addr = "/ch/01/mix/fader";
     set_parameter_async(&client, test_addr, 0.75).await.unwrap();
 
-    // Give emulator a moment to process
-    tokio::time::sleep(Duration::from_millis(50)).await;
-
     let value = get_parameter_async(&client, test_addr).await.unwrap();
     assert_eq!(value, 0.75);
 }
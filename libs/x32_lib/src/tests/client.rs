@@ -63,6 +63,23 @@ async fn test_mixer_client_new_heartbeat() {
     _client.stop_heartbeat();
 }
 
+#[tokio::test]
+async fn test_mixer_client_send_bundle_falls_back_to_messages() {
+    let (transport, _tx) = MockTransport::new();
+    let client = MixerClient::new(transport.clone(), false);
+
+    let bundle = osc_lib::OscBundle::new(vec![
+        OscMessage::new("/ch/01/mix/on".to_string(), vec![osc_lib::OscArg::Int(1)]).into(),
+        OscMessage::new("/ch/02/mix/on".to_string(), vec![osc_lib::OscArg::Int(0)]).into(),
+    ]);
+    client.send_bundle(bundle).await.unwrap();
+
+    let sent = transport.get_sent_messages().await;
+    assert_eq!(sent.len(), 2);
+    assert_eq!(sent[0].path, "/ch/01/mix/on");
+    assert_eq!(sent[1].path, "/ch/02/mix/on");
+}
+
 #[tokio::test]
 async fn test_mixer_client_send_message() {
     let (transport, _tx) = MockTransport::new();
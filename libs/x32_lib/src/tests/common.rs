@@ -159,4 +159,103 @@ mod tests {
         assert_eq!(FxSource::from_id(41), Some(FxSource::Group(1)));
         assert_eq!(FxSource::from_id(50), None);
     }
+
+    #[test]
+    fn test_fader_law_segment_boundaries() {
+        // (level, dB) at the ends of each of the four segments.
+        let points = [
+            (1.0, 10.0),
+            (0.5, -10.0),
+            (0.25, -30.0),
+            (0.0625, -60.0),
+            (0.0, -90.0),
+        ];
+        for (level, db) in points {
+            assert!((level_to_db(level) - db).abs() < 1e-4, "level {}", level);
+            assert!((db_to_level(db) - level).abs() < 1e-6, "{} dB", db);
+        }
+
+        // Either side of each boundary lands in the neighbouring segment.
+        assert!((level_to_db(0.75) - 0.0).abs() < 1e-4);
+        assert!((level_to_db(0.375) - -20.0).abs() < 1e-4);
+        assert!((level_to_db(0.125) - -50.0).abs() < 1e-4);
+        assert!((level_to_db(0.03125) - -75.0).abs() < 1e-4);
+
+        assert_eq!(db_to_level(20.0), 1.0);
+        assert_eq!(db_to_level(-120.0), 0.0);
+    }
+
+    #[test]
+    fn test_level_steps() {
+        assert_eq!(level_to_step(0.0, LEVEL_STEPS), 0);
+        assert_eq!(level_to_step(1.0, LEVEL_STEPS), 160);
+        assert_eq!(level_to_step(0.5, LEVEL_STEPS), 80);
+        assert_eq!(level_to_step(1.5, FADER_STEPS), 1023);
+        assert_eq!(step_to_level(80, LEVEL_STEPS), 0.5);
+        assert_eq!(step_to_level(200, LEVEL_STEPS), 1.0);
+        assert_eq!(step_to_level(1023, FADER_STEPS), 1.0);
+
+        for step in 0..LEVEL_STEPS {
+            assert_eq!(
+                level_to_step(step_to_level(step, LEVEL_STEPS), LEVEL_STEPS),
+                step
+            );
+        }
+    }
+
+    #[test]
+    fn test_format_frequency() {
+        assert_eq!(format_frequency(20.0), "20.0");
+        assert_eq!(format_frequency(124.7), "124.7");
+        assert_eq!(format_frequency(999.96), "1k00");
+        assert_eq!(format_frequency(1970.0), "1k97");
+        assert_eq!(format_frequency(10020.0), "10k02");
+        assert_eq!(format_frequency(20000.0), "20k00");
+    }
+
+    #[test]
+    fn test_fader_steps() {
+        assert_eq!(fader_float_to_step(0.0), 0);
+        assert_eq!(fader_float_to_step(1.0), 1023);
+        assert_eq!(fader_float_to_step(2.0), 1023);
+        assert_eq!(fader_step_to_float(1023), 1.0);
+        for step in 0..1024 {
+            let level = fader_step_to_float(step);
+            assert_eq!(fader_float_to_step(level), step);
+            assert_eq!(quantize_fader(level), level);
+        }
+    }
+
+    #[test]
+    fn test_fader_positions_in_db() {
+        // Known positions: +10 dB at the top, 0 dB at 0.7498, -10 dB at 0.5,
+        // -30 dB at 0.25, -60 dB at 0.0625 and -oo at the bottom.
+        for (db, step) in [
+            (10.0, 1023),
+            (0.0, 767),
+            (-10.0, 512),
+            (-30.0, 256),
+            (-60.0, 64),
+            (-90.0, 0),
+        ] {
+            assert_eq!(db_to_fader_step(db), step, "{} dB", db);
+            assert!((fader_step_to_db(step) - db).abs() < 0.05, "step {}", step);
+        }
+        assert!((fader_step_to_float(767) - 0.7498).abs() < 0.0001);
+    }
+
+    #[test]
+    fn test_strip_number_validation() {
+        assert_eq!(valid_channel(1).unwrap(), 1);
+        assert_eq!(valid_channel(32).unwrap(), 32);
+        assert!(valid_channel(0).is_err());
+        assert!(valid_channel(33).is_err());
+        assert!(valid_bus(16).is_ok() && valid_bus(17).is_err());
+        assert!(valid_dca(8).is_ok() && valid_dca(9).is_err());
+        assert!(valid_mtx(6).is_ok() && valid_mtx(7).is_err());
+        assert!(valid_fx(8).is_ok() && valid_fx(0).is_err());
+
+        let err = valid_channel(40).unwrap_err();
+        assert_eq!(err.to_string(), "X32 error: Channel 40 out of range (1-32)");
+    }
 }
@@ -0,0 +1,150 @@
+use crate::*;
+use osc_lib::OscArg;
+use std::thread;
+use std::time::Duration;
+use x32_emulator::testing::X32Emulator;
+
+#[test]
+fn test_console_set_and_query() {
+    let emulator = X32Emulator::start().unwrap();
+    let addr = emulator.addr().to_string();
+    let console = Console::connect(&addr).unwrap();
+
+    let info = console.query("/info", vec![]).unwrap();
+    assert_eq!(info.path, "/info");
+    assert_eq!(info.args.len(), 4);
+
+    console
+        .set("/ch/01/mix/fader", vec![OscArg::Float(0.75)])
+        .unwrap();
+    let reply = console.query("/ch/01/mix/fader", vec![]).unwrap();
+    assert_eq!(reply.arg_float(0), Some(0.75));
+
+    let node = console
+        .query("/node", vec![OscArg::String("ch/01/mix".to_string())])
+        .unwrap();
+    assert!(node.arg_str(0).unwrap().starts_with("/ch/01/mix "));
+}
+
+#[test]
+fn test_console_reuses_receive_buffer() {
+    let emulator = X32Emulator::start().unwrap();
+    let addr = emulator.addr().to_string();
+    let console = Console::connect(&addr).unwrap();
+    console
+        .set("/ch/01/mix/fader", vec![OscArg::Float(0.25)])
+        .unwrap();
+
+    let buf = console.recv_buf_ptr();
+    for _ in 0..100 {
+        let reply = console.query("/ch/01/mix/fader", vec![]).unwrap();
+        assert_eq!(reply.arg_float(0), Some(0.25));
+    }
+    // Every reply was received into the buffer allocated when connecting.
+    assert_eq!(console.recv_buf_ptr(), buf);
+}
+
+#[test]
+fn test_socket_helpers_share_thread_buffer() {
+    let emulator = X32Emulator::start().unwrap();
+    let addr = emulator.addr().to_string();
+    let socket = create_socket(&addr, 500).unwrap();
+    let name = osc_lib::OscMessage::builder("/ch/03/config/name")
+        .string("Gtr")
+        .build();
+    socket.send(&name.to_bytes().unwrap()).unwrap();
+
+    let buf = RECV_BUF.with(|buf| buf.borrow().as_ptr());
+    for _ in 0..10 {
+        assert_eq!(get_string(&socket, "/ch/03/config/name").unwrap(), "Gtr");
+    }
+    assert_eq!(RECV_BUF.with(|buf| buf.borrow().as_ptr()), buf);
+}
+
+#[test]
+fn test_console_skips_unrelated_packets() {
+    let emulator = X32Emulator::start().unwrap();
+    let addr = emulator.addr().to_string();
+    let console = Console::connect(&addr).unwrap();
+    console.subscribe().unwrap();
+
+    // A second client's change is pushed to the subscribed console before it queries.
+    let other = Console::connect(&addr).unwrap();
+    other
+        .set("/ch/02/mix/fader", vec![OscArg::Float(0.5)])
+        .unwrap();
+    // Once the emulator has answered this, the update has been pushed too.
+    other.query("/ch/02/mix/fader", vec![]).unwrap();
+
+    // The queued update is skipped rather than returned as the reply.
+    let reply = console.query("/info", vec![]).unwrap();
+    assert_eq!(reply.path, "/info");
+}
+
+#[test]
+fn test_console_query_times_out() {
+    // Nothing listens on this socket, so every attempt times out.
+    let silent = std::net::UdpSocket::bind("127.0.0.1:0").unwrap();
+    let console = Console::connect(&silent.local_addr().unwrap().to_string())
+        .unwrap()
+        .with_timeout(Duration::from_millis(20))
+        .with_retries(2);
+
+    assert!(matches!(
+        console.query("/info", vec![]),
+        Err(X32Error::Timeout { path }) if path == "/info"
+    ));
+
+    // The first attempt and both retries reach the socket.
+    silent
+        .set_read_timeout(Some(Duration::from_millis(100)))
+        .unwrap();
+    let mut buf = [0; 64];
+    for _ in 0..3 {
+        silent.recv(&mut buf).unwrap();
+    }
+}
+
+#[test]
+fn test_socket_helpers_report_timeout() {
+    let silent = std::net::UdpSocket::bind("127.0.0.1:0").unwrap();
+    let socket = create_socket(&silent.local_addr().unwrap().to_string(), 20).unwrap();
+
+    assert!(matches!(
+        get_int(&socket, "/ch/01/mix/on"),
+        Err(X32Error::Timeout { path }) if path == "/ch/01/mix/on"
+    ));
+}
+
+#[test]
+fn test_socket_helpers_report_path_mismatch() {
+    // A stand-in mixer that answers every query on the wrong address.
+    let server = std::net::UdpSocket::bind("127.0.0.1:0").unwrap();
+    let addr = server.local_addr().unwrap().to_string();
+    thread::spawn(move || {
+        let mut buf = [0; 512];
+        let (_, peer) = server.recv_from(&mut buf).unwrap();
+        let reply = OscMessage::new("/ch/02/mix/on".to_string(), vec![OscArg::Int(1)]);
+        server.send_to(&reply.to_bytes().unwrap(), peer).unwrap();
+    });
+
+    let socket = create_socket(&addr, 1000).unwrap();
+    match get_int(&socket, "/ch/01/mix/on") {
+        Err(X32Error::PathMismatch { expected, got }) => {
+            assert_eq!(expected, "/ch/01/mix/on");
+            assert_eq!(got, "/ch/02/mix/on");
+        }
+        other => panic!("Expected PathMismatch, got {:?}", other),
+    }
+}
+
+#[test]
+fn test_console_connect_from_local_port() {
+    let probe = std::net::UdpSocket::bind("0.0.0.0:0").unwrap();
+    let local_port = probe.local_addr().unwrap().port();
+    drop(probe);
+
+    let console = Console::connect_from("127.0.0.1", local_port).unwrap();
+    assert_eq!(console.socket().local_addr().unwrap().port(), local_port);
+    assert_eq!(console.socket().peer_addr().unwrap().port(), 10023);
+}
@@ -1,7 +1,7 @@
 #[cfg(test)]
 mod tests {
     use crate::error::*;
-    use osc_lib::OscError;
+    use osc_lib::{OscArg, OscError};
     use std::io;
 
     #[test]
@@ -33,9 +33,49 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_error_from_addr_parse() {
+        let err = crate::create_socket("not an ip", 100).unwrap_err();
+        assert!(matches!(err, X32Error::AddrParse(_)));
+    }
+
+    #[test]
+    fn test_type_mismatch_names_argument() {
+        let err = X32Error::type_mismatch("float", Some(&OscArg::String("Vox".to_string())));
+        assert!(matches!(
+            err,
+            X32Error::TypeMismatch {
+                expected: "float",
+                got: "string"
+            }
+        ));
+        let err = X32Error::type_mismatch("int", None);
+        assert!(matches!(err, X32Error::TypeMismatch { got: "none", .. }));
+    }
+
     #[test]
     fn test_error_display() {
         let err: X32Error = "test error".to_string().into();
         assert_eq!(format!("{}", err), "X32 error: test error");
+
+        let err = X32Error::Timeout {
+            path: "/info".to_string(),
+        };
+        assert_eq!(format!("{}", err), "Timeout waiting for /info");
+
+        let err = X32Error::TypeMismatch {
+            expected: "int",
+            got: "float",
+        };
+        assert_eq!(format!("{}", err), "Type mismatch: expected int, got float");
+
+        let err = X32Error::PathMismatch {
+            expected: "/ch/01/mix/on".to_string(),
+            got: "/ch/02/mix/on".to_string(),
+        };
+        assert_eq!(
+            format!("{}", err),
+            "Reply for /ch/02/mix/on while waiting for /ch/01/mix/on"
+        );
     }
 }
@@ -0,0 +1,55 @@
+use crate::fx::{FxType1, FxType2, type_id, type_name};
+use crate::*;
+use x32_emulator::testing::X32Emulator;
+
+#[test]
+fn test_fx_type1_round_trip() {
+    for (id, fx) in FxType1::ALL.into_iter().enumerate() {
+        assert_eq!(fx.id(), id as i32);
+        assert_eq!(FxType1::from_id(fx.id()), Some(fx));
+        assert_eq!(FxType1::from_name(fx.name()), Some(fx));
+    }
+    assert_eq!(FxType1::from_id(FxType1::ALL.len() as i32), None);
+    assert_eq!(FxType1::from_id(-1), None);
+}
+
+#[test]
+fn test_fx_type2_round_trip() {
+    for (id, fx) in FxType2::ALL.into_iter().enumerate() {
+        assert_eq!(fx.id(), id as i32);
+        assert_eq!(FxType2::from_id(fx.id()), Some(fx));
+        assert_eq!(FxType2::from_name(fx.name()), Some(fx));
+    }
+    assert_eq!(FxType2::from_id(FxType2::ALL.len() as i32), None);
+}
+
+#[test]
+fn test_fx_names() {
+    assert_eq!(FxType1::from_name("Hall"), Some(FxType1::Hall));
+    assert_eq!(FxType1::from_name("d/rv"), Some(FxType1::DelayReverb));
+    assert_eq!(FxType1::StereoDelay.name(), "DLY");
+    assert_eq!(FxType1::from_name("Unknown"), None);
+
+    // The same effect has a different id in slots 1-4 and 5-8.
+    assert_eq!(type_id(1, "GEQ2"), Some(27));
+    assert_eq!(type_id(5, "GEQ2"), Some(0));
+    assert_eq!(type_name(5, 0), Some("GEQ2"));
+    assert_eq!(type_id(5, "HALL"), None);
+    assert_eq!(type_id(9, "HALL"), None);
+}
+
+#[test]
+fn test_verify_fx_type_compares_ids() {
+    let emulator = X32Emulator::start_with(|mixer| {
+        mixer.seed_from_lines(vec!["/fx/1/type,i\t28", "/fx/5/type,i\t1"]);
+    })
+    .unwrap();
+    let addr = emulator.addr().to_string();
+
+    let socket = create_socket(&addr, 1000).unwrap();
+    // Slot 1 holds GEQ, which a substring match would also have accepted as "EQ".
+    assert!(verify_fx_type(&socket, 1, "GEQ").unwrap());
+    assert!(!verify_fx_type(&socket, 1, "GEQ2").unwrap());
+    assert!(verify_fx_type(&socket, 5, "geq").unwrap());
+    assert!(verify_fx_type(&socket, 1, "EQ").is_err());
+}
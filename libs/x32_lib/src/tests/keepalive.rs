@@ -0,0 +1,31 @@
+use super::client::MockTransport;
+use crate::client::MixerClient;
+use crate::keepalive::Keepalive;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+#[tokio::test]
+async fn test_keepalive_tick_respects_interval() {
+    let (transport, _tx) = MockTransport::new();
+    let client = MixerClient::new(transport.clone(), false);
+
+    let start = Instant::now();
+    let now = Arc::new(Mutex::new(start));
+    let clock = now.clone();
+    let mut keepalive =
+        Keepalive::with_clock(Duration::from_secs(9), move || *clock.lock().unwrap());
+
+    assert!(keepalive.tick(&client).await.unwrap());
+    assert!(!keepalive.tick(&client).await.unwrap());
+
+    *now.lock().unwrap() = start + Duration::from_secs(8);
+    assert!(!keepalive.tick(&client).await.unwrap());
+
+    *now.lock().unwrap() = start + Duration::from_secs(9);
+    assert!(keepalive.tick(&client).await.unwrap());
+    assert!(!keepalive.tick(&client).await.unwrap());
+
+    let sent = transport.get_sent_messages().await;
+    assert_eq!(sent.len(), 2);
+    assert!(sent.iter().all(|m| m.path == "/xremote" && m.args.is_empty()));
+}
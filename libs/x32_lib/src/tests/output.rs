@@ -21,4 +21,42 @@ mod tests {
         assert_eq!(args.len(), 1);
         assert_eq!(args[0], OscArg::Int(42));
     }
+
+    #[test]
+    fn test_set_source_and_tap() {
+        let (address, args) = set_source(5, 12);
+        assert_eq!(address, "/outputs/main/05/src");
+        assert_eq!(args, vec![OscArg::Int(12)]);
+
+        let (address, args) = set_tap(1, TapPoint::Post);
+        assert_eq!(address, "/outputs/main/01/pos");
+        assert_eq!(args, vec![OscArg::Int(8)]);
+
+        let (address, args) = set_tap(16, TapPoint::from_name("<eq+m").unwrap());
+        assert_eq!(address, "/outputs/main/16/pos");
+        assert_eq!(args, vec![OscArg::Int(3)]);
+    }
+
+    #[test]
+    fn test_tap_point_names() {
+        for (id, tap) in TapPoint::ALL.into_iter().enumerate() {
+            assert_eq!(TapPoint::from_id(id as u8), Some(tap));
+            assert_eq!(TapPoint::from_name(tap.name()), Some(tap));
+        }
+        assert_eq!(TapPoint::from_name("PRE+M"), Some(TapPoint::PreM));
+        assert_eq!(TapPoint::from_name("FADER"), None);
+        assert_eq!(TapPoint::from_id(9), None);
+    }
+
+    #[test]
+    fn test_set_phase_and_delay() {
+        let (address, args) = set_phase(2, true);
+        assert_eq!(address, "/outputs/main/02/invert");
+        assert_eq!(args, vec![OscArg::Int(1)]);
+
+        let (address, args) = set_delay(2, 500.0);
+        assert_eq!(address, "/outputs/main/02/delay/time");
+        assert_eq!(args, vec![OscArg::Float(1.0)]);
+        assert_eq!(set_delay(2, 0.0).1, vec![OscArg::Float(0.0)]);
+    }
 }
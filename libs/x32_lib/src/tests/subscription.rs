@@ -0,0 +1,29 @@
+use crate::subscription::*;
+use std::time::Duration;
+
+#[test]
+fn test_subscribe_message_bytes() {
+    let msg = subscribe_message("/ch/01/mix/fader", Duration::from_millis(100));
+    let mut expected = Vec::new();
+    expected.extend_from_slice(b"/subscribe\0\0");
+    expected.extend_from_slice(b",si\0");
+    expected.extend_from_slice(b"/ch/01/mix/fader\0\0\0\0");
+    expected.extend_from_slice(&2i32.to_be_bytes());
+    assert_eq!(msg.to_bytes().unwrap(), expected);
+}
+
+#[test]
+fn test_subscribe_interval_is_capped() {
+    let msg = subscribe_message("/-stat/solo", Duration::from_secs(5));
+    assert_eq!(msg.args[1], osc_lib::OscArg::Int(24));
+}
+
+#[test]
+fn test_renew_message_bytes() {
+    let msg = renew_message("/ch/01/mix/fader");
+    let mut expected = Vec::new();
+    expected.extend_from_slice(b"/renew\0\0");
+    expected.extend_from_slice(b",s\0\0");
+    expected.extend_from_slice(b"/ch/01/mix/fader\0\0\0\0");
+    assert_eq!(msg.to_bytes().unwrap(), expected);
+}
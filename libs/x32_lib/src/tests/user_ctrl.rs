@@ -0,0 +1,90 @@
+use crate::user_ctrl::*;
+use osc_lib::OscArg;
+
+// The assignments `x32_reaper` has always sent to bank C.
+const MP: [&str; 4] = ["MP13000", "MP14000", "MP15000", "MP16000"];
+const MN: [&str; 8] = [
+    "MN16000", "MN16001", "MN16002", "MN16003", "MN16004", "MN16005", "MN16006", "MN16007",
+];
+
+#[test]
+fn test_transport_matches_reaper_assignments() {
+    let messages = UserCtrl::new(Bank::C).transport().messages().unwrap();
+    assert_eq!(messages.len(), 24);
+
+    for (i, mp) in MP.iter().enumerate() {
+        let (address, args) = &messages[i];
+        assert_eq!(address, &format!("/config/userctrl/C/enc/{}", i + 1));
+        assert_eq!(args, &vec![OscArg::String(mp.to_string())]);
+    }
+    for (i, mn) in MN.iter().enumerate() {
+        let (address, args) = &messages[4 + i];
+        assert_eq!(address, &format!("/config/userctrl/C/btn/{}", i + 5));
+        assert_eq!(args, &vec![OscArg::String(mn.to_string())]);
+    }
+    for i in 0..4 {
+        let (address, args) = &messages[12 + i];
+        assert_eq!(address, &format!("/-stat/userpar/{}/value", 33 + i));
+        assert_eq!(args, &vec![OscArg::Int(64)]);
+    }
+    for i in 0..8 {
+        let (address, args) = &messages[16 + i];
+        assert_eq!(address, &format!("/-stat/userpar/{}/value", 17 + i));
+        assert_eq!(args, &vec![OscArg::Int(0)]);
+    }
+}
+
+#[test]
+fn test_single_button_matches_reaper_assignment() {
+    for btn in 5..=12u8 {
+        let messages = UserCtrl::new(Bank::C).button(btn).messages().unwrap();
+        assert_eq!(
+            messages,
+            vec![
+                (
+                    format!("/config/userctrl/C/btn/{}", btn),
+                    vec![OscArg::String(MN[btn as usize - 5].to_string())]
+                ),
+                (
+                    format!("/-stat/userpar/{:02}/value", 12 + btn),
+                    vec![OscArg::Int(0)]
+                ),
+            ]
+        );
+    }
+}
+
+#[test]
+fn test_userpar_numbering() {
+    assert_eq!(userpar(Bank::A, Control::Button(5)), 1);
+    assert_eq!(userpar(Bank::B, Control::Button(12)), 16);
+    assert_eq!(userpar(Bank::A, Control::Encoder(1)), 25);
+    assert_eq!(userpar(Bank::C, Control::Encoder(4)), 36);
+}
+
+#[test]
+fn test_assign_replaces_and_validates() {
+    let messages = UserCtrl::new(Bank::A)
+        .button(6)
+        .assign(Control::Button(6), "MN01064", 127)
+        .color(2)
+        .messages()
+        .unwrap();
+    assert_eq!(
+        messages,
+        vec![
+            (
+                "/config/userctrl/A/btn/6".to_string(),
+                vec![OscArg::String("MN01064".to_string())]
+            ),
+            (
+                "/-stat/userpar/02/value".to_string(),
+                vec![OscArg::Int(127)]
+            ),
+            ("/config/userctrl/A/color".to_string(), vec![OscArg::Int(2)]),
+        ]
+    );
+
+    assert!(UserCtrl::new(Bank::C).button(4).messages().is_err());
+    assert!(UserCtrl::new(Bank::C).encoder(5).messages().is_err());
+}
@@ -1,6 +1,6 @@
 use crate::error::Result;
 use async_trait::async_trait;
-use osc_lib::OscMessage;
+use osc_lib::{OscBundle, OscMessage, OscPacket};
 
 pub mod midi;
 pub mod udp;
@@ -10,6 +10,16 @@ pub mod udp;
 pub trait MixerTransport: Send + Sync {
     /// Sends an OSC message to the mixer.
     async fn send(&self, msg: OscMessage) -> Result<()>;
+    /// Sends a bundle of OSC messages to the mixer.
+    ///
+    /// The default implementation sends the messages one at a time, for transports
+    /// that cannot carry a bundle in one packet.
+    async fn send_bundle(&self, bundle: OscBundle) -> Result<()> {
+        for msg in OscPacket::Bundle(bundle).into_messages() {
+            self.send(msg).await?;
+        }
+        Ok(())
+    }
     /// Receives the next OSC message from the mixer.
     async fn recv(&self) -> Result<OscMessage>;
 }
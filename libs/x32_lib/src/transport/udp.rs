@@ -1,7 +1,7 @@
 use crate::error::Result;
 use crate::transport::MixerTransport;
 use async_trait::async_trait;
-use osc_lib::OscMessage;
+use osc_lib::{OscBundle, OscMessage};
 use std::net::SocketAddr;
 use std::sync::Arc;
 use tokio::net::UdpSocket;
@@ -14,6 +14,16 @@ pub struct UdpTransport {
 impl UdpTransport {
     /// Connects a new UdpTransport to the given IP address.
     pub async fn connect(ip: &str) -> Result<Self> {
+        Self::connect_from(ip, 0).await
+    }
+
+    /// Connects a new UdpTransport to the given IP address from a fixed local port.
+    ///
+    /// # Arguments
+    ///
+    /// * `ip` - The IP address of the mixer, with an optional port (default 10023).
+    /// * `local_port` - The local UDP port to send from, or 0 for a dynamic port.
+    pub async fn connect_from(ip: &str, local_port: u16) -> Result<Self> {
         let has_port = ip.contains("]:")
             || (ip.contains(':') && ip.chars().filter(|&c| c == ':').count() == 1);
 
@@ -26,11 +36,12 @@ impl UdpTransport {
         };
         let remote_addr: SocketAddr = full_ip.parse()?;
 
-        let local_addr: SocketAddr = if remote_addr.is_ipv4() {
+        let mut local_addr: SocketAddr = if remote_addr.is_ipv4() {
             "0.0.0.0:0".parse()?
         } else {
             "[::]:0".parse()?
         };
+        local_addr.set_port(local_port);
 
         let socket = UdpSocket::bind(local_addr).await?;
         socket.connect(remote_addr).await?;
@@ -48,6 +59,12 @@ impl MixerTransport for UdpTransport {
         Ok(())
     }
 
+    async fn send_bundle(&self, bundle: OscBundle) -> Result<()> {
+        let bytes = bundle.to_bytes()?;
+        self.socket.send(&bytes).await?;
+        Ok(())
+    }
+
     async fn recv(&self) -> Result<OscMessage> {
         let mut buf = vec![0u8; 1024 * 1024]; // 1MB Sentinel limit
         let len = self.socket.recv(&mut buf).await?;
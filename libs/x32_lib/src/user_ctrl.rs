@@ -0,0 +1,177 @@
+//! # User Controls Module
+//!
+//! Builds the `/config/userctrl` assignments for a user-assignable bank.
+//!
+//! Each of the three user banks (A, B, C) has four encoders (1-4) and eight buttons
+//! (5-12). A control is assigned a function string such as `MN16000`, and its current
+//! position is reported on `/-stat/userpar/NN/value`: buttons of bank A are userpar
+//! 1-8, bank B 9-16 and bank C 17-24, then encoders of bank A are 25-28, bank B 29-32
+//! and bank C 33-36.
+//!
+//! Tools that use a bank as a remote control (transport, markers, bank switching)
+//! assign each button a MIDI note on channel 16 (`MN16000`-`MN16007`) and each
+//! encoder a MIDI program on channels 13-16 (`MP13000`-`MP16000`), centring the
+//! encoders and releasing the buttons.
+
+use crate::error::{Result, X32Error};
+use osc_lib::OscArg;
+
+/// One of the three user-assignable banks.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Bank {
+    A,
+    B,
+    C,
+}
+
+impl Bank {
+    /// The bank's index as selected by `/-stat/userbank`.
+    pub fn index(self) -> i32 {
+        self as i32
+    }
+
+    /// The bank's letter in `/config/userctrl` addresses.
+    pub fn letter(self) -> char {
+        match self {
+            Bank::A => 'A',
+            Bank::B => 'B',
+            Bank::C => 'C',
+        }
+    }
+}
+
+/// A control within a user bank.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Control {
+    /// An encoder, 1-4.
+    Encoder(u8),
+    /// A button, 5-12.
+    Button(u8),
+}
+
+impl Control {
+    /// Fails unless the control number is in range for its kind.
+    fn validate(self) -> Result<()> {
+        match self {
+            Control::Encoder(n) if !(1..=4).contains(&n) => Err(X32Error::Custom(format!(
+                "Invalid encoder: {}. Must be between 1 and 4.",
+                n
+            ))),
+            Control::Button(n) if !(5..=12).contains(&n) => Err(X32Error::Custom(format!(
+                "Invalid button: {}. Must be between 5 and 12.",
+                n
+            ))),
+            _ => Ok(()),
+        }
+    }
+}
+
+/// Returns the `/-stat/userpar` number reporting `control` in `bank`.
+pub fn userpar(bank: Bank, control: Control) -> u8 {
+    let bank = bank as u8;
+    match control {
+        Control::Button(n) => (bank * 8 + n).saturating_sub(4),
+        Control::Encoder(n) => (24 + bank * 4).saturating_add(n),
+    }
+}
+
+/// A control's function string and the value its userpar starts at.
+#[derive(Debug, Clone, PartialEq)]
+struct Assignment {
+    control: Control,
+    function: String,
+    value: i32,
+}
+
+/// Builds the messages that configure a user bank.
+///
+/// # Example
+///
+/// ```
+/// use x32_lib::user_ctrl::{Bank, UserCtrl};
+///
+/// let messages = UserCtrl::new(Bank::C).button(5).color(3).messages().unwrap();
+/// assert_eq!(messages[0].0, "/config/userctrl/C/btn/5");
+/// assert_eq!(messages[1].0, "/-stat/userpar/17/value");
+/// assert_eq!(messages[2].0, "/config/userctrl/C/color");
+/// ```
+#[derive(Debug, Clone)]
+pub struct UserCtrl {
+    bank: Bank,
+    assignments: Vec<Assignment>,
+    color: Option<i32>,
+}
+
+impl UserCtrl {
+    /// Starts an empty set of assignments for `bank`.
+    pub fn new(bank: Bank) -> Self {
+        Self {
+            bank,
+            assignments: Vec::new(),
+            color: None,
+        }
+    }
+
+    /// Assigns `function` to `control` and starts its userpar at `value`.
+    ///
+    /// A later assignment to the same control replaces the earlier one.
+    pub fn assign(mut self, control: Control, function: impl Into<String>, value: i32) -> Self {
+        self.assignments.retain(|a| a.control != control);
+        self.assignments.push(Assignment {
+            control,
+            function: function.into(),
+            value,
+        });
+        self
+    }
+
+    /// Assigns encoder `n` (1-4) its remote-control program, centred at 64.
+    pub fn encoder(self, n: u8) -> Self {
+        let function = format!("MP{:02}000", 12 + n as u32);
+        self.assign(Control::Encoder(n), function, 64)
+    }
+
+    /// Assigns button `n` (5-12) its remote-control note, released.
+    pub fn button(self, n: u8) -> Self {
+        let function = format!("MN16{:03}", (n as u32).saturating_sub(5));
+        self.assign(Control::Button(n), function, 0)
+    }
+
+    /// Assigns every encoder and button for transport control.
+    pub fn transport(self) -> Self {
+        let ctrl = (1..=4).fold(self, Self::encoder);
+        (5..=12).fold(ctrl, Self::button)
+    }
+
+    /// Sets the bank's color.
+    pub fn color(mut self, color: i32) -> Self {
+        self.color = Some(color);
+        self
+    }
+
+    /// Returns the address/args pairs to send: the control assignments, then their
+    /// starting userpar values, then the color.
+    ///
+    /// Fails if any assigned control is out of range.
+    pub fn messages(&self) -> Result<Vec<(String, Vec<OscArg>)>> {
+        let letter = self.bank.letter();
+        let mut messages = Vec::with_capacity(self.assignments.len() * 2 + 1);
+        for a in &self.assignments {
+            a.control.validate()?;
+            let address = match a.control {
+                Control::Encoder(n) => format!("/config/userctrl/{}/enc/{}", letter, n),
+                Control::Button(n) => format!("/config/userctrl/{}/btn/{}", letter, n),
+            };
+            messages.push((address, vec![OscArg::String(a.function.clone())]));
+        }
+        for a in &self.assignments {
+            let address = format!("/-stat/userpar/{:02}/value", userpar(self.bank, a.control));
+            messages.push((address, vec![OscArg::Int(a.value)]));
+        }
+        if let Some(color) = self.color {
+            let address = format!("/config/userctrl/{}/color", letter);
+            messages.push((address, vec![OscArg::Int(color)]));
+        }
+        Ok(messages)
+    }
+}
@@ -0,0 +1,38 @@
+#![cfg(feature = "async")]
+
+use osc_lib::OscArg;
+use std::time::Duration;
+use x32_emulator::testing::X32Emulator;
+use x32_lib::AsyncConsole;
+
+#[tokio::test]
+async fn test_async_console_set_and_query() {
+    let emulator = X32Emulator::start().unwrap();
+    let console = AsyncConsole::connect(&emulator.addr().to_string())
+        .await
+        .unwrap();
+
+    let info = console.query("/info", vec![]).await.unwrap();
+    assert_eq!(info.path, "/info");
+
+    console
+        .set("/ch/03/mix/fader", vec![OscArg::Float(0.25)])
+        .await
+        .unwrap();
+    let reply = console.query("/ch/03/mix/fader", vec![]).await.unwrap();
+    assert_eq!(reply.arg_float(0), Some(0.25));
+    emulator.assert_param("/ch/03/mix/fader", OscArg::Float(0.25));
+}
+
+#[tokio::test]
+async fn test_async_console_query_times_out() {
+    // Nothing answers on this socket, so every attempt times out.
+    let silent = std::net::UdpSocket::bind("127.0.0.1:0").unwrap();
+    let console = AsyncConsole::connect(&silent.local_addr().unwrap().to_string())
+        .await
+        .unwrap()
+        .with_timeout(Duration::from_millis(20))
+        .with_retries(1);
+
+    assert!(console.query("/info", vec![]).await.is_err());
+}
@@ -1,20 +1,12 @@
 #![allow(deprecated)]
-use std::thread;
-use x32_emulator::server;
+use x32_emulator::testing::X32Emulator;
 use x32_lib::command::fx;
 use x32_lib::create_socket;
 
 #[test]
 fn test_xfx_set_array() {
-    // Start the emulator in a separate thread
-    thread::spawn(|| {
-        server::run("127.0.0.1:10023", None, None).unwrap();
-    });
-
-    // Give the server a moment to start
-    thread::sleep(std::time::Duration::from_millis(100));
-
-    let socket = create_socket("127.0.0.1", 2000).unwrap();
+    let emulator = X32Emulator::start().unwrap();
+    let socket = create_socket(&emulator.addr().to_string(), 2000).unwrap();
     let msg = fx::set_fx_param(&socket, 1, 1, 0.5);
     assert!(msg.is_ok());
 
@@ -167,7 +167,9 @@ async fn main() -> Result<()> {
             .map_err(anyhow::Error::msg),
         Commands::X32Usb(args) => x32_usb::run(args).await.map_err(anyhow::Error::msg),
         Commands::X32Midi2osc(args) => x32_midi2osc::run(args).await.map_err(anyhow::Error::msg),
-        Commands::X32Emulator(args) => x32_emulator::run(args).map_err(anyhow::Error::msg),
+        Commands::X32Emulator(args) => x32_emulator::shutdown_on_signals()
+            .and_then(|terminate| x32_emulator::run(args, terminate))
+            .map_err(anyhow::Error::msg),
         Commands::X32Crossfade(args) => x32_crossfade::run(args).await.map_err(anyhow::Error::msg),
         Commands::X32AutoGain(args) => x32_auto_gain::run(args).await.map_err(anyhow::Error::msg),
         Commands::X32AutoRingout(args) => x32_auto_ringout::run(args)
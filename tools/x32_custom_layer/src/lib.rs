@@ -1,11 +1,14 @@
 //! `x32_custom_layer` is a command-line tool for creating and managing custom channel layers on X32/M32 mixers.
 //!
 //! It provides functionality to:
-//! - **Set**: Assign any source channel (1-32 or Aux 1-8) to any destination channel strip.
+//! - **Set**: Assign any source channel (1-32 or Aux 1-8) to any destination channel strip, or
+//!   copy mix buses and matrices onto each other.
 //! - **Save**: Save the current custom layer configuration to a file.
 //! - **Restore**: Restore a previously saved custom layer.
-//! - **Reset**: Reset specific channels to their default "1:1" mapping (e.g., channel 1 source is input 1).
+//! - **Reset**: Reset specific channels to their default "1:1" mapping (e.g., channel 1 source is input 1),
+//!   or buses and matrices to their factory settings.
 //! - **List**: Display the current source assignments for all channels.
+//! - **Diff**: Show the parameters that differ between a saved layer file and the console.
 //!
 //! # Credits
 //!
@@ -101,6 +104,90 @@ const ACH_NODES: [&str; 29] = [
     "/auxin/01/preamp",
 ];
 
+/// OSC nodes to query for a mix bus (1-16).
+const BUS_NODES: [&str; 19] = [
+    "/bus/01/config",
+    "/bus/01/dyn",
+    "/bus/01/dyn/filter",
+    "/bus/01/insert",
+    "/bus/01/eq",
+    "/bus/01/eq/1",
+    "/bus/01/eq/2",
+    "/bus/01/eq/3",
+    "/bus/01/eq/4",
+    "/bus/01/eq/5",
+    "/bus/01/eq/6",
+    "/bus/01/mix",
+    "/bus/01/mix/01",
+    "/bus/01/mix/02",
+    "/bus/01/mix/03",
+    "/bus/01/mix/04",
+    "/bus/01/mix/05",
+    "/bus/01/mix/06",
+    "/bus/01/grp",
+];
+
+/// OSC nodes to query for a matrix (1-6).
+const MTX_NODES: [&str; 12] = [
+    "/mtx/01/config",
+    "/mtx/01/preamp",
+    "/mtx/01/dyn",
+    "/mtx/01/insert",
+    "/mtx/01/eq",
+    "/mtx/01/eq/1",
+    "/mtx/01/eq/2",
+    "/mtx/01/eq/3",
+    "/mtx/01/eq/4",
+    "/mtx/01/eq/5",
+    "/mtx/01/eq/6",
+    "/mtx/01/mix",
+];
+
+/// Factory-default node strings for mix bus 01, used by `reset`.
+const BUS_INISTR: [&str; 24] = [
+    "/bus/01/config \"\" 1 CY",
+    "/bus/01/dyn OFF COMP PEAK LOG 0.0 3.0 1 0.00 10 10.0 151 POST 0 100 OFF",
+    "/bus/01/dyn/filter OFF 3.0 990.9",
+    "/bus/01/insert OFF POST OFF",
+    "/bus/01/eq OFF",
+    "/bus/01/eq/1 PEQ 124.7 +0.00 2.0",
+    "/bus/01/eq/2 PEQ 496.6 +0.00 2.0",
+    "/bus/01/eq/3 PEQ 1k97 +0.00 2.0",
+    "/bus/01/eq/4 PEQ 3k97 +0.00 2.0",
+    "/bus/01/eq/5 PEQ 7k93 +0.00 2.0",
+    "/bus/01/eq/6 HShv 10k02 +0.00 2.0",
+    "/bus/01/mix/fader -oo",
+    "/bus/01/mix/pan +0",
+    "/bus/01/mix/on ON",
+    "/bus/01/mix/st ON",
+    "/bus/01/mix/mono OFF",
+    "/bus/01/mix/mlevel -oo",
+    "/bus/01/mix/01 ON -oo +0 POST",
+    "/bus/01/mix/02 ON -oo",
+    "/bus/01/mix/03 ON -oo +0 POST",
+    "/bus/01/mix/04 ON -oo",
+    "/bus/01/mix/05 ON -oo +0 POST",
+    "/bus/01/mix/06 ON -oo",
+    "/bus/01/grp %00000000 %000000",
+];
+
+/// Factory-default node strings for matrix 01, used by `reset`.
+const MTX_INISTR: [&str; 13] = [
+    "/mtx/01/config \"\" 1 CY",
+    "/mtx/01/preamp OFF",
+    "/mtx/01/dyn OFF COMP PEAK LOG 0.0 3.0 1 0.00 10 10.0 151 POST 0 100 OFF",
+    "/mtx/01/insert OFF POST OFF",
+    "/mtx/01/eq OFF",
+    "/mtx/01/eq/1 PEQ 124.7 +0.00 2.0",
+    "/mtx/01/eq/2 PEQ 496.6 +0.00 2.0",
+    "/mtx/01/eq/3 PEQ 1k97 +0.00 2.0",
+    "/mtx/01/eq/4 PEQ 3k97 +0.00 2.0",
+    "/mtx/01/eq/5 PEQ 7k93 +0.00 2.0",
+    "/mtx/01/eq/6 HShv 10k02 +0.00 2.0",
+    "/mtx/01/mix/fader -oo",
+    "/mtx/01/mix/on ON",
+];
+
 #[derive(Parser, Debug)]
 #[command(author, version, about, long_about = None)]
 pub struct Cli {
@@ -125,37 +212,141 @@ pub enum Commands {
     Set { assignments: Vec<String> },
     Save { file: String },
     Restore { file: String },
+    Diff { file: String },
     Reset { channels: String },
     List,
 }
 
+/// A strip that can take part in a custom layer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+enum Strip {
+    /// Input channel 1-32.
+    Channel(u8),
+    /// Aux input 1-8.
+    Aux(u8),
+    /// Mix bus 1-16.
+    Bus(u8),
+    /// Matrix 1-6.
+    Matrix(u8),
+}
+
+impl Strip {
+    /// Maps the plain numbering used so far (1-32 channels, 33-40 aux inputs) to a strip.
+    fn from_number(n: u8) -> Option<Self> {
+        match n {
+            1..=32 => Some(Strip::Channel(n)),
+            33..=40 => Some(Strip::Aux(n - 32)),
+            _ => None,
+        }
+    }
+
+    /// Returns the strip of the same kind numbered `n`, if there is one.
+    fn with_index(&self, n: u8) -> Option<Self> {
+        let (strip, count) = match self {
+            Strip::Channel(_) => (Strip::Channel(n), 32),
+            Strip::Aux(_) => (Strip::Aux(n), 8),
+            Strip::Bus(_) => (Strip::Bus(n), 16),
+            Strip::Matrix(_) => (Strip::Matrix(n), 6),
+        };
+        (1..=count).contains(&n).then_some(strip)
+    }
+
+    /// The strip number within its kind, e.g. 3 for `bus3`.
+    fn index(&self) -> u8 {
+        match *self {
+            Strip::Channel(n) | Strip::Aux(n) | Strip::Bus(n) | Strip::Matrix(n) => n,
+        }
+    }
+
+    /// The node templates of this kind of strip, all written for strip 01.
+    fn nodes(&self) -> &'static [&'static str] {
+        match self {
+            Strip::Channel(_) => &SCH_NODES,
+            Strip::Aux(_) => &ACH_NODES,
+            Strip::Bus(_) => &BUS_NODES,
+            Strip::Matrix(_) => &MTX_NODES,
+        }
+    }
+
+    /// Returns `true` for strips fed by an input source, i.e. channels and aux inputs.
+    fn is_input(&self) -> bool {
+        matches!(self, Strip::Channel(_) | Strip::Aux(_))
+    }
+
+    /// The `/config` source value selecting this strip's own input.
+    fn source_id(&self) -> i32 {
+        match *self {
+            Strip::Channel(n) => n as i32 - 1,
+            Strip::Aux(n) => n as i32 + 31,
+            Strip::Bus(_) | Strip::Matrix(_) => 0,
+        }
+    }
+
+    /// Rewrites a node template, e.g. `/bus/01/eq/1`, for this strip.
+    fn remap(&self, node: &str) -> String {
+        node.replace("/01/", &format!("/{:02}/", self.index()))
+    }
+}
+
+impl std::fmt::Display for Strip {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match *self {
+            Strip::Channel(n) => write!(f, "ch{}", n),
+            Strip::Aux(n) => write!(f, "aux{}", n),
+            Strip::Bus(n) => write!(f, "bus{}", n),
+            Strip::Matrix(n) => write!(f, "mtx{}", n),
+        }
+    }
+}
+
+impl FromStr for Strip {
+    type Err = X32Error;
+
+    /// Parses `5`, `ch5`, `aux3`, `bus12` or `mtx2`. Plain numbers 33-40 are aux inputs 1-8.
+    fn from_str(s: &str) -> Result<Self> {
+        let lower = s.trim().to_ascii_lowercase();
+        let digits = lower
+            .find(|c: char| c.is_ascii_digit())
+            .ok_or_else(|| X32Error::Custom(format!("Invalid strip: {}", s)))?;
+        let (kind, number) = lower.split_at(digits);
+        let n =
+            u8::from_str(number).map_err(|_| X32Error::Custom(format!("Invalid strip: {}", s)))?;
+
+        let (strip, range) = match kind {
+            "" => (Strip::from_number(n), "1-40"),
+            "ch" => (Strip::Channel(1).with_index(n), "ch1-ch32"),
+            "aux" => (Strip::Aux(1).with_index(n), "aux1-aux8"),
+            "bus" => (Strip::Bus(1).with_index(n), "bus1-bus16"),
+            "mtx" => (Strip::Matrix(1).with_index(n), "mtx1-mtx6"),
+            _ => return Err(X32Error::Custom(format!("Invalid strip: {}", s))),
+        };
+        strip.ok_or_else(|| X32Error::Custom(format!("Strip {} out of range ({})", s, range)))
+    }
+}
+
+#[derive(Debug, PartialEq)]
 struct Assignment {
-    dest: u8,
-    src: u8,
+    dest: Strip,
+    src: Strip,
 }
 
 fn parse_assignments(assignments_str: &[String]) -> Result<Vec<Assignment>> {
     let mut assignments = Vec::new();
     for a in assignments_str {
         let (dest_str, src_str) = a
-            .split_once(':')
+            .split_once([':', '='])
             .ok_or_else(|| X32Error::Custom(format!("Invalid assignment format: {}", a)))?;
 
-        let dest = u8::from_str(dest_str)
-            .map_err(|_| X32Error::Custom(format!("Invalid destination channel: {}", dest_str)))?;
-        let src = u8::from_str(src_str)
-            .map_err(|_| X32Error::Custom(format!("Invalid source channel: {}", src_str)))?;
+        let dest = Strip::from_str(dest_str)?;
+        let src = Strip::from_str(src_str)?;
 
-        if dest == 0 || dest > 40 {
+        // Buses and matrices have no input source to remap, only their own settings to copy.
+        if !(dest.is_input() && src.is_input())
+            && std::mem::discriminant(&dest) != std::mem::discriminant(&src)
+        {
             return Err(X32Error::Custom(format!(
-                "Destination channel {} out of range (1-40)",
-                dest
-            )));
-        }
-        if src == 0 || src > 40 {
-            return Err(X32Error::Custom(format!(
-                "Source channel {} out of range (1-40)",
-                src
+                "Cannot assign {} to {}: buses and matrices only take their own kind",
+                src, dest
             )));
         }
         assignments.push(Assignment { dest, src });
@@ -184,6 +375,7 @@ pub async fn run(cli: Cli) -> anyhow::Result<()> {
         Commands::Set { assignments } => handle_set_command(&client, assignments).await,
         Commands::Save { file } => handle_save_command(&client, file).await,
         Commands::Restore { file } => handle_restore_command(&client, file).await,
+        Commands::Diff { file } => handle_diff_command(&client, file).await,
         Commands::Reset { channels } => handle_reset_command(&client, channels).await,
         Commands::List => handle_list_command(&client).await,
     };
@@ -198,31 +390,16 @@ pub async fn run(cli: Cli) -> anyhow::Result<()> {
 
 async fn handle_set_command(client: &MixerClient, assignments_str: &[String]) -> Result<()> {
     let assignments = parse_assignments(assignments_str)?;
-    let mut saved_strips: HashMap<u8, Vec<String>> = HashMap::new();
+    let mut saved_strips: HashMap<Strip, Vec<String>> = HashMap::new();
 
     println!("Saving states of source channels...");
     for a in &assignments {
         if let std::collections::hash_map::Entry::Vacant(e) = saved_strips.entry(a.src) {
             // ⚡ Bolt: Pre-allocate capacity for strip data vector to avoid N dynamic reallocations
-            let mut strip_data = if a.src <= 32 {
-                Vec::with_capacity(SCH_NODES.len())
-            } else {
-                Vec::with_capacity(ACH_NODES.len())
-            };
-            if a.src <= 32 {
-                // ⚡ Bolt: Hoist string formatting outside the node loop to prevent O(N) allocations
-                let src_repl = format!("/{:02}/", a.src);
-                for &node in SCH_NODES.iter() {
-                    let formatted_node = node.replace("/01/", &src_repl);
-                    strip_data.push(get_node_state(client, &formatted_node).await?);
-                }
-            } else {
-                // ⚡ Bolt: Hoist string formatting outside the node loop to prevent O(N) allocations
-                let src_repl = format!("/{:02}/", a.src - 32);
-                for &node in ACH_NODES.iter() {
-                    let formatted_node = node.replace("/01/", &src_repl);
-                    strip_data.push(get_node_state(client, &formatted_node).await?);
-                }
+            let mut strip_data = Vec::with_capacity(a.src.nodes().len());
+            for &node in a.src.nodes() {
+                let formatted_node = a.src.remap(node);
+                strip_data.push(get_node_state(client, &formatted_node).await?);
             }
             e.insert(strip_data);
         }
@@ -231,66 +408,40 @@ async fn handle_set_command(client: &MixerClient, assignments_str: &[String]) ->
     println!("Applying states to destination channels...");
     for a in &assignments {
         let strip_data = saved_strips.get(&a.src).unwrap();
-        if a.dest <= 32 {
-            // ⚡ Bolt: Hoist string formatting outside the node loop to prevent O(N) allocations
-            let dest_repl = format!("/{:02}/", a.dest);
-            for (i, &node) in SCH_NODES.iter().enumerate() {
-                let dest_node = node.replace("/01/", &dest_repl);
-                let mut state_to_apply = strip_data[i].clone();
-                if let Some(pos) = state_to_apply.find(' ') {
-                    state_to_apply.replace_range(..pos, &dest_node);
-                }
-
-                let msg = OscMessage::from_str(&state_to_apply)?;
-                client.send_message(&msg.path, msg.args).await?;
+        for (&node, state) in a.dest.nodes().iter().zip(strip_data) {
+            let dest_node = a.dest.remap(node);
+            let mut state_to_apply = state.clone();
+            if let Some(pos) = state_to_apply.find(' ') {
+                state_to_apply.replace_range(..pos, &dest_node);
             }
-            let config_node = format!("/ch/{:02}/config", a.dest);
-            let config_val = if a.src <= 32 {
-                a.src - 1
-            } else {
-                a.src - 33 + 32
-            };
-            client
-                .send_message(
-                    &config_node,
-                    vec![
-                        OscArg::String(format!("C{:02}", a.src)),
-                        OscArg::Int(22), // custom color
-                        OscArg::Int(config_val as i32),
-                        OscArg::Int(0),
-                    ],
-                )
-                .await?;
-        } else {
-            // ⚡ Bolt: Hoist string formatting outside the node loop to prevent O(N) allocations
-            let dest_repl = format!("/{:02}/", a.dest - 32);
-            for (i, &node) in ACH_NODES.iter().enumerate() {
-                let dest_node = node.replace("/01/", &dest_repl);
-                let mut state_to_apply = strip_data[i].clone();
-                if let Some(pos) = state_to_apply.find(' ') {
-                    state_to_apply.replace_range(..pos, &dest_node);
-                }
-                let msg = OscMessage::from_str(&state_to_apply)?;
-                client.send_message(&msg.path, msg.args).await?;
-            }
-            let config_node = format!("/auxin/{:02}/config", a.dest - 32);
-            let config_val = if a.src <= 32 {
-                a.src - 1
-            } else {
-                a.src - 33 + 32
-            };
-            client
-                .send_message(
-                    &config_node,
-                    vec![
-                        OscArg::String(format!("A{:02}", a.src - 32)),
-                        OscArg::Int(22), // custom color
-                        OscArg::Int(config_val as i32),
-                        OscArg::Int(0),
-                    ],
-                )
-                .await?;
+
+            let msg = OscMessage::from_str(&state_to_apply)?;
+            client.send_message(&msg.path, msg.args).await?;
         }
+
+        let (config_node, name) = match a.dest {
+            Strip::Channel(n) => (
+                format!("/ch/{:02}/config", n),
+                format!("C{:02}", a.src.index()),
+            ),
+            Strip::Aux(n) => (
+                format!("/auxin/{:02}/config", n),
+                format!("A{:02}", a.src.index()),
+            ),
+            // Buses and matrices keep the config copied from their source.
+            Strip::Bus(_) | Strip::Matrix(_) => continue,
+        };
+        client
+            .send_message(
+                &config_node,
+                vec![
+                    OscArg::String(name),
+                    OscArg::Int(22), // custom color
+                    OscArg::Int(a.src.source_id()),
+                    OscArg::Int(0),
+                ],
+            )
+            .await?;
     }
     println!("Set command completed.");
     Ok(())
@@ -335,7 +486,8 @@ fn format_node_state(args: &[OscArg]) -> Result<String> {
         for arg in args.iter().skip(1) {
             match arg {
                 OscArg::Float(f) => {
-                    write!(result, " {:.4}", f).unwrap();
+                    result.push(' ');
+                    osc_lib::write_x32_float(&mut result, *f).unwrap();
                 }
                 OscArg::Int(i) => {
                     write!(result, " {}", i).unwrap();
@@ -350,6 +502,10 @@ fn format_node_state(args: &[OscArg]) -> Result<String> {
                         write!(result, "{:02x}", byte).unwrap();
                     }
                 }
+                OscArg::Bool(b) => {
+                    write!(result, " {}", if *b { "ON" } else { "OFF" }).unwrap();
+                }
+                OscArg::Nil | OscArg::Infinitum => {}
             }
         }
         Ok(result)
@@ -367,8 +523,18 @@ async fn get_node_state(client: &MixerClient, node: &str) -> Result<String> {
     let timeout_dur = Duration::from_secs(2);
     while start.elapsed() < timeout_dur {
         if let Ok(Ok(msg)) = timeout(timeout_dur - start.elapsed(), rx.recv()).await {
-            if msg.path == "/node" {
-                if let Some(OscArg::String(response_node)) = msg.args.first() {
+            if msg.path == "node" || msg.path == "/node" {
+                if let Some(response_node) = msg.arg_str(0) {
+                    // The console replies with a single string: the full node path,
+                    // its values and a trailing newline.
+                    let line = response_node.trim_end_matches('\n');
+                    let path = format!("/{}", node.trim_start_matches('/'));
+                    if line
+                        .strip_prefix(&path)
+                        .is_some_and(|rest| rest.is_empty() || rest.starts_with(' '))
+                    {
+                        return Ok(line.to_string());
+                    }
                     if response_node == node {
                         return format_node_state(&msg.args);
                     }
@@ -382,7 +548,8 @@ async fn get_node_state(client: &MixerClient, node: &str) -> Result<String> {
     )))
 }
 
-async fn handle_restore_command(client: &MixerClient, file_path: &str) -> Result<()> {
+/// Reads a saved layer file, refusing anything over 1 MiB.
+fn read_layer_file(file_path: &str) -> Result<String> {
     let file = File::open(file_path)?;
 
     if file.metadata()?.len() > 1024 * 1024 {
@@ -394,6 +561,11 @@ async fn handle_restore_command(client: &MixerClient, file_path: &str) -> Result
     if content.len() > 1024 * 1024 {
         return Err(X32Error::Custom("File too large".to_string()));
     }
+    Ok(content)
+}
+
+async fn handle_restore_command(client: &MixerClient, file_path: &str) -> Result<()> {
+    let content = read_layer_file(file_path)?;
     let mut reader = std::io::Cursor::new(content);
 
     println!("Restoring configuration from {}...", file_path);
@@ -449,30 +621,34 @@ async fn handle_reset_command(client: &MixerClient, channels_str: &str) -> Resul
     let channels_to_reset = parse_channel_range(channels_str)?;
     let mut rx = client.subscribe();
 
-    for &ch in &channels_to_reset {
-        let config_node = if ch <= 32 {
-            format!("/ch/{:02}/config", ch)
-        } else {
-            format!("/auxin/{:02}/config", ch - 32)
-        };
-
-        let src_val = if ch <= 32 {
-            ch as i32 - 1
-        } else {
-            ch as i32 - 33 + 32
+    for &strip in &channels_to_reset {
+        let (config_node, name) = match strip {
+            Strip::Channel(n) => (format!("/ch/{:02}/config", n), format!("CH{:02}", n)),
+            Strip::Aux(n) => (format!("/auxin/{:02}/config", n), format!("A{:02}", n)),
+            Strip::Bus(_) | Strip::Matrix(_) => {
+                let inistr: &[&str] = if let Strip::Bus(_) = strip {
+                    &BUS_INISTR
+                } else {
+                    &MTX_INISTR
+                };
+                // The baselines are in node text form, which the console takes via "/".
+                for line in inistr {
+                    client
+                        .send_message("/", vec![OscArg::String(strip.remap(line))])
+                        .await?;
+                    let _ = timeout(Duration::from_millis(5), rx.recv()).await;
+                }
+                continue;
+            }
         };
 
         client
             .send_message(
                 &config_node,
                 vec![
-                    OscArg::String(format!(
-                        "{}{:02}",
-                        if ch <= 32 { "CH" } else { "A" },
-                        if ch <= 32 { ch } else { ch - 32 }
-                    )),
+                    OscArg::String(name),
                     OscArg::Int(1), // default color
-                    OscArg::Int(src_val),
+                    OscArg::Int(strip.source_id()),
                     OscArg::Int(0),
                 ],
             )
@@ -480,34 +656,36 @@ async fn handle_reset_command(client: &MixerClient, channels_str: &str) -> Resul
 
         let _ = timeout(Duration::from_millis(5), rx.recv()).await;
     }
-    println!("Reset completed for channels: {:?}", channels_to_reset);
+    let names: Vec<String> = channels_to_reset.iter().map(Strip::to_string).collect();
+    println!("Reset completed for: {}", names.join(", "));
     Ok(())
 }
 
-fn parse_channel_range(range_str: &str) -> Result<Vec<u8>> {
+/// Parses a list such as `1,3,5-8,bus1-4,mtx2` into strips.
+///
+/// A range takes the kind of its first strip, so `bus1-4` and `bus1-bus4` are the same.
+fn parse_channel_range(range_str: &str) -> Result<Vec<Strip>> {
     let mut channels = Vec::new();
     for part in range_str.split(',') {
         let part = part.trim();
-        if let Some(pos) = part.find('-') {
-            let start = u8::from_str(&part[..pos]).map_err(|_| {
-                X32Error::Custom(format!("Invalid start channel: {}", &part[..pos]))
-            })?;
-            let end = u8::from_str(&part[pos + 1..]).map_err(|_| {
-                X32Error::Custom(format!("Invalid end channel: {}", &part[pos + 1..]))
-            })?;
-            if start > end || start == 0 || end > 40 {
-                return Err(X32Error::Custom(format!("Invalid range: {}", part)));
-            }
-            for i in start..=end {
-                channels.push(i);
-            }
+        if let Some((start_str, end_str)) = part.split_once('-') {
+            let start = Strip::from_str(start_str)?;
+            let end_index = end_str.trim_start_matches(|c: char| c.is_ascii_alphabetic());
+            let end = u8::from_str(end_index)
+                .map_err(|_| X32Error::Custom(format!("Invalid end channel: {}", end_str)))?;
+            let range: Option<Vec<Strip>> = match u8::from_str(start_str.trim()) {
+                // Plain numbers run across channels into aux inputs, as before.
+                Ok(first) if first <= end => (first..=end).map(Strip::from_number).collect(),
+                Ok(_) => None,
+                Err(_) if start.index() <= end => {
+                    (start.index()..=end).map(|n| start.with_index(n)).collect()
+                }
+                Err(_) => None,
+            };
+            channels
+                .extend(range.ok_or_else(|| X32Error::Custom(format!("Invalid range: {}", part)))?);
         } else {
-            let ch = u8::from_str(part)
-                .map_err(|_| X32Error::Custom(format!("Invalid channel: {}", part)))?;
-            if ch == 0 || ch > 40 {
-                return Err(X32Error::Custom(format!("Channel {} out of range", ch)));
-            }
-            channels.push(ch);
+            channels.push(Strip::from_str(part)?);
         }
     }
     channels.sort_unstable();
@@ -515,6 +693,85 @@ fn parse_channel_range(range_str: &str) -> Result<Vec<u8>> {
     Ok(channels)
 }
 
+/// One parameter whose saved value differs from the console.
+#[derive(Debug, PartialEq)]
+struct ParamDiff {
+    path: String,
+    /// The 1-based position of the parameter in the node string.
+    index: usize,
+    saved: Option<String>,
+    live: Option<String>,
+}
+
+/// Compares saved node strings with the live ones, pairwise, parameter by parameter.
+///
+/// Both sides are tokenized first, so spacing and quoting do not count as differences.
+///
+/// # Arguments
+///
+/// * `saved` - Node strings from a layer file, e.g. `/ch/01/mix/fader -oo`.
+/// * `live` - The console's node strings, in the same order.
+///
+/// # Returns
+///
+/// The parameters that differ, in order.
+fn diff_node_states(saved: &[&str], live: &[String]) -> Result<Vec<ParamDiff>> {
+    let mut diffs = Vec::new();
+    for (saved_line, live_line) in saved.iter().zip(live) {
+        let saved_tokens = osc_lib::tokenize(saved_line)?;
+        let live_tokens = osc_lib::tokenize(live_line)?;
+        let Some(path) = saved_tokens.first() else {
+            continue;
+        };
+        let count = saved_tokens.len().max(live_tokens.len());
+        for index in 1..count {
+            let saved_value = saved_tokens.get(index);
+            let live_value = live_tokens.get(index);
+            if saved_value != live_value {
+                diffs.push(ParamDiff {
+                    path: path.clone(),
+                    index,
+                    saved: saved_value.cloned(),
+                    live: live_value.cloned(),
+                });
+            }
+        }
+    }
+    Ok(diffs)
+}
+
+async fn handle_diff_command(client: &MixerClient, file_path: &str) -> Result<()> {
+    let content = read_layer_file(file_path)?;
+    let saved: Vec<&str> = content
+        .lines()
+        .map(str::trim)
+        .filter(|line| line.starts_with('/'))
+        .collect();
+
+    let mut live = Vec::with_capacity(saved.len());
+    for line in &saved {
+        let path = line.split_whitespace().next().unwrap_or(line);
+        live.push(get_node_state(client, path).await?);
+    }
+
+    let diffs = diff_node_states(&saved, &live)?;
+    for diff in &diffs {
+        println!(
+            "{} [{}]: saved {}, live {}",
+            diff.path,
+            diff.index,
+            diff.saved.as_deref().unwrap_or("(none)"),
+            diff.live.as_deref().unwrap_or("(none)")
+        );
+    }
+    if diffs.is_empty() {
+        println!("The console matches {}.", file_path);
+    } else {
+        println!("{} parameter(s) differ from {}.", diffs.len(), file_path);
+    }
+    Ok(())
+}
+
 async fn handle_list_command(client: &MixerClient) -> Result<()> {
     println!("Current Channel Assignments:");
     println!("----------------------------");
@@ -591,3 +848,118 @@ fn map_source_id_to_name(id: i32) -> &'static str {
         _ => "OFF",
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn strings(items: &[&str]) -> Vec<String> {
+        items.iter().map(|s| s.to_string()).collect()
+    }
+
+    #[test]
+    fn test_parse_assignments_keeps_channel_numbers() {
+        let parsed = parse_assignments(&strings(&["1:17", "2=35", "ch3=aux2"])).unwrap();
+        assert_eq!(
+            parsed,
+            vec![
+                Assignment {
+                    dest: Strip::Channel(1),
+                    src: Strip::Channel(17)
+                },
+                Assignment {
+                    dest: Strip::Channel(2),
+                    src: Strip::Aux(3)
+                },
+                Assignment {
+                    dest: Strip::Channel(3),
+                    src: Strip::Aux(2)
+                },
+            ]
+        );
+        assert!(parse_assignments(&strings(&["0:1"])).is_err());
+        assert!(parse_assignments(&strings(&["1:41"])).is_err());
+    }
+
+    #[test]
+    fn test_parse_bus_and_matrix_assignments() {
+        let parsed = parse_assignments(&strings(&["bus1=bus2", "MTX6:mtx1"])).unwrap();
+        assert_eq!(
+            parsed,
+            vec![
+                Assignment {
+                    dest: Strip::Bus(1),
+                    src: Strip::Bus(2)
+                },
+                Assignment {
+                    dest: Strip::Matrix(6),
+                    src: Strip::Matrix(1)
+                },
+            ]
+        );
+        assert_eq!(Strip::Bus(2).remap(BUS_NODES[5]), "/bus/02/eq/1");
+        assert_eq!(Strip::Bus(12).remap("/bus/01/mix/01"), "/bus/12/mix/01");
+
+        assert!(parse_assignments(&strings(&["bus17=bus1"])).is_err());
+        assert!(parse_assignments(&strings(&["bus1=ch1"])).is_err());
+        assert!(parse_assignments(&strings(&["mtx1=bus1"])).is_err());
+    }
+
+    #[test]
+    fn test_diff_node_states_reports_changed_parameters() {
+        let saved = [
+            "/ch/01/config \"Kick\" 1 RD 0",
+            "/ch/01/mix/fader   -oo",
+            "/ch/01/eq/1 PEQ 124.7 +0.00 2.0",
+        ];
+        let live = strings(&[
+            "/ch/01/config Kick 1 RD 0",
+            "/ch/01/mix/fader -oo",
+            "/ch/01/eq/1 PEQ 124.7 +3.50 2.0 EXTRA",
+        ]);
+
+        let diffs = diff_node_states(&saved, &live).unwrap();
+        assert_eq!(
+            diffs,
+            vec![
+                ParamDiff {
+                    path: "/ch/01/eq/1".to_string(),
+                    index: 3,
+                    saved: Some("+0.00".to_string()),
+                    live: Some("+3.50".to_string()),
+                },
+                ParamDiff {
+                    path: "/ch/01/eq/1".to_string(),
+                    index: 5,
+                    saved: None,
+                    live: Some("EXTRA".to_string()),
+                },
+            ]
+        );
+        assert!(diff_node_states(&saved[..2], &live[..2])
+            .unwrap()
+            .is_empty());
+    }
+
+    #[test]
+    fn test_parse_channel_range() {
+        assert_eq!(
+            parse_channel_range("3,1-2,31-34").unwrap(),
+            vec![
+                Strip::Channel(1),
+                Strip::Channel(2),
+                Strip::Channel(3),
+                Strip::Channel(31),
+                Strip::Channel(32),
+                Strip::Aux(1),
+                Strip::Aux(2),
+            ]
+        );
+        assert_eq!(
+            parse_channel_range("bus15-16,mtx2").unwrap(),
+            vec![Strip::Bus(15), Strip::Bus(16), Strip::Matrix(2)]
+        );
+        assert!(parse_channel_range("bus16-17").is_err());
+        assert!(parse_channel_range("5-3").is_err());
+    }
+}
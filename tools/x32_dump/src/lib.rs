@@ -36,6 +36,10 @@ fn dump_osc_message(msg: &OscMessage, mut out: &mut String) {
             OscArg::Float(_) => out.push('f'),
             OscArg::String(_) => out.push('s'),
             OscArg::Blob(_) => out.push('b'),
+            OscArg::Bool(true) => out.push('T'),
+            OscArg::Bool(false) => out.push('F'),
+            OscArg::Nil => out.push('N'),
+            OscArg::Infinitum => out.push('I'),
         }
     }
 
@@ -130,6 +134,7 @@ fn dump_osc_message(msg: &OscMessage, mut out: &mut String) {
                     }
                 }
             }
+            OscArg::Bool(_) | OscArg::Nil | OscArg::Infinitum => {}
         }
     }
     println!("{}", out);
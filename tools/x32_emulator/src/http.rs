@@ -0,0 +1,168 @@
+//! A read-only HTTP endpoint that serves the mixer state as JSON.
+//!
+//! Enabled with the `http` feature. `GET /state` returns [`Mixer::state_json`], so
+//! web dashboards can read the emulator without speaking OSC. Connections are
+//! accepted and read on their own thread, so a slow client never holds up OSC
+//! traffic. Only the JSON itself is produced on the server loop, which keeps the
+//! mixer on a single thread.
+
+use std::io::{self, BufRead, BufReader, Read, Write};
+use std::net::{IpAddr, Ipv4Addr, SocketAddr, TcpListener, TcpStream};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::mpsc::{self, Receiver, Sender};
+use std::sync::Arc;
+use std::thread::{self, JoinHandle};
+use std::time::{Duration, Instant};
+use x32_core::Mixer;
+
+/// How long a client has to send its whole request before it is dropped.
+const REQUEST_TIMEOUT: Duration = Duration::from_millis(500);
+
+/// The largest request, request line and headers included, that is read.
+const MAX_REQUEST_SIZE: u64 = 8192;
+
+/// How long to wait for the server loop to produce the state.
+const STATE_TIMEOUT: Duration = Duration::from_secs(1);
+
+/// The HTTP listener for the state endpoint.
+///
+/// Requests for the state are queued for [`StateServer::poll`], which the server
+/// loop calls between packets. Dropping the server stops its thread.
+pub(crate) struct StateServer {
+    requests: Receiver<Sender<String>>,
+    addr: SocketAddr,
+    stop: Arc<AtomicBool>,
+    handle: Option<JoinHandle<()>>,
+}
+
+impl StateServer {
+    /// Binds the HTTP listener and starts accepting connections.
+    ///
+    /// # Arguments
+    ///
+    /// * `addr` - The address to listen on.
+    pub(crate) fn bind(addr: SocketAddr) -> io::Result<Self> {
+        let listener = TcpListener::bind(addr)?;
+        let addr = listener.local_addr()?;
+        println!("Serving mixer state on http://{}/state", addr);
+
+        let (request_tx, requests) = mpsc::channel();
+        let stop = Arc::new(AtomicBool::new(false));
+        let thread_stop = Arc::clone(&stop);
+        let handle = thread::spawn(move || {
+            for stream in listener.incoming() {
+                if thread_stop.load(Ordering::Relaxed) {
+                    break;
+                }
+                let result = stream.and_then(|stream| respond(stream, &request_tx));
+                if let Err(e) = result {
+                    eprintln!("Error handling HTTP request: {}", e);
+                }
+            }
+        });
+        Ok(Self {
+            requests,
+            addr,
+            stop,
+            handle: Some(handle),
+        })
+    }
+
+    /// Answers every state request that is waiting, without blocking for new ones.
+    ///
+    /// # Arguments
+    ///
+    /// * `mixer` - The mixer whose state is served.
+    pub(crate) fn poll(&self, mixer: &Mixer) {
+        while let Ok(reply) = self.requests.try_recv() {
+            let _ = reply.send(mixer.state_json());
+        }
+    }
+}
+
+impl Drop for StateServer {
+    fn drop(&mut self) {
+        self.stop.store(true, Ordering::Relaxed);
+        // Wake the thread from `accept` so it sees the flag.
+        let mut wake = self.addr;
+        if wake.ip().is_unspecified() {
+            wake.set_ip(IpAddr::V4(Ipv4Addr::LOCALHOST));
+        }
+        if TcpStream::connect_timeout(&wake, REQUEST_TIMEOUT).is_ok() {
+            if let Some(handle) = self.handle.take() {
+                let _ = handle.join();
+            }
+        }
+    }
+}
+
+/// Reads one request from `stream` and writes the response.
+///
+/// The request line and headers are read within [`REQUEST_TIMEOUT`] and
+/// [`MAX_REQUEST_SIZE`]; a client that sends more, or sends it more slowly, is dropped.
+fn respond(stream: TcpStream, state: &Sender<Sender<String>>) -> io::Result<()> {
+    let deadline = Instant::now() + REQUEST_TIMEOUT;
+    let mut reader = BufReader::new((&stream).take(MAX_REQUEST_SIZE));
+    let mut read_line = |line: &mut String| {
+        let remaining = deadline.saturating_duration_since(Instant::now());
+        if remaining.is_zero() {
+            return Err(io::Error::new(io::ErrorKind::TimedOut, "request too slow"));
+        }
+        stream.set_read_timeout(Some(remaining))?;
+        reader.read_line(line)
+    };
+
+    let mut request_line = String::new();
+    if read_line(&mut request_line)? == 0 {
+        // The client closed the connection without asking for anything.
+        return Ok(());
+    }
+    // Skip the headers; the endpoint doesn't use them.
+    loop {
+        let mut line = String::new();
+        if read_line(&mut line)? == 0 {
+            return Err(io::Error::new(
+                io::ErrorKind::UnexpectedEof,
+                "incomplete request",
+            ));
+        }
+        if line.trim().is_empty() {
+            break;
+        }
+    }
+
+    let mut parts = request_line.split_whitespace();
+    let method = parts.next().unwrap_or("");
+    let path = parts.next().unwrap_or("").split('?').next().unwrap_or("");
+    let (status, body) = match (method, path) {
+        ("GET", "/" | "/state") => {
+            let (reply_tx, reply) = mpsc::channel();
+            let json = state
+                .send(reply_tx)
+                .ok()
+                .and_then(|()| reply.recv_timeout(STATE_TIMEOUT).ok());
+            match json {
+                Some(json) => ("200 OK", json),
+                None => (
+                    "503 Service Unavailable",
+                    r#"{"error":"emulator not responding"}"#.to_string(),
+                ),
+            }
+        }
+        ("GET", _) => ("404 Not Found", r#"{"error":"not found"}"#.to_string()),
+        _ => (
+            "405 Method Not Allowed",
+            r#"{"error":"method not allowed"}"#.to_string(),
+        ),
+    };
+
+    let mut stream = &stream;
+    write!(
+        stream,
+        "HTTP/1.1 {}\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+        status,
+        body.len(),
+        body
+    )?;
+    stream.flush()
+}
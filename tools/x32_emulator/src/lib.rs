@@ -9,10 +9,57 @@
 
 pub mod server {
     use anyhow::Result;
+    use osc_lib::OscMessage;
+    use std::io::ErrorKind;
     use std::net::{SocketAddr, UdpSocket};
+    use std::path::PathBuf;
+    use std::sync::atomic::{AtomicBool, Ordering};
     use std::sync::mpsc::Receiver;
+    use std::sync::Arc;
+    use std::thread;
+    use std::time::{Duration, Instant};
     use x32_core::Mixer;
 
+    /// The interval between two meter updates, matching the console's 50 ms default.
+    const METER_INTERVAL: Duration = Duration::from_millis(50);
+
+    /// The default for how long the server waits for a packet before checking for shutdown.
+    pub const DEFAULT_POLL_INTERVAL: Duration = METER_INTERVAL;
+
+    /// Optional settings for [`run_with_options`].
+    #[derive(Debug, Clone)]
+    pub struct ServerOptions {
+        /// The longest time to wait for a packet before checking for shutdown.
+        ///
+        /// Packets are answered as soon as they arrive whatever the interval. The wait
+        /// also ends when the next meter update is due, so intervals above 50 ms act
+        /// as 50 ms.
+        pub poll_interval: Duration,
+        /// The file the mixer state is restored from on start and saved to on exit.
+        ///
+        /// Without one, the state lives only as long as the server.
+        pub state_path: Option<PathBuf>,
+        /// A flag that stops the server when set, e.g. by [`crate::shutdown_on_signals`].
+        pub terminate: Option<Arc<AtomicBool>>,
+        /// Where to serve the mixer state as JSON over HTTP, if anywhere.
+        ///
+        /// Requires the `http` feature.
+        #[cfg(feature = "http")]
+        pub http_addr: Option<SocketAddr>,
+    }
+
+    impl Default for ServerOptions {
+        fn default() -> Self {
+            Self {
+                poll_interval: DEFAULT_POLL_INTERVAL,
+                state_path: None,
+                terminate: None,
+                #[cfg(feature = "http")]
+                http_addr: None,
+            }
+        }
+    }
+
     /// A type alias for a closure that can be used to initialize the mixer's state.
     type Seeder = Option<Box<dyn FnOnce(&mut Mixer) + Send>>;
 
@@ -20,7 +67,16 @@ pub mod server {
     ///
     /// This function binds to the specified UDP address and enters a loop where it
     /// receives OSC messages, dispatches them to the `Mixer` instance, and sends
-    /// back any responses.
+    /// back any responses. Meter blobs for active `/meters` subscriptions are sent
+    /// every 50 ms whether or not any packets arrive.
+    ///
+    /// While the seeder runs, `/status` is answered with `initializing` and other
+    /// packets are held until it is done; after that `/status` reports `active`.
+    /// `/status` and `/xinfo` report the bound IP address unless the seeder set one
+    /// with [`Mixer::set_local_ip`].
+    ///
+    /// The server stops when `shutdown` fires. The state is not persisted; use
+    /// [`run_with_options`] with [`ServerOptions::state_path`] to keep it across runs.
     ///
     /// # Arguments
     ///
@@ -32,25 +88,136 @@ pub mod server {
     ///
     /// A `Result` indicating success or failure.
     pub fn run(bind_addr: &str, seeder: Seeder, shutdown: Option<Receiver<()>>) -> Result<()> {
+        run_with_options(bind_addr, seeder, shutdown, ServerOptions::default())
+    }
+
+    /// Runs the X32 emulator server with the given [`ServerOptions`].
+    ///
+    /// With `options.state_path` set, the state is restored from that file before the
+    /// seeder runs and saved back to it when the server stops. The server also stops
+    /// when `options.terminate` is set.
+    ///
+    /// With the `http` feature and `options.http_addr` set, `GET /state` on that
+    /// address returns the mixer state as JSON. Requests are read on a separate
+    /// thread; the JSON is produced between packets, so a reply may wait up to one
+    /// poll interval.
+    ///
+    /// # Arguments
+    ///
+    /// * `bind_addr` - The address to bind the UDP socket to (e.g., "0.0.0.0:10023").
+    /// * `seeder` - An optional closure to initialize the mixer's state before starting.
+    /// * `shutdown` - An optional channel receiver to signal the server to stop.
+    /// * `options` - The poll interval, state file, stop flag and optional HTTP address.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` indicating success or failure.
+    pub fn run_with_options(
+        bind_addr: &str,
+        seeder: Seeder,
+        shutdown: Option<Receiver<()>>,
+        options: ServerOptions,
+    ) -> Result<()> {
         let addr: SocketAddr = bind_addr.parse()?;
-        let socket = UdpSocket::bind(addr)?;
-        socket.set_read_timeout(Some(std::time::Duration::from_millis(10)))?;
-        let mut mixer = Mixer::new();
+        run_on_socket(UdpSocket::bind(addr)?, seeder, shutdown, options)
+    }
 
-        if let Some(seeder) = seeder {
-            seeder(&mut mixer);
+    /// Runs the X32 emulator server on a socket that is already bound.
+    ///
+    /// This lets a caller bind port 0 and learn the assigned port before the server
+    /// starts, as [`crate::testing::X32Emulator`] does.
+    ///
+    /// # Arguments
+    ///
+    /// * `socket` - The bound UDP socket to serve on.
+    /// * `seeder` - An optional closure to initialize the mixer's state before starting.
+    /// * `shutdown` - An optional channel receiver to signal the server to stop.
+    /// * `options` - The poll interval, state file, stop flag and optional HTTP address.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` indicating success or failure.
+    pub fn run_on_socket(
+        socket: UdpSocket,
+        seeder: Seeder,
+        shutdown: Option<Receiver<()>>,
+        options: ServerOptions,
+    ) -> Result<()> {
+        let addr = socket.local_addr()?;
+        #[cfg(feature = "http")]
+        let http = options
+            .http_addr
+            .map(crate::http::StateServer::bind)
+            .transpose()?;
+        let mut mixer = match &options.state_path {
+            Some(path) => {
+                let mut mixer = Mixer::new_with_state_path(path);
+                match mixer.load() {
+                    Ok(()) => println!("Restored mixer state from {}", path.display()),
+                    Err(e) if e.kind() == ErrorKind::NotFound => {}
+                    Err(e) => return Err(e.into()),
+                }
+                mixer
+            }
+            None => Mixer::new(),
+        };
+
+        let held = seed(&socket, &mut mixer, seeder)?;
+        if mixer.local_ip().is_none() {
+            mixer.set_local_ip(addr.ip());
         }
 
         println!("X32 Emulator listening on {}", addr);
+        for (packet, remote_addr) in held {
+            match mixer.dispatch(&packet, remote_addr) {
+                Ok(responses) => {
+                    for (addr, response) in responses {
+                        socket.send_to(&response, addr)?;
+                    }
+                }
+                Err(e) => eprintln!("Error handling message: {}", e),
+            }
+        }
 
         let mut buf = [0; 8192];
+        let mut next_meters = Instant::now() + METER_INTERVAL;
         loop {
+            if let Some(terminate) = &options.terminate {
+                if terminate.load(Ordering::Relaxed) {
+                    break;
+                }
+            }
             if let Some(shutdown) = &shutdown {
                 if shutdown.try_recv().is_ok() {
                     break;
                 }
             }
 
+            let now = Instant::now();
+            if now >= next_meters {
+                for (addr, response) in mixer.tick() {
+                    socket.send_to(&response, addr)?;
+                }
+                next_meters += METER_INTERVAL;
+                // Don't try to catch up on updates missed while the loop was busy.
+                if next_meters < now {
+                    next_meters = now + METER_INTERVAL;
+                }
+            }
+
+            #[cfg(feature = "http")]
+            if let Some(http) = &http {
+                http.poll(&mixer);
+            }
+
+            // Wait for a packet only until the next meter update or poll is due. A zero
+            // timeout is rejected by the socket, so always wait at least a millisecond.
+            let timeout = next_meters
+                .saturating_duration_since(Instant::now())
+                .min(options.poll_interval)
+                .max(Duration::from_millis(1));
+            socket.set_read_timeout(Some(timeout))?;
+
             match socket.recv_from(&mut buf) {
                 Ok((len, remote_addr)) => match mixer.dispatch(&buf[..len], remote_addr) {
                     Ok(responses) => {
@@ -63,11 +230,16 @@ pub mod server {
                     }
                 },
                 Err(ref e)
-                    if e.kind() == std::io::ErrorKind::WouldBlock
-                        || e.kind() == std::io::ErrorKind::TimedOut
-                        || e.kind() == std::io::ErrorKind::ConnectionReset =>
+                    if matches!(
+                        e.kind(),
+                        ErrorKind::WouldBlock
+                            | ErrorKind::TimedOut
+                            | ErrorKind::Interrupted
+                            | ErrorKind::ConnectionReset
+                    ) =>
                 {
-                    // No data received or connection reset (Windows client disconnect), continue
+                    // No data received, a signal interrupted the wait (the loop checks
+                    // `terminate` next) or a Windows client disconnected; continue.
                 }
                 Err(e) => {
                     eprintln!("Error receiving data: {}", e);
@@ -75,13 +247,88 @@ pub mod server {
                 }
             }
         }
+
+        if options.state_path.is_some() {
+            mixer.save()?;
+        }
         Ok(())
     }
+
+    /// Runs `seeder` on `mixer` while answering `/status` with `initializing`.
+    ///
+    /// Other packets that arrive while the seeder runs are held, so the server can
+    /// dispatch them in order once the state is ready.
+    ///
+    /// # Returns
+    ///
+    /// The held packets and their senders.
+    fn seed(
+        socket: &UdpSocket,
+        mixer: &mut Mixer,
+        seeder: Seeder,
+    ) -> Result<Vec<(Vec<u8>, SocketAddr)>> {
+        let Some(seeder) = seeder else {
+            return Ok(Vec::new());
+        };
+
+        // A stand-in with the same identity answers /status until the seeder is done.
+        let mut stand_in = Mixer::new();
+        stand_in.set_server_info(mixer.server_info().clone());
+        stand_in.set_local_ip(match mixer.local_ip() {
+            Some(ip) => ip,
+            None => socket.local_addr()?.ip(),
+        });
+        stand_in.set_ready(false);
+        let receiver = socket.try_clone()?;
+        receiver.set_read_timeout(Some(Duration::from_millis(10)))?;
+        let seeding = AtomicBool::new(true);
+
+        let held = thread::scope(|scope| {
+            let answerer = scope.spawn(|| {
+                let mut held = Vec::new();
+                let mut buf = [0; 8192];
+                while seeding.load(Ordering::Relaxed) {
+                    let Ok((len, remote_addr)) = receiver.recv_from(&mut buf) else {
+                        continue;
+                    };
+                    let packet = &buf[..len];
+                    let is_status =
+                        OscMessage::from_bytes(packet).is_ok_and(|msg| msg.path == "/status");
+                    if !is_status {
+                        held.push((packet.to_vec(), remote_addr));
+                        continue;
+                    }
+                    if let Ok(responses) = stand_in.dispatch(packet, remote_addr) {
+                        for (addr, response) in responses {
+                            let _ = receiver.send_to(&response, addr);
+                        }
+                    }
+                }
+                held
+            });
+            seeder(mixer);
+            seeding.store(false, Ordering::Relaxed);
+            answerer.join()
+        });
+        held.map_err(|_| anyhow::anyhow!("the /status thread panicked while seeding"))
+    }
 }
+
+pub mod testing;
+
 pub use x32_core::Mixer;
 
+#[cfg(feature = "http")]
+mod http;
+
 use anyhow::Result;
 use clap::Parser;
+use std::net::{IpAddr, SocketAddr};
+use std::path::PathBuf;
+use std::sync::atomic::AtomicBool;
+use std::sync::Arc;
+use std::time::Duration;
+use x32_lib::scene_parse::SceneParser;
 
 /// Command-line arguments for the `x32_emulator`.
 #[derive(Parser, Debug)]
@@ -94,9 +341,144 @@ pub struct Cli {
     /// Port number to bind to.
     #[arg(short, long, default_value_t = 10023)]
     pub port: u16,
+
+    /// How often the idle server checks for shutdown, in milliseconds (at most 50).
+    #[arg(long, default_value_t = 50)]
+    pub poll_ms: u64,
+
+    /// Serve the mixer state as JSON on this HTTP port, at `/state`.
+    #[cfg(feature = "http")]
+    #[arg(long)]
+    pub http_port: Option<u16>,
+
+    /// Listen on all interfaces so broadcast `/info` and `/xinfo` discovery
+    /// requests are received. `--ip` is still reported as the console's address.
+    #[arg(short, long)]
+    pub broadcast: bool,
+
+    /// A scene (.scn) or snippet (.snp) file to load before serving.
+    #[arg(short, long)]
+    pub scene: Option<PathBuf>,
+}
+
+/// The outcome of [`load_scene`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SceneLoad {
+    /// The number of lines applied to the mixer.
+    pub loaded: usize,
+    /// The number of lines that could not be parsed or applied.
+    pub skipped: usize,
 }
 
-pub fn run(cli: Cli) -> Result<()> {
-    let bind_addr = format!("{}:{}", cli.ip, cli.port);
-    server::run(&bind_addr, None, None)
+/// Applies the lines of a scene or snippet file to `mixer`.
+///
+/// Each line is parsed with [`SceneParser`], the same parser `x32_set_scene` uses to
+/// send scene files to a console, so values written in display units such as dB
+/// are converted. The resulting messages are applied as if a client had sent them,
+/// and a line counts as loaded only if the mixer accepted all of them. Blank lines
+/// and `#` header or comment lines are not counted.
+///
+/// # Arguments
+///
+/// * `mixer` - The mixer to load the scene into.
+/// * `contents` - The text of the scene file.
+///
+/// # Returns
+///
+/// The number of lines loaded and skipped.
+pub fn load_scene(mixer: &mut Mixer, contents: &str) -> SceneLoad {
+    // No client is subscribed yet, so the sender address only has to be distinct.
+    let sender = SocketAddr::from(([0, 0, 0, 0], 0));
+    let mut parser = SceneParser::new();
+    let mut load = SceneLoad {
+        loaded: 0,
+        skipped: 0,
+    };
+
+    for line in contents.lines().map(str::trim) {
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+        let messages = parser.parse_scene_line(line);
+        if messages.is_empty() {
+            load.skipped += 1;
+            continue;
+        }
+        let mut applied = true;
+        for msg in messages {
+            let result = match msg.to_bytes() {
+                Ok(bytes) => mixer.dispatch(&bytes, sender).map(drop),
+                Err(e) => Err(e.into()),
+            };
+            if let Err(e) = result {
+                eprintln!("Error applying scene line '{}': {}", line, e);
+                applied = false;
+            }
+        }
+        if applied {
+            load.loaded += 1;
+        } else {
+            load.skipped += 1;
+        }
+    }
+    load
+}
+
+/// The file the command-line emulator keeps its state in, as the original C emulator did.
+pub const STATE_FILE: &str = ".X32res.rc";
+
+/// Returns a flag that is set when the process receives SIGINT or SIGTERM.
+///
+/// Call this once, from `main`, and pass the flag to [`run`]. Registering again adds
+/// another handler rather than replacing the first.
+pub fn shutdown_on_signals() -> Result<Arc<AtomicBool>> {
+    let terminate = Arc::new(AtomicBool::new(false));
+    for signal in [signal_hook::consts::SIGINT, signal_hook::consts::SIGTERM] {
+        signal_hook::flag::register(signal, Arc::clone(&terminate))?;
+    }
+    Ok(terminate)
+}
+
+/// Runs the emulator from the command line until `terminate` is set.
+///
+/// The state is restored from [`STATE_FILE`] in the working directory and saved back
+/// to it on exit.
+pub fn run(cli: Cli, terminate: Arc<AtomicBool>) -> Result<()> {
+    let ip: IpAddr = cli.ip.parse()?;
+    let scene = match &cli.scene {
+        Some(path) => Some((path.clone(), std::fs::read_to_string(path)?)),
+        None => None,
+    };
+    let options = server::ServerOptions {
+        poll_interval: Duration::from_millis(cli.poll_ms.max(1)),
+        state_path: Some(PathBuf::from(STATE_FILE)),
+        terminate: Some(terminate),
+        #[cfg(feature = "http")]
+        http_addr: cli.http_port.map(|port| SocketAddr::new(ip, port)),
+    };
+
+    // A socket bound to a unicast address doesn't see broadcast packets, so in
+    // broadcast mode bind the wildcard address and report the requested IP instead.
+    let bind_addr = if cli.broadcast {
+        format!("0.0.0.0:{}", cli.port)
+    } else {
+        format!("{}:{}", cli.ip, cli.port)
+    };
+    let report_ip = cli.broadcast && !ip.is_unspecified();
+
+    let seeder = Box::new(move |mixer: &mut Mixer| {
+        if report_ip {
+            mixer.set_local_ip(ip);
+        }
+        if let Some((path, contents)) = scene {
+            let load = load_scene(mixer, &contents);
+            println!(
+                "Loaded {} of {} scene lines from {}",
+                load.loaded,
+                load.loaded + load.skipped,
+                path.display()
+            );
+        }
+    });
+    server::run_with_options(&bind_addr, Some(seeder), None, options)
 }
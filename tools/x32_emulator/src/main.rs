@@ -3,5 +3,6 @@ use clap::Parser;
 
 fn main() -> Result<()> {
     let cli = x32_emulator::Cli::parse();
-    x32_emulator::run(cli)
+    let terminate = x32_emulator::shutdown_on_signals()?;
+    x32_emulator::run(cli, terminate)
 }
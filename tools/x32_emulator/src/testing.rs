@@ -0,0 +1,145 @@
+//! An in-process emulator for the integration tests of other tools.
+//!
+//! [`X32Emulator`] starts the server on an ephemeral local port, so tests can run in
+//! parallel without picking port numbers, and stops it when dropped. A tool under test
+//! is pointed at [`X32Emulator::addr`], and its effect on the console is checked with
+//! [`X32Emulator::get`] or [`X32Emulator::assert_param`].
+
+use crate::server::{self, ServerOptions};
+use anyhow::Result;
+use osc_lib::{OscArg, OscMessage};
+use std::net::{SocketAddr, UdpSocket};
+use std::path::PathBuf;
+use std::sync::mpsc::{self, Sender};
+use std::thread::{self, JoinHandle};
+use std::time::{Duration, Instant};
+use x32_core::Mixer;
+
+/// How long [`X32Emulator::get`] waits for the emulator to answer.
+const REPLY_TIMEOUT: Duration = Duration::from_secs(1);
+
+/// An emulator running on a background thread for the length of a test.
+pub struct X32Emulator {
+    addr: SocketAddr,
+    state_path: PathBuf,
+    socket: UdpSocket,
+    shutdown: Sender<()>,
+    handle: Option<JoinHandle<()>>,
+}
+
+impl X32Emulator {
+    /// Starts an emulator with the default console state.
+    pub fn start() -> Result<Self> {
+        Self::start_with(|_| {})
+    }
+
+    /// Starts an emulator whose state is first set up by `seed`.
+    ///
+    /// The state is saved to a temporary file rather than `.X32res.rc` in the working
+    /// directory, and the file is removed when the emulator is dropped.
+    ///
+    /// # Arguments
+    ///
+    /// * `seed` - A closure that initializes the mixer, e.g. with
+    ///   [`Mixer::seed_from_lines`] or [`Mixer::set_signal_level`].
+    pub fn start_with<F>(seed: F) -> Result<Self>
+    where
+        F: FnOnce(&mut Mixer) + Send + 'static,
+    {
+        let server_socket = UdpSocket::bind("127.0.0.1:0")?;
+        let addr = server_socket.local_addr()?;
+        let state_path = std::env::temp_dir().join(format!(
+            "x32_emulator_test_{}_{}.rc",
+            std::process::id(),
+            addr.port()
+        ));
+
+        let options = ServerOptions {
+            state_path: Some(state_path.clone()),
+            ..ServerOptions::default()
+        };
+        let (shutdown, shutdown_rx) = mpsc::channel();
+        let handle = thread::spawn(move || {
+            if let Err(e) = server::run_on_socket(
+                server_socket,
+                Some(Box::new(seed)),
+                Some(shutdown_rx),
+                options,
+            ) {
+                eprintln!("Emulator stopped: {}", e);
+            }
+        });
+
+        // The server socket is already bound, so requests sent before the server
+        // thread is running wait in its receive buffer.
+        let socket = UdpSocket::bind("127.0.0.1:0")?;
+        socket.set_read_timeout(Some(REPLY_TIMEOUT))?;
+        Ok(Self {
+            addr,
+            state_path,
+            socket,
+            shutdown,
+            handle: Some(handle),
+        })
+    }
+
+    /// Returns the address the emulator listens on.
+    pub fn addr(&self) -> SocketAddr {
+        self.addr
+    }
+
+    /// Sets a parameter, as a control surface would.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - The parameter address, e.g. `/ch/01/mix/on`.
+    /// * `arg` - The new value.
+    pub fn set(&self, path: &str, arg: OscArg) -> Result<()> {
+        let bytes = OscMessage::new(path.to_string(), vec![arg]).to_bytes()?;
+        self.socket.send_to(&bytes, self.addr)?;
+        Ok(())
+    }
+
+    /// Reads a parameter from the emulator.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - The parameter address.
+    ///
+    /// # Returns
+    ///
+    /// The value, or `None` if the emulator has no value for `path` or didn't answer
+    /// within a second.
+    pub fn get(&self, path: &str) -> Option<OscArg> {
+        let bytes = OscMessage::new(path.to_string(), vec![]).to_bytes().ok()?;
+        self.socket.send_to(&bytes, self.addr).ok()?;
+
+        let deadline = Instant::now() + REPLY_TIMEOUT;
+        let mut buf = [0u8; 8192];
+        while Instant::now() < deadline {
+            let (len, _) = self.socket.recv_from(&mut buf).ok()?;
+            // Skip late replies to earlier requests.
+            match OscMessage::from_bytes(&buf[..len]) {
+                Ok(reply) if reply.path == path => return reply.args.into_iter().next(),
+                _ => continue,
+            }
+        }
+        None
+    }
+
+    /// Panics unless the parameter at `path` equals `expected`.
+    #[track_caller]
+    pub fn assert_param(&self, path: &str, expected: OscArg) {
+        assert_eq!(self.get(path), Some(expected), "parameter {}", path);
+    }
+}
+
+impl Drop for X32Emulator {
+    fn drop(&mut self) {
+        let _ = self.shutdown.send(());
+        if let Some(handle) = self.handle.take() {
+            let _ = handle.join();
+        }
+        let _ = std::fs::remove_file(&self.state_path);
+    }
+}
@@ -0,0 +1,92 @@
+#![cfg(feature = "http")]
+
+use osc_lib::OscMessage;
+use std::io::{Read, Write};
+use std::net::{SocketAddr, TcpListener, TcpStream, UdpSocket};
+use std::sync::mpsc::{self, Sender};
+use std::thread::{self, JoinHandle};
+use std::time::{Duration, Instant};
+use x32_emulator::server::{self, ServerOptions};
+use x32_emulator::Mixer;
+
+/// Starts a server with the state endpoint and waits until it accepts connections.
+fn start(seed: &'static str) -> (SocketAddr, SocketAddr, Sender<()>, JoinHandle<()>) {
+    let socket = UdpSocket::bind("127.0.0.1:0").unwrap();
+    let osc_addr = socket.local_addr().unwrap();
+    let http_addr = TcpListener::bind("127.0.0.1:0")
+        .unwrap()
+        .local_addr()
+        .unwrap();
+    let (shutdown_tx, shutdown_rx) = mpsc::channel();
+    let handle = thread::spawn(move || {
+        let seeder = Box::new(move |mixer: &mut Mixer| mixer.seed_from_lines(vec![seed]));
+        let options = ServerOptions {
+            http_addr: Some(http_addr),
+            ..ServerOptions::default()
+        };
+        server::run_on_socket(socket, Some(seeder), Some(shutdown_rx), options).unwrap();
+    });
+
+    let deadline = Instant::now() + Duration::from_secs(5);
+    while TcpStream::connect(http_addr).is_err() {
+        assert!(Instant::now() < deadline, "HTTP endpoint never came up");
+        thread::sleep(Duration::from_millis(10));
+    }
+    (osc_addr, http_addr, shutdown_tx, handle)
+}
+
+#[test]
+fn test_http_state_endpoint_returns_json() {
+    let (_, http_addr, shutdown_tx, handle) = start("/ch/01/mix/fader,f\t0.75");
+
+    let get = |path: &str| {
+        let mut stream = TcpStream::connect(http_addr).unwrap();
+        write!(stream, "GET {} HTTP/1.1\r\nHost: localhost\r\n\r\n", path).unwrap();
+        let mut response = String::new();
+        stream.read_to_string(&mut response).unwrap();
+        response
+    };
+
+    let response = get("/state");
+    assert!(response.starts_with("HTTP/1.1 200 OK\r\n"));
+    assert!(response.contains("Content-Type: application/json\r\n"));
+    assert!(response.contains(r#""/ch/01/mix/fader":0.75"#));
+
+    assert!(get("/nope").starts_with("HTTP/1.1 404 Not Found\r\n"));
+
+    shutdown_tx.send(()).unwrap();
+    handle.join().unwrap();
+}
+
+#[test]
+fn test_slow_http_client_does_not_delay_osc() {
+    let (osc_addr, http_addr, shutdown_tx, handle) = start("/ch/01/mix/on,i\t1");
+
+    // A client that connects and never finishes its request.
+    let mut stalled = TcpStream::connect(http_addr).unwrap();
+    write!(stalled, "GET /state HTTP/1.1\r\n").unwrap();
+
+    let socket = UdpSocket::bind("127.0.0.1:0").unwrap();
+    socket
+        .set_read_timeout(Some(Duration::from_secs(2)))
+        .unwrap();
+    let info = OscMessage::new("/info".to_string(), vec![])
+        .to_bytes()
+        .unwrap();
+    let mut buf = [0; 1024];
+    let start = Instant::now();
+    socket.send_to(&info, osc_addr).unwrap();
+    let (len, _) = socket.recv_from(&mut buf).unwrap();
+    assert_eq!(OscMessage::from_bytes(&buf[..len]).unwrap().path, "/info");
+    assert!(start.elapsed() < Duration::from_millis(200));
+
+    // The stalled request is dropped once its time is up.
+    let mut rest = String::new();
+    stalled
+        .set_read_timeout(Some(Duration::from_secs(5)))
+        .unwrap();
+    assert_eq!(stalled.read_to_string(&mut rest).unwrap_or(0), 0);
+
+    shutdown_tx.send(()).unwrap();
+    handle.join().unwrap();
+}
@@ -0,0 +1,34 @@
+use osc_lib::OscMessage;
+use std::net::UdpSocket;
+use std::time::{Duration, Instant};
+use x32_emulator::testing::X32Emulator;
+
+#[test]
+fn test_reply_latency_is_not_tied_to_poll_interval() {
+    let emulator = X32Emulator::start().unwrap();
+
+    let socket = UdpSocket::bind("127.0.0.1:0").unwrap();
+    socket
+        .set_read_timeout(Some(Duration::from_secs(2)))
+        .unwrap();
+    let info = OscMessage::new("/info".to_string(), vec![])
+        .to_bytes()
+        .unwrap();
+
+    // The server waits up to a poll interval for each packet, so a reply that is
+    // only sent once the loop wakes up would take tens of milliseconds.
+    let mut buf = [0; 1024];
+    let mut round_trips = Vec::new();
+    for _ in 0..20 {
+        let start = Instant::now();
+        socket.send_to(&info, emulator.addr()).unwrap();
+        let (len, _) = socket.recv_from(&mut buf).unwrap();
+        round_trips.push(start.elapsed());
+        assert_eq!(OscMessage::from_bytes(&buf[..len]).unwrap().path, "/info");
+    }
+
+    // Use the median so a single scheduling hiccup doesn't fail the test.
+    round_trips.sort();
+    let median = round_trips[round_trips.len() / 2];
+    assert!(median < Duration::from_millis(10), "median {:?}", median);
+}
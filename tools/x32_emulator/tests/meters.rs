@@ -0,0 +1,29 @@
+use osc_lib::{OscArg, OscMessage};
+use std::net::UdpSocket;
+use std::time::Duration;
+use x32_emulator::testing::X32Emulator;
+
+#[test]
+fn test_meter_subscription_streams_frames() {
+    let emulator = X32Emulator::start().unwrap();
+    let socket = UdpSocket::bind("127.0.0.1:0").unwrap();
+    socket
+        .set_read_timeout(Some(Duration::from_secs(2)))
+        .unwrap();
+    let subscribe = OscMessage::new(
+        "/meters".to_string(),
+        vec![OscArg::String("/meters/1".to_string())],
+    );
+    socket
+        .send_to(&subscribe.to_bytes().unwrap(), emulator.addr())
+        .unwrap();
+
+    // No further packets are sent, so these frames can only come from the timer.
+    let mut buf = [0; 1024];
+    for _ in 0..2 {
+        let (len, _) = socket.recv_from(&mut buf).unwrap();
+        let msg = OscMessage::from_bytes(&buf[..len]).unwrap();
+        assert_eq!(msg.path, "/meters/1");
+        assert_eq!(msg.args[0].as_blob().map(<[u8]>::len), Some(388));
+    }
+}
@@ -0,0 +1,72 @@
+use osc_lib::{OscArg, OscMessage};
+use std::net::{SocketAddr, UdpSocket};
+use std::time::Duration;
+use x32_emulator::testing::X32Emulator;
+
+/// Connects a client socket to the emulator at `addr`.
+fn client(addr: SocketAddr) -> UdpSocket {
+    let socket = UdpSocket::bind("127.0.0.1:0").unwrap();
+    socket
+        .set_read_timeout(Some(Duration::from_secs(2)))
+        .unwrap();
+    socket.connect(addr).unwrap();
+    socket
+}
+
+fn send(socket: &UdpSocket, path: &str, args: Vec<OscArg>) {
+    let msg = OscMessage::new(path.to_string(), args);
+    socket.send(&msg.to_bytes().unwrap()).unwrap();
+}
+
+fn recv(socket: &UdpSocket) -> OscMessage {
+    let mut buf = [0; 1024];
+    let (len, _) = socket.recv_from(&mut buf).unwrap();
+    OscMessage::from_bytes(&buf[..len]).unwrap()
+}
+
+#[test]
+fn test_sets_reach_other_xremote_clients_in_order() {
+    let emulator = X32Emulator::start().unwrap();
+    let a = client(emulator.addr());
+    let b = client(emulator.addr());
+    // The /info reply shows the emulator has handled the subscription before it.
+    for socket in [&a, &b] {
+        send(socket, "/xremote", vec![]);
+        send(socket, "/info", vec![]);
+        assert_eq!(recv(socket).path, "/info");
+    }
+
+    // B sees every change A makes, in the order A made them.
+    let values: Vec<f32> = (1..=10).map(|i| i as f32 / 10.0).collect();
+    for &value in &values {
+        send(&a, "/ch/01/mix/fader", vec![OscArg::Float(value)]);
+    }
+    for &value in &values {
+        let msg = recv(&b);
+        assert_eq!(msg.path, "/ch/01/mix/fader");
+        let got = msg.args[0].as_float().unwrap();
+        assert!(
+            (got - value).abs() < 1e-3,
+            "expected {}, got {}",
+            value,
+            got
+        );
+    }
+
+    // A change from B reaches A. Like the console, A's own sets were not echoed
+    // back to it, so B's change is the first thing A receives. The originator gets
+    // no echo on purpose: that is the synth-508~2 rule, which fixed x32_reaper's
+    // feedback loop, so don't change this to expect one.
+    send(&b, "/ch/02/mix/on", vec![OscArg::Int(0)]);
+    let msg = recv(&a);
+    assert_eq!(msg.path, "/ch/02/mix/on");
+    assert_eq!(msg.args, vec![OscArg::Int(0)]);
+
+    // Both clients read back the same final state.
+    for socket in [&a, &b] {
+        send(socket, "/ch/01/mix/fader", vec![]);
+        let msg = recv(socket);
+        assert_eq!(msg.path, "/ch/01/mix/fader");
+        assert!((msg.args[0].as_float().unwrap() - 1.0).abs() < 1e-3);
+    }
+}
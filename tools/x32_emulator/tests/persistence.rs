@@ -0,0 +1,130 @@
+use osc_lib::{OscArg, OscMessage};
+use std::net::UdpSocket;
+use std::sync::mpsc;
+use std::thread;
+use std::time::Duration;
+use x32_emulator::server::{self, ServerOptions};
+
+/// Starts a server persisting to `state_path` and returns its address, shutdown
+/// sender and thread.
+fn start(
+    state_path: &std::path::Path,
+) -> (
+    std::net::SocketAddr,
+    mpsc::Sender<()>,
+    thread::JoinHandle<()>,
+) {
+    let socket = UdpSocket::bind("127.0.0.1:0").unwrap();
+    let addr = socket.local_addr().unwrap();
+    let options = ServerOptions {
+        state_path: Some(state_path.to_path_buf()),
+        ..ServerOptions::default()
+    };
+    let (shutdown_tx, shutdown_rx) = mpsc::channel();
+    let handle = thread::spawn(move || {
+        server::run_on_socket(socket, None, Some(shutdown_rx), options).unwrap();
+    });
+    (addr, shutdown_tx, handle)
+}
+
+#[test]
+fn test_state_round_trips_through_state_path() {
+    let state_path = std::env::temp_dir().join(format!("x32_emulator_{}.rc", std::process::id()));
+    let _ = std::fs::remove_file(&state_path);
+
+    let client = UdpSocket::bind("127.0.0.1:0").unwrap();
+    client
+        .set_read_timeout(Some(Duration::from_secs(2)))
+        .unwrap();
+    let fader = |args| {
+        OscMessage::new("/ch/01/mix/fader".to_string(), args)
+            .to_bytes()
+            .unwrap()
+    };
+
+    // The first run saves its state on shutdown. The read is answered only once the
+    // set before it has been applied.
+    let (addr, shutdown_tx, handle) = start(&state_path);
+    client
+        .send_to(&fader(vec![OscArg::Float(0.5)]), addr)
+        .unwrap();
+    client.send_to(&fader(vec![]), addr).unwrap();
+    let mut buf = [0; 1024];
+    client.recv_from(&mut buf).unwrap();
+    shutdown_tx.send(()).unwrap();
+    handle.join().unwrap();
+
+    let saved = std::fs::read_to_string(&state_path).unwrap();
+    assert!(saved.contains("/ch/01/mix/fader,f\t0.5"));
+
+    // The second run restores it on start.
+    let (addr, shutdown_tx, handle) = start(&state_path);
+    client.send_to(&fader(vec![]), addr).unwrap();
+    let (len, _) = client.recv_from(&mut buf).unwrap();
+    let reply = OscMessage::from_bytes(&buf[..len]).unwrap();
+    shutdown_tx.send(()).unwrap();
+    handle.join().unwrap();
+    std::fs::remove_file(&state_path).unwrap();
+
+    assert_eq!(reply.args, vec![OscArg::Float(0.5)]);
+}
+
+#[test]
+fn test_stored_scene_recalls_after_restart() {
+    let state_path =
+        std::env::temp_dir().join(format!("x32_emulator_scene_{}.rc", std::process::id()));
+    let _ = std::fs::remove_file(&state_path);
+
+    let client = UdpSocket::bind("127.0.0.1:0").unwrap();
+    client
+        .set_read_timeout(Some(Duration::from_secs(2)))
+        .unwrap();
+    let send = |addr, path: &str, args| {
+        let bytes = OscMessage::new(path.to_string(), args).to_bytes().unwrap();
+        client.send_to(&bytes, addr).unwrap();
+    };
+    let mut buf = [0; 1024];
+    let mut recv = || {
+        let (len, _) = client.recv_from(&mut buf).unwrap();
+        OscMessage::from_bytes(&buf[..len]).unwrap()
+    };
+
+    // The first run stores the scene and saves it with the state on shutdown.
+    let (addr, shutdown_tx, handle) = start(&state_path);
+    send(addr, "/ch/01/mix/fader", vec![OscArg::Float(0.75)]);
+    send(
+        addr,
+        "/save",
+        vec![
+            OscArg::String("scene".to_string()),
+            OscArg::Int(2),
+            OscArg::String("Verse".to_string()),
+            OscArg::String("".to_string()),
+        ],
+    );
+    assert_eq!(recv().path, "/save");
+    shutdown_tx.send(()).unwrap();
+    handle.join().unwrap();
+
+    // The second run recalls it and tells subscribers what changed.
+    let (addr, shutdown_tx, handle) = start(&state_path);
+    send(addr, "/xremote", vec![]);
+    send(addr, "/ch/01/mix/fader", vec![OscArg::Float(0.1)]);
+    send(
+        addr,
+        "/load",
+        vec![OscArg::String("scene".to_string()), OscArg::Int(2)],
+    );
+    let update = recv();
+    let reply = recv();
+    shutdown_tx.send(()).unwrap();
+    handle.join().unwrap();
+    std::fs::remove_file(&state_path).unwrap();
+
+    assert_eq!(
+        update,
+        OscMessage::new("/ch/01/mix/fader".to_string(), vec![OscArg::Float(0.75)])
+    );
+    assert_eq!(reply.path, "/load");
+    assert_eq!(reply.args[1], OscArg::Int(1));
+}
@@ -0,0 +1,123 @@
+use osc_lib::{OscArg, OscMessage};
+use std::io::{BufRead, BufReader};
+use std::net::{SocketAddr, UdpSocket};
+use std::process::{Command, Stdio};
+use std::time::Duration;
+use x32_emulator::{load_scene, Mixer, SceneLoad};
+
+fn get(mixer: &mut Mixer, path: &str) -> Option<OscArg> {
+    let bytes = OscMessage::new(path.to_string(), vec![])
+        .to_bytes()
+        .unwrap();
+    let addr: SocketAddr = "127.0.0.1:1234".parse().unwrap();
+    let responses = mixer.dispatch(&bytes, addr).unwrap();
+    let (_, reply) = responses.last()?;
+    OscMessage::from_bytes(reply)
+        .unwrap()
+        .args
+        .into_iter()
+        .next()
+}
+
+#[test]
+fn test_load_scene_file_sets_channel_names() {
+    let scene_path = std::env::temp_dir().join(format!("x32_emulator_{}.scn", std::process::id()));
+    std::fs::write(
+        &scene_path,
+        "#4.0# \"Test\" \"\" %000000000 1 X32\n\
+         /ch/01/config/name \"Kick\"\n\
+         /ch/02/config/name \"Snare\"\n\
+         /ch/01/mix/fader -10.0\n\
+         /ch/02/mix/on OFF\n\
+         \n\
+         not a scene line\n",
+    )
+    .unwrap();
+
+    let contents = std::fs::read_to_string(&scene_path).unwrap();
+    std::fs::remove_file(&scene_path).unwrap();
+    let mut mixer = Mixer::new();
+    let load = load_scene(&mut mixer, &contents);
+
+    assert_eq!(
+        load,
+        SceneLoad {
+            loaded: 4,
+            skipped: 1
+        }
+    );
+    assert_eq!(
+        get(&mut mixer, "/ch/01/config/name"),
+        Some(OscArg::String("Kick".to_string()))
+    );
+    assert_eq!(
+        get(&mut mixer, "/ch/02/config/name"),
+        Some(OscArg::String("Snare".to_string()))
+    );
+    assert_eq!(
+        get(&mut mixer, "/ch/01/mix/fader"),
+        Some(OscArg::Float(0.5))
+    );
+    assert_eq!(get(&mut mixer, "/ch/02/mix/on"), Some(OscArg::Int(0)));
+}
+
+#[test]
+fn test_scene_option_counts_only_applied_lines() {
+    let dir = std::env::temp_dir().join(format!("x32_emulator_scene_{}", std::process::id()));
+    std::fs::create_dir_all(&dir).unwrap();
+    let scene_path = dir.join("test.scn");
+    // The second line parses, but its address has no leading slash, so the mixer
+    // rejects it.
+    std::fs::write(
+        &scene_path,
+        "/ch/01/mix/fader -10.0\n\
+         ch/02/mix/on OFF\n\
+         not a scene line\n",
+    )
+    .unwrap();
+    let port = UdpSocket::bind("127.0.0.1:0")
+        .unwrap()
+        .local_addr()
+        .unwrap()
+        .port();
+
+    // Run in its own directory so the state file doesn't land in the crate.
+    let mut child = Command::new(env!("CARGO_BIN_EXE_x32_emulator"))
+        .current_dir(&dir)
+        .args(["--ip", "127.0.0.1", "--port", &port.to_string(), "--scene"])
+        .arg(&scene_path)
+        .stdout(Stdio::piped())
+        .stderr(Stdio::null())
+        .spawn()
+        .expect("Failed to spawn x32_emulator");
+    // Keep reading until the end, so the emulator never writes to a closed pipe.
+    let mut lines = BufReader::new(child.stdout.take().unwrap())
+        .lines()
+        .map_while(Result::ok);
+    let loaded = lines.find(|line| line.starts_with("Loaded"));
+
+    // The scene is loaded before any request is answered.
+    let client = UdpSocket::bind("127.0.0.1:0").unwrap();
+    client
+        .set_read_timeout(Some(Duration::from_secs(2)))
+        .unwrap();
+    let fader = OscMessage::new("/ch/01/mix/fader".to_string(), vec![])
+        .to_bytes()
+        .unwrap();
+    client.send_to(&fader, ("127.0.0.1", port)).unwrap();
+    let mut buf = [0; 1024];
+    let reply = client
+        .recv_from(&mut buf)
+        .map(|(len, _)| OscMessage::from_bytes(&buf[..len]).unwrap());
+
+    child.kill().unwrap();
+    child.wait().unwrap();
+    lines.for_each(drop);
+    std::fs::remove_dir_all(&dir).unwrap();
+
+    assert_eq!(
+        loaded.as_deref(),
+        Some(format!("Loaded 1 of 3 scene lines from {}", scene_path.display()).as_str())
+    );
+    assert_eq!(reply.unwrap().args, vec![OscArg::Float(0.5)]);
+}
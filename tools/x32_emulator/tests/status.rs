@@ -0,0 +1,80 @@
+use osc_lib::{OscArg, OscMessage};
+use std::net::UdpSocket;
+use std::sync::mpsc;
+use std::thread;
+use std::time::Duration;
+use x32_emulator::server::{self, ServerOptions};
+use x32_emulator::testing::X32Emulator;
+use x32_emulator::Mixer;
+
+#[test]
+fn test_status_reports_active_and_bound_ip() {
+    let emulator = X32Emulator::start_with(|mixer| {
+        mixer.seed_from_lines(vec!["/ch/01/mix/fader,f\t0.75"]);
+    })
+    .unwrap();
+
+    let socket = UdpSocket::bind("127.0.0.1:0").unwrap();
+    socket
+        .set_read_timeout(Some(Duration::from_secs(2)))
+        .unwrap();
+    let status = OscMessage::new("/status".to_string(), vec![]);
+    socket
+        .send_to(&status.to_bytes().unwrap(), emulator.addr())
+        .unwrap();
+
+    let mut buf = [0; 1024];
+    let (len, _) = socket.recv_from(&mut buf).unwrap();
+    let msg = OscMessage::from_bytes(&buf[..len]).unwrap();
+    assert_eq!(msg.path, "/status");
+    assert_eq!(msg.args[0], OscArg::String("active".to_string()));
+    assert_eq!(msg.args[1], OscArg::String("127.0.0.1".to_string()));
+}
+
+#[test]
+fn test_status_reports_initializing_while_seeding() {
+    let server_socket = UdpSocket::bind("127.0.0.1:0").unwrap();
+    let addr = server_socket.local_addr().unwrap();
+    let (seeded_tx, seeded_rx) = mpsc::channel::<()>();
+    let (shutdown_tx, shutdown_rx) = mpsc::channel();
+    let handle = thread::spawn(move || {
+        // The seeder doesn't finish until the test has seen `initializing`.
+        let seeder = Box::new(move |mixer: &mut Mixer| {
+            seeded_rx.recv().unwrap();
+            mixer.seed_from_lines(vec!["/ch/01/mix/fader,f\t0.75"]);
+        });
+        let options = ServerOptions::default();
+        server::run_on_socket(server_socket, Some(seeder), Some(shutdown_rx), options).unwrap();
+    });
+
+    let socket = UdpSocket::bind("127.0.0.1:0").unwrap();
+    socket
+        .set_read_timeout(Some(Duration::from_secs(2)))
+        .unwrap();
+    let send = |path: &str, args: Vec<OscArg>| {
+        let msg = OscMessage::new(path.to_string(), args);
+        socket.send_to(&msg.to_bytes().unwrap(), addr).unwrap();
+    };
+    let mut buf = [0; 1024];
+    let mut recv = || {
+        let (len, _) = socket.recv_from(&mut buf).unwrap();
+        OscMessage::from_bytes(&buf[..len]).unwrap()
+    };
+
+    send("/status", vec![]);
+    let status = recv();
+    assert_eq!(status.args[0], OscArg::String("initializing".to_string()));
+
+    // A request sent while seeding is answered from the seeded state.
+    send("/ch/01/mix/fader", vec![]);
+    seeded_tx.send(()).unwrap();
+    let fader = recv();
+    assert_eq!(fader.path, "/ch/01/mix/fader");
+    assert_eq!(fader.args, vec![OscArg::Float(0.75)]);
+
+    send("/status", vec![]);
+    assert_eq!(recv().args[0], OscArg::String("active".to_string()));
+
+    shutdown_tx.send(()).unwrap();
+    handle.join().unwrap();
+}
@@ -3,7 +3,7 @@
 //! It can fetch Channel, Effects, or Routing presets and save them to local files.
 //! This tool allows you to backup your library presets or transfer them between consoles.
 
-use anyhow::Result;
+use anyhow::{Result, anyhow};
 use clap::{Parser, ValueEnum};
 use osc_lib::OscArg;
 use std::fs::File;
@@ -64,6 +64,83 @@ impl LibType {
     }
 }
 
+/// The number of library slots for each preset type.
+const LIB_SLOTS: i32 = 100;
+
+/// How often, in slots, the scan progress is printed.
+const PROGRESS_EVERY: i32 = 10;
+
+/// What a scan found for one preset type.
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub struct ScanCount {
+    /// Slots queried.
+    pub scanned: u32,
+    /// Slots holding a preset.
+    pub with_data: u32,
+    /// Presets written to disk.
+    pub saved: u32,
+}
+
+/// Per-type results of a library scan, printed at the end of a run.
+#[derive(Debug, Default, PartialEq)]
+pub struct Summary {
+    pub channel: ScanCount,
+    pub effects: ScanCount,
+    pub routing: ScanCount,
+}
+
+impl Summary {
+    fn count_mut(&mut self, t: &LibType) -> Option<&mut ScanCount> {
+        match t {
+            LibType::Channel => Some(&mut self.channel),
+            LibType::Effects => Some(&mut self.effects),
+            LibType::Routing => Some(&mut self.routing),
+            LibType::All => None,
+        }
+    }
+
+    /// Records one scanned slot.
+    ///
+    /// # Arguments
+    ///
+    /// * `t` - The preset type being scanned.
+    /// * `has_data` - Whether the slot holds a preset.
+    /// * `saved` - Whether that preset was written to disk.
+    pub fn record(&mut self, t: &LibType, has_data: bool, saved: bool) {
+        if let Some(count) = self.count_mut(t) {
+            count.scanned += 1;
+            count.with_data += has_data as u32;
+            count.saved += (has_data && saved) as u32;
+        }
+    }
+
+    /// Returns the number of presets that were found but could not be saved.
+    pub fn failed(&self) -> u32 {
+        [self.channel, self.effects, self.routing]
+            .iter()
+            .map(|count| count.with_data - count.saved)
+            .sum()
+    }
+
+    /// Returns one line per scanned type, e.g. `Channel: saved 3 of 3 presets (100 slots scanned)`.
+    pub fn report(&self) -> Vec<String> {
+        [
+            ("Channel", self.channel),
+            ("Effects", self.effects),
+            ("Routing", self.routing),
+        ]
+        .into_iter()
+        .filter(|(_, count)| count.scanned > 0)
+        .map(|(name, count)| {
+            format!(
+                "{}: saved {} of {} presets ({} slots scanned)",
+                name, count.saved, count.with_data, count.scanned
+            )
+        })
+        .collect()
+    }
+}
+
 pub async fn run(args: Args) -> Result<()> {
     let (client, _) = MixerClient::connect_with_transport(
         &args.ip,
@@ -83,23 +160,47 @@ pub async fn run(args: Args) -> Result<()> {
     };
 
     let mut rx = client.subscribe();
+    let mut summary = Summary::default();
 
     for t in types {
         println!("Processing library type: {:?}", t);
-        for i in 1..=100 {
+        let mut found = 0;
+        for i in 1..=LIB_SLOTS {
             let type_str = t.as_str();
             let addr = format!("/-libs/{}/{:03}/hasdata", type_str, i);
             client.send_message(&addr, vec![]).await?;
 
-            if let Ok(Ok(resp)) = timeout(Duration::from_millis(50), rx.recv()).await {
-                if let Some(OscArg::Int(1)) = resp.args.first() {
-                    process_lib_slot(&client, t.clone(), i, &args.output_dir, args.verbose).await?;
+            let has_data = matches!(
+                timeout(Duration::from_millis(50), rx.recv()).await,
+                Ok(Ok(resp)) if resp.arg_int(0) == Some(1)
+            );
+            let mut saved = false;
+            if has_data {
+                found += 1;
+                let result =
+                    process_lib_slot(&client, t.clone(), i, &args.output_dir, args.verbose).await;
+                match result {
+                    Ok(()) => saved = true,
+                    Err(e) => eprintln!("  Failed to save preset {}: {}", i, e),
                 }
             }
+            summary.record(&t, has_data, saved);
+
+            if i % PROGRESS_EVERY == 0 {
+                println!("  Scanned {}/{} slots, {} with data", i, LIB_SLOTS, found);
+            }
         }
     }
 
-    Ok(())
+    println!("Summary:");
+    for line in summary.report() {
+        println!("  {}", line);
+    }
+
+    match summary.failed() {
+        0 => Ok(()),
+        failed => Err(anyhow!("{} preset(s) could not be saved", failed)),
+    }
 }
 
 async fn process_lib_slot(
@@ -122,11 +223,10 @@ async fn process_lib_slot(
         _ => return Err(X32Error::from("Timeout waiting for node".to_string()).into()),
     };
 
-    let name = if let Some(OscArg::String(s)) = resp.args.get(1) {
-        s.clone()
-    } else {
-        format!("Preset_{:03}", id)
-    };
+    let name = resp
+        .arg_str(1)
+        .map(str::to_string)
+        .unwrap_or_else(|| format!("Preset_{:03}", id));
 
     println!("  Found preset {}: {}", id, name);
 
@@ -162,10 +262,8 @@ async fn process_lib_slot(
     let _ = timeout(Duration::from_millis(200), rx.recv()).await;
 
     let mut flags = String::from("%000000000 1");
-    if let Some(OscArg::String(s)) = resp.args.get(3) {
-        if let Some(OscArg::Int(i)) = resp.args.get(4) {
-            flags = format!("{} {}", s, i);
-        }
+    if let (Some(s), Some(i)) = (resp.arg_str(3), resp.arg_int(4)) {
+        flags = format!("{} {}", s, i);
     }
 
     writeln!(file, "#2.1# \"{}\" {}", name, flags)?;
@@ -227,8 +325,8 @@ async fn process_lib_slot(
 
         if let Ok(Ok(resp)) = timeout(Duration::from_millis(500), rx.recv()).await {
             if resp.path == "/node" || resp.path == "node" {
-                if let Some(OscArg::String(val)) = resp.args.first() {
-                    let mut output = val.clone();
+                if let Some(val) = resp.arg_str(0) {
+                    let mut output = val.to_string();
 
                     match t {
                         LibType::Channel => {
@@ -272,7 +370,7 @@ async fn process_lib_slot(
             .await?;
         if let Ok(Ok(resp)) = timeout(Duration::from_millis(500), rx.recv()).await {
             if resp.path == "/node" || resp.path == "node" {
-                if let Some(OscArg::String(val)) = resp.args.first() {
+                if let Some(val) = resp.arg_str(0) {
                     writeln!(file, "{}", val)?;
                 }
             }
@@ -284,3 +382,46 @@ async fn process_lib_slot(
     file.flush()?;
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_summary_counts_each_type() {
+        let mut summary = Summary::default();
+        for i in 1..=LIB_SLOTS {
+            summary.record(&LibType::Channel, i <= 3, true);
+        }
+        for i in 1..=LIB_SLOTS {
+            // Slot 2 has data but fails to save.
+            summary.record(&LibType::Routing, i <= 2, i != 2);
+        }
+        summary.record(&LibType::All, true, true);
+
+        assert_eq!(
+            summary.channel,
+            ScanCount {
+                scanned: 100,
+                with_data: 3,
+                saved: 3
+            }
+        );
+        assert_eq!(
+            summary.routing,
+            ScanCount {
+                scanned: 100,
+                with_data: 2,
+                saved: 1
+            }
+        );
+        assert_eq!(
+            summary.report(),
+            vec![
+                "Channel: saved 3 of 3 presets (100 slots scanned)",
+                "Routing: saved 1 of 2 presets (100 slots scanned)",
+            ]
+        );
+        assert_eq!(summary.failed(), 1);
+    }
+}
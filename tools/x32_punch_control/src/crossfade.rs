@@ -0,0 +1,277 @@
+//! Fader crossfades at punch-in/out boundaries.
+//!
+//! Switching between file and live fader data in one step makes the fader jump. At a punch
+//! boundary, each fader known on both sides is ramped from the value it was following to the
+//! value it will follow next.
+
+use std::collections::HashMap;
+use std::time::Duration;
+use tokio::time::Instant;
+
+/// Returns `true` for fader and send level addresses, which are crossfaded at punch boundaries.
+pub fn is_fader(path: &str) -> bool {
+    path.ends_with("/fader") || path.ends_with("/level")
+}
+
+/// A linear ramp of one fader value.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Crossfade {
+    from: f32,
+    to: f32,
+    start: Instant,
+    duration: Duration,
+}
+
+impl Crossfade {
+    /// Creates a ramp from `from` to `to` that starts at `start` and lasts `duration`.
+    pub fn new(from: f32, to: f32, start: Instant, duration: Duration) -> Self {
+        Self {
+            from,
+            to,
+            start,
+            duration,
+        }
+    }
+
+    /// Returns the fader value at `now`.
+    pub fn value_at(&self, now: Instant) -> f32 {
+        if self.is_done(now) {
+            return self.to;
+        }
+        let t =
+            now.saturating_duration_since(self.start).as_secs_f32() / self.duration.as_secs_f32();
+        self.from + (self.to - self.from) * t
+    }
+
+    /// Returns `true` once the ramp has reached its target.
+    pub fn is_done(&self, now: Instant) -> bool {
+        now.saturating_duration_since(self.start) >= self.duration
+    }
+}
+
+/// The crossfades in progress, keyed by OSC address.
+#[derive(Debug, Default)]
+pub struct Crossfader {
+    fades: HashMap<String, Crossfade>,
+}
+
+impl Crossfader {
+    /// Starts a ramp for every address that has a value in both `from` and `to`.
+    ///
+    /// A ramp already running for an address restarts from its current value.
+    pub fn start(
+        &mut self,
+        from: &HashMap<String, f32>,
+        to: &HashMap<String, f32>,
+        now: Instant,
+        duration: Duration,
+    ) {
+        for (path, &target) in to {
+            let current = match self.fades.get(path) {
+                Some(fade) => fade.value_at(now),
+                None => match from.get(path) {
+                    Some(&value) => value,
+                    None => continue,
+                },
+            };
+            self.fades
+                .insert(path.clone(), Crossfade::new(current, target, now, duration));
+        }
+    }
+
+    /// Returns `true` while `path` is being crossfaded.
+    pub fn contains(&self, path: &str) -> bool {
+        self.fades.contains_key(path)
+    }
+
+    /// Moves the target of a running ramp, e.g. when the file has a newer value.
+    pub fn retarget(&mut self, path: &str, to: f32) {
+        if let Some(fade) = self.fades.get_mut(path) {
+            fade.to = to;
+        }
+    }
+
+    /// Returns the value every running ramp should send now.
+    ///
+    /// Finished ramps return their target one last time and are then dropped.
+    pub fn tick(&mut self, now: Instant) -> Vec<(String, f32)> {
+        let values = self
+            .fades
+            .iter()
+            .map(|(path, fade)| (path.clone(), fade.value_at(now)))
+            .collect();
+        self.fades.retain(|_, fade| !fade.is_done(now));
+        values
+    }
+}
+
+/// Decides which fader values are sent around punch boundaries.
+///
+/// With a crossfade time of zero, punching leaves fader playback alone, as it did before
+/// crossfades existed: file values are sent as they are read and nothing is faded.
+#[derive(Debug, Default)]
+pub struct PunchFader {
+    crossfade_time: Duration,
+    /// Last fader values read from the file.
+    file_faders: HashMap<String, f32>,
+    crossfader: Crossfader,
+    punched: bool,
+}
+
+impl PunchFader {
+    /// Creates a punch fader that ramps over `crossfade_time`, or not at all if it is zero.
+    pub fn new(crossfade_time: Duration) -> Self {
+        Self {
+            crossfade_time,
+            ..Self::default()
+        }
+    }
+
+    /// Returns `true` if punching fades faders between file and live values.
+    pub fn is_enabled(&self) -> bool {
+        !self.crossfade_time.is_zero()
+    }
+
+    /// Starts the crossfades when the punch state changes, and returns the fader values
+    /// to send now.
+    ///
+    /// # Arguments
+    ///
+    /// * `punched` - Whether the tool is punched in.
+    /// * `live_faders` - The last fader values received from the console.
+    /// * `now` - The current time.
+    pub fn tick(
+        &mut self,
+        punched: bool,
+        live_faders: &HashMap<String, f32>,
+        now: Instant,
+    ) -> Vec<(String, f32)> {
+        if !self.is_enabled() {
+            return Vec::new();
+        }
+        if punched != self.punched {
+            self.punched = punched;
+            if punched {
+                self.crossfader
+                    .start(&self.file_faders, live_faders, now, self.crossfade_time);
+            } else {
+                self.crossfader
+                    .start(live_faders, &self.file_faders, now, self.crossfade_time);
+            }
+        }
+        self.crossfader.tick(now)
+    }
+
+    /// Returns `true` if a fader value read from the file should be sent.
+    ///
+    /// Faders follow the live console while punched in, and the fade while one runs.
+    pub fn passes(&mut self, path: &str, value: f32) -> bool {
+        if !self.is_enabled() {
+            return true;
+        }
+        self.file_faders.insert(path.to_string(), value);
+        if self.punched {
+            return false;
+        }
+        if self.crossfader.contains(path) {
+            self.crossfader.retarget(path, value);
+            return false;
+        }
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_is_fader() {
+        assert!(is_fader("/ch/01/mix/fader"));
+        assert!(is_fader("/dca/1/fader"));
+        assert!(is_fader("/ch/01/mix/03/level"));
+        assert!(!is_fader("/ch/01/mix/on"));
+        assert!(!is_fader("/ch/01/config/name"));
+    }
+
+    #[test]
+    fn test_crossfade_is_monotonic_over_window() {
+        let start = Instant::now();
+        let window = Duration::from_millis(200);
+        let fade = Crossfade::new(0.8, 0.2, start, window);
+
+        let values: Vec<f32> = (0..=10)
+            .map(|i| fade.value_at(start + window * i / 10))
+            .collect();
+        assert_eq!(values[0], 0.8);
+        assert!((values[5] - 0.5).abs() < 1e-6);
+        assert_eq!(values[10], 0.2);
+        assert!(values.windows(2).all(|w| w[1] < w[0]), "{:?}", values);
+        assert_eq!(fade.value_at(start + window * 2), 0.2);
+        assert!(!fade.is_done(start + window / 2));
+        assert!(fade.is_done(start + window));
+    }
+
+    #[test]
+    fn test_crossfader_only_fades_known_faders() {
+        let start = Instant::now();
+        let window = Duration::from_millis(100);
+        let file = HashMap::from([
+            ("/ch/01/mix/fader".to_string(), 0.0),
+            ("/ch/02/mix/fader".to_string(), 0.5),
+        ]);
+        let live = HashMap::from([
+            ("/ch/01/mix/fader".to_string(), 1.0),
+            ("/ch/03/mix/fader".to_string(), 0.7),
+        ]);
+
+        let mut crossfader = Crossfader::default();
+        crossfader.start(&file, &live, start, window);
+        assert!(crossfader.contains("/ch/01/mix/fader"));
+        assert!(!crossfader.contains("/ch/02/mix/fader"));
+        assert!(!crossfader.contains("/ch/03/mix/fader"));
+
+        assert_eq!(
+            crossfader.tick(start + window / 4),
+            vec![("/ch/01/mix/fader".to_string(), 0.25)]
+        );
+        assert_eq!(
+            crossfader.tick(start + window),
+            vec![("/ch/01/mix/fader".to_string(), 1.0)]
+        );
+        assert!(!crossfader.contains("/ch/01/mix/fader"));
+    }
+
+    #[test]
+    fn test_punch_without_crossfade_keeps_file_faders() {
+        let start = Instant::now();
+        let live = HashMap::from([("/ch/01/mix/fader".to_string(), 1.0)]);
+
+        let mut punch = PunchFader::new(Duration::ZERO);
+        assert!(punch.passes("/ch/01/mix/fader", 0.2));
+        assert!(punch.tick(true, &live, start).is_empty());
+        // Punched in, file faders are still sent and nothing is faded.
+        assert!(punch.passes("/ch/01/mix/fader", 0.3));
+        assert!(punch.tick(false, &live, start).is_empty());
+    }
+
+    #[test]
+    fn test_punch_crossfade_follows_live_faders() {
+        let start = Instant::now();
+        let window = Duration::from_millis(100);
+        let live = HashMap::from([("/ch/01/mix/fader".to_string(), 1.0)]);
+
+        let mut punch = PunchFader::new(window);
+        assert!(punch.passes("/ch/01/mix/fader", 0.2));
+        assert_eq!(
+            punch.tick(true, &live, start),
+            vec![("/ch/01/mix/fader".to_string(), 0.2)]
+        );
+        assert!(!punch.passes("/ch/01/mix/fader", 0.3));
+        assert_eq!(
+            punch.tick(true, &live, start + window),
+            vec![("/ch/01/mix/fader".to_string(), 1.0)]
+        );
+        assert!(punch.tick(true, &live, start + window).is_empty());
+    }
+}
@@ -5,12 +5,16 @@
 //! and handles logic to merge, catch-up, or catch-back fader/parameter updates based on
 //! User Bank button presses and timecode states.
 //!
+//! Timecode can be read as LTC from an audio input with `--ltc-channel` (requires the
+//! `ltc` feature).
+//!
 //! # Credits
 //! *   **Original concept and work on the C library:** Patrick-Gilles Maillot
 //! *   **Rust implementation by:** mcelb1200
 
 use anyhow::Result;
 use clap::Parser;
+use osc_lib::{OscArg, OscMessage};
 use std::sync::Arc;
 use std::time::{SystemTime, UNIX_EPOCH};
 use tokio::net::UdpSocket;
@@ -18,10 +22,14 @@ use tokio::sync::Mutex;
 use tokio::time::{self, Duration, Instant};
 
 pub mod config;
+pub mod crossfade;
 pub mod format;
+pub mod ltc;
 pub mod state;
 
 use config::Config;
+use crossfade::{PunchFader, is_fader};
+use ltc::LtcCapture;
 use state::AppState;
 
 #[derive(Parser, Debug)]
@@ -38,6 +46,18 @@ pub struct Args {
     /// Punch control file to read/write (.xpc)
     #[arg(short, long)]
     pub file: Option<String>,
+
+    /// Read LTC timecode from this 1-based channel of an audio input (e.g. the X32's USB card)
+    #[arg(long)]
+    pub ltc_channel: Option<usize>,
+
+    /// Part of the name of the audio input carrying LTC (default: the system default input)
+    #[arg(long, requires = "ltc_channel")]
+    pub ltc_device: Option<String>,
+
+    /// Time to fade faders between file and live values when punching in or out (0 = switch)
+    #[arg(long, default_value_t = 0)]
+    pub punch_crossfade_ms: u64,
 }
 
 pub async fn run(args: Args) -> Result<()> {
@@ -59,14 +79,26 @@ pub async fn run(args: Args) -> Result<()> {
 
     let state = Arc::new(Mutex::new(AppState::default()));
 
+    let (ltc_tx, mut ltc_rx) = tokio::sync::mpsc::unbounded_channel();
+    let _ltc_capture = match args.ltc_channel {
+        Some(channel) => {
+            let capture = LtcCapture::start(args.ltc_device.as_deref(), channel, ltc_tx)?;
+            println!("Reading LTC from audio input channel {}", channel);
+            Some(capture)
+        }
+        None => None,
+    };
+
     // Background task to handle time-based playback/merge
     let bg_state = state.clone();
     let bg_sock = socket.clone();
     let config_clone = config.clone();
     let bg_file = args.file.clone();
+    let crossfade_time = Duration::from_millis(args.punch_crossfade_ms);
+    let track_live_faders = !crossfade_time.is_zero();
 
     tokio::spawn(async move {
-        run_logic(bg_state, bg_sock, config_clone, bg_file).await;
+        run_logic(bg_state, bg_sock, config_clone, bg_file, crossfade_time).await;
     });
 
     let mut buf = [0u8; 2048];
@@ -79,10 +111,23 @@ pub async fn run(args: Args) -> Result<()> {
                 let _ = socket.send(b"/xremote").await;
                 last_xremote = Instant::now();
             }
+            Some((tc, frame_rate)) = ltc_rx.recv() => {
+                state.lock().await.set_timecode(&tc, frame_rate);
+            }
             res = socket.recv(&mut buf) => {
                 if let Ok(len) = res {
                     let data = &buf[..len];
 
+                    // Follow live fader moves so that punching in can fade to them.
+                    if let Ok(msg) = OscMessage::from_bytes(data) {
+                        #[allow(clippy::collapsible_if)]
+                        if track_live_faders && is_fader(&msg.path) {
+                            if let Some(value) = msg.arg_float(0) {
+                                state.lock().await.live_faders.insert(msg.path, value);
+                            }
+                        }
+                    }
+
                     // Parse User Bank inputs (buttons mapped to play/stop/rew/etc)
                     // ⚡ Bolt: Use byte slice operations instead of String::from_utf8_lossy to avoid allocations.
                     if data.starts_with(b"/-stat/userpar/") && data.len() >= 17 {
@@ -170,6 +215,7 @@ async fn run_logic(
     socket: Arc<UdpSocket>,
     _config: Config,
     file_path: Option<String>,
+    crossfade_time: Duration,
 ) {
     let mut interval = time::interval(Duration::from_millis(50));
 
@@ -177,6 +223,8 @@ async fn run_logic(
     let mut writer: Option<PunchWriter> = None;
     let mut current_record: Option<PunchRecord> = None;
 
+    let mut punch_fader = PunchFader::new(crossfade_time);
+
     if let Some(ref path) = file_path {
         #[allow(clippy::collapsible_if)]
         if let Ok(f) = File::open(path).await {
@@ -199,6 +247,12 @@ async fn run_logic(
 
         let mut s = state.lock().await;
 
+        for (path, value) in punch_fader.tick(s.xpunch, &s.live_faders, Instant::now()) {
+            if let Ok(bytes) = OscMessage::new(path, vec![OscArg::Float(value)]).to_bytes() {
+                let _ = socket.send(&bytes).await;
+            }
+        }
+
         // If file dataready flag is false, try to read the next record
         #[allow(clippy::collapsible_if)]
         if !s.xfiledataready && s.xreadfile {
@@ -225,10 +279,7 @@ async fn run_logic(
                     eprintln!("Warning: System clock drifted backward or is before UNIX EPOCH ({}). Proceeding with duration zero.", e);
                     Duration::ZERO
                 });
-            if s.t_play.is_zero() {
-                s.t_play = now;
-            }
-            s.dt_play = now.saturating_sub(s.t_play);
+            s.advance_play(now);
 
             #[allow(clippy::collapsible_if)]
             if s.xfiledataready {
@@ -247,6 +298,18 @@ async fn run_logic(
                             }
                         }
 
+                        // Faders follow the live console while punched in, and the fade while one runs.
+                        if let Ok(msg) = OscMessage::from_bytes(&record.data) {
+                            #[allow(clippy::collapsible_if)]
+                            if is_fader(&msg.path) {
+                                if let Some(value) = msg.arg_float(0) {
+                                    if !punch_fader.passes(&msg.path, value) {
+                                        should_send = false;
+                                    }
+                                }
+                            }
+                        }
+
                         if should_send {
                             let _ = socket.send(&record.data).await;
                         }
@@ -280,4 +343,55 @@ mod tests {
         assert_eq!(state.xpause, false);
         assert_eq!(state.xmerge, true);
     }
+
+    #[test]
+    fn test_ltc_frames_drive_punch() {
+        use crate::ltc::LtcDecoder;
+        use crate::ltc::tests::{biphase, encode_frame, timecode};
+        use std::collections::HashMap;
+
+        // A file fader record at 01:00:00.5, i.e. halfway through frame 12 at 25 fps.
+        let mut state = AppState {
+            xplay: true,
+            xpunch: true,
+            xfiledataready: true,
+            dt_read: Duration::from_secs(3600) + Duration::from_millis(500),
+            ..AppState::default()
+        };
+        let wall = Duration::from_secs(1_700_000_000);
+        state.advance_play(wall);
+        assert!(state.dt_play < state.dt_read);
+
+        // 25 fps LTC at 48 kHz, 24 samples per bit, fed one frame at a time.
+        let frames: Vec<u128> = (10..16)
+            .map(|f| encode_frame(&timecode(1, 0, 0, f)))
+            .collect();
+        let samples = biphase(&frames, 24);
+        let mut decoder = LtcDecoder::new(48_000);
+        let mut positions = Vec::new();
+        for chunk in samples.chunks(80 * 24) {
+            if let Some(tc) = decoder.push_samples(chunk) {
+                state.set_timecode(&tc, decoder.frame_rate());
+            }
+            // The wall clock stands still, so only the timecode moves the position.
+            state.advance_play(wall);
+            positions.push(state.dt_play);
+        }
+        assert!(
+            positions.windows(2).all(|w| w[0] <= w[1]),
+            "{:?}",
+            positions
+        );
+        assert_eq!(
+            state.timecode_position(),
+            Some(Duration::from_secs(3600) + Duration::from_millis(600))
+        );
+        assert!(state.dt_play > state.dt_read);
+
+        // The record is due, and punched in, its fader value is held back for the live one.
+        let live = HashMap::from([("/ch/01/mix/fader".to_string(), 0.8)]);
+        let mut punch = PunchFader::new(Duration::from_millis(100));
+        punch.tick(state.xpunch, &live, Instant::now());
+        assert!(!punch.passes("/ch/01/mix/fader", 0.2));
+    }
 }
@@ -0,0 +1,302 @@
+//! Linear Time Code (SMPTE LTC) decoding.
+//!
+//! LTC is an 80-bit frame sent as a biphase-mark audio signal: the level flips at the start
+//! of every bit, and a `1` bit flips once more in the middle. The X32 does not send audio over
+//! OSC, so the samples come from an audio input, e.g. a channel of the console's USB card.
+
+use anyhow::Result;
+
+/// The sync word in bits 64-79 of every frame, read with bit 64 as the least significant bit.
+const SYNC_WORD: u16 = 0xBFFC;
+
+/// A decoded timecode position.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Timecode {
+    pub hours: u32,
+    pub minutes: u32,
+    pub seconds: u32,
+    pub frames: u32,
+    /// Set when the source is running drop-frame (29.97 fps) timecode.
+    pub drop_frame: bool,
+}
+
+/// Decodes one LTC frame.
+///
+/// # Arguments
+///
+/// * `bits` - The 80 frame bits, with the first bit received in bit 0.
+///
+/// # Returns
+///
+/// The timecode, or `None` if the sync word is missing or a field is out of range.
+pub fn decode_frame(bits: u128) -> Option<Timecode> {
+    if (bits >> 64) as u16 != SYNC_WORD {
+        return None;
+    }
+    let field = |start: u32, len: u32| ((bits >> start) & ((1 << len) - 1)) as u32;
+    let bcd = |units: u32, tens: u32, tens_len: u32| {
+        let units = field(units, 4);
+        (units <= 9).then(|| units + 10 * field(tens, tens_len))
+    };
+
+    let timecode = Timecode {
+        frames: bcd(0, 8, 2)?,
+        drop_frame: field(10, 1) == 1,
+        seconds: bcd(16, 24, 3)?,
+        minutes: bcd(32, 40, 3)?,
+        hours: bcd(48, 56, 2)?,
+    };
+    (timecode.frames < 30 && timecode.seconds < 60 && timecode.minutes < 60 && timecode.hours < 24)
+        .then_some(timecode)
+}
+
+/// Recovers LTC frames from a stream of audio samples.
+pub struct LtcDecoder {
+    /// Sample rate of the audio, in Hz.
+    sample_rate: u32,
+    /// Estimated length of one bit, in samples.
+    period: f32,
+    /// Current signal polarity.
+    high: bool,
+    /// Samples since the last level change.
+    since: f32,
+    /// Length of a half bit waiting for its second half, if any.
+    half: Option<f32>,
+    /// The last 80 bits, oldest in bit 0.
+    bits: u128,
+}
+
+impl LtcDecoder {
+    /// Level a sample must cross before it counts as a change of polarity.
+    const HYSTERESIS: f32 = 0.02;
+
+    /// Creates a decoder for audio at the given sample rate.
+    pub fn new(sample_rate: u32) -> Self {
+        // Start between the 24 fps and 30 fps bit rates (1920-2400 bits/s) and adapt from there.
+        Self {
+            sample_rate,
+            period: sample_rate as f32 / 2160.0,
+            high: false,
+            since: 0.0,
+            half: None,
+            bits: 0,
+        }
+    }
+
+    /// Feeds audio samples to the decoder.
+    ///
+    /// # Returns
+    ///
+    /// The last complete frame found in `samples`, if any.
+    pub fn push_samples(&mut self, samples: &[f32]) -> Option<Timecode> {
+        let mut found = None;
+        for &sample in samples {
+            self.since += 1.0;
+            let flipped = if self.high {
+                sample < -Self::HYSTERESIS
+            } else {
+                sample > Self::HYSTERESIS
+            };
+            if flipped {
+                self.high = !self.high;
+                let interval = std::mem::take(&mut self.since);
+                if let Some(timecode) = self.transition(interval) {
+                    found = Some(timecode);
+                }
+            }
+        }
+        found
+    }
+
+    /// Returns the nominal frame rate (24, 25 or 30 fps) closest to the bit rate being received.
+    pub fn frame_rate(&self) -> u32 {
+        let fps = self.sample_rate as f32 / (80.0 * self.period);
+        [24, 25, 30]
+            .into_iter()
+            .min_by(|a, b| (*a as f32 - fps).abs().total_cmp(&(*b as f32 - fps).abs()))
+            .unwrap_or(30)
+    }
+
+    /// Classifies the time between two level changes as a whole or half bit.
+    fn transition(&mut self, interval: f32) -> Option<Timecode> {
+        if interval > 2.5 * self.period {
+            // Silence or a dropout: wait for the signal to settle again.
+            self.half = None;
+            return None;
+        }
+        if interval > 0.75 * self.period {
+            self.half = None;
+            self.period = 0.75 * self.period + 0.25 * interval;
+            return self.push_bit(false);
+        }
+        match self.half.take() {
+            Some(first) => {
+                self.period = 0.75 * self.period + 0.25 * (first + interval);
+                self.push_bit(true)
+            }
+            None => {
+                self.half = Some(interval);
+                None
+            }
+        }
+    }
+
+    fn push_bit(&mut self, bit: bool) -> Option<Timecode> {
+        self.bits = (self.bits >> 1) | ((bit as u128) << 79);
+        decode_frame(self.bits)
+    }
+}
+
+/// An open audio input feeding an [`LtcDecoder`].
+pub struct LtcCapture {
+    #[cfg(feature = "ltc")]
+    _stream: cpal::Stream,
+}
+
+impl LtcCapture {
+    /// Starts decoding LTC from one channel of an audio input.
+    ///
+    /// # Arguments
+    ///
+    /// * `device_query` - Part of the input device name, or `None` for the default input.
+    /// * `channel` - The 1-based input channel carrying LTC.
+    /// * `sender` - Receives every decoded timecode, with its frame rate.
+    ///
+    /// The capture stops when the returned value is dropped.
+    #[allow(unused_variables)]
+    pub fn start(
+        device_query: Option<&str>,
+        channel: usize,
+        sender: tokio::sync::mpsc::UnboundedSender<(Timecode, u32)>,
+    ) -> Result<Self> {
+        #[cfg(feature = "ltc")]
+        {
+            use cpal::traits::{DeviceTrait, HostTrait, StreamTrait};
+
+            let host = cpal::default_host();
+            let device = match device_query {
+                Some(query) => host
+                    .input_devices()?
+                    .find(|d| d.name().map(|n| n.contains(query)).unwrap_or(false))
+                    .ok_or_else(|| anyhow::anyhow!("No audio input matching '{}'", query))?,
+                None => host
+                    .default_input_device()
+                    .ok_or_else(|| anyhow::anyhow!("No default audio input available"))?,
+            };
+            let config: cpal::StreamConfig = device.default_input_config()?.into();
+            let channels = config.channels as usize;
+            if channel == 0 || channel > channels {
+                anyhow::bail!("LTC channel {} is out of range (1-{})", channel, channels);
+            }
+
+            let mut decoder = LtcDecoder::new(config.sample_rate.0);
+            let mut mono = Vec::new();
+            let stream = device.build_input_stream(
+                &config,
+                move |data: &[f32], _: &cpal::InputCallbackInfo| {
+                    mono.clear();
+                    mono.extend(data.chunks(channels).map(|frame| frame[channel - 1]));
+                    if let Some(timecode) = decoder.push_samples(&mono) {
+                        let _ = sender.send((timecode, decoder.frame_rate()));
+                    }
+                },
+                |err| eprintln!("LTC input error: {}", err),
+                None,
+            )?;
+            stream.play()?;
+            Ok(Self { _stream: stream })
+        }
+
+        #[cfg(not(feature = "ltc"))]
+        {
+            anyhow::bail!("LTC input needs x32_punch_control built with the `ltc` feature.")
+        }
+    }
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::*;
+
+    pub(crate) fn encode_frame(tc: &Timecode) -> u128 {
+        let mut bits = (SYNC_WORD as u128) << 64;
+        let mut put = |start: u32, value: u32| bits |= (value as u128) << start;
+        put(0, tc.frames % 10);
+        put(8, tc.frames / 10);
+        put(10, tc.drop_frame as u32);
+        put(16, tc.seconds % 10);
+        put(24, tc.seconds / 10);
+        put(32, tc.minutes % 10);
+        put(40, tc.minutes / 10);
+        put(48, tc.hours % 10);
+        put(56, tc.hours / 10);
+        bits
+    }
+
+    /// Renders frames as a biphase-mark signal with `period` samples per bit.
+    pub(crate) fn biphase(frames: &[u128], period: usize) -> Vec<f32> {
+        let mut level = 0.5;
+        let mut samples = Vec::new();
+        for frame in frames {
+            for i in 0..80 {
+                level = -level;
+                let one = (frame >> i) & 1 == 1;
+                for s in 0..period {
+                    if one && s == period / 2 {
+                        level = -level;
+                    }
+                    samples.push(level);
+                }
+            }
+        }
+        // The edge that starts the next frame ends the last bit.
+        samples.extend(std::iter::repeat_n(-level, period));
+        samples
+    }
+
+    pub(crate) fn timecode(hours: u32, minutes: u32, seconds: u32, frames: u32) -> Timecode {
+        Timecode {
+            hours,
+            minutes,
+            seconds,
+            frames,
+            drop_frame: false,
+        }
+    }
+
+    #[test]
+    fn test_decode_frame_fields() {
+        let tc = timecode(13, 57, 42, 24);
+        assert_eq!(decode_frame(encode_frame(&tc)), Some(tc));
+
+        let drop = Timecode {
+            drop_frame: true,
+            ..timecode(0, 1, 0, 2)
+        };
+        assert_eq!(decode_frame(encode_frame(&drop)), Some(drop));
+
+        // Without the sync word, or with an impossible value, there is no frame.
+        assert_eq!(decode_frame(encode_frame(&tc) & !(1 << 79)), None);
+        assert_eq!(decode_frame(encode_frame(&timecode(0, 61, 0, 0))), None);
+    }
+
+    #[test]
+    fn test_decoder_reads_synthetic_ltc() {
+        // 25 fps at 48 kHz is 24 samples per bit.
+        let frames: Vec<u128> = (0..3)
+            .map(|f| encode_frame(&timecode(1, 2, 3, 10 + f)))
+            .collect();
+        let samples = biphase(&frames, 24);
+
+        let mut decoder = LtcDecoder::new(48_000);
+        let mut decoded = Vec::new();
+        for chunk in samples.chunks(256) {
+            decoded.extend(decoder.push_samples(chunk));
+        }
+
+        // The first frame only primes the decoder; the next ones are read in full.
+        assert_eq!(decoded.last(), Some(&timecode(1, 2, 3, 12)));
+        assert!(decoded.contains(&timecode(1, 2, 3, 11)));
+        assert_eq!(decoder.frame_rate(), 25);
+    }
+}
@@ -1,5 +1,8 @@
+use std::collections::HashMap;
 use std::time::Duration;
 
+use crate::ltc::Timecode;
+
 #[derive(Debug, Clone, Copy, PartialEq)]
 #[allow(dead_code)]
 pub enum Mode {
@@ -41,6 +44,9 @@ pub struct AppState {
     pub xmidiss: u32,
     pub xmidifr: u32,
     pub xfrrate: u32,
+
+    /// Last fader values received from the console, by OSC address.
+    pub live_faders: HashMap<String, f32>,
 }
 
 impl Default for AppState {
@@ -69,6 +75,50 @@ impl Default for AppState {
             xmidiss: 0,
             xmidifr: 0,
             xfrrate: 0,
+            live_faders: HashMap::new(),
+        }
+    }
+}
+
+impl AppState {
+    /// Records the current timecode position from an external source.
+    ///
+    /// Once timecode has been received, it sets the play position instead of the clock.
+    pub fn set_timecode(&mut self, tc: &Timecode, frame_rate: u32) {
+        self.xmidihr = tc.hours;
+        self.xmidimn = tc.minutes;
+        self.xmidiss = tc.seconds;
+        self.xmidifr = tc.frames;
+        self.xfrrate = frame_rate;
+        self.xmtcon = true;
+    }
+
+    /// Returns the last timecode position received, or `None` without timecode.
+    pub fn timecode_position(&self) -> Option<Duration> {
+        if !self.xmtcon || self.xfrrate == 0 {
+            return None;
+        }
+        let secs = self.xmidihr * 3600 + self.xmidimn * 60 + self.xmidiss;
+        Some(
+            Duration::from_secs(secs as u64)
+                + Duration::from_secs(self.xmidifr as u64) / self.xfrrate,
+        )
+    }
+
+    /// Updates `dt_play`, the play position that file records are due against.
+    ///
+    /// The position follows timecode when there is some, and otherwise the time since play
+    /// started.
+    ///
+    /// # Arguments
+    ///
+    /// * `now` - The current wall-clock time, since the UNIX epoch.
+    pub fn advance_play(&mut self, now: Duration) {
+        if self.t_play.is_zero() {
+            self.t_play = now;
         }
+        self.dt_play = self
+            .timecode_position()
+            .unwrap_or_else(|| now.saturating_sub(self.t_play));
     }
 }
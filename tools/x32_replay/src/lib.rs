@@ -3,6 +3,8 @@
 //! It can:
 //! - **Record**: Capture all incoming OSC messages from the mixer to a binary file, preserving timing.
 //! - **Play**: Replay a recorded file back to the mixer, respecting the original timing intervals.
+//! - **Loop**: Replay a recorded file over and over until stopped.
+//! - **Dump / Compile**: Convert a recording to an editable text file and back.
 //!
 //! This is useful for diagnosing issues, creating regression tests, or automating repetitive tasks.
 //!
@@ -12,15 +14,17 @@
 //! *   **Additional concepts by:** mcelb1200
 //! *   **Rust implementation by:** mcelb1200
 
-use anyhow::Result;
-use clap::Parser;
-use osc_lib::OscMessage;
+use anyhow::{anyhow, Context, Result};
+use clap::{Parser, Subcommand};
+use osc_lib::{OscBundle, OscMessage, OscPacket};
+use std::fmt::Write as _;
+use std::str::FromStr;
 use std::sync::{Arc, Mutex};
 use std::time::{SystemTime, UNIX_EPOCH};
 use tokio::fs::File;
 use tokio::io::{AsyncReadExt, AsyncWriteExt, BufReader, BufWriter};
 use tokio::time::{self, Duration, Instant};
-use x32_lib::MixerClient;
+use x32_lib::{Keepalive, MixerClient};
 
 /// Command-line arguments for `x32_replay`.
 #[derive(Parser, Debug)]
@@ -35,6 +39,242 @@ pub struct Args {
     /// Enable verbose output.
     #[arg(short, long)]
     pub verbose: bool,
+    /// Restart playback from the beginning of the file when it ends.
+    #[arg(short, long = "loop")]
+    pub loop_playback: bool,
+    /// Only record messages whose path starts with this prefix (repeatable).
+    #[arg(long, value_name = "PREFIX")]
+    pub include: Vec<String>,
+    /// Never record messages whose path starts with this prefix (repeatable).
+    #[arg(long, value_name = "PREFIX")]
+    pub exclude: Vec<String>,
+    /// Playback speed multiplier, 0.01-100 (e.g. 2 for twice as fast, 0.5 for half speed).
+    #[arg(long, default_value_t = 1.0, value_parser = parse_speed)]
+    pub speed: f64,
+    /// Convert a file instead of connecting to the console.
+    #[command(subcommand)]
+    pub command: Option<Command>,
+}
+
+/// Offline conversions between the binary replay format and text.
+#[derive(Subcommand, Debug)]
+pub enum Command {
+    /// Print the replay file given by `--file` as text, one message per line.
+    Dump,
+    /// Write the replay file given by `--file` from a text file produced by `dump`.
+    Compile {
+        /// The text file to read.
+        input: String,
+    },
+}
+
+/// The largest record the player accepts.
+const MAX_RECORD_LEN: usize = 2048;
+
+/// How long looped playback waits after the last packet of a pass before replaying the first.
+///
+/// The file does not record how long the take ran on after its last packet, so without a
+/// pause a short or empty file would be replayed back to back as fast as it can be read.
+const LOOP_GAP: Duration = Duration::from_millis(100);
+
+/// Appends one record (timestamp, length, datagram) in the replay file format.
+fn write_record(out: &mut Vec<u8>, time: Duration, data: &[u8]) {
+    out.extend_from_slice(&time.as_secs().to_le_bytes());
+    out.extend_from_slice(&time.subsec_micros().to_le_bytes());
+    out.extend_from_slice(&(data.len() as u32).to_le_bytes());
+    out.extend_from_slice(data);
+}
+
+/// Converts a replay file to text.
+///
+/// Each message is written on its own line, prefixed with its time in seconds relative to
+/// the first record, e.g. `0.020000 /ch/01/mix/fader ,f 0.75`.
+///
+/// # Arguments
+///
+/// * `bytes` - The contents of a replay file.
+///
+/// # Returns
+///
+/// A `Result` containing the text, or an error if a record is truncated or undecodable.
+pub fn dump(bytes: &[u8]) -> Result<String> {
+    let mut text = String::new();
+    let mut first = None;
+    let mut rest = bytes;
+    let mut index = 0;
+    while !rest.is_empty() {
+        index += 1;
+        let header = rest
+            .get(..16)
+            .ok_or_else(|| anyhow!("Record {} is truncated", index))?;
+        let sec = u64::from_le_bytes(header[0..8].try_into()?);
+        let usec = u32::from_le_bytes(header[8..12].try_into()?);
+        let len = u32::from_le_bytes(header[12..16].try_into()?) as usize;
+        let data = rest
+            .get(16..16 + len)
+            .ok_or_else(|| anyhow!("Record {} is truncated", index))?;
+        rest = &rest[16 + len..];
+
+        let time = Duration::from_secs(sec) + Duration::from_micros(usec as u64);
+        let relative = time.saturating_sub(*first.get_or_insert(time));
+        let mut offset = 0;
+        while offset < data.len() {
+            let (msg, consumed) = OscMessage::from_bytes_prefix(&data[offset..])
+                .map_err(|e| anyhow!("Record {}: {}", index, e))?;
+            offset += consumed;
+            let _ = writeln!(
+                text,
+                "{}.{:06} {}",
+                relative.as_secs(),
+                relative.subsec_micros(),
+                msg
+            );
+        }
+    }
+    Ok(text)
+}
+
+/// Converts text written by [`dump`] back to the replay file format.
+///
+/// Blank lines and lines starting with `#` are ignored. Each remaining line becomes one
+/// record.
+///
+/// # Arguments
+///
+/// * `text` - Lines of `<seconds> <message>`.
+///
+/// # Returns
+///
+/// A `Result` containing the file contents, or an error naming the first bad line.
+pub fn compile(text: &str) -> Result<Vec<u8>> {
+    let mut out = Vec::new();
+    for (i, line) in text.lines().enumerate() {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+        let (time, message) = line
+            .split_once(' ')
+            .ok_or_else(|| anyhow!("Line {}: expected '<seconds> <message>'", i + 1))?;
+        let time = parse_time(time).with_context(|| format!("Line {}", i + 1))?;
+        let data = OscMessage::from_str(message.trim())
+            .and_then(|msg| msg.to_bytes())
+            .map_err(|e| anyhow!("Line {}: {}", i + 1, e))?;
+        if data.len() >= MAX_RECORD_LEN {
+            return Err(anyhow!("Line {}: message is too long to replay", i + 1));
+        }
+        write_record(&mut out, time, &data);
+    }
+    Ok(out)
+}
+
+/// Parses a time in seconds such as `12` or `0.020000` without floating-point rounding.
+fn parse_time(s: &str) -> Result<Duration> {
+    let invalid = || anyhow!("invalid time: {}", s);
+    let (secs, frac) = s.split_once('.').unwrap_or((s, ""));
+    if frac.len() > 6 || !frac.bytes().all(|b| b.is_ascii_digit()) {
+        return Err(invalid());
+    }
+    let secs = secs.parse::<u64>().map_err(|_| invalid())?;
+    let micros = format!("{:0<6}", frac)
+        .parse::<u64>()
+        .map_err(|_| invalid())?;
+    Ok(Duration::from_secs(secs) + Duration::from_micros(micros))
+}
+
+/// The range of playback speed factors accepted.
+const SPEED_RANGE: std::ops::RangeInclusive<f64> = 0.01..=100.0;
+
+/// Parses a playback speed factor between 0.01 and 100.
+pub fn parse_speed(s: &str) -> std::result::Result<f64, String> {
+    match s.trim().parse::<f64>() {
+        Ok(speed) if SPEED_RANGE.contains(&speed) => Ok(speed),
+        Ok(_) => Err(format!(
+            "speed must be between {} and {}: {}",
+            SPEED_RANGE.start(),
+            SPEED_RANGE.end(),
+            s
+        )),
+        Err(e) => Err(format!("invalid speed {}: {}", s, e)),
+    }
+}
+
+/// Computes how long to wait before sending a packet.
+///
+/// # Arguments
+///
+/// * `packet_time` - The packet's recorded timestamp.
+/// * `first_packet_time` - The recorded timestamp of the first packet played.
+/// * `start` - When the first packet was played.
+/// * `now` - The current time.
+/// * `speed` - The playback speed multiplier.
+///
+/// # Returns
+///
+/// The time to sleep, or `None` if the packet is already due.
+fn playback_delay(
+    packet_time: Duration,
+    first_packet_time: Duration,
+    start: Instant,
+    now: Instant,
+    speed: f64,
+) -> Option<Duration> {
+    if packet_time <= first_packet_time {
+        return None;
+    }
+    let delta = (packet_time - first_packet_time).div_f64(speed);
+    let target_time = start + delta;
+    if target_time > now {
+        Some(target_time - now)
+    } else {
+        None
+    }
+}
+
+/// Selects which messages are written to the file while recording.
+#[derive(Debug, Default, Clone)]
+pub struct PathFilter {
+    include: Vec<String>,
+    exclude: Vec<String>,
+}
+
+impl PathFilter {
+    /// Creates a filter from include and exclude path prefixes.
+    ///
+    /// An empty include list lets every path through; excludes always win.
+    pub fn new(include: Vec<String>, exclude: Vec<String>) -> Self {
+        Self { include, exclude }
+    }
+
+    /// Returns `true` if a message with this path should be recorded.
+    pub fn allows(&self, path: &str) -> bool {
+        (self.include.is_empty() || self.include.iter().any(|p| path.starts_with(p.as_str())))
+            && !self.exclude.iter().any(|p| path.starts_with(p.as_str()))
+    }
+
+    /// Removes the messages this filter rejects from a packet.
+    ///
+    /// Bundles are filtered per contained message.
+    ///
+    /// # Returns
+    ///
+    /// The remaining packet, or `None` if nothing in it should be recorded.
+    pub fn apply(&self, packet: OscPacket) -> Option<OscPacket> {
+        match packet {
+            OscPacket::Message(msg) => self.allows(&msg.path).then_some(OscPacket::Message(msg)),
+            OscPacket::Bundle(bundle) => {
+                let content: Vec<OscPacket> = bundle
+                    .content
+                    .into_iter()
+                    .filter_map(|p| self.apply(p))
+                    .collect();
+                (!content.is_empty()).then_some(OscPacket::Bundle(OscBundle {
+                    timetag: bundle.timetag,
+                    content,
+                }))
+            }
+        }
+    }
 }
 
 /// Represents the current operating mode of the application.
@@ -57,10 +297,34 @@ struct AppState {
     file_path: String,
     start_time: Option<Instant>,
     last_play_time: Option<Duration>, // Relative time in file
+    /// Whether playback restarts at the end of the file.
+    looping: bool,
+    /// Number of completed passes through the file while looping.
+    loop_count: u32,
+    /// Playback speed multiplier.
+    speed: f64,
 }
 
 /// The main entry point for the application.
 pub async fn run(args: Args) -> Result<()> {
+    match &args.command {
+        Some(Command::Dump) => {
+            let bytes = std::fs::read(&args.file)
+                .with_context(|| format!("Failed to read {}", args.file))?;
+            print!("{}", dump(&bytes)?);
+            return Ok(());
+        }
+        Some(Command::Compile { input }) => {
+            let text = std::fs::read_to_string(input)
+                .with_context(|| format!("Failed to read {}", input))?;
+            std::fs::write(&args.file, compile(&text)?)
+                .with_context(|| format!("Failed to write {}", args.file))?;
+            println!("Wrote {}.", args.file);
+            return Ok(());
+        }
+        None => {}
+    }
+
     let addr = format!("{}:10023", args.ip);
     let client = Arc::new(MixerClient::connect(&addr, false).await?);
 
@@ -69,22 +333,27 @@ pub async fn run(args: Args) -> Result<()> {
     }
 
     println!("X32Replay connected to {}.", args.ip);
-    println!("Commands: record, play, stop, pause, exit");
+    println!("Commands: record, play, loop, speed <factor>, stop, pause, exit");
 
     let state = Arc::new(Mutex::new(AppState {
         mode: Mode::Idle,
         file_path: args.file.clone(),
         start_time: None,
         last_play_time: None,
+        looping: args.loop_playback,
+        loop_count: 0,
+        speed: args.speed,
     }));
 
     // Background task for logic
     let state_clone = state.clone();
     let client_clone = client.clone();
     let file_path = args.file.clone();
+    let verbose = args.verbose;
+    let filter = PathFilter::new(args.include.clone(), args.exclude.clone());
 
     tokio::spawn(async move {
-        run_logic(state_clone, client_clone, file_path).await;
+        run_logic(state_clone, client_clone, file_path, verbose, filter).await;
     });
 
     // Stdin loop
@@ -151,13 +420,32 @@ pub async fn run(args: Args) -> Result<()> {
             }
             "play" => {
                 s.mode = Mode::Playing;
+                s.looping = args.loop_playback;
+                s.loop_count = 0;
                 println!("Playing...");
             }
+            "loop" => {
+                s.mode = Mode::Playing;
+                s.looping = true;
+                s.loop_count = 0;
+                println!("Playing in a loop...");
+            }
             "pause" => {
                 s.mode = Mode::Paused;
                 println!("Paused.");
             }
-            _ => println!("Unknown command."),
+            _ => match cmd.strip_prefix("speed ") {
+                Some(factor) => match parse_speed(factor) {
+                    Ok(speed) => {
+                        s.speed = speed;
+                        // Re-anchor the playback clock so the new speed applies from here on.
+                        s.start_time = None;
+                        println!("Speed set to {}x.", speed);
+                    }
+                    Err(e) => println!("{}", e),
+                },
+                None => println!("Unknown command."),
+            },
         }
     }
 
@@ -167,10 +455,19 @@ pub async fn run(args: Args) -> Result<()> {
 /// The core logic loop handling recording and playback.
 ///
 /// This function runs in a background task and switches behavior based on the `AppState`.
-/// - **Recording**: Captures packets from UDP, timestamps them, and writes to file.
+/// - **Recording**: Captures packets from UDP, drops those rejected by `filter`, timestamps the
+///   rest, and writes them to file.
 /// - **Playing**: Reads packets from file, sleeps for the correct duration, and sends to UDP.
-async fn run_logic(state: Arc<Mutex<AppState>>, client: Arc<MixerClient>, default_file: String) {
-    let mut last_xremote = Instant::now();
+///   When looping, the file is reopened at the end and, after [`LOOP_GAP`], timing restarts from
+///   its first packet.
+async fn run_logic(
+    state: Arc<Mutex<AppState>>,
+    client: Arc<MixerClient>,
+    default_file: String,
+    verbose: bool,
+    filter: PathFilter,
+) {
+    let mut keepalive = Keepalive::new();
     let mut file_writer: Option<BufWriter<File>> = None;
     let mut file_reader: Option<BufReader<tokio::io::Take<File>>> = None;
 
@@ -203,16 +500,16 @@ async fn run_logic(state: Arc<Mutex<AppState>>, client: Arc<MixerClient>, defaul
                 }
 
                 // Send /xremote keepalive
-                if last_xremote.elapsed() > Duration::from_secs(9) {
-                    if let Err(e) = client.send_message("/xremote", vec![]).await {
-                        eprintln!("Failed to send keepalive: {}", e);
-                    }
-                    last_xremote = Instant::now();
+                if let Err(e) = keepalive.tick(&client).await {
+                    eprintln!("Failed to send keepalive: {}", e);
                 }
 
                 // Recv with timeout
                 if let Ok(Ok(msg)) = time::timeout(Duration::from_millis(100), rx.recv()).await {
-                    if let Ok(bytes) = msg.to_bytes() {
+                    let Some(packet) = filter.apply(msg.into()) else {
+                        continue;
+                    };
+                    if let Ok(bytes) = packet.to_bytes() {
                         let len = bytes.len();
                         // Write timestamp + len + data
                         if let Some(w) = &mut file_writer {
@@ -258,6 +555,7 @@ async fn run_logic(state: Arc<Mutex<AppState>>, client: Arc<MixerClient>, defaul
                         }
                         Err(e) => {
                             eprintln!("Failed to open file: {}", e);
+                            time::sleep(Duration::from_millis(100)).await;
                             continue;
                         }
                     }
@@ -269,7 +567,7 @@ async fn run_logic(state: Arc<Mutex<AppState>>, client: Arc<MixerClient>, defaul
                             let usec = r.read_u32_le().await.unwrap_or(0);
                             let len = r.read_u32_le().await.unwrap_or(0);
 
-                            if len > 0 && len < 2048 {
+                            if len > 0 && (len as usize) < MAX_RECORD_LEN {
                                 let mut data = vec![0u8; len as usize];
                                 if r.read_exact(&mut data).await.is_ok() {
                                     // Timing Logic
@@ -295,18 +593,13 @@ async fn run_logic(state: Arc<Mutex<AppState>>, client: Arc<MixerClient>, defaul
                                         if let (Some(start), Some(first_packet_time)) =
                                             (s.start_time, s.last_play_time)
                                         {
-                                            if packet_time > first_packet_time {
-                                                let delta = packet_time - first_packet_time;
-                                                let target_time = start + delta;
-                                                let now = Instant::now();
-                                                if target_time > now {
-                                                    Some(target_time - now)
-                                                } else {
-                                                    None
-                                                }
-                                            } else {
-                                                None
-                                            }
+                                            playback_delay(
+                                                packet_time,
+                                                first_packet_time,
+                                                start,
+                                                Instant::now(),
+                                                s.speed,
+                                            )
                                         } else {
                                             None
                                         }
@@ -316,22 +609,41 @@ async fn run_logic(state: Arc<Mutex<AppState>>, client: Arc<MixerClient>, defaul
                                         time::sleep(dur).await;
                                     }
 
-                                    if let Ok(msg) = OscMessage::from_bytes(&data) {
+                                    // A recorded datagram may hold several concatenated messages.
+                                    let mut offset = 0;
+                                    while let Ok((msg, consumed)) =
+                                        OscMessage::from_bytes_prefix(&data[offset..])
+                                    {
+                                        offset += consumed;
                                         let _ = client.send_message(&msg.path, msg.args).await;
                                     }
                                 }
                             }
                         }
                         Err(_) => {
-                            println!("End of file.");
-                            if let Ok(mut s) = state.lock() {
-                                s.mode = Mode::Idle;
+                            let looping = if let Ok(mut s) = state.lock() {
+                                // Clearing the timing makes the next pass start from its own t0.
                                 s.start_time = None;
+                                s.last_play_time = None;
+                                if s.looping {
+                                    s.loop_count += 1;
+                                    if verbose {
+                                        println!("Loop {} complete.", s.loop_count);
+                                    }
+                                } else {
+                                    println!("End of file.");
+                                    s.mode = Mode::Idle;
+                                }
+                                s.looping
                             } else {
                                 eprintln!("State mutex poisoned in background task, exiting.");
                                 break;
-                            }
+                            };
                             file_reader = None;
+                            if looping {
+                                // The next pass's first packet is due one gap after this pass ends.
+                                time::sleep(LOOP_GAP).await;
+                            }
                         }
                     }
                 }
@@ -346,3 +658,248 @@ async fn run_logic(state: Arc<Mutex<AppState>>, client: Arc<MixerClient>, defaul
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use osc_lib::OscArg;
+    use std::io::Write;
+
+    /// Writes a replay file with one message every `interval_ms`.
+    fn write_replay_file(path: &std::path::Path, messages: &[OscMessage], interval_ms: u32) {
+        let mut f = std::fs::File::create(path).unwrap();
+        for (i, msg) in messages.iter().enumerate() {
+            let bytes = msg.to_bytes().unwrap();
+            f.write_all(&1_000u64.to_le_bytes()).unwrap();
+            f.write_all(&(i as u32 * interval_ms * 1000).to_le_bytes())
+                .unwrap();
+            f.write_all(&(bytes.len() as u32).to_le_bytes()).unwrap();
+            f.write_all(&bytes).unwrap();
+        }
+    }
+
+    #[tokio::test]
+    async fn test_loop_replays_whole_file_each_pass() {
+        let server = tokio::net::UdpSocket::bind("127.0.0.1:0").await.unwrap();
+        let addr = server.local_addr().unwrap();
+
+        let messages: Vec<OscMessage> = (1..=3)
+            .map(|ch| {
+                OscMessage::new(
+                    format!("/ch/{:02}/mix/fader", ch),
+                    vec![OscArg::Float(ch as f32 / 10.0)],
+                )
+            })
+            .collect();
+        let path = std::env::temp_dir().join(format!("x32_replay_loop_{}.bin", std::process::id()));
+        write_replay_file(&path, &messages, 20);
+
+        let client = Arc::new(
+            MixerClient::connect(&addr.to_string(), false)
+                .await
+                .unwrap(),
+        );
+        let state = Arc::new(Mutex::new(AppState {
+            mode: Mode::Playing,
+            file_path: path.to_string_lossy().into_owned(),
+            start_time: None,
+            last_play_time: None,
+            looping: true,
+            loop_count: 0,
+            speed: 1.0,
+        }));
+        let task = tokio::spawn(run_logic(
+            state.clone(),
+            client,
+            path.to_string_lossy().into_owned(),
+            false,
+            PathFilter::default(),
+        ));
+
+        let mut buf = [0u8; 1024];
+        let mut received = Vec::new();
+        let mut started = None;
+        while received.len() < 2 * messages.len() {
+            let len = server.recv(&mut buf).await.unwrap();
+            let msg = OscMessage::from_bytes(&buf[..len]).unwrap();
+            if msg.path == "/info" {
+                continue;
+            }
+            started.get_or_insert_with(Instant::now);
+            received.push(msg);
+        }
+        let elapsed = started.unwrap().elapsed();
+        task.abort();
+        let _ = std::fs::remove_file(&path);
+
+        assert_eq!(&received[..3], &messages[..]);
+        assert_eq!(&received[3..], &messages[..]);
+        assert!(state.lock().unwrap().loop_count >= 1);
+        // Each pass keeps its 40ms of spacing instead of replaying instantly.
+        assert!(elapsed >= Duration::from_millis(70), "{:?}", elapsed);
+    }
+
+    #[tokio::test]
+    async fn test_loop_waits_between_passes_of_an_empty_file() {
+        let server = tokio::net::UdpSocket::bind("127.0.0.1:0").await.unwrap();
+        let addr = server.local_addr().unwrap();
+        let path =
+            std::env::temp_dir().join(format!("x32_replay_empty_{}.bin", std::process::id()));
+        write_replay_file(&path, &[], 0);
+
+        let client = Arc::new(
+            MixerClient::connect(&addr.to_string(), false)
+                .await
+                .unwrap(),
+        );
+        let state = Arc::new(Mutex::new(AppState {
+            mode: Mode::Playing,
+            file_path: path.to_string_lossy().into_owned(),
+            start_time: None,
+            last_play_time: None,
+            looping: true,
+            loop_count: 0,
+            speed: 1.0,
+        }));
+        let task = tokio::spawn(run_logic(
+            state.clone(),
+            client,
+            path.to_string_lossy().into_owned(),
+            false,
+            PathFilter::default(),
+        ));
+
+        time::sleep(LOOP_GAP * 3 + LOOP_GAP / 2).await;
+        task.abort();
+        let _ = std::fs::remove_file(&path);
+
+        // Every pass ends at once, so only the gap between passes limits how many run.
+        let passes = state.lock().unwrap().loop_count;
+        assert!((1..=4).contains(&passes), "{} passes", passes);
+    }
+
+    fn fader(ch: u32) -> OscMessage {
+        OscMessage::new(
+            format!("/ch/{:02}/mix/fader", ch),
+            vec![OscArg::Float(0.75)],
+        )
+    }
+
+    #[test]
+    fn test_path_filter_drops_meters() {
+        let meters = OscMessage::new("/meters/1".to_string(), vec![OscArg::Blob(vec![0; 8])]);
+
+        let filter = PathFilter::new(vec![], vec!["/meters".to_string()]);
+        assert_eq!(filter.apply(meters.clone().into()), None);
+        assert_eq!(filter.apply(fader(1).into()), Some(fader(1).into()));
+
+        let filter = PathFilter::new(vec!["/ch/".to_string()], vec!["/ch/02".to_string()]);
+        assert_eq!(filter.apply(meters.into()), None);
+        assert_eq!(filter.apply(fader(1).into()), Some(fader(1).into()));
+        assert_eq!(filter.apply(fader(2).into()), None);
+    }
+
+    #[test]
+    fn test_path_filter_applies_to_bundle_contents() {
+        let meters = OscMessage::new("/meters/1".to_string(), vec![]);
+        let filter = PathFilter::new(vec![], vec!["/meters".to_string()]);
+
+        let bundle = OscBundle::new(vec![meters.clone().into(), fader(1).into()]);
+        assert_eq!(
+            filter.apply(bundle.into()),
+            Some(OscBundle::new(vec![fader(1).into()]).into())
+        );
+
+        let bundle = OscBundle::new(vec![meters.into()]);
+        assert_eq!(filter.apply(bundle.into()), None);
+    }
+
+    #[test]
+    fn test_playback_delay_scales_with_speed() {
+        let start = Instant::now();
+        let first = Duration::from_secs(1_000);
+        let next = first + Duration::from_millis(200);
+
+        assert_eq!(
+            playback_delay(next, first, start, start, 1.0),
+            Some(Duration::from_millis(200))
+        );
+        assert_eq!(
+            playback_delay(next, first, start, start, 2.0),
+            Some(Duration::from_millis(100))
+        );
+        assert_eq!(
+            playback_delay(next, first, start, start, 0.5),
+            Some(Duration::from_millis(400))
+        );
+        assert_eq!(playback_delay(first, first, start, start, 2.0), None);
+    }
+
+    #[test]
+    fn test_parse_speed_rejects_out_of_range() {
+        assert_eq!(parse_speed("2"), Ok(2.0));
+        assert_eq!(parse_speed("0.5"), Ok(0.5));
+        assert!(parse_speed("0").is_err());
+        assert!(parse_speed("-1").is_err());
+        assert!(parse_speed("inf").is_err());
+        assert!(parse_speed("fast").is_err());
+        assert_eq!(parse_speed("0.01"), Ok(0.01));
+        assert_eq!(parse_speed("100"), Ok(100.0));
+        assert!(parse_speed("1e-20").is_err());
+        assert!(parse_speed("101").is_err());
+    }
+
+    #[test]
+    fn test_dump_compile_round_trip() {
+        let messages = [
+            fader(1),
+            OscMessage::new(
+                "/ch/02/config/name".to_string(),
+                vec![OscArg::String("Kick \"In\"".to_string())],
+            ),
+            OscMessage::new("/-stat/solosw/01".to_string(), vec![OscArg::Int(1)]),
+        ];
+        let start = Duration::from_secs(1_700_000_000);
+        let mut capture = Vec::new();
+        for (i, msg) in messages.iter().enumerate() {
+            let time = start + Duration::from_micros(i as u64 * 20_500);
+            write_record(&mut capture, time, &msg.to_bytes().unwrap());
+        }
+
+        let text = dump(&capture).unwrap();
+        assert_eq!(
+            text.lines().next(),
+            Some("0.000000 /ch/01/mix/fader ,f 0.75")
+        );
+        assert!(text.lines().nth(2).unwrap().starts_with("0.041000 "));
+
+        let compiled = compile(&text).unwrap();
+        assert_eq!(dump(&compiled).unwrap(), text);
+        // Only the base time differs: compiled files start at zero.
+        let mut expected = Vec::new();
+        for (i, msg) in messages.iter().enumerate() {
+            let time = Duration::from_micros(i as u64 * 20_500);
+            write_record(&mut expected, time, &msg.to_bytes().unwrap());
+        }
+        assert_eq!(compiled, expected);
+    }
+
+    #[test]
+    fn test_compile_reports_bad_lines() {
+        let msg = OscMessage::new("/ch/01/mix/on".to_string(), vec![OscArg::Int(1)]);
+        let mut expected = Vec::new();
+        write_record(
+            &mut expected,
+            Duration::from_millis(1_500),
+            &msg.to_bytes().unwrap(),
+        );
+        assert_eq!(
+            compile("# comment\n\n1.5 /ch/01/mix/on ,i 1\n").unwrap(),
+            expected
+        );
+
+        let err = compile("0.0 /ch/01/mix/on ,i 1\nsoon /ch/01/mix/on ,i 0").unwrap_err();
+        assert!(format!("{:#}", err).starts_with("Line 2"), "{:#}", err);
+        assert!(dump(&[0u8; 10]).is_err());
+    }
+}
@@ -1,5 +1,6 @@
 //! `x32_set_preset` is a command-line tool for loading preset files (.chn, .efx, .rou)
-//! directly onto a specific target slot on a Behringer X32/M32 mixer.
+//! directly onto a specific target slot on a Behringer X32/M32 mixer, and for pushing
+//! whole scenes (.scn) and shows (.shw).
 //!
 //! Unlike `x32_set_lib`, which uploads to the mixer's library, this tool applies the
 //! preset's parameters directly to a channel strip, effects slot, or the global routing table.
@@ -16,9 +17,10 @@ use anyhow::{Context, Result, anyhow};
 use clap::Parser;
 use osc_lib::{OscArg, OscMessage};
 use std::fs::File;
-use std::io::{BufRead, Read};
+use std::io::Read;
 use std::path::PathBuf;
-use x32_lib::MixerClient;
+use x32_fxparse::MixerModel;
+use x32_lib::{MixerClient, query_message_retry};
 
 /// Command-line arguments for `x32_set_preset`.
 #[derive(Parser, Debug)]
@@ -36,7 +38,7 @@ pub struct Args {
     #[arg(long, default_value = "")]
     pub aes50_ip: String,
 
-    /// The preset file to load (.chn, .efx, .rou).
+    /// The preset file to load (.chn, .efx, .rou, .scn, .shw).
     pub file: PathBuf,
 
     /// The target slot (e.g., ch01, aux01, fx1, bus01, mtx01, main_st, main_m).
@@ -70,8 +72,22 @@ pub struct Args {
     /// Enable verbose output.
     #[arg(short, long)]
     pub verbose: bool,
+
+    /// Print the messages that would be sent instead of connecting to the mixer.
+    #[arg(long)]
+    pub dry_run: bool,
+
+    /// Read every parameter back after setting it and report any that did not take.
+    #[arg(long)]
+    pub verify: bool,
 }
 
+/// How far a float read back may be from the value sent: one step of a 1024-step fader.
+const FLOAT_TOLERANCE: f32 = 1.0 / 1023.0;
+
+/// Extra attempts for each verify query before the parameter is reported as unanswered.
+const VERIFY_RETRIES: u32 = 2;
+
 /// Types of presets supported by the tool.
 #[derive(Debug, PartialEq)]
 enum PresetType {
@@ -81,6 +97,8 @@ enum PresetType {
     Effect,
     /// Routing preset (.rou).
     Routing,
+    /// Scene (.scn) or show (.shw), whose lines already carry full addresses.
+    Scene,
     /// Unknown file type.
     Unknown,
 }
@@ -93,26 +111,60 @@ pub async fn run(args: Args) -> Result<()> {
         "chn" => PresetType::Channel,
         "efx" => PresetType::Effect,
         "rou" => PresetType::Routing,
+        "scn" | "shw" => PresetType::Scene,
         _ => PresetType::Unknown,
     };
 
     if preset_type == PresetType::Unknown {
         return Err(anyhow!(
-            "Unknown file extension. Expected .chn, .efx, or .rou"
+            "Unknown file extension. Expected .chn, .efx, .rou, .scn or .shw"
         ));
     }
 
     // Validate target for Channel/Effect presets
     let target_prefix = if let Some(t) = &args.target {
         parse_target(t, &preset_type)?
-    } else if preset_type == PresetType::Routing {
-        String::new() // Routing presets don't need a target prefix usually
+    } else if preset_type == PresetType::Routing || preset_type == PresetType::Scene {
+        String::new() // Routing presets and scenes don't need a target prefix
     } else {
         return Err(anyhow!(
             "--target is required for Channel and Effect presets."
         ));
     };
 
+    println!("Loading preset: {:?}", args.file);
+    let file = File::open(&args.file).context("Failed to open preset file")?;
+
+    if file.metadata()?.len() > 1024 * 1024 {
+        return Err(anyhow!("Preset file too large to load (max 1MB)"));
+    }
+
+    let mut content = String::new();
+    file.take(1024 * 1024 + 1).read_to_string(&mut content)?;
+    if content.len() > 1024 * 1024 {
+        return Err(anyhow!("Preset file too large to load (max 1MB)"));
+    }
+
+    let mut messages = Vec::new();
+    // Master Safe: Mute mains if requested
+    if args.master_safe {
+        messages.push(OscMessage::builder("/main/st/mix/on").int(0).build());
+        messages.push(OscMessage::builder("/main/m/mix/on").int(0).build());
+    }
+    messages.extend(preset_messages(
+        &content,
+        &preset_type,
+        &target_prefix,
+        &args,
+    ));
+
+    if args.dry_run {
+        for msg in &messages {
+            println!("{}", msg);
+        }
+        return Ok(());
+    }
+
     // Connect to X32
     println!("Connecting to X32 at {}...", args.ip);
     let (client, _transport) = MixerClient::connect_with_transport(
@@ -125,33 +177,106 @@ pub async fn run(args: Args) -> Result<()> {
     .await?;
     let client = std::sync::Arc::new(client);
 
-    // Master Safe: Mute mains if requested
     if args.master_safe {
         println!("Muting Main L/R and M/C...");
-        client
-            .send_message("/main/st/mix/on", vec![OscArg::Int(0)])
-            .await?;
-        client
-            .send_message("/main/m/mix/on", vec![OscArg::Int(0)])
-            .await?;
+    }
+    let mut mismatches = Vec::new();
+    for msg in messages {
+        if args.verbose {
+            println!("Sending: {}", msg);
+        }
+        client.send_message(&msg.path, msg.args.clone()).await?;
+
+        if args.verify && !msg.args.is_empty() {
+            match query_message_retry(&client, &msg.path, VERIFY_RETRIES).await {
+                Ok(reply) if args_match(&msg.path, &msg.args, &reply.args) => {}
+                Ok(reply) => mismatches.push(format!(
+                    "{}: sent {:?}, read back {:?}",
+                    msg.path, msg.args, reply.args
+                )),
+                Err(_) => mismatches.push(format!("{}: no reply", msg.path)),
+            }
+        }
     }
 
-    println!("Loading preset: {:?}", args.file);
-    let file = File::open(&args.file).context("Failed to open preset file")?;
+    if args.verify {
+        if !mismatches.is_empty() {
+            for mismatch in &mismatches {
+                eprintln!("Mismatch: {}", mismatch);
+            }
+            return Err(anyhow!("{} parameter(s) did not verify", mismatches.len()));
+        }
+        println!("Verified all parameters.");
+    }
 
-    if file.metadata()?.len() > 1024 * 1024 {
-        return Err(anyhow!("Preset file too large to load (max 1MB)"));
+    println!("Done.");
+    Ok(())
+}
+
+/// Compares the arguments sent to `path` with the ones read back.
+///
+/// Every argument is compared, after enum names such as `ON` are turned into the ids the
+/// console answers with.
+fn args_match(path: &str, expected: &[OscArg], actual: &[OscArg]) -> bool {
+    expected.len() == actual.len()
+        && expected
+            .iter()
+            .zip(actual)
+            .all(|(e, a)| values_match(&enum_id(path, e), &enum_id(path, a)))
+}
+
+/// Returns the id of an enum name sent to `path`, or the argument unchanged.
+fn enum_id(path: &str, arg: &OscArg) -> OscArg {
+    let OscArg::String(name) = arg else {
+        return arg.clone();
+    };
+    match x32_fxparse::parse_parameter(MixerModel::X32, path, name)
+        .and_then(|msg| msg.args.into_iter().next())
+    {
+        Some(OscArg::Int(id)) => OscArg::Int(id),
+        _ => arg.clone(),
     }
+}
 
-    let mut content = String::new();
-    file.take(1024 * 1024 + 1).read_to_string(&mut content)?;
-    if content.len() > 1024 * 1024 {
-        return Err(anyhow!("Preset file too large to load (max 1MB)"));
+/// Compares a value sent to the console with the one read back.
+///
+/// Numbers compare within [`FLOAT_TOLERANCE`], whether sent as int or float, since the console
+/// quantizes faders and may answer with the other type.
+fn values_match(expected: &OscArg, actual: &OscArg) -> bool {
+    let number = |arg: &OscArg| match arg {
+        OscArg::Int(i) => Some(*i as f32),
+        OscArg::Float(f) => Some(*f),
+        _ => None,
+    };
+    match (number(expected), number(actual)) {
+        (Some(a), Some(b)) => (a - b).abs() <= FLOAT_TOLERANCE,
+        _ => expected == actual,
     }
-    let reader = std::io::Cursor::new(content);
+}
 
-    for line in reader.lines() {
-        let line = line?;
+/// Turns the lines of a preset file into the messages for the target.
+///
+/// Header lines are dropped, safes are applied, and addresses are remapped to the target.
+///
+/// # Arguments
+///
+/// * `content` - The text of the preset file.
+/// * `preset_type` - The kind of preset, from the file extension.
+/// * `target_prefix` - The target address prefix, e.g. `/ch/05`.
+/// * `args` - The command-line arguments holding the safe flags.
+///
+/// # Returns
+///
+/// The messages to send, in file order.
+fn preset_messages(
+    content: &str,
+    preset_type: &PresetType,
+    target_prefix: &str,
+    args: &Args,
+) -> Vec<OscMessage> {
+    let mut messages = Vec::new();
+
+    for line in content.lines() {
         let line = line.trim();
         if line.is_empty() {
             continue;
@@ -173,8 +298,12 @@ pub async fn run(args: Args) -> Result<()> {
             None => (line, ""),
         };
 
-        // Apply Safes
-        if should_skip(cmd_addr, &args) {
+        // Apply Safes. Scene lines are checked by the part below their strip, e.g. /eq/1.
+        let safe_addr = match preset_type {
+            PresetType::Scene => strip_section(cmd_addr),
+            _ => cmd_addr,
+        };
+        if should_skip(safe_addr, args) {
             if args.verbose {
                 println!("Skipping (safe): {}", line);
             }
@@ -183,9 +312,9 @@ pub async fn run(args: Args) -> Result<()> {
 
         // Remap Address
         let full_address = match preset_type {
-            PresetType::Channel => map_channel_address(&target_prefix, cmd_addr),
-            PresetType::Effect => map_effect_address(&target_prefix, cmd_addr),
-            PresetType::Routing => cmd_addr.to_string(),
+            PresetType::Channel => map_channel_address(target_prefix, cmd_addr),
+            PresetType::Effect => map_effect_address(target_prefix, cmd_addr),
+            PresetType::Routing | PresetType::Scene => cmd_addr.to_string(),
             _ => cmd_addr.to_string(),
         };
 
@@ -197,15 +326,10 @@ pub async fn run(args: Args) -> Result<()> {
         // Parse Arguments
         let osc_args = parse_args(args_str);
 
-        let msg = OscMessage::new(full_address, osc_args);
-        if args.verbose {
-            println!("Sending: {}", msg);
-        }
-        client.send_message(&msg.path, msg.args).await?;
+        messages.push(OscMessage::new(full_address, osc_args));
     }
 
-    println!("Done.");
-    Ok(())
+    messages
 }
 
 /// Parses the target string into an OSC address prefix.
@@ -267,14 +391,17 @@ fn map_channel_address(prefix: &str, addr: &str) -> String {
         // If target is a channel (ch01..ch32), map to global headamp (HA 1-32).
         // This follows the C code logic assuming 1:1 mapping.
         if prefix.starts_with("/ch/") {
-            let ch_str = &prefix[4..6]; // "01"
+            let Ok(ch) = prefix[4..6].parse::<u32>() else {
+                return String::new();
+            };
             let mut parts = addr.split('/');
             let _ = parts.next(); // ""
             let _ = parts.next(); // "headamp"
             let _ = parts.next(); // "000"
             if let Some(part3) = parts.next() {
                 // "", "headamp", "000", "gain"
-                return format!("/headamp/{}/{}", ch_str, part3);
+                // Headamp indices are three digits wide, e.g. /headamp/005.
+                return format!("/headamp/{:03}/{}", ch, part3);
             }
         }
         return String::new(); // Skip headamp for non-physical channels or if parsing fails
@@ -288,9 +415,41 @@ fn map_effect_address(prefix: &str, addr: &str) -> String {
     format!("{}{}", prefix, addr)
 }
 
+/// Returns the part of a full address below its strip, e.g. `/eq/1` for `/ch/05/eq/1`.
+///
+/// Addresses outside a channel strip, such as `/headamp/005/gain`, are returned unchanged.
+fn strip_section(addr: &str) -> &str {
+    for strip in ["/main/st", "/main/m"] {
+        if let Some(rest) = addr.strip_prefix(strip) {
+            if rest.starts_with('/') {
+                return rest;
+            }
+        }
+    }
+    for kind in ["/ch/", "/auxin/", "/fxrtn/", "/bus/", "/mtx/"] {
+        if let Some(rest) = addr.strip_prefix(kind) {
+            if let Some(pos) = rest.find('/') {
+                if rest[..pos].bytes().all(|b| b.is_ascii_digit()) {
+                    return &rest[pos..];
+                }
+            }
+        }
+    }
+    addr
+}
+
+/// The strip configuration fields `--safe-config` leaves untouched.
+const SAFE_CONFIG_FIELDS: [&str; 4] = ["name", "icon", "color", "source"];
+
 /// Checks if a command should be skipped based on safety flags.
 fn should_skip(addr: &str, args: &Args) -> bool {
-    if args.safe_config && addr.starts_with("/config") {
+    // Only a strip's own config is safed, not console-wide settings such as
+    // /config/chlink or /config/routing.
+    let strip_config = addr == "/config"
+        || addr
+            .strip_prefix("/config/")
+            .is_some_and(|field| SAFE_CONFIG_FIELDS.contains(&field));
+    if args.safe_config && strip_config {
         return true;
     }
     if args.safe_headamp && (addr.starts_with("/headamp") || addr.starts_with("/preamp")) {
@@ -348,3 +507,133 @@ fn parse_single_arg(s: &str) -> OscArg {
         OscArg::String(s.to_string())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const KICK_CHN: &str = "#2.7# \"Kick\" 8191 -1 0 0 1
+/config \"Kick\" 1 RD 1
+/headamp/000/gain +12.5
+/eq ON
+/eq/1 PEQ 124.7 +3.00 2.0
+/dyn ON COMP PEAK LOG -20.0 4.0 1 0.00 10 10.0 151 POST 0 100 OFF
+/mix/fader -10.0
+";
+
+    #[test]
+    fn test_values_match_tolerates_fader_rounding() {
+        assert!(values_match(&OscArg::Float(0.75), &OscArg::Float(0.7498)));
+        assert!(!values_match(&OscArg::Float(0.75), &OscArg::Float(0.74)));
+        assert!(values_match(&OscArg::Int(1), &OscArg::Float(1.0)));
+        assert!(values_match(
+            &OscArg::String("Kick".to_string()),
+            &OscArg::String("Kick".to_string())
+        ));
+        assert!(!values_match(
+            &OscArg::String("Kick".to_string()),
+            &OscArg::Int(0)
+        ));
+    }
+
+    #[test]
+    fn test_args_match_compares_every_arg_and_enum_ids() {
+        assert!(args_match(
+            "/ch/05/mix/on",
+            &[OscArg::String("ON".to_string())],
+            &[OscArg::Int(1)]
+        ));
+        assert!(!args_match(
+            "/ch/05/mix/on",
+            &[OscArg::String("OFF".to_string())],
+            &[OscArg::Int(1)]
+        ));
+        assert!(args_match(
+            "/ch/05/gate/mode",
+            &[OscArg::String("GATE".to_string())],
+            &[OscArg::String("GATE".to_string())]
+        ));
+
+        // A difference after the first argument, or a missing argument, is a mismatch.
+        let sent = [OscArg::String("Vox".to_string()), OscArg::Int(1)];
+        assert!(args_match("/ch/05/config", &sent, &sent));
+        assert!(!args_match(
+            "/ch/05/config",
+            &sent,
+            &[OscArg::String("Vox".to_string()), OscArg::Int(2)]
+        ));
+        assert!(!args_match("/ch/05/config", &sent, &sent[..1]));
+    }
+
+    #[test]
+    fn test_scene_lines_pass_through_with_safes() {
+        let scene = "#2.7# \"Show\" \"\" %000000000 1
+/ch/01/config \"Kick\" 1 RD 1
+/ch/01/eq ON
+/ch/01/eq/1 PEQ 124.7 +3.00 2.0
+/bus/03/eq/2 PEQ 1k97 -2.00 2.0
+/ch/01/mix/fader -10.0
+/headamp/000/gain +12.5
+/config/chlink OFF OFF
+";
+        let args = Args::parse_from(["x32_set_preset", "show.scn", "--safe-eq"]);
+        let output: Vec<String> = preset_messages(scene, &PresetType::Scene, "", &args)
+            .iter()
+            .map(|msg| msg.path.clone())
+            .collect();
+
+        assert_eq!(
+            output,
+            vec![
+                "/ch/01/config",
+                "/ch/01/mix/fader",
+                "/headamp/000/gain",
+                "/config/chlink",
+            ]
+        );
+        assert_eq!(strip_section("/main/st/eq/1"), "/eq/1");
+        assert_eq!(strip_section("/mtx/06/mix/fader"), "/mix/fader");
+        assert_eq!(strip_section("/-prefs/name"), "/-prefs/name");
+    }
+
+    #[test]
+    fn test_safe_config_keeps_console_config() {
+        let scene = "/ch/01/config \"Kick\" 1 RD 1
+/ch/01/config/name \"Kick\"
+/bus/02/config/color 3
+/ch/01/config/insrc 0
+/config/chlink OFF OFF
+/config/routing/IN 1 2 3 4
+";
+        let args = Args::parse_from(["x32_set_preset", "show.scn", "--safe-config"]);
+        let output: Vec<String> = preset_messages(scene, &PresetType::Scene, "", &args)
+            .iter()
+            .map(|msg| msg.path.clone())
+            .collect();
+
+        assert_eq!(
+            output,
+            vec!["/ch/01/config/insrc", "/config/chlink", "/config/routing/IN"]
+        );
+    }
+
+    #[test]
+    fn test_dry_run_output_honors_safe_eq() {
+        let args = Args::parse_from(["x32_set_preset", "kick.chn", "-t", "ch05", "--safe-eq"]);
+        let prefix = parse_target("ch05", &PresetType::Channel).unwrap();
+        let output: Vec<String> = preset_messages(KICK_CHN, &PresetType::Channel, &prefix, &args)
+            .iter()
+            .map(|msg| msg.to_string())
+            .collect();
+
+        assert_eq!(output.len(), 4, "{:?}", output);
+        assert!(
+            output.iter().all(|line| !line.contains("/eq")),
+            "{:?}",
+            output
+        );
+        assert!(output[0].starts_with("/ch/05/config"));
+        assert!(output[1].starts_with("/headamp/005/gain"));
+        assert!(output[3].starts_with("/ch/05/mix/fader"));
+    }
+}
@@ -0,0 +1,30 @@
+use osc_lib::OscArg;
+use std::io::Write;
+use x32_emulator::testing::X32Emulator;
+
+#[test]
+fn test_load_and_verify_channel_preset() {
+    let emulator = X32Emulator::start().unwrap();
+
+    let mut preset = tempfile::Builder::new().suffix(".chn").tempfile().unwrap();
+    writeln!(preset, "#2.7# \"Vocal\" 8191 -1 0 0 1").unwrap();
+    writeln!(preset, "/mix/fader 0.75").unwrap();
+    writeln!(preset, "/mix/on ON").unwrap();
+    writeln!(preset, "/config/name \"Vocal\"").unwrap();
+    preset.flush().unwrap();
+
+    let mut cmd = assert_cmd::cargo::cargo_bin_cmd!("x32_set_preset");
+    cmd.arg("--ip")
+        .arg(emulator.addr().to_string())
+        .arg("-t")
+        .arg("ch05")
+        .arg("--verify")
+        .arg(preset.path());
+
+    let output = cmd.output().unwrap();
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    let stderr = String::from_utf8(output.stderr).unwrap();
+    assert!(output.status.success(), "{}\n{}", stdout, stderr);
+    assert!(stdout.contains("Verified all parameters."), "{}", stdout);
+    emulator.assert_param("/ch/05/config/name", OscArg::String("Vocal".to_string()));
+}
@@ -1,8 +1,8 @@
 //! `x32_tap` is a command-line tool for setting the tempo of X32 delay effects by tapping.
 //!
-//! It connects to the mixer and listens for user input (pressing Enter). By measuring the
-//! time interval between taps, it calculates the tempo in milliseconds and updates the
-//! corresponding parameter of the delay effect in the specified FX slot.
+//! It connects to the mixer and listens for user input (pressing Enter). By averaging the
+//! time intervals between the last few taps, it calculates the tempo in milliseconds and
+//! updates the corresponding parameter of the delay effect in the specified FX slot.
 //!
 //! # Credits
 //!
@@ -14,6 +14,7 @@
 use anyhow::{Context, Result, anyhow};
 use clap::Parser;
 use osc_lib::OscArg;
+use std::collections::VecDeque;
 use std::io::{self, Write};
 use std::time::Instant;
 use x32_lib::MixerClient;
@@ -50,10 +51,167 @@ pub struct Args {
     /// In auto mode, the gate meter threshold level (e.g. 0.5) to trigger a tap.
     #[arg(short = 't', long, default_value_t = 0.5)]
     pub threshold: f32,
+
+    /// The number of tap intervals to average.
+    #[arg(long, default_value_t = DEFAULT_TAPS)]
+    pub taps: usize,
+
+    /// For 3-tap and 4-tap delays, the tap factor values (0.0-1.0) to send with each tempo.
+    #[arg(long, value_delimiter = ',')]
+    pub factors: Vec<f32>,
+
+    /// Show the tempo in BPM only, without the delay time in milliseconds.
+    #[arg(long)]
+    pub bpm_only: bool,
+}
+
+/// The number of tap intervals averaged by default.
+pub const DEFAULT_TAPS: usize = 4;
+
+/// Intervals further than this fraction from the running mean are treated as mistimed taps.
+const OUTLIER_RATIO: f32 = 0.5;
+
+/// Averages the intervals between the most recent taps.
+///
+/// An interval more than 50% away from the mean of the window is ignored. Two such intervals
+/// in a row mean the tempo itself changed, so the window restarts from the latest one.
+#[derive(Debug, Clone)]
+pub struct TapAverager {
+    window: usize,
+    intervals: VecDeque<f32>,
+    rejected: usize,
+}
+
+impl TapAverager {
+    /// Creates an averager over the last `window` intervals (at least one).
+    pub fn new(window: usize) -> Self {
+        Self {
+            window: window.max(1),
+            intervals: VecDeque::with_capacity(window.max(1)),
+            rejected: 0,
+        }
+    }
+
+    /// Adds the interval since the previous tap.
+    ///
+    /// # Arguments
+    ///
+    /// * `interval_ms` - The time between the last two taps, in milliseconds.
+    ///
+    /// # Returns
+    ///
+    /// The averaged interval in milliseconds, or `None` if this interval was discarded.
+    pub fn push(&mut self, interval_ms: f32) -> Option<f32> {
+        if let Some(mean) = mean(&self.intervals) {
+            if (interval_ms - mean).abs() > OUTLIER_RATIO * mean {
+                self.rejected += 1;
+                if self.rejected < 2 {
+                    return None;
+                }
+                self.intervals.clear();
+            }
+        }
+        self.rejected = 0;
+        if self.intervals.len() == self.window {
+            self.intervals.pop_front();
+        }
+        self.intervals.push_back(interval_ms);
+        mean(&self.intervals)
+    }
+}
+
+/// Converts a beat interval in milliseconds to beats per minute.
+pub fn ms_to_bpm(interval_ms: f32) -> f32 {
+    60_000.0 / interval_ms
+}
+
+/// Formats a tempo for display, e.g. `500ms (120.0 BPM)`.
+fn format_tempo(tempo_ms: i32, bpm_only: bool) -> String {
+    let bpm = ms_to_bpm(tempo_ms as f32);
+    if bpm_only {
+        format!("{:.1} BPM", bpm)
+    } else {
+        format!("{}ms ({:.1} BPM)", tempo_ms, bpm)
+    }
+}
+
+/// Returns the mean of the intervals, or `None` if there are none.
+fn mean(intervals: &VecDeque<f32>) -> Option<f32> {
+    (!intervals.is_empty()).then(|| intervals.iter().sum::<f32>() / intervals.len() as f32)
+}
+
+/// Where a delay effect keeps its time and tap sub-division parameters.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DelayLayout {
+    /// The parameter holding the delay time.
+    pub time_param: u8,
+    /// The parameters holding the tap factors, relative to the delay time.
+    pub factor_params: &'static [u8],
+}
+
+impl DelayLayout {
+    /// Returns the OSC address of the delay time in the given FX slot.
+    pub fn time_address(&self, slot: u8) -> String {
+        format!("/fx/{}/par/{:02}", slot, self.time_param)
+    }
+
+    /// Returns the OSC addresses of the tap factors in the given FX slot.
+    pub fn factor_addresses(&self, slot: u8) -> Vec<String> {
+        self.factor_params
+            .iter()
+            .map(|par| format!("/fx/{}/par/{:02}", slot, par))
+            .collect()
+    }
+}
+
+/// Returns the parameter layout of a delay effect, by `/fx/N/type` value.
+///
+/// # Arguments
+///
+/// * `fx_type` - The effect type ID (10 is DLY, 11 3TAP, 12 4TAP, 21 D/RV, 24 D/CR, 25 D/FL, 26 MODD).
+///
+/// # Returns
+///
+/// The layout, or `None` if the effect is not a known delay.
+pub fn delay_layout(fx_type: i32) -> Option<DelayLayout> {
+    let (time_param, factor_params): (u8, &'static [u8]) = match fx_type {
+        // The stereo delay has its mix level first and the time in par 02.
+        10 => (2, &[]),
+        11 => (1, &[7, 10]),
+        12 => (1, &[7, 9, 11]),
+        21 | 24 | 25 | 26 => (1, &[]),
+        _ => return None,
+    };
+    Some(DelayLayout {
+        time_param,
+        factor_params,
+    })
 }
 
-// Stereo delay FX number (from C source): 10
-// Other delay types from C source: 11, 12, 21, 24, 25, 26
+/// The layout assumed for an unrecognized effect: the time in par 01, as on most delays.
+const FALLBACK_LAYOUT: DelayLayout = DelayLayout {
+    time_param: 1,
+    factor_params: &[],
+};
+
+/// Sends a new delay time, and the requested tap factors, to an FX slot.
+async fn send_tempo(
+    client: &MixerClient,
+    slot: u8,
+    layout: &DelayLayout,
+    f_val: f32,
+    factors: &[f32],
+) -> Result<()> {
+    client
+        .send_message(&layout.time_address(slot), vec![OscArg::Float(f_val)])
+        .await?;
+    for (address, &factor) in layout.factor_addresses(slot).iter().zip(factors) {
+        client
+            .send_message(address, vec![OscArg::Float(factor.clamp(0.0, 1.0))])
+            .await?;
+    }
+    Ok(())
+}
 
 /// The main entry point for the application.
 pub async fn run(args: Args) -> Result<()> {
@@ -65,6 +223,11 @@ pub async fn run(args: Args) -> Result<()> {
         return Err(anyhow!("Channel must be between 1 and 32."));
     }
 
+    if args.taps < 1 {
+        return Err(anyhow!("The number of taps to average must be at least 1."));
+    }
+    let mut averager = TapAverager::new(args.taps);
+
     println!("Connecting to X32 at {}...", args.ip);
     let (client, _) = MixerClient::connect_with_transport(
         &args.ip,
@@ -84,16 +247,26 @@ pub async fn run(args: Args) -> Result<()> {
         fx_type = t;
     }
 
-    // List of known delay FX types from C source
-    let valid_delays = [10, 11, 12, 21, 24, 25, 26];
-    if !valid_delays.contains(&fx_type) {
+    let fx_name = x32_lib::fx::type_name(args.slot, fx_type).unwrap_or("unknown");
+    let layout = match delay_layout(fx_type) {
+        Some(layout) => {
+            println!("Found valid delay effect {} (Type ID: {}).", fx_name, fx_type);
+            layout
+        }
+        None => {
+            eprintln!(
+                "Warning: FX slot {} does not appear to contain a standard delay effect ({}, Type ID: {}).",
+                args.slot, fx_name, fx_type
+            );
+            eprintln!("Proceeding anyway, but commands may not work as expected.");
+            FALLBACK_LAYOUT
+        }
+    };
+    if args.factors.len() > layout.factor_params.len() {
         eprintln!(
-            "Warning: FX slot {} does not appear to contain a standard delay effect (Type ID: {}).",
-            args.slot, fx_type
+            "Warning: this effect has {} tap factor(s); extra --factors values are ignored.",
+            layout.factor_params.len()
         );
-        eprintln!("Proceeding anyway, but commands may not work as expected.");
-    } else {
-        println!("Found valid delay effect (Type ID: {}).", fx_type);
     }
 
     if args.auto {
@@ -107,9 +280,6 @@ pub async fn run(args: Args) -> Result<()> {
         let mut last_tap: Option<Instant> = None;
         let mut was_above_threshold = false;
         let mut last_keepalive = Instant::now() - std::time::Duration::from_secs(10);
-        let param_idx = if fx_type == 10 { 2 } else { 1 };
-        let address = format!("/fx/{}/par/{:02}", args.slot, param_idx);
-
         let mut rx = client.subscribe();
 
         loop {
@@ -161,18 +331,35 @@ pub async fn run(args: Args) -> Result<()> {
 
                                         // Minimum resolution is 60ms to avoid rapid-fire updates
                                         if delta_ms > 60.0 {
-                                            let f_val = (delta_ms / 3000.0).clamp(0.0, 1.0);
-                                            let tempo_ms = (f_val * 3000.0) as i32;
-                                            println!(
-                                                "Auto Tap: {}ms (level: {:.2})",
-                                                tempo_ms, level
-                                            );
-
-                                            if let Err(e) = client
-                                                .send_message(&address, vec![OscArg::Float(f_val)])
-                                                .await
-                                            {
-                                                eprintln!("Failed to update FX parameter: {}", e);
+                                            match averager.push(delta_ms) {
+                                                Some(avg_ms) => {
+                                                    let f_val = (avg_ms / 3000.0).clamp(0.0, 1.0);
+                                                    let tempo_ms = (f_val * 3000.0) as i32;
+                                                    println!(
+                                                        "Auto Tap: {} (level: {:.2})",
+                                                        format_tempo(tempo_ms, args.bpm_only),
+                                                        level
+                                                    );
+
+                                                    if let Err(e) = send_tempo(
+                                                        &client,
+                                                        args.slot,
+                                                        &layout,
+                                                        f_val,
+                                                        &args.factors,
+                                                    )
+                                                    .await
+                                                    {
+                                                        eprintln!(
+                                                            "Failed to update FX parameter: {}",
+                                                            e
+                                                        );
+                                                    }
+                                                }
+                                                None => println!(
+                                                    "Ignored mistimed tap: {}ms (level: {:.2})",
+                                                    delta_ms as i32, level
+                                                ),
                                             }
                                             last_tap = Some(tap_time);
                                         }
@@ -255,18 +442,19 @@ pub async fn run(args: Args) -> Result<()> {
                 let delta = now.duration_since(last);
                 let delta_ms = delta.as_millis() as f32;
 
+                let Some(avg_ms) = averager.push(delta_ms) else {
+                    println!("Ignored mistimed tap: {}ms", delta_ms as i32);
+                    last_tap = Some(now);
+                    continue;
+                };
+
                 // Calculate parameter value (0.0 - 1.0 represents 0ms - 3000ms)
-                let f_val = (delta_ms / 3000.0).clamp(0.0, 1.0);
+                let f_val = (avg_ms / 3000.0).clamp(0.0, 1.0);
 
                 let tempo_ms = (f_val * 3000.0) as i32;
-                println!("Tempo: {}ms", tempo_ms);
-
-                let param_idx = if fx_type == 10 { 2 } else { 1 };
-                let address = format!("/fx/{}/par/{:02}", args.slot, param_idx);
+                println!("Tempo: {}", format_tempo(tempo_ms, args.bpm_only));
 
-                if let Err(e) = client
-                    .send_message(&address, vec![OscArg::Float(f_val)])
-                    .await
+                if let Err(e) = send_tempo(&client, args.slot, &layout, f_val, &args.factors).await
                 {
                     eprintln!("Failed to send OSC message: {}", e);
                 }
@@ -280,3 +468,66 @@ pub async fn run(args: Args) -> Result<()> {
 
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_tap_averager_smooths_noisy_taps() {
+        let mut averager = TapAverager::new(4);
+        assert_eq!(averager.push(500.0), Some(500.0));
+        assert_eq!(averager.push(520.0), Some(510.0));
+        assert_eq!(averager.push(480.0), Some(500.0));
+        // A late tap is discarded rather than dragging the tempo.
+        assert_eq!(averager.push(900.0), None);
+        assert_eq!(averager.push(500.0), Some(500.0));
+        // The window only holds the last four intervals.
+        assert_eq!(averager.push(540.0), Some(510.0));
+    }
+
+    #[test]
+    fn test_tap_averager_follows_tempo_change() {
+        let mut averager = TapAverager::new(4);
+        for _ in 0..4 {
+            averager.push(500.0);
+        }
+        assert_eq!(averager.push(200.0), None);
+        assert_eq!(averager.push(200.0), Some(200.0));
+        assert_eq!(averager.push(210.0), Some(205.0));
+    }
+
+    #[test]
+    fn test_ms_to_bpm() {
+        assert_eq!(ms_to_bpm(500.0), 120.0);
+        assert_eq!(ms_to_bpm(1000.0), 60.0);
+        assert_eq!(format_tempo(500, false), "500ms (120.0 BPM)");
+        assert_eq!(format_tempo(500, true), "120.0 BPM");
+    }
+
+    #[test]
+    fn test_delay_layout_addresses() {
+        let dly = delay_layout(10).unwrap();
+        assert_eq!(dly.time_address(1), "/fx/1/par/02");
+        assert!(dly.factor_addresses(1).is_empty());
+
+        let tap4 = delay_layout(12).unwrap();
+        assert_eq!(tap4.time_address(1), "/fx/1/par/01");
+        assert_eq!(
+            tap4.factor_addresses(1),
+            vec!["/fx/1/par/07", "/fx/1/par/09", "/fx/1/par/11"]
+        );
+
+        assert_eq!(delay_layout(11).unwrap().factor_params, &[7, 10]);
+        assert_eq!(delay_layout(21).unwrap().time_address(3), "/fx/3/par/01");
+        assert_eq!(delay_layout(0), None);
+    }
+
+    #[test]
+    fn test_tap_averager_single_tap_window() {
+        let mut averager = TapAverager::new(1);
+        assert_eq!(averager.push(500.0), Some(500.0));
+        assert_eq!(averager.push(600.0), Some(600.0));
+        assert_eq!(TapAverager::new(0).push(400.0), Some(400.0));
+    }
+}
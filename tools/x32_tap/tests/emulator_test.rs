@@ -1,125 +1,118 @@
 use osc_lib::{OscArg, OscMessage};
-use std::process::Command;
-use std::time::Duration;
-
-#[tokio::test]
-async fn test_auto_mode_mock_server() {
-    let mock_server = tokio::net::UdpSocket::bind("127.0.0.1:0").await.unwrap();
-    let local_addr = mock_server.local_addr().unwrap();
-    let port = local_addr.port();
-
-    let mut cmd = Command::new(env!("CARGO_BIN_EXE_x32_tap"));
-    cmd.args(&[
-        "--ip",
-        &format!("127.0.0.1:{}", port),
-        "--slot",
-        "1",
-        "--auto",
-        "--threshold",
-        "0.5",
-    ]);
-    let mut child = cmd.spawn().expect("Failed to spawn x32_tap");
+use std::io::{BufRead, BufReader};
+use std::net::UdpSocket;
+use std::process::{Command, Stdio};
+use std::sync::mpsc;
+use std::thread;
+use std::time::{Duration, Instant};
+use x32_emulator::testing::X32Emulator;
 
+/// Waits for a `/meters/6` blob from `socket` whose post-dynamics level satisfies `pred`.
+fn wait_for_level(socket: &UdpSocket, pred: impl Fn(f32) -> bool) {
+    let deadline = Instant::now() + Duration::from_secs(3);
     let mut buf = [0u8; 1024];
-
-    // 1. Respond to /info
-    if let Ok((len, src)) = mock_server.recv_from(&mut buf).await {
-        if let Ok(msg) = OscMessage::from_bytes(&buf[..len]) {
-            if msg.path == "/info" {
-                mock_server
-                    .send_to(&msg.to_bytes().unwrap(), src)
-                    .await
-                    .unwrap();
+    while Instant::now() < deadline {
+        let Ok((len, _)) = socket.recv_from(&mut buf) else {
+            continue;
+        };
+        let Ok(msg) = OscMessage::from_bytes(&buf[..len]) else {
+            continue;
+        };
+        if let Some(OscArg::Blob(data)) = msg.args.first() {
+            let level = f32::from_le_bytes(data[12..16].try_into().unwrap());
+            if msg.path == "/meters/6" && pred(level) {
+                return;
             }
         }
     }
+    panic!("No matching /meters/6 level before the deadline");
+}
 
-    // 2. Respond to /fx/1/type (Type 10 = Standard Delay)
-    if let Ok((len, src)) = mock_server.recv_from(&mut buf).await {
-        if let Ok(msg) = OscMessage::from_bytes(&buf[..len]) {
-            if msg.path == "/fx/1/type" {
-                let resp = OscMessage::new("/fx/1/type".to_string(), vec![OscArg::Int(10)]);
-                mock_server
-                    .send_to(&resp.to_bytes().unwrap(), src)
-                    .await
-                    .unwrap();
-            }
-        }
-    }
+#[test]
+fn test_auto_mode_taps_on_meter_edges() {
+    // Type 10 = Standard Delay, with a signal on channel 7 above the 0.5 threshold.
+    // Channel 1 stays silent, so the tap only works if it meters the right channel.
+    let emulator = X32Emulator::start_with(|mixer| {
+        mixer.seed_from_lines(vec!["/fx/1/type,i\t10", "/fx/1/par/02,f\t0"]);
+        mixer.set_signal_level(7, 0.8);
+    })
+    .unwrap();
 
-    // 3. Receive /xremote and /meters
-    let mut src_addr = None;
-    for _ in 0..5 {
-        if let Ok((len, src)) =
-            tokio::time::timeout(Duration::from_secs(2), mock_server.recv_from(&mut buf))
-                .await
-                .unwrap()
-        {
-            if let Ok(msg) = OscMessage::from_bytes(&buf[..len]) {
-                if msg.path == "/meters" {
-                    src_addr = Some(src);
-                    break;
-                }
-            }
+    let mut child = Command::new(env!("CARGO_BIN_EXE_x32_tap"))
+        .args([
+            "--ip",
+            &emulator.addr().to_string(),
+            "--slot",
+            "1",
+            "--auto",
+            "--channel",
+            "7",
+            "--threshold",
+            "0.5",
+        ])
+        .stdout(Stdio::piped())
+        .spawn()
+        .expect("Failed to spawn x32_tap");
+    let (lines_tx, lines) = mpsc::channel();
+    let stdout = child.stdout.take().unwrap();
+    thread::spawn(move || {
+        for line in BufReader::new(stdout).lines().map_while(Result::ok) {
+            let _ = lines_tx.send(line);
         }
-    }
-
-    let src = src_addr.expect("Never received /meters subscription");
-
-    // 4. Send back two mock /meters/6 blobs indicating high level, separated by >60ms
-    let mut blob = vec![0u8; 16];
-    let level_f32 = 0.8f32;
-    blob[12..16].copy_from_slice(&level_f32.to_le_bytes());
+    });
 
-    let meter_msg = OscMessage::new("/meters/6".to_string(), vec![OscArg::Blob(blob.clone())]);
-    mock_server
-        .send_to(&meter_msg.to_bytes().unwrap(), src)
-        .await
+    // Watch the same meter as the tool, so the test knows which frames it has seen.
+    let meters = UdpSocket::bind("127.0.0.1:0").unwrap();
+    meters
+        .set_read_timeout(Some(Duration::from_millis(100)))
         .unwrap();
-
-    // Wait for the first tap to register and the signal to 'drop' below threshold
-    // So we simulate the edge dropping, then rising again
-    tokio::time::sleep(Duration::from_millis(50)).await;
-
-    // Send low level blob
-    let mut low_blob = vec![0u8; 16];
-    let level_low = 0.1f32;
-    low_blob[12..16].copy_from_slice(&level_low.to_le_bytes());
-    let meter_low = OscMessage::new("/meters/6".to_string(), vec![OscArg::Blob(low_blob)]);
-    mock_server
-        .send_to(&meter_low.to_bytes().unwrap(), src)
-        .await
+    let subscribe = OscMessage::new(
+        "/meters".to_string(),
+        vec![
+            OscArg::String("/meters/6".to_string()),
+            OscArg::Int(0),
+            OscArg::Int(0),
+            OscArg::Int(6),
+        ],
+    );
+    meters
+        .send_to(&subscribe.to_bytes().unwrap(), emulator.addr())
         .unwrap();
 
-    // Sleep a bit more to ensure time delta > 60ms
-    tokio::time::sleep(Duration::from_millis(60)).await;
+    // The first meter blob is the first tap.
+    let deadline = Instant::now() + Duration::from_secs(5);
+    loop {
+        let remaining = deadline.saturating_duration_since(Instant::now());
+        match lines.recv_timeout(remaining) {
+            Ok(line) if line.starts_with("First auto tap") => break,
+            Ok(_) => continue,
+            Err(e) => panic!("x32_tap never tapped: {}", e),
+        }
+    }
 
-    // Send the second tap (rising edge)
-    mock_server
-        .send_to(&meter_msg.to_bytes().unwrap(), src)
-        .await
-        .unwrap();
+    // Muting the channel drops its meter to zero, so unmuting it is the second
+    // rising edge. Every subscriber gets the same frames in order, so once this
+    // socket has seen a silent frame, the tool has one queued too.
+    emulator.set("/ch/07/mix/on", OscArg::Int(0)).unwrap();
+    wait_for_level(&meters, |level| level == 0.0);
+    emulator.set("/ch/07/mix/on", OscArg::Int(1)).unwrap();
 
-    // 5. Expect a set command to /fx/1/par/02
-    let mut got_update = false;
-    for _ in 0..5 {
-        if let Ok(Ok((len, _))) =
-            tokio::time::timeout(Duration::from_secs(2), mock_server.recv_from(&mut buf)).await
-        {
-            if let Ok(msg) = OscMessage::from_bytes(&buf[..len]) {
-                if msg.path == "/fx/1/par/02" {
-                    got_update = true;
-                    break;
-                }
-            }
-        } else {
-            break;
-        }
+    let deadline = Instant::now() + Duration::from_secs(3);
+    let mut tempo = emulator.get("/fx/1/par/02");
+    while tempo == Some(OscArg::Float(0.0)) && Instant::now() < deadline {
+        thread::sleep(Duration::from_millis(100));
+        tempo = emulator.get("/fx/1/par/02");
     }
 
     child.kill().unwrap();
-    assert!(
-        got_update,
-        "Did not receive update to /fx/1/par/02 upon high meter value"
-    );
+    child.wait().unwrap();
+    match tempo {
+        Some(OscArg::Float(f)) => assert!(
+            f > 0.0 && f < 1.0,
+            "Unexpected delay time {} for /fx/1/par/02",
+            f
+        ),
+        other => panic!("Did not receive update to /fx/1/par/02: {:?}", other),
+    }
+    emulator.assert_param("/fx/1/type", OscArg::Int(10));
 }
@@ -14,7 +14,7 @@
 
 use anyhow::{Result, anyhow};
 use clap::{Parser, Subcommand};
-use osc_lib::OscArg;
+use osc_lib::{address_matches, is_address_pattern, OscArg};
 use std::error::Error;
 use std::str::FromStr;
 use x32_lib::client::MixerClient;
@@ -73,6 +73,13 @@ pub enum Commands {
         /// The index or name of the file to load.
         target: String,
     },
+    #[command(about = "List the directory tree")]
+    /// Lists the current directory and all of its subdirectories.
+    Tree {
+        #[arg(long, default_value_t = 8, help = "Maximum directory depth")]
+        /// How many directory levels to descend into.
+        max_depth: usize,
+    },
     #[command(about = "Unmount the USB drive")]
     /// Unmounts the USB drive from the console.
     Umount,
@@ -140,6 +147,36 @@ impl FromStr for FileType {
     }
 }
 
+/// Returns `true` for the preset files that `load` accepts.
+fn is_loadable(file_type: &FileType) -> bool {
+    matches!(
+        file_type,
+        FileType::Scene
+            | FileType::Snippet
+            | FileType::Effects
+            | FileType::Routing
+            | FileType::Channel
+    )
+}
+
+/// Returns a short description of a file type for listings.
+fn file_type_to_str(file_type: &FileType) -> &'static str {
+    match file_type {
+        FileType::Unknown => "unknown",
+        FileType::Volume => "volume",
+        FileType::Parent => "parent",
+        FileType::Directory => "directory",
+        FileType::Wav => "wav",
+        FileType::Show => "show",
+        FileType::Scene => "scene",
+        FileType::Snippet => "snippet",
+        FileType::Effects => "effects",
+        FileType::Preference => "preferences",
+        FileType::Routing => "routing",
+        FileType::Channel => "channel",
+    }
+}
+
 #[derive(Debug)]
 /// Represents a file or directory on the USB drive.
 struct FileEntry {
@@ -178,11 +215,7 @@ impl X32Client {
     /// A `Result` containing `true` if a drive is mounted, `false` otherwise.
     async fn is_usb_mounted(&self) -> Result<bool> {
         let response = self.client.query_value("/-stat/usbmounted").await?;
-        if let OscArg::Int(val) = response {
-            Ok(val == 1)
-        } else {
-            Ok(false)
-        }
+        Ok(response.as_int() == Some(1))
     }
 
     /// Gets a list of files and directories in the current directory on the USB drive.
@@ -192,11 +225,9 @@ impl X32Client {
     /// A `Result` containing a vector of `FileEntry` structs.
     async fn get_file_list(&self) -> Result<Vec<FileEntry>> {
         let response = self.client.query_value("/-usb/dir/maxpos").await?;
-        let num_files = if let OscArg::Int(val) = response {
-            val
-        } else {
-            return Err(anyhow!("Failed to get number of files from X32."));
-        };
+        let num_files = response
+            .as_int()
+            .ok_or_else(|| anyhow!("Failed to get number of files from X32."))?;
 
         let mut files = Vec::new();
         for i in 1..=num_files {
@@ -226,32 +257,34 @@ impl X32Client {
         Ok(())
     }
 
-    /// Finds a file or directory by its index or name.
+    /// Finds the files and directories matching an index, a name or a glob pattern.
     ///
     /// # Arguments
     ///
-    /// * `target` - The index or name of the file to search for.
+    /// * `target` - The index, name or pattern (e.g. `*.wav`) to search for.
     ///
     /// # Returns
     ///
-    /// A `Result` containing the `FileEntry` if found.
+    /// A `Result` containing the matching entries, or an error if there are none.
+    async fn find_files(&self, target: &str) -> Result<Vec<FileEntry>> {
+        let files = select_entries(self.get_file_list().await?, target);
+        if files.is_empty() {
+            return Err(anyhow!("File not found: {}", target));
+        }
+        Ok(files)
+    }
+
+    /// Finds a single file or directory by its index, name or a glob pattern.
+    ///
+    /// # Arguments
+    ///
+    /// * `target` - The index, name or pattern of the file to search for.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing the `FileEntry` if exactly one entry matches.
     async fn find_file(&self, target: &str) -> Result<FileEntry> {
-        let files = self.get_file_list().await?;
-        files
-            .into_iter()
-            .find(|f| {
-                if let Ok(index) = target.parse::<i32>() {
-                    f.index == index
-                } else {
-                    let name_to_compare = if f.file_type == FileType::Directory {
-                        &f.name[1..f.name.len() - 1]
-                    } else {
-                        &f.name
-                    };
-                    name_to_compare == target
-                }
-            })
-            .ok_or_else(|| anyhow!("File not found: {}", target))
+        single_entry(self.find_files(target).await?, target)
     }
 
     /// Sets the playback state of the tape deck.
@@ -275,6 +308,127 @@ impl X32Client {
     }
 }
 
+/// Returns the name of an entry as typed by the user, without a directory's brackets.
+fn entry_name(file: &FileEntry) -> &str {
+    if file.file_type == FileType::Directory {
+        &file.name[1..file.name.len() - 1]
+    } else {
+        &file.name
+    }
+}
+
+/// Selects the entries of a listing that match `target`.
+///
+/// An exact index or name is tried first. Otherwise, if `target` contains `*`, `?`,
+/// `[...]` or `{...}`, every entry whose name matches it as a glob is returned.
+fn select_entries(files: Vec<FileEntry>, target: &str) -> Vec<FileEntry> {
+    let exact = |f: &FileEntry| match target.parse::<i32>() {
+        Ok(index) => f.index == index,
+        Err(_) => entry_name(f) == target,
+    };
+    if files.iter().any(exact) {
+        return files.into_iter().filter(exact).take(1).collect();
+    }
+    if !is_address_pattern(target) {
+        return Vec::new();
+    }
+    files
+        .into_iter()
+        .filter(|f| f.file_type != FileType::Parent && address_matches(target, entry_name(f)))
+        .collect()
+}
+
+/// Returns the only entry in `files`, or an error naming the candidates.
+fn single_entry(mut files: Vec<FileEntry>, target: &str) -> Result<FileEntry> {
+    match files.len() {
+        0 => Err(anyhow!("File not found: {}", target)),
+        1 => Ok(files.remove(0)),
+        _ => Err(anyhow!(
+            "{} matches more than one file: {}",
+            target,
+            files
+                .iter()
+                .map(|f| f.name.as_str())
+                .collect::<Vec<_>>()
+                .join(", ")
+        )),
+    }
+}
+
+/// Directory navigation on the USB drive, as used by [`walk_tree`].
+trait UsbDirectory {
+    /// Lists the current directory.
+    async fn list(&self) -> Result<Vec<FileEntry>>;
+    /// Selects an entry of the current directory, entering it if it is a directory.
+    async fn enter(&self, index: i32) -> Result<()>;
+}
+
+impl UsbDirectory for X32Client {
+    async fn list(&self) -> Result<Vec<FileEntry>> {
+        self.get_file_list().await
+    }
+
+    async fn enter(&self, index: i32) -> Result<()> {
+        self.select_file(index).await
+    }
+}
+
+/// Walks the directory tree depth-first from the current directory.
+///
+/// Each subdirectory is entered with its index and left again through its `[..]`
+/// entry, so the drive ends up back in the starting directory. Directories deeper
+/// than `max_depth` are listed but not entered, which also stops a malformed `[..]`
+/// entry from looping forever.
+///
+/// # Arguments
+///
+/// * `dir` - The USB drive to walk.
+/// * `max_depth` - How many directory levels to descend into.
+///
+/// # Returns
+///
+/// A `Result` containing one indented line per entry.
+async fn walk_tree(dir: &impl UsbDirectory, max_depth: usize) -> Result<Vec<String>> {
+    let mut lines = Vec::new();
+    // One listing per directory level entered, with the index of its [..] entry.
+    let mut stack = vec![(dir.list().await?.into_iter(), None)];
+
+    while let Some((entries, parent)) = stack.last_mut() {
+        let Some(entry) = entries.next() else {
+            let parent = *parent;
+            stack.pop();
+            if !stack.is_empty() {
+                let parent = parent.ok_or_else(|| anyhow!("Directory has no [..] entry"))?;
+                dir.enter(parent).await?;
+            }
+            continue;
+        };
+        if entry.file_type == FileType::Parent {
+            continue;
+        }
+
+        let depth = stack.len() - 1;
+        lines.push(format!(
+            "{}{} ({})",
+            "  ".repeat(depth),
+            entry.name,
+            file_type_to_str(&entry.file_type)
+        ));
+
+        if entry.file_type == FileType::Directory && depth < max_depth {
+            dir.enter(entry.index).await?;
+            let children = dir.list().await?;
+            let parent = children
+                .iter()
+                .find(|f| f.file_type == FileType::Parent)
+                .map(|f| f.index);
+            stack.push((children.into_iter(), parent));
+        }
+    }
+
+    Ok(lines)
+}
+
 /// The main logic for the utility.
 pub async fn run(args: Args) -> Result<()> {
     let client = X32Client::new(&args.ip).await?;
@@ -301,17 +455,22 @@ pub async fn run(args: Args) -> Result<()> {
             }
         }
         Commands::Load { target } => {
-            let file = client.find_file(target).await?;
-            match file.file_type {
-                FileType::Scene
-                | FileType::Snippet
-                | FileType::Effects
-                | FileType::Routing
-                | FileType::Channel => {
-                    client.select_file(file.index).await?;
-                    println!("Loaded file: {}", file.name);
-                }
-                _ => return Err(anyhow!("Not a loadable file: {}", file.name)),
+            let mut files = client.find_files(target).await?;
+            if files.len() > 1 {
+                // Loading the wrong preset would change the mix, so a pattern must be unambiguous.
+                files.retain(|f| is_loadable(&f.file_type));
+            }
+            let file = single_entry(files, target)?;
+            if is_loadable(&file.file_type) {
+                client.select_file(file.index).await?;
+                println!("Loaded file: {}", file.name);
+            } else {
+                return Err(anyhow!("Not a loadable file: {}", file.name));
+            }
+        }
+        Commands::Tree { max_depth } => {
+            for line in walk_tree(&client, *max_depth).await? {
+                println!("{}", line);
             }
         }
         Commands::Umount => {
@@ -319,7 +478,12 @@ pub async fn run(args: Args) -> Result<()> {
             println!("USB drive unmounted.");
         }
         Commands::Play { target } => {
-            let file = client.find_file(target).await?;
+            // With a pattern, play the first matching WAV file.
+            let files = client.find_files(target).await?;
+            let file = match files.iter().position(|f| f.file_type == FileType::Wav) {
+                Some(i) => files.into_iter().nth(i).unwrap(),
+                None => files.into_iter().next().unwrap(),
+            };
             if file.file_type == FileType::Wav {
                 client.select_file(file.index).await?;
                 println!("Playing file: {}", file.name);
@@ -343,3 +507,159 @@ pub async fn run(args: Args) -> Result<()> {
 
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::cell::RefCell;
+
+    /// An in-memory drive: directories by path, each a list of entry names.
+    struct MockDrive {
+        dirs: Vec<(&'static str, Vec<&'static str>)>,
+        cwd: RefCell<String>,
+    }
+
+    impl MockDrive {
+        fn entries(&self) -> Vec<&'static str> {
+            let cwd = self.cwd.borrow();
+            self.dirs
+                .iter()
+                .find(|(path, _)| *path == cwd.as_str())
+                .map(|(_, entries)| entries.clone())
+                .unwrap_or_default()
+        }
+    }
+
+    impl UsbDirectory for MockDrive {
+        async fn list(&self) -> Result<Vec<FileEntry>> {
+            Ok(listing(&self.entries()))
+        }
+
+        async fn enter(&self, index: i32) -> Result<()> {
+            let name = self.entries()[index as usize - 1];
+            let mut cwd = self.cwd.borrow_mut();
+            if name == "[..]" {
+                let end = cwd.rfind('/').unwrap();
+                cwd.truncate(end);
+            } else if let Some(dir) = name.strip_prefix('[').and_then(|n| n.strip_suffix(']')) {
+                cwd.push('/');
+                cwd.push_str(dir);
+            }
+            Ok(())
+        }
+    }
+
+    fn listing(names: &[&str]) -> Vec<FileEntry> {
+        names
+            .iter()
+            .enumerate()
+            .map(|(i, name)| FileEntry {
+                index: i as i32 + 1,
+                name: name.to_string(),
+                file_type: FileType::from_str(name).unwrap(),
+            })
+            .collect()
+    }
+
+    fn names(files: &[FileEntry]) -> Vec<&str> {
+        files.iter().map(|f| f.name.as_str()).collect()
+    }
+
+    #[test]
+    fn test_select_entries_glob() {
+        let files = || {
+            listing(&[
+                "[..]",
+                "[Scenes]",
+                "opening.scn",
+                "walk_in.wav",
+                "encore.scn",
+                "drums.snp",
+                "scene_notes.txt",
+            ])
+        };
+
+        assert_eq!(
+            names(&select_entries(files(), "*.scn")),
+            vec!["opening.scn", "encore.scn"]
+        );
+        assert_eq!(
+            names(&select_entries(files(), "scene*")),
+            vec!["scene_notes.txt"]
+        );
+        assert_eq!(
+            names(&select_entries(files(), "*.{scn,snp}")),
+            vec!["opening.scn", "encore.scn", "drums.snp"]
+        );
+        // Directories match without their brackets, and [..] never matches.
+        assert_eq!(names(&select_entries(files(), "S*")), vec!["[Scenes]"]);
+        assert!(select_entries(files(), "*.shw").is_empty());
+    }
+
+    #[test]
+    fn test_select_entries_exact_match_first() {
+        let files = || listing(&["a*.wav", "ab.wav", "[Scenes]"]);
+
+        // An exact name wins over the same text as a pattern.
+        assert_eq!(names(&select_entries(files(), "a*.wav")), vec!["a*.wav"]);
+        assert_eq!(names(&select_entries(files(), "2")), vec!["ab.wav"]);
+        assert_eq!(names(&select_entries(files(), "Scenes")), vec!["[Scenes]"]);
+        assert!(select_entries(files(), "missing.wav").is_empty());
+    }
+
+    #[test]
+    fn test_single_entry_ambiguous() {
+        let err = single_entry(listing(&["a.scn", "b.scn"]), "*.scn").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "*.scn matches more than one file: a.scn, b.scn"
+        );
+        assert_eq!(
+            single_entry(listing(&["a.scn"]), "*.scn").unwrap().name,
+            "a.scn"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_walk_tree_depth_first() {
+        let drive = MockDrive {
+            dirs: vec![
+                ("", vec!["[Shows]", "intro.wav", "[Empty]"]),
+                ("/Shows", vec!["[..]", "[2024]", "main.shw"]),
+                ("/Shows/2024", vec!["[..]", "opening.scn", "drums.snp"]),
+                ("/Empty", vec!["[..]"]),
+            ],
+            cwd: RefCell::new(String::new()),
+        };
+
+        let lines = walk_tree(&drive, 8).await.unwrap();
+        assert_eq!(
+            lines,
+            vec![
+                "[Shows] (directory)",
+                "  [2024] (directory)",
+                "    opening.scn (scene)",
+                "    drums.snp (snippet)",
+                "  main.shw (show)",
+                "intro.wav (wav)",
+                "[Empty] (directory)",
+            ]
+        );
+        // Back where it started.
+        assert_eq!(*drive.cwd.borrow(), "");
+    }
+
+    #[tokio::test]
+    async fn test_walk_tree_max_depth() {
+        // A directory whose [..] entry is missing would never lead back up, and one
+        // that contains itself would recurse forever; the depth limit stops both.
+        let drive = MockDrive {
+            dirs: vec![("", vec!["[Loop]"]), ("/Loop", vec!["[..]", "[Loop]"])],
+            cwd: RefCell::new(String::new()),
+        };
+
+        let lines = walk_tree(&drive, 1).await.unwrap();
+        assert_eq!(lines, vec!["[Loop] (directory)", "  [Loop] (directory)"]);
+        assert_eq!(*drive.cwd.borrow(), "");
+    }
+}
@@ -1,31 +1,14 @@
-use std::sync::mpsc::{Sender, channel};
-use std::thread::{self, JoinHandle};
-use std::time::Duration;
-use x32_emulator::Mixer;
-use x32_emulator::server;
-
-fn run_server_with_seeder<F>(port: u16, seeder: F) -> (JoinHandle<()>, Sender<()>)
-where
-    F: FnOnce(&mut Mixer) + Send + 'static,
-{
-    let (tx, rx) = channel();
-    let handle = thread::spawn(move || {
-        server::run(
-            &format!("127.0.0.1:{}", port),
-            Some(Box::new(seeder)),
-            Some(rx),
-        )
-        .unwrap();
-    });
-    thread::sleep(Duration::from_millis(200));
-    (handle, tx)
-}
+use std::net::UdpSocket;
+use x32_emulator::testing::X32Emulator;
 
 #[test]
 fn test_not_connected() {
-    // We don't start a server for this test, to simulate a connection failure.
+    // Nothing answers on this socket, to simulate a connection failure.
+    let silent = UdpSocket::bind("127.0.0.1:0").unwrap();
     let mut cmd = assert_cmd::cargo::cargo_bin_cmd!("x32_usb");
-    cmd.arg("--ip").arg("127.0.0.1:10047").arg("ls");
+    cmd.arg("--ip")
+        .arg(silent.local_addr().unwrap().to_string())
+        .arg("ls");
 
     let output = cmd.output().unwrap();
     assert!(!output.status.success());
@@ -35,7 +18,7 @@ fn test_not_connected() {
 
 #[test]
 fn test_ls_command() {
-    let (handle, tx) = run_server_with_seeder(10048, |mixer| {
+    let emulator = X32Emulator::start_with(|mixer| {
         mixer.seed_from_lines(vec![
             "/-stat/usbmounted,i\t1",
             "/-usb/dir/maxpos,i\t3",
@@ -43,10 +26,11 @@ fn test_ls_command() {
             "/-usb/dir/002/name,s\t[System Volume Information]",
             "/-usb/dir/003/name,s\ttrack01.wav",
         ]);
-    });
+    })
+    .unwrap();
 
     let mut cmd = assert_cmd::cargo::cargo_bin_cmd!("x32_usb");
-    cmd.arg("--ip").arg("127.0.0.1:10048").arg("ls");
+    cmd.arg("--ip").arg(emulator.addr().to_string()).arg("ls");
 
     let output = cmd.output().unwrap();
     assert!(output.status.success());
@@ -57,14 +41,11 @@ fn test_ls_command() {
          FileEntry { index: 2, name: \"[System Volume Information]\", file_type: Volume }\n\
          FileEntry { index: 3, name: \"track01.wav\", file_type: Wav }\n"
     );
-
-    let _ = tx.send(());
-    handle.join().unwrap();
 }
 
 #[test]
 fn test_file_operations() {
-    let (handle, tx) = run_server_with_seeder(10049, |mixer| {
+    let emulator = X32Emulator::start_with(|mixer| {
         mixer.seed_from_lines(vec![
             "/-stat/usbmounted,i\t1",
             "/-usb/dir/maxpos,i\t4",
@@ -73,11 +54,12 @@ fn test_file_operations() {
             "/-usb/dir/003/name,s\tmyscene.scn",
             "/-usb/dir/004/name,s\ttrack02.wav",
         ]);
-    });
+    })
+    .unwrap();
 
     let mut cmd = assert_cmd::cargo::cargo_bin_cmd!("x32_usb");
     cmd.arg("--ip")
-        .arg("127.0.0.1:10049")
+        .arg(emulator.addr().to_string())
         .arg("cd")
         .arg("MyScenes");
 
@@ -98,13 +80,13 @@ fn test_file_operations() {
     assert!(stdout.contains("Not connected to X32."));
 
     let mut cmd = assert_cmd::cargo::cargo_bin_cmd!("x32_usb");
-    cmd.arg("--ip").arg("127.0.0.1:10049").arg("play").arg("4");
+    cmd.arg("--ip")
+        .arg(emulator.addr().to_string())
+        .arg("play")
+        .arg("4");
 
     let output = cmd.output().unwrap();
     assert!(output.status.success());
     let stdout = String::from_utf8(output.stdout).unwrap();
     assert_eq!(stdout, "Playing file: track02.wav\n");
-
-    let _ = tx.send(());
-    handle.join().unwrap();
 }
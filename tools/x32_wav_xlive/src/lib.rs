@@ -13,17 +13,22 @@
 //!
 //! ```sh
 //! x32_wav_xlive <SESSION_DIR> [SESSION_NAME] [-f <MARKER_FILE>] [-m <MARKER>] [-u] [-S]
+//! x32_wav_xlive <SESSION_DIR> --split <XLIVE_SESSION> [-u] [-S]
 //! ```
 //!
-//! The program takes a directory of mono, 24-bit WAV files (named `ch_1.wav`, `ch_2.wav`, etc.)
+//! The program takes a directory of mono WAV files (named `ch_1.wav`, `ch_2.wav`, etc.)
 //! and creates a new session directory containing one or more multi-channel, 32-bit WAV files
-//! and a `SE_LOG.BIN` metadata file.
+//! and a `SE_LOG.BIN` metadata file. Inputs may be 16-, 24- or 32-bit integer PCM, or 32-bit
+//! float.
+//!
+//! With `--split`, it does the reverse: the takes of an existing X-Live! session are split
+//! back into mono, 24-bit `ch_N.wav` files in `SESSION_DIR`.
 
 use anyhow::{Result, anyhow};
-use byteorder::{LittleEndian, WriteBytesExt};
+use byteorder::{LittleEndian, ReadBytesExt, WriteBytesExt};
 use chrono::{Datelike, Timelike, Utc};
 use clap::Parser;
-use hound::{WavReader, WavSpec, WavWriter};
+use hound::{SampleFormat, WavReader, WavSpec, WavWriter};
 use std::fs::{self, File};
 use std::io::{BufWriter, Read, Write};
 use std::path::{Path, PathBuf};
@@ -40,13 +45,13 @@ pub struct Args {
     #[arg(value_name = "SESSION_NAME")]
     pub session_name: Option<String>,
 
-    /// Path to a file containing a list of markers (one per line, in seconds).
+    /// Path to a marker file: one time per line, or a Reaper marker/region `.csv` export.
     #[arg(short = 'f', long, value_name = "MARKER_FILE")]
     pub marker_file: Option<PathBuf>,
 
-    /// A single marker time (in seconds). Can be specified multiple times.
-    #[arg(short = 'm', long, value_name = "MARKER", action = clap::ArgAction::Append)]
-    pub markers: Vec<f32>,
+    /// A single marker time, in seconds or as `MM:SS.mmm`. Can be specified multiple times.
+    #[arg(short = 'm', long, value_name = "MARKER", action = clap::ArgAction::Append, value_parser = parse_marker_arg)]
+    pub markers: Vec<f64>,
 
     /// Use uppercase .WAV file extension instead of .wav.
     #[arg(short = 'u', long)]
@@ -55,6 +60,25 @@ pub struct Args {
     /// Suppress non-error output.
     #[arg(short = 'S', long)]
     pub silent: bool,
+
+    /// Fill gaps in the channel numbering with silent channels instead of failing.
+    #[arg(long)]
+    pub allow_gaps: bool,
+
+    /// Split this X-Live! session directory into mono WAV files in SESSION_DIR instead of merging.
+    #[arg(long, value_name = "XLIVE_SESSION")]
+    pub split: Option<PathBuf>,
+}
+
+/// The fields of `SE_LOG.BIN` needed to read a session's takes.
+#[derive(Debug, Clone, PartialEq)]
+struct SessionLog {
+    num_channels: u32,
+    sample_rate: u32,
+    /// Length of the session in samples per channel.
+    total_length: u32,
+    /// Size of each take, in 32-bit samples across all channels.
+    take_sizes: Vec<u32>,
 }
 
 /// The main logic of the application.
@@ -71,24 +95,51 @@ pub struct Args {
 /// A `Result` indicating success or failure.
 pub fn run(args: Args) -> Result<()> {
     let args = &args;
-    let mut input_files = Vec::new();
+    if let Some(xlive_session) = &args.split {
+        return split_session(xlive_session, args);
+    }
     let mut first_spec = None;
     let mut first_duration = 0;
 
-    for i in 1..=32 {
-        let filename = if args.uppercase {
-            format!("CH_{}.WAV", i)
-        } else {
-            format!("ch_{}.wav", i)
-        };
-        let path = args.session_dir.join(filename);
+    let mut input_files: Vec<Option<PathBuf>> = (1..=32)
+        .map(|i| {
+            let filename = if args.uppercase {
+                format!("CH_{}.WAV", i)
+            } else {
+                format!("ch_{}.wav", i)
+            };
+            let path = args.session_dir.join(filename);
+            path.exists().then_some(path)
+        })
+        .collect();
+
+    // Channels after the last file found are not part of the session.
+    let last_found = input_files.iter().rposition(Option::is_some);
+    input_files.truncate(last_found.map_or(0, |i| i + 1));
 
-        if !path.exists() {
-            // Stop searching for files as soon as one is missing.
-            break;
+    let missing: Vec<String> = input_files
+        .iter()
+        .enumerate()
+        .filter(|(_, path)| path.is_none())
+        .map(|(i, _)| (i + 1).to_string())
+        .collect();
+    if !missing.is_empty() {
+        if !args.allow_gaps {
+            return Err(anyhow!(
+                "Missing WAV file(s) for channel(s) {}. Use --allow-gaps to fill them with silence.",
+                missing.join(", ")
+            ));
         }
+        if !args.silent {
+            println!(
+                "Filling missing channel(s) {} with silence.",
+                missing.join(", ")
+            );
+        }
+    }
 
-        let reader = WavReader::open(&path)?;
+    for path in input_files.iter().flatten() {
+        let reader = WavReader::open(path)?;
         let spec = reader.spec();
         let duration = reader.duration();
 
@@ -98,13 +149,12 @@ pub fn run(args: Args) -> Result<()> {
         }
 
         validate_wav_file(
-            &path,
+            path,
             spec,
             duration,
             first_spec.as_ref().unwrap(),
             first_duration,
         )?;
-        input_files.push(path);
     }
 
     if input_files.is_empty() {
@@ -112,7 +162,10 @@ pub fn run(args: Args) -> Result<()> {
     }
 
     if !args.silent {
-        println!("Found {} WAV files to process.", input_files.len());
+        println!(
+            "Found {} WAV files to process.",
+            input_files.len() - missing.len()
+        );
     }
 
     let session_timestamp = create_session_timestamp()?;
@@ -166,7 +219,8 @@ pub fn run(args: Args) -> Result<()> {
 /// # Arguments
 ///
 /// * `session_path` - The directory to write the output files to.
-/// * `input_files` - A slice of paths to the input mono WAV files.
+/// * `input_files` - A slice of paths to the input mono WAV files; `None` entries are
+///   written as silent channels.
 /// * `spec` - The WAV specification of the input files.
 /// * `take_sizes` - A slice of sizes (in samples) for each output take file.
 /// * `args` - The parsed command-line arguments.
@@ -176,7 +230,7 @@ pub fn run(args: Args) -> Result<()> {
 /// A `Result` indicating success or failure.
 fn write_wav_takes(
     session_path: &Path,
-    input_files: &[PathBuf],
+    input_files: &[Option<PathBuf>],
     spec: &WavSpec,
     take_sizes: &[u32],
     args: &Args,
@@ -184,7 +238,7 @@ fn write_wav_takes(
     let num_channels = input_files.len();
     let mut readers: Vec<_> = input_files
         .iter()
-        .map(WavReader::open)
+        .map(|path| path.as_ref().map(WavReader::open).transpose())
         .collect::<Result<Vec<_>, _>>()?;
 
     for (i, take_size_samples) in take_sizes.iter().enumerate() {
@@ -207,10 +261,10 @@ fn write_wav_takes(
 
         for _ in 0..samples_to_write {
             for reader in &mut readers {
-                let sample = reader
-                    .samples::<i32>()
-                    .next()
-                    .ok_or_else(|| anyhow!("Unexpected end of file in input WAV file"))??;
+                let sample = match reader {
+                    Some(reader) => read_sample(reader)?,
+                    None => 0,
+                };
                 writer.write_sample(sample)?;
             }
         }
@@ -220,6 +274,200 @@ fn write_wav_takes(
     Ok(())
 }
 
+/// Reads the next sample of an input file, converted to the 24-bit scale of the output.
+///
+/// Output samples carry 24-bit values, as read from 24-bit inputs, in 32-bit words. 16-bit
+/// samples are shifted up, 32-bit samples shifted down, and float samples scaled and clamped.
+///
+/// # Arguments
+///
+/// * `reader` - The input file, validated by `validate_wav_file`.
+///
+/// # Returns
+///
+/// A `Result` containing the converted sample.
+fn read_sample<R: Read>(reader: &mut WavReader<R>) -> Result<i32> {
+    let spec = reader.spec();
+    let eof = || anyhow!("Unexpected end of file in input WAV file");
+    if spec.sample_format == SampleFormat::Float {
+        let sample = reader.samples::<f32>().next().ok_or_else(eof)??;
+        return Ok(float_to_24_bit(sample));
+    }
+    let sample = reader.samples::<i32>().next().ok_or_else(eof)??;
+    Ok(int_to_24_bit(sample, spec.bits_per_sample))
+}
+
+/// Rescales an integer sample of the given bit depth to 24 bits.
+fn int_to_24_bit(sample: i32, bits_per_sample: u16) -> i32 {
+    match bits_per_sample {
+        16 => sample << 8,
+        32 => sample >> 8,
+        _ => sample,
+    }
+}
+
+/// Converts a float sample (full scale at +/-1.0) to a 24-bit integer sample.
+fn float_to_24_bit(sample: f32) -> i32 {
+    const FULL_SCALE: f32 = 8_388_607.0;
+    (sample.clamp(-1.0, 1.0) * FULL_SCALE).round() as i32
+}
+
+/// Splits the takes of an X-Live! session into one mono, 24-bit WAV file per channel.
+///
+/// # Arguments
+///
+/// * `xlive_session` - The session directory holding `SE_LOG.BIN` and the take files.
+/// * `args` - The parsed command-line arguments; the files are written to `args.session_dir`.
+///
+/// # Returns
+///
+/// A `Result` indicating success or failure.
+fn split_session(xlive_session: &Path, args: &Args) -> Result<()> {
+    let log = read_se_log_bin(&xlive_session.join("SE_LOG.BIN"))?;
+    if log.num_channels == 0 || log.num_channels > 32 {
+        return Err(anyhow!(
+            "SE_LOG.BIN has an invalid channel count: {}",
+            log.num_channels
+        ));
+    }
+
+    let paths = (1..=log.num_channels)
+        .map(|i| {
+            let filename = if args.uppercase {
+                format!("CH_{}.WAV", i)
+            } else {
+                format!("ch_{}.wav", i)
+            };
+            let path = args.session_dir.join(filename);
+            if path.exists() {
+                return Err(anyhow!("{} already exists.", path.display()));
+            }
+            Ok(path)
+        })
+        .collect::<Result<Vec<_>>>()?;
+
+    // Write to temporary names so that a failed split leaves no partial ch_N.wav files.
+    let partial: Vec<PathBuf> = paths
+        .iter()
+        .map(|path| path.with_extension("part"))
+        .collect();
+    if let Err(e) = write_split_channels(xlive_session, &log, &partial) {
+        for path in &partial {
+            let _ = fs::remove_file(path);
+        }
+        return Err(e);
+    }
+    for (from, to) in partial.iter().zip(&paths) {
+        fs::rename(from, to)?;
+    }
+
+    if !args.silent {
+        println!(
+            "Split {} channels of {} samples into {}.",
+            log.num_channels,
+            log.total_length,
+            args.session_dir.display()
+        );
+    }
+    Ok(())
+}
+
+/// Demultiplexes the takes of a session into one mono, 24-bit WAV file per channel.
+///
+/// # Arguments
+///
+/// * `xlive_session` - The session directory holding the take files.
+/// * `log` - The session layout read from `SE_LOG.BIN`.
+/// * `paths` - The file to write for each channel.
+///
+/// # Returns
+///
+/// A `Result` indicating success or failure.
+fn write_split_channels(xlive_session: &Path, log: &SessionLog, paths: &[PathBuf]) -> Result<()> {
+    let out_spec = WavSpec {
+        channels: 1,
+        sample_rate: log.sample_rate,
+        bits_per_sample: 24,
+        sample_format: SampleFormat::Int,
+    };
+    let mut writers = paths
+        .iter()
+        .map(|path| Ok(WavWriter::create(path, out_spec)?))
+        .collect::<Result<Vec<_>>>()?;
+
+    let num_channels = log.num_channels as usize;
+    for (i, &take_size) in log.take_sizes.iter().enumerate() {
+        let path = take_path(xlive_session, i + 1)?;
+        let mut reader = WavReader::open(&path)?;
+        let spec = reader.spec();
+        if spec.channels as u32 != log.num_channels || spec.bits_per_sample != 32 {
+            return Err(anyhow!(
+                "Take {} does not match SE_LOG.BIN ({} channels, {}-bit).",
+                path.display(),
+                spec.channels,
+                spec.bits_per_sample
+            ));
+        }
+
+        // Merged takes carry 24-bit values in their 32-bit words, so samples are copied as is.
+        let mut samples = reader.samples::<i32>();
+        for _ in 0..take_size as usize / num_channels {
+            for writer in &mut writers {
+                let sample = samples
+                    .next()
+                    .ok_or_else(|| anyhow!("Take {} ends early.", path.display()))??;
+                writer.write_sample(sample)?;
+            }
+        }
+    }
+
+    for writer in writers {
+        writer.finalize()?;
+    }
+    Ok(())
+}
+
+/// Returns the path of a take file, whichever case its extension uses.
+fn take_path(xlive_session: &Path, take: usize) -> Result<PathBuf> {
+    ["wav", "WAV"]
+        .iter()
+        .map(|ext| xlive_session.join(format!("{:08X}.{}", take, ext)))
+        .find(|path| path.exists())
+        .ok_or_else(|| anyhow!("Take {:08X} not found in {}", take, xlive_session.display()))
+}
+
+/// Reads the session layout from an `SE_LOG.BIN` file.
+///
+/// # Arguments
+///
+/// * `path` - The path to `SE_LOG.BIN`.
+///
+/// # Returns
+///
+/// A `Result` containing the channel count, sample rate, length and take sizes.
+fn read_se_log_bin(path: &Path) -> Result<SessionLog> {
+    let mut file = std::io::BufReader::new(File::open(path)?);
+    let _session_timestamp = file.read_u32::<LittleEndian>()?;
+    let num_channels = file.read_u32::<LittleEndian>()?;
+    let sample_rate = file.read_u32::<LittleEndian>()?;
+    let _session_timestamp = file.read_u32::<LittleEndian>()?;
+    let nb_takes = file.read_u32::<LittleEndian>()?;
+    let _num_markers = file.read_u32::<LittleEndian>()?;
+    let total_length = file.read_u32::<LittleEndian>()?;
+    if nb_takes > 256 {
+        return Err(anyhow!("SE_LOG.BIN lists too many takes: {}", nb_takes));
+    }
+    let take_sizes = (0..nb_takes)
+        .map(|_| file.read_u32::<LittleEndian>())
+        .collect::<std::io::Result<Vec<_>>>()?;
+    Ok(SessionLog {
+        num_channels,
+        sample_rate,
+        total_length,
+        take_sizes,
+    })
+}
+
 /// Creates a timestamp for the session directory name, matching the format used by the X32.
 ///
 /// # Returns
@@ -281,11 +529,10 @@ fn write_se_log_bin(
         if s.len() > 1024 * 1024 {
             return Err(anyhow::anyhow!("Marker file too large to load (max 1MB)"));
         }
-        for line in s.lines() {
-            if let Ok(marker) = line.trim().parse::<f32>() {
-                markers.push(marker);
-            }
-        }
+        let is_csv = marker_file
+            .extension()
+            .is_some_and(|ext| ext.eq_ignore_ascii_case("csv"));
+        markers.extend(parse_marker_file(&s, is_csv)?);
     }
     markers.sort_by(|a, b| a.partial_cmp(b).unwrap_or(std::cmp::Ordering::Equal));
 
@@ -310,8 +557,8 @@ fn write_se_log_bin(
     let zero_buf = vec![0u8; 4 * (256 - take_sizes.len())];
     file.write_all(&zero_buf)?;
 
-    for marker in &markers {
-        file.write_u32::<LittleEndian>((*marker * sample_rate as f32) as u32)?;
+    for &marker in &markers {
+        file.write_u32::<LittleEndian>(marker_offset(marker, sample_rate))?;
     }
     if markers.len() > 125 {
         return Err(anyhow::anyhow!("Too many markers (max 125)"));
@@ -335,9 +582,124 @@ fn write_se_log_bin(
     Ok(())
 }
 
+/// Converts a marker time in seconds to a sample offset.
+fn marker_offset(seconds: f64, sample_rate: u32) -> u32 {
+    (seconds * sample_rate as f64).round() as u32
+}
+
+/// Parses a marker time given in seconds (`90.5`) or as `MM:SS.mmm` or `HH:MM:SS.mmm`.
+///
+/// # Arguments
+///
+/// * `s` - The time to parse.
+///
+/// # Returns
+///
+/// A `Result` containing the time in seconds.
+fn parse_marker_time(s: &str) -> Result<f64> {
+    let invalid = || anyhow!("Invalid marker time: {}", s);
+    let parts: Vec<&str> = s.trim().split(':').collect();
+    if parts.len() > 3 {
+        return Err(invalid());
+    }
+    let (fields, seconds) = parts.split_at(parts.len() - 1);
+    let seconds: f64 = seconds[0].parse().map_err(|_| invalid())?;
+    if !seconds.is_finite() || seconds < 0.0 || (!fields.is_empty() && seconds >= 60.0) {
+        return Err(invalid());
+    }
+    let mut total = 0.0;
+    for (i, field) in fields.iter().enumerate() {
+        let value: u32 = field.parse().map_err(|_| invalid())?;
+        // Minutes below an hours field wrap at 60, like seconds.
+        if i > 0 && value >= 60 {
+            return Err(invalid());
+        }
+        total = total * 60.0 + value as f64;
+    }
+    Ok(total * 60.0 + seconds)
+}
+
+/// Parses a `-m` marker for clap.
+fn parse_marker_arg(s: &str) -> std::result::Result<f64, String> {
+    parse_marker_time(s).map_err(|e| e.to_string())
+}
+
+/// Reads marker times, in seconds, from the contents of a marker file.
+///
+/// Reaper marker/region exports (a `.csv` file, or a `#,Name,Start,...` header) use their
+/// `Start` column. Any other file holds one time per line; lines that are not a time are
+/// skipped.
+///
+/// # Arguments
+///
+/// * `text` - The contents of the marker file.
+/// * `is_csv` - Whether the file has a `.csv` extension.
+///
+/// # Returns
+///
+/// A `Result` containing the marker times, or an error naming a CSV row with a bad time.
+fn parse_marker_file(text: &str, is_csv: bool) -> Result<Vec<f64>> {
+    let mut lines = text
+        .lines()
+        .filter(|line| !line.trim().is_empty())
+        .peekable();
+    let header = lines
+        .peek()
+        .filter(|line| line.starts_with('#') && line.contains(','))
+        .map(|line| csv_fields(line));
+    if !is_csv && header.is_none() {
+        return Ok(lines
+            .filter_map(|line| parse_marker_time(line).ok())
+            .collect());
+    }
+
+    let start = header
+        .as_ref()
+        .and_then(|fields| fields.iter().position(|f| f.eq_ignore_ascii_case("start")))
+        .unwrap_or(2);
+    if header.is_some() {
+        lines.next();
+    }
+    lines
+        .map(|line| {
+            let fields = csv_fields(line);
+            let time = fields.get(start).map(String::as_str).unwrap_or("");
+            parse_marker_time(time).map_err(|_| {
+                anyhow!(
+                    "Marker '{}' has an unsupported time '{}'. Export markers with a time-based ruler.",
+                    line,
+                    time
+                )
+            })
+        })
+        .collect()
+}
+
+/// Splits a CSV line into fields, honoring double-quoted fields with embedded commas.
+fn csv_fields(line: &str) -> Vec<String> {
+    let mut fields = Vec::new();
+    let mut field = String::new();
+    let mut quoted = false;
+    let mut chars = line.trim().chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '"' if quoted && chars.peek() == Some(&'"') => {
+                field.push('"');
+                chars.next();
+            }
+            '"' => quoted = !quoted,
+            ',' if !quoted => fields.push(std::mem::take(&mut field)),
+            _ => field.push(c),
+        }
+    }
+    fields.push(field);
+    fields
+}
+
 /// Validates that a WAV file meets the requirements for processing.
 ///
-/// The file must be a mono, 24-bit PCM WAV file with a sample rate of 44100 or 48000 Hz.
+/// The file must be a mono WAV file holding 16-, 24- or 32-bit integer PCM or 32-bit float
+/// samples, with a sample rate of 44100 or 48000 Hz.
 /// It must also have the same sample rate and duration as the first file in the sequence.
 ///
 /// # Arguments
@@ -361,11 +723,20 @@ fn validate_wav_file(
     if spec.channels != 1 {
         return Err(anyhow!("File {} is not a mono WAV file.", path.display()));
     }
-    if spec.bits_per_sample != 24 {
-        return Err(anyhow!("File {} is not a 24-bit WAV file.", path.display()));
-    }
-    if spec.sample_format != hound::SampleFormat::Int {
-        return Err(anyhow!("File {} is not a PCM WAV file.", path.display()));
+    match (spec.sample_format, spec.bits_per_sample) {
+        (SampleFormat::Int, 16 | 24 | 32) | (SampleFormat::Float, 32) => {}
+        (SampleFormat::Int, _) => {
+            return Err(anyhow!(
+                "File {} is not a 16-, 24- or 32-bit WAV file.",
+                path.display()
+            ));
+        }
+        (SampleFormat::Float, _) => {
+            return Err(anyhow!(
+                "File {} is not a 32-bit float WAV file.",
+                path.display()
+            ));
+        }
     }
     if spec.sample_rate != 44100 && spec.sample_rate != 48000 {
         return Err(anyhow!(
@@ -396,12 +767,22 @@ mod tests {
     use tempfile::tempdir;
 
     fn create_test_wav(dir: &Path, name: &str, spec: WavSpec, duration_ms: u32) {
+        create_test_wav_with_sample(dir, name, spec, duration_ms, 0);
+    }
+
+    fn create_test_wav_with_sample(
+        dir: &Path,
+        name: &str,
+        spec: WavSpec,
+        duration_ms: u32,
+        sample: i32,
+    ) {
         let path = dir.join(name);
         let mut writer = WavWriter::create(&path, spec).unwrap();
         let num_samples = (spec.sample_rate as u32 * duration_ms) / 1000;
         for _ in 0..num_samples {
             // Write a 24-bit sample
-            writer.write_sample(0i32).unwrap();
+            writer.write_sample(sample).unwrap();
         }
         writer.finalize().unwrap();
     }
@@ -424,6 +805,8 @@ mod tests {
             markers: vec![],
             uppercase: false,
             silent: true,
+            allow_gaps: false,
+            split: None,
         };
         assert!(run(args).is_ok());
     }
@@ -434,7 +817,7 @@ mod tests {
         let spec = WavSpec {
             channels: 1,
             sample_rate: 48000,
-            bits_per_sample: 16,
+            bits_per_sample: 8,
             sample_format: hound::SampleFormat::Int,
         };
         create_test_wav(dir.path(), "ch_1.wav", spec, 100);
@@ -446,6 +829,8 @@ mod tests {
             markers: vec![],
             uppercase: false,
             silent: true,
+            allow_gaps: false,
+            split: None,
         };
         let result = run(args);
         assert!(result.is_err());
@@ -453,7 +838,7 @@ mod tests {
             result
                 .unwrap_err()
                 .to_string()
-                .contains("is not a 24-bit WAV file")
+                .contains("is not a 16-, 24- or 32-bit WAV file")
         );
     }
 
@@ -475,16 +860,13 @@ mod tests {
             markers: vec![0.5],
             uppercase: false,
             silent: true,
+            allow_gaps: false,
+            split: None,
         };
         run(args).unwrap();
 
         // Find the created session directory
-        let session_dir = fs::read_dir(dir.path())
-            .unwrap()
-            .find(|entry| entry.as_ref().unwrap().path().is_dir())
-            .expect("No session directory found in temp dir")
-            .unwrap()
-            .path();
+        let session_dir = find_session_dir(dir.path());
 
         let log_path = session_dir.join("SE_LOG.BIN");
         assert!(log_path.exists(), "SE_LOG.BIN was not created");
@@ -522,15 +904,12 @@ mod tests {
             markers: vec![],
             uppercase: false,
             silent: true,
+            allow_gaps: false,
+            split: None,
         };
         run(args).unwrap();
 
-        let session_dir = fs::read_dir(dir.path())
-            .unwrap()
-            .find(|entry| entry.as_ref().unwrap().path().is_dir())
-            .expect("No session directory found")
-            .unwrap()
-            .path();
+        let session_dir = find_session_dir(dir.path());
 
         let wav_files: Vec<_> = fs::read_dir(&session_dir)
             .unwrap()
@@ -552,4 +931,329 @@ mod tests {
         assert_eq!(output_spec.sample_rate, 48000);
         assert_eq!(output_spec.bits_per_sample, 32);
     }
+
+    #[test]
+    fn test_channel_gap() {
+        let dir = tempdir().unwrap();
+        let spec = WavSpec {
+            channels: 1,
+            sample_rate: 48000,
+            bits_per_sample: 24,
+            sample_format: hound::SampleFormat::Int,
+        };
+        create_test_wav(dir.path(), "ch_1.wav", spec, 100);
+        create_test_wav(dir.path(), "ch_3.wav", spec, 100);
+
+        let args = Args {
+            session_dir: dir.path().to_path_buf(),
+            session_name: None,
+            marker_file: None,
+            markers: vec![],
+            uppercase: false,
+            silent: true,
+            allow_gaps: false,
+            split: None,
+        };
+        let result = run(args);
+        assert!(result.is_err());
+        assert!(
+            result
+                .unwrap_err()
+                .to_string()
+                .contains("Missing WAV file(s) for channel(s) 2")
+        );
+    }
+
+    #[test]
+    fn test_channel_gap_filled_with_silence() {
+        let dir = tempdir().unwrap();
+        let spec = WavSpec {
+            channels: 1,
+            sample_rate: 48000,
+            bits_per_sample: 24,
+            sample_format: hound::SampleFormat::Int,
+        };
+        create_test_wav_with_sample(dir.path(), "ch_1.wav", spec, 100, 111);
+        create_test_wav_with_sample(dir.path(), "ch_3.wav", spec, 100, 333);
+
+        let args = Args {
+            session_dir: dir.path().to_path_buf(),
+            session_name: None,
+            marker_file: None,
+            markers: vec![],
+            uppercase: false,
+            silent: true,
+            allow_gaps: true,
+            split: None,
+        };
+        run(args).unwrap();
+
+        let session_dir = find_session_dir(dir.path());
+
+        let mut reader = WavReader::open(session_dir.join("00000001.wav")).unwrap();
+        assert_eq!(reader.spec().channels, 3);
+        assert_eq!(reader.duration(), 4800);
+        let samples: Vec<i32> = reader.samples::<i32>().map(|s| s.unwrap()).collect();
+        assert_eq!(samples.len(), 4800 * 3);
+        for frame in samples.chunks(3) {
+            assert_eq!(frame, [111, 0, 333]);
+        }
+    }
+
+    /// Returns the session directory that a merge created in `dir`.
+    fn find_session_dir(dir: &Path) -> PathBuf {
+        fs::read_dir(dir)
+            .unwrap()
+            .find(|entry| entry.as_ref().unwrap().path().is_dir())
+            .expect("No session directory found")
+            .unwrap()
+            .path()
+    }
+
+    /// Converts a session whose inputs are written by `write` and returns the output samples.
+    fn convert_and_read(write: impl Fn(&Path)) -> Vec<i32> {
+        let dir = tempdir().unwrap();
+        write(dir.path());
+        let args = Args {
+            session_dir: dir.path().to_path_buf(),
+            session_name: None,
+            marker_file: None,
+            markers: vec![],
+            uppercase: false,
+            silent: true,
+            allow_gaps: false,
+            split: None,
+        };
+        run(args).unwrap();
+
+        let session_dir = find_session_dir(dir.path());
+        let mut reader = WavReader::open(session_dir.join("00000001.wav")).unwrap();
+        assert_eq!(reader.spec().bits_per_sample, 32);
+        reader.samples::<i32>().map(|s| s.unwrap()).collect()
+    }
+
+    #[test]
+    fn test_16_bit_input_is_up_converted() {
+        let samples = convert_and_read(|dir| {
+            let spec = WavSpec {
+                channels: 1,
+                sample_rate: 48000,
+                bits_per_sample: 16,
+                sample_format: hound::SampleFormat::Int,
+            };
+            let mut writer = WavWriter::create(dir.join("ch_1.wav"), spec).unwrap();
+            for sample in [0i16, 1, -1, 0x1234, i16::MAX, i16::MIN] {
+                writer.write_sample(sample).unwrap();
+            }
+            writer.finalize().unwrap();
+        });
+        assert_eq!(
+            samples,
+            vec![0, 0x100, -0x100, 0x123400, 0x7fff00, -0x800000]
+        );
+    }
+
+    #[test]
+    fn test_mixed_bit_depths_share_one_scale() {
+        let samples = convert_and_read(|dir| {
+            let int_spec = |bits_per_sample| WavSpec {
+                channels: 1,
+                sample_rate: 48000,
+                bits_per_sample,
+                sample_format: hound::SampleFormat::Int,
+            };
+            let mut writer = WavWriter::create(dir.join("ch_1.wav"), int_spec(16)).unwrap();
+            writer.write_sample(0x4000i16).unwrap();
+            writer.finalize().unwrap();
+
+            let mut writer = WavWriter::create(dir.join("ch_2.wav"), int_spec(24)).unwrap();
+            writer.write_sample(0x400000i32).unwrap();
+            writer.finalize().unwrap();
+
+            let mut writer = WavWriter::create(dir.join("ch_3.wav"), int_spec(32)).unwrap();
+            writer.write_sample(0x40000000i32).unwrap();
+            writer.finalize().unwrap();
+
+            let float_spec = WavSpec {
+                sample_format: hound::SampleFormat::Float,
+                ..int_spec(32)
+            };
+            let mut writer = WavWriter::create(dir.join("ch_4.wav"), float_spec).unwrap();
+            writer.write_sample(0.5f32).unwrap();
+            writer.finalize().unwrap();
+        });
+        // Half scale in every format comes out as the same 24-bit value.
+        assert_eq!(samples, vec![0x400000, 0x400000, 0x400000, 0x400000]);
+    }
+
+    #[test]
+    fn test_float_to_24_bit_clamps() {
+        assert_eq!(float_to_24_bit(0.0), 0);
+        assert_eq!(float_to_24_bit(1.0), 8_388_607);
+        assert_eq!(float_to_24_bit(-1.0), -8_388_607);
+        assert_eq!(float_to_24_bit(2.5), 8_388_607);
+        assert_eq!(float_to_24_bit(-3.0), -8_388_607);
+    }
+
+    #[test]
+    fn test_merge_then_split_recovers_channels() {
+        let dir = tempdir().unwrap();
+        let spec = WavSpec {
+            channels: 1,
+            sample_rate: 44100,
+            bits_per_sample: 24,
+            sample_format: hound::SampleFormat::Int,
+        };
+        let channel = |ch: i32| -> Vec<i32> {
+            (0..441)
+                .map(|i| (i * 1000 * ch - 200_000).clamp(-8_388_608, 8_388_607))
+                .collect()
+        };
+        for ch in 1..=3 {
+            let mut writer =
+                WavWriter::create(dir.path().join(format!("ch_{}.wav", ch)), spec).unwrap();
+            for sample in channel(ch) {
+                writer.write_sample(sample).unwrap();
+            }
+            writer.finalize().unwrap();
+        }
+        run(Args {
+            session_dir: dir.path().to_path_buf(),
+            session_name: None,
+            marker_file: None,
+            markers: vec![],
+            uppercase: false,
+            silent: true,
+            allow_gaps: false,
+            split: None,
+        })
+        .unwrap();
+        let session_dir = find_session_dir(dir.path());
+
+        let out = tempdir().unwrap();
+        run(Args {
+            session_dir: out.path().to_path_buf(),
+            session_name: None,
+            marker_file: None,
+            markers: vec![],
+            uppercase: false,
+            silent: true,
+            allow_gaps: false,
+            split: Some(session_dir),
+        })
+        .unwrap();
+
+        for ch in 1..=3 {
+            let mut reader = WavReader::open(out.path().join(format!("ch_{}.wav", ch))).unwrap();
+            assert_eq!(reader.spec(), spec);
+            let samples: Vec<i32> = reader.samples::<i32>().map(|s| s.unwrap()).collect();
+            assert_eq!(samples, channel(ch), "channel {}", ch);
+        }
+        assert!(!out.path().join("ch_4.wav").exists());
+    }
+
+    #[test]
+    fn test_split_refuses_to_overwrite() {
+        let dir = tempdir().unwrap();
+        let spec = WavSpec {
+            channels: 1,
+            sample_rate: 48000,
+            bits_per_sample: 24,
+            sample_format: hound::SampleFormat::Int,
+        };
+        create_test_wav(dir.path(), "ch_1.wav", spec, 10);
+        let args = |split| Args {
+            session_dir: dir.path().to_path_buf(),
+            session_name: None,
+            marker_file: None,
+            markers: vec![],
+            uppercase: false,
+            silent: true,
+            allow_gaps: false,
+            split,
+        };
+        run(args(None)).unwrap();
+        let session_dir = find_session_dir(dir.path());
+
+        let err = run(args(Some(session_dir))).unwrap_err();
+        assert!(err.to_string().contains("already exists"), "{}", err);
+    }
+
+    #[test]
+    fn test_failed_split_leaves_no_files() {
+        let dir = tempdir().unwrap();
+        let spec = WavSpec {
+            channels: 1,
+            sample_rate: 48000,
+            bits_per_sample: 24,
+            sample_format: hound::SampleFormat::Int,
+        };
+        create_test_wav(dir.path(), "ch_1.wav", spec, 100);
+        create_test_wav(dir.path(), "ch_2.wav", spec, 100);
+        let args = |session_dir: &Path, split| Args {
+            session_dir: session_dir.to_path_buf(),
+            session_name: None,
+            marker_file: None,
+            markers: vec![],
+            uppercase: false,
+            silent: true,
+            allow_gaps: false,
+            split,
+        };
+        run(args(dir.path(), None)).unwrap();
+        let session_dir = find_session_dir(dir.path());
+
+        // Cut the take short, so the split fails after writing part of every channel.
+        let take_spec = WavSpec {
+            channels: 2,
+            bits_per_sample: 32,
+            ..spec
+        };
+        let mut writer = WavWriter::create(session_dir.join("00000001.wav"), take_spec).unwrap();
+        for _ in 0..20 {
+            writer.write_sample(1i32).unwrap();
+        }
+        writer.finalize().unwrap();
+
+        let out = tempdir().unwrap();
+        let err = run(args(out.path(), Some(session_dir))).unwrap_err();
+        assert!(err.to_string().contains("ends early"), "{}", err);
+        assert_eq!(fs::read_dir(out.path()).unwrap().count(), 0);
+    }
+
+    #[test]
+    fn test_marker_timecode_to_sample_offset() {
+        let seconds = parse_marker_time("00:01:30.500").unwrap();
+        assert_eq!(seconds, 90.5);
+        assert_eq!(marker_offset(seconds, 48000), 4_344_000);
+
+        assert_eq!(parse_marker_time("1:30.5").unwrap(), 90.5);
+        assert_eq!(parse_marker_time("75").unwrap(), 75.0);
+        assert_eq!(parse_marker_time("1:00:00").unwrap(), 3600.0);
+        assert!(parse_marker_time("1:75").is_err());
+        assert!(parse_marker_time("1:61:00").is_err());
+        assert!(parse_marker_time("-3").is_err());
+        assert!(parse_marker_time("1.1.00").is_err());
+    }
+
+    #[test]
+    fn test_parse_marker_file_formats() {
+        let plain = "30\n1:15\nnot a marker\n\n00:02:00.250\n";
+        assert_eq!(
+            parse_marker_file(plain, false).unwrap(),
+            vec![30.0, 75.0, 120.25]
+        );
+
+        let reaper = "#,Name,Start,End,Length\n\
+                      M1,Intro,0:00.000,,\n\
+                      R1,\"Verse, take 2\",1:23.456,2:00.000,0:36.544\n\
+                      M2,Outro,1:02:03.000,,\n";
+        assert_eq!(
+            parse_marker_file(reaper, false).unwrap(),
+            vec![0.0, 83.456, 3723.0]
+        );
+
+        let bars = "#,Name,Start,End,Length\nM1,Intro,1.1.00,,\n";
+        assert!(parse_marker_file(bars, true).is_err());
+    }
 }